@@ -18,8 +18,9 @@ use k256::PublicKey;
 use num_bigint::BigUint;
 use ripemd::Ripemd160;
 use serde::Deserialize;
+use sha1::Sha1;
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::path::Path;
@@ -48,6 +49,40 @@ fn sha256(data: &[u8]) -> [u8; 32] {
     hasher.finalize().into()
 }
 
+/// UUIDv5 in boha's namespace (itself UUIDv5 of the repository URL in the
+/// RFC 4122 URL namespace) of `collection:address`. Hex addresses are
+/// lowercased so fixing an EIP-55 checksum never changes the identifier.
+/// `collection` is the name the puzzle was first published under; keep
+/// passing it if the collection or id is ever renamed.
+fn puzzle_uuid(collection: &str, address: &str) -> String {
+    const NAMESPACE: [u8; 16] = [
+        0x68, 0x31, 0xce, 0x0e, 0x0c, 0x5a, 0x5d, 0x45, 0x84, 0x69, 0xbf, 0x7b, 0xc3, 0xaf, 0x87,
+        0xa6,
+    ];
+    let address = if address.starts_with("0x") {
+        address.to_lowercase()
+    } else {
+        address.to_string()
+    };
+    let mut hasher = Sha1::new();
+    hasher.update(NAMESPACE);
+    hasher.update(format!("{}:{}", collection, address));
+    let digest: [u8; 20] = hasher.finalize().into();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x50;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
 fn hash160(data: &[u8]) -> [u8; 20] {
     let sha = sha256(data);
     let mut hasher = Ripemd160::new();
@@ -56,6 +91,14 @@ fn hash160(data: &[u8]) -> [u8; 20] {
 }
 
 fn private_key_to_address(hex_key: &str, compressed: bool) -> Option<String> {
+    private_key_to_versioned_address(hex_key, compressed, 0x00)
+}
+
+fn private_key_to_versioned_address(
+    hex_key: &str,
+    compressed: bool,
+    version: u8,
+) -> Option<String> {
     let key_bytes = hex::decode(hex_key).ok()?;
     if key_bytes.len() != 32 {
         return None;
@@ -72,7 +115,7 @@ fn private_key_to_address(hex_key: &str, compressed: bool) -> Option<String> {
 
     let hash = hash160(&pubkey_bytes);
 
-    let mut data = vec![0x00];
+    let mut data = vec![version];
     data.extend_from_slice(&hash);
     let checksum = &sha256(&sha256(&data))[..4];
     data.extend_from_slice(checksum);
@@ -285,6 +328,24 @@ fn validate_hex_derives_address(hex_key: &str, expected_address: &str, puzzle_id
     }
 }
 
+fn validate_sha256_brainwallet_derives_address(
+    passphrase: &str,
+    expected_address: &str,
+    puzzle_id: &str,
+) {
+    let hex_key = hex::encode(sha256(passphrase.as_bytes()));
+    let matches = [false, true].into_iter().any(|compressed| {
+        private_key_to_address(&hex_key, compressed).as_deref() == Some(expected_address)
+    });
+
+    if !matches {
+        panic!(
+            "Puzzle '{}' has sha256 brainwallet passphrase that does not derive {}",
+            puzzle_id, expected_address
+        );
+    }
+}
+
 /// Validates that solved/claimed/swept puzzles with claim/sweep transactions have a pubkey.
 /// Panics if a puzzle has a claim or sweep transaction but no pubkey (except P2SH addresses).
 /// This enforces the invariant that public keys are always revealed in claim transactions.
@@ -330,6 +391,8 @@ struct TomlProfile {
 struct AuthorConfig {
     name: Option<String>,
     #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
     addresses: Vec<String>,
     #[serde(default)]
     profiles: Vec<TomlProfile>,
@@ -344,6 +407,18 @@ struct SolverDefinition {
     profiles: Vec<TomlProfile>,
 }
 
+/// A puzzle's `solver`: an id from solvers.jsonc, or that id with details
+/// of this particular solve.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TomlSolverRef {
+    Id(String),
+    Solve {
+        id: String,
+        announcement_url: Option<String>,
+    },
+}
+
 #[derive(Debug, Deserialize)]
 struct TomlTransaction {
     #[serde(rename = "type")]
@@ -402,6 +477,14 @@ struct TomlAssets {
     source_url: Option<String>,
 }
 
+/// Retirement markers shared by every collection's puzzle entries.
+#[derive(Debug, Deserialize)]
+struct TomlLifecycle {
+    superseded_by: Option<String>,
+    #[serde(default)]
+    withdrawn: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct TomlPubkey {
     value: String,
@@ -422,6 +505,7 @@ struct TomlWif {
     decrypted: Option<String>,
     passphrase: Option<String>,
     salt: Option<String>,
+    brainwallet: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -458,8 +542,11 @@ struct Btc1000File {
 #[derive(Debug, Deserialize)]
 struct Btc1000Puzzle {
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     status: String,
     #[allow(dead_code)]
     has_pubkey: Option<bool>,
@@ -471,9 +558,12 @@ struct Btc1000Puzzle {
     #[serde(default)]
     pre_genesis: bool,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -492,16 +582,22 @@ struct HashCollisionFile {
 struct HashCollisionPuzzle {
     name: String,
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -520,18 +616,56 @@ struct WarpFile {
 struct WarpPuzzle {
     name: String,
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     pubkey: Option<TomlPubkey>,
     key: Option<TomlKey>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExampleMetadata {
+    source_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExampleFile {
+    author: Option<AuthorConfig>,
+    metadata: Option<ExampleMetadata>,
+    puzzles: Vec<ExamplePuzzle>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExamplePuzzle {
+    name: String,
+    address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
+    status: String,
+    prize: Option<f64>,
+    currency: Option<String>,
+    pubkey: Option<TomlPubkey>,
+    key: TomlKey,
+    start_date: Option<String>,
+    solve_date: Option<String>,
+    solve_time: Option<u64>,
+    source_url: Option<String>,
+    description: Option<String>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -549,18 +683,24 @@ struct GsmgFile {
 #[derive(Debug, Deserialize)]
 struct GsmgPuzzle {
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     pubkey: Option<TomlPubkey>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
     assets: Option<TomlAssets>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -580,19 +720,25 @@ struct ZdenPuzzle {
     name: String,
     chain: String,
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     pubkey: Option<TomlPubkey>,
     key: Option<TomlKey>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
     assets: Option<TomlAssets>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -612,19 +758,25 @@ struct ArweavePuzzle {
     name: String,
     chain: String,
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     pubkey: Option<TomlPubkey>,
     key: Option<TomlKey>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
     assets: Option<TomlAssets>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -653,19 +805,25 @@ struct BitimageFile {
 struct BitimagePuzzle {
     name: String,
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     pubkey: Option<TomlPubkey>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     key: Option<TomlKey>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
     assets: Option<TomlAssets>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -679,19 +837,25 @@ struct BalletFile {
 struct BalletPuzzle {
     name: String,
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     pubkey: Option<TomlPubkey>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     key: Option<TomlKey>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
     assets: Option<TomlAssets>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 #[derive(Debug, Deserialize)]
@@ -704,23 +868,32 @@ struct BitapsFile {
 #[derive(Debug, Deserialize)]
 struct BitapsPuzzle {
     address: Address,
+    #[serde(default)]
+    extra_addresses: Vec<Address>,
     status: String,
     prize: Option<f64>,
     currency: Option<String>,
+    currency_decimals: Option<u8>,
     pubkey: Option<TomlPubkey>,
     key: Option<TomlKey>,
     start_date: Option<String>,
     solve_date: Option<String>,
     solve_time: Option<u64>,
     source_url: Option<String>,
+    description: Option<String>,
     #[serde(default)]
     transactions: Vec<TomlTransaction>,
-    solver: Option<String>,
+    solver: Option<TomlSolverRef>,
+    #[serde(flatten)]
+    lifecycle: TomlLifecycle,
 }
 
 fn format_hash160(address: &Address, chain: &str, puzzle_id: &str) -> String {
     let requires_hash160 = matches!(address.kind.as_str(), "p2pkh" | "p2wpkh")
-        && matches!(chain, "bitcoin" | "litecoin");
+        && matches!(
+            chain,
+            "bitcoin" | "litecoin" | "dogecoin" | "bitcoincash" | "zcash" | "dash"
+        );
     if requires_hash160 && address.hash160.is_none() {
         panic!(
             "Puzzle '{}' ({}) requires hash160 but none provided",
@@ -775,7 +948,101 @@ fn format_witness_program(address: &Address, puzzle_id: &str) -> String {
     }
 }
 
-fn generate_transactions_code(transactions: &[TomlTransaction]) -> String {
+/// Addresses beyond the primary one (split prizes, migrated funds). They sit
+/// on the puzzle's chain and follow the same per-kind rules as `address`.
+fn generate_extra_addresses_code(
+    extra: &[Address],
+    primary: &Address,
+    chain: &str,
+    chain_code: &str,
+    puzzle_id: &str,
+) -> String {
+    if extra.is_empty() {
+        return "&[]".to_string();
+    }
+    let mut seen = HashSet::from([primary.value.as_str()]);
+    let items: Vec<String> = extra
+        .iter()
+        .map(|address| {
+            if !seen.insert(address.value.as_str()) {
+                panic!(
+                    "Puzzle '{}' lists address '{}' more than once",
+                    puzzle_id, address.value
+                );
+            }
+            format!(
+                "Address {{ value: \"{}\", chain: {}, kind: \"{}\", hash160: {}, witness_program: {}, redeem_script: {} }}",
+                address.value,
+                chain_code,
+                address.kind,
+                format_hash160(address, chain, puzzle_id),
+                format_witness_program(address, puzzle_id),
+                generate_redeem_script_code(&address.redeem_script),
+            )
+        })
+        .collect();
+    format!("&[{}]", items.join(", "))
+}
+
+/// Reject txids that are not in `boha::txid::normalize` form. Chain-agnostic:
+/// hex ids must be 64 lowercase digits (Ethereum's with a lowercase `0x`),
+/// anything else must be a 43-character base64url Arweave id.
+fn validate_txid_spelling(txid: &str) {
+    let hex = txid.strip_prefix("0x").unwrap_or(txid);
+    let is_hex = hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit());
+    let ok = if is_hex {
+        hex.bytes().all(|b| !b.is_ascii_uppercase())
+    } else {
+        txid.len() == 43
+            && txid
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    };
+    if !ok {
+        panic!(
+            "Malformed txid '{}': expected 64 lowercase hex digits (0x-prefixed on Ethereum) \
+             or a 43-character Arweave id. Normalize it with boha::txid::normalize.",
+            txid
+        );
+    }
+}
+
+include!("src/chain_decimals.rs");
+
+/// Base-unit decimals for a puzzle's currency: its chain's native coin, or
+/// the dataset's `currency_decimals` when the prize is a token.
+fn currency_decimals(chain: &str, currency: Option<&str>, decimals: Option<u8>) -> u8 {
+    match (currency, decimals) {
+        (None, None) => native_decimals(chain),
+        (Some(_), Some(decimals)) => decimals,
+        (Some(currency), None) => panic!("Currency '{}' needs currency_decimals", currency),
+        (None, Some(_)) => panic!("currency_decimals is only valid with a currency"),
+    }
+}
+
+/// `amount` in base units, from the shortest decimal that round-trips the
+/// parsed `f64` — i.e. the digits written in the dataset.
+fn amount_units(amount: f64, decimals: u8) -> u128 {
+    let text = amount.to_string();
+    let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+    assert!(
+        amount >= 0.0 && fraction.len() <= usize::from(decimals),
+        "Amount {} does not fit in {} decimals",
+        text,
+        decimals
+    );
+    let padded = format!(
+        "{}{:0<width$}",
+        whole,
+        fraction,
+        width = usize::from(decimals)
+    );
+    padded
+        .parse()
+        .unwrap_or_else(|_| panic!("Amount {} is out of range", text))
+}
+
+fn generate_transactions_code(transactions: &[TomlTransaction], decimals: u8) -> String {
     if transactions.is_empty() {
         return "&[]".to_string();
     }
@@ -793,20 +1060,30 @@ fn generate_transactions_code(transactions: &[TomlTransaction]) -> String {
                 other => panic!("Unknown transaction type: {}", other),
             };
             let txid = match &t.txid {
-                Some(id) => format!("Some(\"{}\")", id),
+                Some(id) => {
+                    validate_txid_spelling(id);
+                    format!("Some(\"{}\")", id)
+                }
                 None => "None".to_string(),
             };
             let date = match &t.date {
                 Some(d) => format!("Some(\"{}\")", d),
                 None => "None".to_string(),
             };
-            let amount = match t.amount {
-                Some(a) => format!("Some({:.8})", a),
-                None => "None".to_string(),
+            let (amount, native_amount) = match t.amount {
+                Some(a) => (
+                    format!("Some({:?})", a),
+                    format!(
+                        "Some(Amount::new({}, {}))",
+                        amount_units(a, decimals),
+                        decimals
+                    ),
+                ),
+                None => ("None".to_string(), "None".to_string()),
             };
             format!(
-                "Transaction {{ tx_type: {}, txid: {}, date: {}, amount: {} }}",
-                tx_type, txid, date, amount
+                "Transaction::new({}, {}, {}, {}, {})",
+                tx_type, txid, date, amount, native_amount
             )
         })
         .collect();
@@ -814,6 +1091,64 @@ fn generate_transactions_code(transactions: &[TomlTransaction]) -> String {
     format!("&[{}]", tx_list.join(", "))
 }
 
+/// A dataset `YYYY-MM-DD HH:MM:SS` date as a `DateTime` literal, checked
+/// against the calendar so the library never has to re-parse it.
+fn generate_date_code(date: &Option<String>) -> String {
+    let Some(date) = date else {
+        return "None".to_string();
+    };
+    let malformed = || -> ! {
+        panic!(
+            "Malformed date '{}': expected a valid UTC 'YYYY-MM-DD HH:MM:SS'",
+            date
+        )
+    };
+    let bytes = date.as_bytes();
+    let shape_ok = bytes.len() == 19
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            10 => *b == b' ',
+            13 | 16 => *b == b':',
+            _ => b.is_ascii_digit(),
+        });
+    if !shape_ok {
+        malformed();
+    }
+    let field = |range: std::ops::Range<usize>| -> u16 { date[range].parse().unwrap() };
+    let (year, month, day) = (field(0..4), field(5..7), field(8..10));
+    let (hour, minute, second) = (field(11..13), field(14..16), field(17..19));
+    let days_in_month = match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    };
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        malformed();
+    }
+    format!(
+        "Some(DateTime::dataset({}, {}, {}, {}, {}, {}))",
+        year, month, day, hour, minute, second
+    )
+}
+
+/// Free-form provenance text as a string literal; quotes and newlines are
+/// escaped rather than trusted.
+fn generate_description_code(description: &Option<String>) -> String {
+    match description {
+        Some(text) => {
+            assert!(!text.trim().is_empty(), "Empty description: {:?}", text);
+            format!("Some({:?})", text.trim())
+        }
+        None => "None".to_string(),
+    }
+}
+
 fn generate_profiles_code(profiles: &[TomlProfile]) -> String {
     if profiles.is_empty() {
         "&[]".to_string()
@@ -826,6 +1161,11 @@ fn generate_profiles_code(profiles: &[TomlProfile]) -> String {
     }
 }
 
+fn generate_string_slice_code(values: &[String]) -> String {
+    let quoted: Vec<String> = values.iter().map(|v| format!("\"{}\"", v)).collect();
+    format!("&[{}]", quoted.join(", "))
+}
+
 fn generate_author_code(author: &Option<AuthorConfig>) -> String {
     match author {
         Some(a) => {
@@ -833,55 +1173,66 @@ fn generate_author_code(author: &Option<AuthorConfig>) -> String {
                 Some(n) => format!("Some(\"{}\")", n),
                 None => "None".to_string(),
             };
-            let addresses = if a.addresses.is_empty() {
-                "&[]".to_string()
-            } else {
-                let addrs: Vec<String> = a.addresses.iter().map(|addr| format!("\"{}\"", addr)).collect();
-                format!("&[{}]", addrs.join(", "))
-            };
+            let aliases = generate_string_slice_code(&a.aliases);
+            let addresses = generate_string_slice_code(&a.addresses);
             let profiles = generate_profiles_code(&a.profiles);
             format!(
-                "static AUTHOR: Author = Author {{\n    name: {},\n    addresses: {},\n    profiles: {},\n}};\n",
-                name, addresses, profiles
+                "static AUTHOR: Author = Author {{\n    name: {},\n    aliases: {},\n    addresses: {},\n    profiles: {},\n}};\n",
+                name, aliases, addresses, profiles
             )
         }
         None => {
-            "static AUTHOR: Author = Author {\n    name: None,\n    addresses: &[],\n    profiles: &[],\n};\n".to_string()
+            "static AUTHOR: Author = Author {\n    name: None,\n    aliases: &[],\n    addresses: &[],\n    profiles: &[],\n};\n".to_string()
         }
     }
 }
 
 fn generate_solver_code(
-    solver_id: &Option<String>,
+    solver_ref: &Option<TomlSolverRef>,
     solvers: &HashMap<String, SolverDefinition>,
 ) -> String {
-    match solver_id {
-        Some(id) => {
-            let solver = solvers
-                .get(id)
-                .unwrap_or_else(|| panic!("Unknown solver: {}", id));
-            let name = match &solver.name {
-                Some(n) => format!("Some(\"{}\")", n),
-                None => "None".to_string(),
-            };
-            let addresses = if solver.addresses.is_empty() {
-                "&[]".to_string()
-            } else {
-                let addrs: Vec<String> = solver
-                    .addresses
-                    .iter()
-                    .map(|addr| format!("\"{}\"", addr))
-                    .collect();
-                format!("&[{}]", addrs.join(", "))
-            };
-            let profiles = generate_profiles_code(&solver.profiles);
-            format!(
-                "Some(Solver {{ name: {}, addresses: {}, profiles: {} }})",
-                name, addresses, profiles
-            )
+    let (id, announcement_url) = match solver_ref {
+        Some(TomlSolverRef::Id(id)) => (id, None),
+        Some(TomlSolverRef::Solve {
+            id,
+            announcement_url,
+        }) => (id, announcement_url.as_ref()),
+        None => return "None".to_string(),
+    };
+    let solver = solvers
+        .get(id)
+        .unwrap_or_else(|| panic!("Unknown solver: {}", id));
+    let name = match &solver.name {
+        Some(n) => format!("Some(\"{}\")", n),
+        None => "None".to_string(),
+    };
+    let addresses = if solver.addresses.is_empty() {
+        "&[]".to_string()
+    } else {
+        let addrs: Vec<String> = solver
+            .addresses
+            .iter()
+            .map(|addr| format!("\"{}\"", addr))
+            .collect();
+        format!("&[{}]", addrs.join(", "))
+    };
+    let profiles = generate_profiles_code(&solver.profiles);
+    let announcement_url = match announcement_url {
+        Some(url) => {
+            assert!(
+                url.starts_with("https://"),
+                "Announcement URL for solver {} must be https: {}",
+                id,
+                url
+            );
+            format!("Some(\"{}\")", url)
         }
         None => "None".to_string(),
-    }
+    };
+    format!(
+        "Some(Solver {{ name: {}, addresses: {}, profiles: {}, announcement_url: {} }})",
+        name, addresses, profiles, announcement_url
+    )
 }
 
 fn generate_entropy_code(entropy: &Option<TomlEntropy>) -> String {
@@ -958,9 +1309,23 @@ fn generate_wif_code(wif: &Option<TomlWif>, puzzle_id: &str, expected_address: &
                 Some(s) => format!("Some(\"{}\")", s),
                 None => "None".to_string(),
             };
+            let brainwallet = match w.brainwallet.as_deref() {
+                Some("sha256") => {
+                    if let Some(p) = &w.passphrase {
+                        validate_sha256_brainwallet_derives_address(p, expected_address, puzzle_id);
+                    }
+                    "Some(BrainwalletScheme::Sha256)".to_string()
+                }
+                Some("warpwallet") => "Some(BrainwalletScheme::WarpWallet)".to_string(),
+                Some(other) => panic!(
+                    "Puzzle '{}' has unknown brainwallet scheme '{}'",
+                    puzzle_id, other
+                ),
+                None => "None".to_string(),
+            };
             format!(
-                "Some(Wif {{ encrypted: {}, decrypted: {}, passphrase: {}, salt: {} }})",
-                encrypted, decrypted, passphrase, salt
+                "Some(Wif {{ encrypted: {}, decrypted: {}, passphrase: {}, salt: {}, brainwallet: {} }})",
+                encrypted, decrypted, passphrase, salt, brainwallet
             )
         }
         None => "None".to_string(),
@@ -1003,6 +1368,7 @@ fn generate_key_code_required(key: &TomlKey, puzzle_id: &str, expected_address:
             decrypted: None,
             passphrase: None,
             salt: None,
+            brainwallet: None,
         });
         wif_with_derived.decrypted = derived_decrypted;
         generate_wif_code(&Some(wif_with_derived), puzzle_id, expected_address)
@@ -1047,6 +1413,20 @@ fn generate_key_code_required(key: &TomlKey, puzzle_id: &str, expected_address:
     )
 }
 
+fn generate_superseded_by_code(lifecycle: &TomlLifecycle, puzzle_id: &str) -> String {
+    match &lifecycle.superseded_by {
+        Some(_) if lifecycle.withdrawn => panic!(
+            "Puzzle '{}' sets both superseded_by and withdrawn; a withdrawn entry has no successor",
+            puzzle_id
+        ),
+        Some(id) if id == puzzle_id => {
+            panic!("Puzzle '{}' cannot supersede itself", puzzle_id)
+        }
+        Some(id) => format!("Some(\"{}\")", id),
+        None => "None".to_string(),
+    }
+}
+
 fn generate_redeem_script_code(rs: &Option<TomlRedeemScript>) -> String {
     match rs {
         Some(r) => format!(
@@ -1215,6 +1595,7 @@ fn main() {
     println!("cargo:rerun-if-changed=data/bitaps.jsonc");
     println!("cargo:rerun-if-changed=data/bitimage.jsonc");
     println!("cargo:rerun-if-changed=data/ballet.jsonc");
+    println!("cargo:rerun-if-changed=data/example.jsonc");
     println!("cargo:rerun-if-changed=data/solvers.jsonc");
     println!("cargo:rerun-if-changed=assets");
     println!("cargo:rerun-if-changed=.git/HEAD");
@@ -1233,6 +1614,61 @@ fn main() {
     generate_bitimage(&out_dir, &solvers);
     generate_ballet(&out_dir, &solvers);
     generate_warp(&out_dir, &solvers);
+
+    generate_example(
+        &out_dir,
+        env::var_os("CARGO_FEATURE_EXAMPLES_DATA").is_some(),
+    );
+    if env::var_os("CARGO_FEATURE_BALANCE_SNAPSHOT").is_some() {
+        generate_balance_snapshot(&out_dir);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BalanceSnapshotFile {
+    taken_at: String,
+    /// Address to confirmed balance in base units; sorted, so the
+    /// generated table can be binary searched.
+    balances: std::collections::BTreeMap<String, u128>,
+}
+
+/// `data/balances.json` as a sorted table; empty when the file is missing.
+fn generate_balance_snapshot(out_dir: &str) {
+    println!("cargo:rerun-if-changed=data/balances.json");
+    let dest_path = Path::new(out_dir).join("balance_snapshot.rs");
+
+    let snapshot = fs::read_to_string("data/balances.json")
+        .ok()
+        .map(|content| {
+            serde_json::from_str::<BalanceSnapshotFile>(&content)
+                .expect("Failed to parse data/balances.json")
+        });
+    let mut output = String::new();
+    match &snapshot {
+        Some(snapshot) => {
+            let shape = snapshot
+                .taken_at
+                .bytes()
+                .map(|b| if b.is_ascii_digit() { b'0' } else { b });
+            assert!(
+                shape.eq(b"0000-00-00 00:00:00".iter().copied()),
+                "data/balances.json: taken_at must be YYYY-MM-DD HH:MM:SS, got {:?}",
+                snapshot.taken_at
+            );
+            output.push_str(&format!(
+                "static TAKEN_AT: Option<&str> = Some({:?});\n",
+                snapshot.taken_at
+            ));
+        }
+        None => output.push_str("static TAKEN_AT: Option<&str> = None;\n"),
+    }
+    output.push_str("static BALANCES: &[(&str, u128)] = &[\n");
+    for (address, balance) in snapshot.iter().flat_map(|s| &s.balances) {
+        output.push_str(&format!("    ({:?}, {}),\n", address, balance));
+    }
+    output.push_str("];\n");
+
+    fs::write(&dest_path, output).expect("Failed to write balance_snapshot.rs");
 }
 
 fn generate_b1000(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
@@ -1303,21 +1739,17 @@ fn generate_b1000(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             .map(|c| format!("Some(\"{}\")", c))
             .unwrap_or_else(|| "None".to_string());
 
-        let start_date = match &puzzle.start_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let start_date = generate_date_code(&puzzle.start_date);
 
-        let solve_date = match &puzzle.solve_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let solve_date = generate_date_code(&puzzle.solve_date);
 
         let solve_time = match puzzle.solve_time {
             Some(t) => format!("Some({})", t),
             None => "None".to_string(),
         };
 
+        let description = generate_description_code(&puzzle.description);
+
         let source_url = puzzle
             .source_url
             .as_ref()
@@ -1329,12 +1761,20 @@ fn generate_b1000(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         let witness_program = format_witness_program(&puzzle.address, &format!("b1000/{}", bits));
         let redeem_script = generate_redeem_script_code(&puzzle.address.redeem_script);
 
-        let transactions = generate_transactions_code(&puzzle.transactions);
+        let transactions = generate_transactions_code(
+            &puzzle.transactions,
+            currency_decimals(
+                "bitcoin",
+                puzzle.currency.as_deref(),
+                puzzle.currency_decimals,
+            ),
+        );
         let solver = generate_solver_code(&puzzle.solver, solvers);
 
         output.push_str(&format!(
             r#"    Puzzle {{
         id: "b1000/{}",
+        uuid: "{uuid}",
         chain: Chain::Bitcoin,
         address: Address {{
             value: "{}",
@@ -1344,6 +1784,7 @@ fn generate_b1000(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             witness_program: {},
             redeem_script: {},
         }},
+        extra_addresses: {extra_addresses},
         status: {},
         pubkey: {},
         key: {},
@@ -1353,7 +1794,10 @@ fn generate_b1000(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         solve_date: {},
         solve_time: {},
         pre_genesis: {},
+        superseded_by: {superseded_by},
+        withdrawn: {withdrawn},
         source_url: {},
+        description: {description},
         transactions: {},
         solver: {},
         assets: None,
@@ -1377,6 +1821,16 @@ fn generate_b1000(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             source_url,
             transactions,
             solver,
+            uuid = puzzle_uuid("b1000", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                "bitcoin",
+                "Chain::Bitcoin",
+                &format!("b1000/{}", bits),
+            ),
+            superseded_by = generate_superseded_by_code(&puzzle.lifecycle, &puzzle_id),
+            withdrawn = puzzle.lifecycle.withdrawn,
         ));
     }
 
@@ -1422,21 +1876,17 @@ fn generate_hash_collision(out_dir: &str, solvers: &HashMap<String, SolverDefini
             .map(|c| format!("Some(\"{}\")", c))
             .unwrap_or_else(|| "None".to_string());
 
-        let start_date = match &puzzle.start_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let start_date = generate_date_code(&puzzle.start_date);
 
-        let solve_date = match &puzzle.solve_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let solve_date = generate_date_code(&puzzle.solve_date);
 
         let solve_time = match puzzle.solve_time {
             Some(t) => format!("Some({})", t),
             None => "None".to_string(),
         };
 
+        let description = generate_description_code(&puzzle.description);
+
         let source_url = puzzle
             .source_url
             .as_ref()
@@ -1453,12 +1903,20 @@ fn generate_hash_collision(out_dir: &str, solvers: &HashMap<String, SolverDefini
             format_witness_program(&puzzle.address, &format!("hash_collision/{}", puzzle.name));
         let redeem_script = generate_redeem_script_code(&puzzle.address.redeem_script);
 
-        let transactions = generate_transactions_code(&puzzle.transactions);
+        let transactions = generate_transactions_code(
+            &puzzle.transactions,
+            currency_decimals(
+                "bitcoin",
+                puzzle.currency.as_deref(),
+                puzzle.currency_decimals,
+            ),
+        );
         let solver = generate_solver_code(&puzzle.solver, solvers);
 
         output.push_str(&format!(
             r#"    Puzzle {{
         id: "hash_collision/{}",
+        uuid: "{uuid}",
         chain: Chain::Bitcoin,
         address: Address {{
             value: "{}",
@@ -1468,6 +1926,7 @@ fn generate_hash_collision(out_dir: &str, solvers: &HashMap<String, SolverDefini
             witness_program: {},
             redeem_script: {},
         }},
+        extra_addresses: {extra_addresses},
         status: {},
         pubkey: None,
         key: None,
@@ -1477,7 +1936,10 @@ fn generate_hash_collision(out_dir: &str, solvers: &HashMap<String, SolverDefini
         solve_date: {},
         solve_time: {},
         pre_genesis: false,
+        superseded_by: {superseded_by},
+        withdrawn: {withdrawn},
         source_url: {},
+        description: {description},
         transactions: {},
         solver: {},
         assets: None,
@@ -1498,6 +1960,19 @@ fn generate_hash_collision(out_dir: &str, solvers: &HashMap<String, SolverDefini
             source_url,
             transactions,
             solver,
+            uuid = puzzle_uuid("hash_collision", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                "bitcoin",
+                "Chain::Bitcoin",
+                &format!("hash_collision/{}", puzzle.name),
+            ),
+            superseded_by = generate_superseded_by_code(
+                &puzzle.lifecycle,
+                &format!("hash_collision/{}", puzzle.name)
+            ),
+            withdrawn = puzzle.lifecycle.withdrawn,
         ));
     }
 
@@ -1546,21 +2021,17 @@ fn generate_gsmg(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         .map(|c| format!("Some(\"{}\")", c))
         .unwrap_or_else(|| "None".to_string());
 
-    let start_date = match &puzzle.start_date {
-        Some(d) => format!("Some(\"{}\")", d),
-        None => "None".to_string(),
-    };
+    let start_date = generate_date_code(&puzzle.start_date);
 
-    let solve_date = match &puzzle.solve_date {
-        Some(d) => format!("Some(\"{}\")", d),
-        None => "None".to_string(),
-    };
+    let solve_date = generate_date_code(&puzzle.solve_date);
 
     let solve_time = match puzzle.solve_time {
         Some(t) => format!("Some({})", t),
         None => "None".to_string(),
     };
 
+    let description = generate_description_code(&puzzle.description);
+
     let source_url = puzzle
         .source_url
         .as_ref()
@@ -1574,7 +2045,14 @@ fn generate_gsmg(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
     let witness_program = format_witness_program(&puzzle.address, "gsmg");
     let redeem_script = generate_redeem_script_code(&puzzle.address.redeem_script);
 
-    let transactions = generate_transactions_code(&puzzle.transactions);
+    let transactions = generate_transactions_code(
+        &puzzle.transactions,
+        currency_decimals(
+            "bitcoin",
+            puzzle.currency.as_deref(),
+            puzzle.currency_decimals,
+        ),
+    );
     let solver = generate_solver_code(&puzzle.solver, solvers);
     let assets = generate_assets_code(&puzzle.assets, "gsmg", "gsmg");
 
@@ -1584,6 +2062,7 @@ fn generate_gsmg(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
     output.push_str(&format!(
         r#"static PUZZLE: Puzzle = Puzzle {{
     id: "gsmg",
+    uuid: "{uuid}",
     chain: Chain::Bitcoin,
     address: Address {{
         value: "{}",
@@ -1593,6 +2072,7 @@ fn generate_gsmg(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         witness_program: {},
         redeem_script: {},
     }},
+    extra_addresses: {extra_addresses},
     status: {},
     pubkey: {},
     key: None,
@@ -1602,7 +2082,10 @@ fn generate_gsmg(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
     solve_date: {},
     solve_time: {},
     pre_genesis: false,
+    superseded_by: {superseded_by},
+    withdrawn: {withdrawn},
     source_url: {},
+    description: {description},
     transactions: {},
     solver: {},
     assets: {},
@@ -1624,6 +2107,16 @@ fn generate_gsmg(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         transactions,
         solver,
         assets,
+        uuid = puzzle_uuid("gsmg", &puzzle.address.value),
+        extra_addresses = generate_extra_addresses_code(
+            &puzzle.extra_addresses,
+            &puzzle.address,
+            "bitcoin",
+            "Chain::Bitcoin",
+            "gsmg",
+        ),
+        superseded_by = generate_superseded_by_code(&puzzle.lifecycle, "gsmg"),
+        withdrawn = puzzle.lifecycle.withdrawn,
     ));
 
     fs::write(&dest_path, output).expect("Failed to write gsmg_data.rs");
@@ -1665,6 +2158,10 @@ fn generate_zden(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             "litecoin" => "Chain::Litecoin",
             "monero" => "Chain::Monero",
             "decred" => "Chain::Decred",
+            "dogecoin" => "Chain::Dogecoin",
+            "bitcoincash" => "Chain::BitcoinCash",
+            "zcash" => "Chain::Zcash",
+            "dash" => "Chain::Dash",
             other => panic!("Unknown chain '{}' for puzzle {}", other, puzzle.name),
         };
 
@@ -1687,21 +2184,17 @@ fn generate_zden(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             .map(|c| format!("Some(\"{}\")", c))
             .unwrap_or_else(|| "None".to_string());
 
-        let start_date = match &puzzle.start_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let start_date = generate_date_code(&puzzle.start_date);
 
-        let solve_date = match &puzzle.solve_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let solve_date = generate_date_code(&puzzle.solve_date);
 
         let solve_time = match puzzle.solve_time {
             Some(t) => format!("Some({})", t),
             None => "None".to_string(),
         };
 
+        let description = generate_description_code(&puzzle.description);
+
         let source_url = puzzle
             .source_url
             .as_ref()
@@ -1720,7 +2213,14 @@ fn generate_zden(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         let puzzle_id = format!("zden/{}", puzzle.name);
         let key = generate_key_code(&puzzle.key, &puzzle_id, &puzzle.address.value);
 
-        let transactions = generate_transactions_code(&puzzle.transactions);
+        let transactions = generate_transactions_code(
+            &puzzle.transactions,
+            currency_decimals(
+                &puzzle.chain,
+                puzzle.currency.as_deref(),
+                puzzle.currency_decimals,
+            ),
+        );
         let solver = generate_solver_code(&puzzle.solver, solvers);
         let assets = generate_assets_code(&puzzle.assets, "zden", &format!("zden/{}", puzzle.name));
 
@@ -1729,6 +2229,7 @@ fn generate_zden(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         output.push_str(&format!(
             r#"    Puzzle {{
         id: "zden/{}",
+        uuid: "{uuid}",
         chain: {},
         address: Address {{
             value: "{}",
@@ -1738,6 +2239,7 @@ fn generate_zden(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             witness_program: {},
             redeem_script: {},
         }},
+        extra_addresses: {extra_addresses},
         status: {},
         pubkey: {},
         key: {},
@@ -1747,7 +2249,10 @@ fn generate_zden(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         solve_date: {},
         solve_time: {},
         pre_genesis: false,
+        superseded_by: {superseded_by},
+        withdrawn: {withdrawn},
         source_url: {},
+        description: {description},
         transactions: {},
         solver: {},
         assets: {},
@@ -1773,6 +2278,16 @@ fn generate_zden(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             transactions,
             solver,
             assets,
+            uuid = puzzle_uuid("zden", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                &puzzle.chain,
+                chain,
+                &format!("zden/{}", puzzle.name),
+            ),
+            superseded_by = generate_superseded_by_code(&puzzle.lifecycle, &puzzle_id),
+            withdrawn = puzzle.lifecycle.withdrawn,
         ));
     }
 
@@ -1821,21 +2336,17 @@ fn generate_bitaps(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         .map(|c| format!("Some(\"{}\")", c))
         .unwrap_or_else(|| "None".to_string());
 
-    let start_date = match &puzzle.start_date {
-        Some(d) => format!("Some(\"{}\")", d),
-        None => "None".to_string(),
-    };
+    let start_date = generate_date_code(&puzzle.start_date);
 
-    let solve_date = match &puzzle.solve_date {
-        Some(d) => format!("Some(\"{}\")", d),
-        None => "None".to_string(),
-    };
+    let solve_date = generate_date_code(&puzzle.solve_date);
 
     let solve_time = match puzzle.solve_time {
         Some(t) => format!("Some({})", t),
         None => "None".to_string(),
     };
 
+    let description = generate_description_code(&puzzle.description);
+
     let source_url = puzzle
         .source_url
         .as_ref()
@@ -1850,7 +2361,14 @@ fn generate_bitaps(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
     let redeem_script = generate_redeem_script_code(&puzzle.address.redeem_script);
     let key = generate_key_code(&puzzle.key, "bitaps", &puzzle.address.value);
 
-    let transactions = generate_transactions_code(&puzzle.transactions);
+    let transactions = generate_transactions_code(
+        &puzzle.transactions,
+        currency_decimals(
+            "bitcoin",
+            puzzle.currency.as_deref(),
+            puzzle.currency_decimals,
+        ),
+    );
     let solver = generate_solver_code(&puzzle.solver, solvers);
 
     let mut output = String::new();
@@ -1859,6 +2377,7 @@ fn generate_bitaps(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
     output.push_str(&format!(
         r#"static PUZZLE: Puzzle = Puzzle {{
     id: "bitaps",
+    uuid: "{uuid}",
     chain: Chain::Bitcoin,
     address: Address {{
         value: "{}",
@@ -1868,6 +2387,7 @@ fn generate_bitaps(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         witness_program: {},
         redeem_script: {},
     }},
+    extra_addresses: {extra_addresses},
     status: {},
     pubkey: {},
     key: {},
@@ -1877,7 +2397,10 @@ fn generate_bitaps(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
     solve_date: {},
     solve_time: {},
     pre_genesis: false,
+    superseded_by: {superseded_by},
+    withdrawn: {withdrawn},
     source_url: {},
+    description: {description},
     transactions: {},
     solver: {},
     assets: None,
@@ -1899,6 +2422,16 @@ fn generate_bitaps(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         source_url,
         transactions,
         solver,
+        uuid = puzzle_uuid("bitaps", &puzzle.address.value),
+        extra_addresses = generate_extra_addresses_code(
+            &puzzle.extra_addresses,
+            &puzzle.address,
+            "bitcoin",
+            "Chain::Bitcoin",
+            "bitaps",
+        ),
+        superseded_by = generate_superseded_by_code(&puzzle.lifecycle, "bitaps"),
+        withdrawn = puzzle.lifecycle.withdrawn,
     ));
 
     fs::write(&dest_path, output).expect("Failed to write bitaps_data.rs");
@@ -1941,21 +2474,17 @@ fn generate_bitimage(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
             .map(|c| format!("Some(\"{}\")", c))
             .unwrap_or_else(|| "None".to_string());
 
-        let start_date = match &puzzle.start_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let start_date = generate_date_code(&puzzle.start_date);
 
-        let solve_date = match &puzzle.solve_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let solve_date = generate_date_code(&puzzle.solve_date);
 
         let solve_time = match puzzle.solve_time {
             Some(t) => format!("Some({})", t),
             None => "None".to_string(),
         };
 
+        let description = generate_description_code(&puzzle.description);
+
         let source_url = puzzle
             .source_url
             .as_ref()
@@ -1975,7 +2504,14 @@ fn generate_bitimage(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
         let key = generate_key_code(&puzzle.key, &puzzle_id, &puzzle.address.value);
         let pubkey = format_pubkey(&puzzle.pubkey, &puzzle_id);
 
-        let transactions = generate_transactions_code(&puzzle.transactions);
+        let transactions = generate_transactions_code(
+            &puzzle.transactions,
+            currency_decimals(
+                "bitcoin",
+                puzzle.currency.as_deref(),
+                puzzle.currency_decimals,
+            ),
+        );
         let solver = generate_solver_code(&puzzle.solver, solvers);
         let assets = generate_assets_code(
             &puzzle.assets,
@@ -1986,6 +2522,7 @@ fn generate_bitimage(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
         output.push_str(&format!(
             r#"    Puzzle {{
          id: "bitimage/{}",
+         uuid: "{uuid}",
          chain: Chain::Bitcoin,
          address: Address {{
              value: "{}",
@@ -1995,6 +2532,7 @@ fn generate_bitimage(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
              witness_program: {},
              redeem_script: {},
          }},
+         extra_addresses: {extra_addresses},
          status: {},
          pubkey: {},
          key: {},
@@ -2004,7 +2542,10 @@ fn generate_bitimage(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
          solve_date: {},
          solve_time: {},
          pre_genesis: false,
+         superseded_by: {superseded_by},
+         withdrawn: {withdrawn},
          source_url: {},
+         description: {description},
          transactions: {},
          solver: {},
          assets: {},
@@ -2028,6 +2569,16 @@ fn generate_bitimage(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
             transactions,
             solver,
             assets,
+            uuid = puzzle_uuid("bitimage", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                "bitcoin",
+                "Chain::Bitcoin",
+                &format!("bitimage/{}", puzzle.name),
+            ),
+            superseded_by = generate_superseded_by_code(&puzzle.lifecycle, &puzzle_id),
+            withdrawn = puzzle.lifecycle.withdrawn,
         ));
     }
 
@@ -2085,21 +2636,17 @@ fn generate_ballet(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             .map(|c| format!("Some(\"{}\")", c))
             .unwrap_or_else(|| "None".to_string());
 
-        let start_date = match &puzzle.start_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let start_date = generate_date_code(&puzzle.start_date);
 
-        let solve_date = match &puzzle.solve_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let solve_date = generate_date_code(&puzzle.solve_date);
 
         let solve_time = match puzzle.solve_time {
             Some(t) => format!("Some({})", t),
             None => "None".to_string(),
         };
 
+        let description = generate_description_code(&puzzle.description);
+
         let source_url = puzzle
             .source_url
             .as_ref()
@@ -2118,7 +2665,14 @@ fn generate_ballet(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         let puzzle_id = format!("ballet/{}", puzzle.name);
         let key = generate_key_code(&puzzle.key, &puzzle_id, &puzzle.address.value);
 
-        let transactions = generate_transactions_code(&puzzle.transactions);
+        let transactions = generate_transactions_code(
+            &puzzle.transactions,
+            currency_decimals(
+                "bitcoin",
+                puzzle.currency.as_deref(),
+                puzzle.currency_decimals,
+            ),
+        );
         let solver = generate_solver_code(&puzzle.solver, solvers);
         let assets =
             generate_assets_code(&puzzle.assets, "ballet", &format!("ballet/{}", puzzle.name));
@@ -2128,6 +2682,7 @@ fn generate_ballet(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         output.push_str(&format!(
             r#"    Puzzle {{
         id: "ballet/{}",
+        uuid: "{uuid}",
         chain: Chain::Bitcoin,
         address: Address {{
             value: "{}",
@@ -2137,6 +2692,7 @@ fn generate_ballet(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             witness_program: {},
             redeem_script: {},
         }},
+        extra_addresses: {extra_addresses},
         status: {},
         pubkey: {},
         key: {},
@@ -2146,7 +2702,10 @@ fn generate_ballet(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         solve_date: {},
         solve_time: {},
         pre_genesis: false,
+        superseded_by: {superseded_by},
+        withdrawn: {withdrawn},
         source_url: {},
+        description: {description},
         transactions: {},
         solver: {},
         assets: {},
@@ -2170,6 +2729,16 @@ fn generate_ballet(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             transactions,
             solver,
             assets,
+            uuid = puzzle_uuid("ballet", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                "bitcoin",
+                "Chain::Bitcoin",
+                &format!("ballet/{}", puzzle.name),
+            ),
+            superseded_by = generate_superseded_by_code(&puzzle.lifecycle, &puzzle_id),
+            withdrawn = puzzle.lifecycle.withdrawn,
         ));
     }
 
@@ -2224,21 +2793,17 @@ fn generate_arweave(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
             .map(|c| format!("Some(\"{}\")", c))
             .unwrap_or_else(|| "None".to_string());
 
-        let start_date = match &puzzle.start_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let start_date = generate_date_code(&puzzle.start_date);
 
-        let solve_date = match &puzzle.solve_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let solve_date = generate_date_code(&puzzle.solve_date);
 
         let solve_time = match puzzle.solve_time {
             Some(t) => format!("Some({})", t),
             None => "None".to_string(),
         };
 
+        let description = generate_description_code(&puzzle.description);
+
         let source_url = puzzle
             .source_url
             .as_ref()
@@ -2259,7 +2824,14 @@ fn generate_arweave(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
         let key = generate_key_code(&puzzle.key, &puzzle_id, &puzzle.address.value);
         let pubkey = format_pubkey(&puzzle.pubkey, &puzzle.name);
 
-        let transactions = generate_transactions_code(&puzzle.transactions);
+        let transactions = generate_transactions_code(
+            &puzzle.transactions,
+            currency_decimals(
+                &puzzle.chain,
+                puzzle.currency.as_deref(),
+                puzzle.currency_decimals,
+            ),
+        );
         let solver = generate_solver_code(&puzzle.solver, solvers);
         let assets = generate_assets_code(
             &puzzle.assets,
@@ -2270,6 +2842,7 @@ fn generate_arweave(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
         output.push_str(&format!(
             r#"    Puzzle {{
         id: "arweave/{}",
+        uuid: "{uuid}",
         chain: {},
         address: Address {{
             value: "{}",
@@ -2279,6 +2852,7 @@ fn generate_arweave(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
             witness_program: {},
             redeem_script: {},
         }},
+        extra_addresses: {extra_addresses},
         status: {},
         pubkey: {},
         key: {},
@@ -2288,7 +2862,10 @@ fn generate_arweave(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
         solve_date: {},
         solve_time: {},
         pre_genesis: false,
+        superseded_by: {superseded_by},
+        withdrawn: {withdrawn},
         source_url: {},
+        description: {description},
         transactions: {},
         solver: {},
         assets: {},
@@ -2314,6 +2891,16 @@ fn generate_arweave(out_dir: &str, solvers: &HashMap<String, SolverDefinition>)
             transactions,
             solver,
             assets,
+            uuid = puzzle_uuid("arweave", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                &puzzle.chain,
+                chain,
+                &format!("arweave/{}", puzzle.name),
+            ),
+            superseded_by = generate_superseded_by_code(&puzzle.lifecycle, &puzzle_id),
+            withdrawn = puzzle.lifecycle.withdrawn,
         ));
     }
 
@@ -2369,21 +2956,17 @@ fn generate_warp(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             .map(|c| format!("Some(\"{}\")", c))
             .unwrap_or_else(|| "None".to_string());
 
-        let start_date = match &puzzle.start_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let start_date = generate_date_code(&puzzle.start_date);
 
-        let solve_date = match &puzzle.solve_date {
-            Some(d) => format!("Some(\"{}\")", d),
-            None => "None".to_string(),
-        };
+        let solve_date = generate_date_code(&puzzle.solve_date);
 
         let solve_time = match puzzle.solve_time {
             Some(t) => format!("Some({})", t),
             None => "None".to_string(),
         };
 
+        let description = generate_description_code(&puzzle.description);
+
         let source_url = puzzle
             .source_url
             .as_ref()
@@ -2396,12 +2979,20 @@ fn generate_warp(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         let redeem_script = generate_redeem_script_code(&puzzle.address.redeem_script);
         let pubkey = format_pubkey(&puzzle.pubkey, &puzzle_id);
         let key = generate_key_code(&puzzle.key, &puzzle_id, &puzzle.address.value);
-        let transactions = generate_transactions_code(&puzzle.transactions);
+        let transactions = generate_transactions_code(
+            &puzzle.transactions,
+            currency_decimals(
+                "bitcoin",
+                puzzle.currency.as_deref(),
+                puzzle.currency_decimals,
+            ),
+        );
         let solver = generate_solver_code(&puzzle.solver, solvers);
 
         output.push_str(&format!(
             r#"    Puzzle {{
         id: "warp/{}",
+        uuid: "{uuid}",
         chain: Chain::Bitcoin,
         address: Address {{
             value: "{}",
@@ -2411,6 +3002,7 @@ fn generate_warp(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             witness_program: {},
             redeem_script: {},
         }},
+        extra_addresses: {extra_addresses},
         status: {},
         pubkey: {},
         key: {},
@@ -2420,7 +3012,10 @@ fn generate_warp(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
         solve_date: {},
         solve_time: {},
         pre_genesis: false,
+        superseded_by: {superseded_by},
+        withdrawn: {withdrawn},
         source_url: {},
+        description: {description},
         transactions: {},
         solver: {},
         assets: None,
@@ -2443,6 +3038,16 @@ fn generate_warp(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
             source_url,
             transactions,
             solver,
+            uuid = puzzle_uuid("warp", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                "bitcoin",
+                "Chain::Bitcoin",
+                &puzzle_id,
+            ),
+            superseded_by = generate_superseded_by_code(&puzzle.lifecycle, &puzzle_id),
+            withdrawn = puzzle.lifecycle.withdrawn,
         ));
     }
 
@@ -2450,3 +3055,156 @@ fn generate_warp(out_dir: &str, solvers: &HashMap<String, SolverDefinition>) {
 
     fs::write(&dest_path, output).expect("Failed to write warp_data.rs");
 }
+
+/// Fake testnet puzzles for docs and tutorials; without the `examples-data`
+/// feature only the author is emitted and the puzzle list is empty.
+///
+/// Keys are validated against testnet P2PKH (version `0x6f`) addresses, so the
+/// mainnet key helpers are not used here.
+fn generate_example(out_dir: &str, with_puzzles: bool) {
+    const TESTNET_P2PKH_VERSION: u8 = 0x6f;
+
+    let dest_path = Path::new(out_dir).join("example_data.rs");
+
+    let mut content =
+        fs::read_to_string("data/example.jsonc").expect("Failed to read data/example.jsonc");
+    strip(&mut content).expect("Failed to strip comments from example.jsonc");
+    let wrapped: WithSchema<ExampleFile> =
+        serde_json::from_str(&content).expect("Failed to parse example.jsonc");
+    let data = wrapped.inner;
+
+    let default_source_url = data.metadata.as_ref().and_then(|m| m.source_url.as_ref());
+
+    let mut output = String::new();
+    output.push_str(&generate_author_code(&data.author));
+    output.push('\n');
+    output.push_str("static PUZZLES: &[Puzzle] = &[\n");
+
+    let puzzles = if with_puzzles { &data.puzzles[..] } else { &[] };
+    for puzzle in puzzles {
+        let puzzle_id = format!("example/{}", puzzle.name);
+
+        let hex_key = puzzle
+            .key
+            .hex
+            .as_ref()
+            .unwrap_or_else(|| panic!("Example puzzle '{}' must have a hex key", puzzle_id));
+        let derived = private_key_to_versioned_address(hex_key, true, TESTNET_P2PKH_VERSION);
+        if derived.as_deref() != Some(puzzle.address.value.as_str()) {
+            panic!(
+                "Example puzzle '{}' hex key derives {:?}, expected {}",
+                puzzle_id, derived, puzzle.address.value
+            );
+        }
+        if puzzle.key.bits != bits_from_private_key(hex_key) {
+            panic!(
+                "Example puzzle '{}' bits {:?} do not match its key",
+                puzzle_id, puzzle.key.bits
+            );
+        }
+
+        let status = match puzzle.status.as_str() {
+            "solved" => "Status::Solved",
+            "claimed" => "Status::Claimed",
+            "swept" => "Status::Swept",
+            "expired" => "Status::Expired",
+            _ => "Status::Unsolved",
+        };
+
+        let prize = match puzzle.prize {
+            Some(p) => format!("Some({:.6})", p),
+            None => "None".to_string(),
+        };
+
+        let currency = puzzle
+            .currency
+            .as_ref()
+            .map(|c| format!("Some(\"{}\")", c))
+            .unwrap_or_else(|| "None".to_string());
+
+        let start_date = generate_date_code(&puzzle.start_date);
+
+        let solve_date = generate_date_code(&puzzle.solve_date);
+
+        let solve_time = match puzzle.solve_time {
+            Some(t) => format!("Some({})", t),
+            None => "None".to_string(),
+        };
+
+        let description = generate_description_code(&puzzle.description);
+
+        let source_url = puzzle
+            .source_url
+            .as_ref()
+            .or(default_source_url)
+            .map(|url| format!("Some(\"{}\")", url))
+            .unwrap_or_else(|| "None".to_string());
+
+        let hash160 = format_hash160(&puzzle.address, "bitcoin", &puzzle_id);
+        let pubkey = format_pubkey(&puzzle.pubkey, &puzzle_id);
+        let bits = match puzzle.key.bits {
+            Some(b) => format!("Some({})", b),
+            None => "None".to_string(),
+        };
+
+        output.push_str(&format!(
+            r#"    Puzzle {{
+        id: "example/{}",
+        uuid: "{uuid}",
+        chain: Chain::Bitcoin,
+        address: Address {{
+            value: "{}",
+            chain: Chain::Bitcoin,
+            kind: "{}",
+            hash160: {},
+            witness_program: None,
+            redeem_script: None,
+        }},
+        extra_addresses: {extra_addresses},
+        status: {},
+        pubkey: {},
+        key: Some(Key {{ hex: Some("{}"), wif: None, seed: None, mini: None, bits: {}, shares: None }}),
+        prize: {},
+        currency: {},
+        start_date: {},
+        solve_date: {},
+        solve_time: {},
+        pre_genesis: false,
+        superseded_by: {superseded_by},
+        withdrawn: {withdrawn},
+        source_url: {},
+        description: {description},
+        transactions: &[],
+        solver: None,
+        assets: None,
+    }},
+"#,
+            puzzle.name,
+            puzzle.address.value,
+            puzzle.address.kind,
+            hash160,
+            status,
+            pubkey,
+            hex_key,
+            bits,
+            prize,
+            currency,
+            start_date,
+            solve_date,
+            solve_time,
+            source_url,
+            uuid = puzzle_uuid("example", &puzzle.address.value),
+            extra_addresses = generate_extra_addresses_code(
+                &puzzle.extra_addresses,
+                &puzzle.address,
+                "bitcoin", "Chain::Bitcoin", &puzzle_id,
+            ),
+            superseded_by = generate_superseded_by_code(&puzzle.lifecycle, &puzzle_id),
+            withdrawn = puzzle.lifecycle.withdrawn,
+        ));
+    }
+
+    output.push_str("];\n");
+
+    fs::write(&dest_path, output).expect("Failed to write example_data.rs");
+}
@@ -0,0 +1,143 @@
+//! Balance lookups without an async runtime (`balance-blocking` feature).
+//!
+//! Same explorers, `BOHA_*_URL` overrides and parsing as [`super::fetch`],
+//! over ureq instead of reqwest, so tokio never gets pulled in. Proxies come
+//! from `HTTPS_PROXY`/`ALL_PROXY`/`NO_PROXY`; [`crate::http::set_proxy`] only
+//! configures the async client.
+
+use std::sync::OnceLock;
+
+use serde::de::DeserializeOwned;
+
+use super::{
+    ar_balance, ar_url, blockchair_balance, blockchair_url, dcr_balance, dcr_url, eth_balance,
+    eth_request, is_invalid_address_status, Balance, BalanceError, EsploraAddressResponse,
+};
+use crate::endpoints;
+use boha::Chain;
+
+fn agent() -> &'static ureq::Agent {
+    static AGENT: OnceLock<ureq::Agent> = OnceLock::new();
+    AGENT.get_or_init(|| {
+        ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into()
+    })
+}
+
+/// GET `url`, mapping 400/422 to [`BalanceError::InvalidAddress`] when the
+/// explorer judges `address` itself.
+fn get(url: &str, address: Option<&str>) -> Result<ureq::Body, BalanceError> {
+    let response = agent().get(url).call()?;
+    let status = response.status().as_u16();
+    match address {
+        Some(address) if is_invalid_address_status(status) => {
+            Err(BalanceError::InvalidAddress(address.to_string()))
+        }
+        // Not the URL: Etherscan's carries the API key.
+        _ if !response.status().is_success() => Err(BalanceError::Api(format!(
+            "HTTP status {}",
+            response.status()
+        ))),
+        _ => Ok(response.into_body()),
+    }
+}
+
+fn get_json<T: DeserializeOwned>(url: &str, address: Option<&str>) -> Result<T, BalanceError> {
+    Ok(get(url, address)?.read_json()?)
+}
+
+fn esplora(base_url: &str, address: &str, chain: Chain) -> Result<Balance, BalanceError> {
+    let url = format!("{}/api/address/{}", base_url.trim_end_matches('/'), address);
+    tracing::debug!(%url, "GET");
+    let response: EsploraAddressResponse = get_json(&url, Some(address))?;
+    Ok(response.balance(chain))
+}
+
+/// Blocking [`super::fetch`]: the balance of `address` from the public
+/// explorer for `chain`.
+///
+/// ```no_run
+/// use boha::Chain;
+/// use boha_chain::balance;
+///
+/// let balance = balance::fetch_blocking("1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU", Chain::Bitcoin)?;
+/// println!("{} BTC", balance.confirmed_btc());
+/// # Ok::<(), balance::BalanceError>(())
+/// ```
+pub fn fetch_blocking(address: &str, chain: Chain) -> Result<Balance, BalanceError> {
+    match chain {
+        Chain::Bitcoin => esplora(&endpoints::MEMPOOL.base_url(), address, chain),
+        Chain::Litecoin => esplora(&endpoints::LITECOINSPACE.base_url(), address, chain),
+        Chain::Ethereum => {
+            let (provider, url) = eth_request(address);
+            eth_balance(provider, &get_json(&url, None)?)
+        }
+        Chain::Decred => dcr_balance(&get_json(&dcr_url(address), Some(address))?),
+        Chain::Arweave => ar_balance(&get(&ar_url(address), Some(address))?.read_to_string()?),
+        Chain::Dogecoin | Chain::BitcoinCash | Chain::Zcash | Chain::Dash => blockchair_balance(
+            &get_json(&blockchair_url(address, chain)?, Some(address))?,
+            chain,
+        ),
+        _ => Err(BalanceError::UnsupportedChain(chain.name().to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Answer one request with `status` and `body`; returns the base URL.
+    fn serve_once(status: &'static str, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                line.clear();
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        });
+        url
+    }
+
+    #[test]
+    fn esplora_balance_without_a_runtime() {
+        let url = serve_once(
+            "200 OK",
+            r#"{"chain_stats":{"funded_txo_sum":900,"spent_txo_sum":200},"mempool_stats":{"funded_txo_sum":0,"spent_txo_sum":50}}"#,
+        );
+        let balance = esplora(&url, "1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU", Chain::Bitcoin).unwrap();
+        assert_eq!(balance.confirmed, 700);
+        assert_eq!(balance.unconfirmed, -50);
+    }
+
+    #[test]
+    fn bad_request_is_an_invalid_address() {
+        let url = serve_once("400 Bad Request", "Invalid Bitcoin address");
+        assert!(matches!(
+            esplora(&url, "1nope", Chain::Bitcoin),
+            Err(BalanceError::InvalidAddress(address)) if address == "1nope"
+        ));
+    }
+
+    #[test]
+    fn monero_is_unsupported() {
+        assert!(matches!(
+            fetch_blocking("4...", Chain::Monero),
+            Err(BalanceError::UnsupportedChain(_))
+        ));
+    }
+}
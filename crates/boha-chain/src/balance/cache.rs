@@ -0,0 +1,158 @@
+//! On-disk balance cache, one JSON file per address.
+//!
+//! Lives in `$XDG_CACHE_HOME/boha/balances` (or `~/.cache/boha/balances`)
+//! so repeated lookups within the TTL never reach the explorer.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::Balance;
+use boha::Chain;
+
+/// Default time a cached balance stays fresh.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    chain: String,
+    confirmed: u128,
+    unconfirmed: i128,
+    fetched_at: u64,
+}
+
+impl BalanceCache {
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    /// Cache under the user's XDG cache directory, if one can be found.
+    pub fn open_default(ttl: Duration) -> Option<Self> {
+        default_dir().map(|dir| Self::new(dir, ttl))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Cached balance of `address`, unless missing, stale or for another chain.
+    pub fn get(&self, address: &str, chain: Chain) -> Option<Balance> {
+        let text = fs::read_to_string(self.path(address)).ok()?;
+        let entry: Entry = serde_json::from_str(&text).ok()?;
+        if entry.chain != chain.symbol() {
+            return None;
+        }
+        let age = now_secs().saturating_sub(entry.fetched_at);
+        if age >= self.ttl.as_secs() {
+            return None;
+        }
+        Some(Balance {
+            chain,
+            confirmed: entry.confirmed,
+            unconfirmed: entry.unconfirmed,
+        })
+    }
+
+    pub fn put(&self, address: &str, balance: &Balance) -> io::Result<()> {
+        let entry = Entry {
+            chain: balance.chain.symbol().to_string(),
+            confirmed: balance.confirmed,
+            unconfirmed: balance.unconfirmed,
+            fetched_at: now_secs(),
+        };
+        let json = serde_json::to_string(&entry).map_err(io::Error::other)?;
+
+        fs::create_dir_all(&self.dir)?;
+        // Write then rename, so concurrent readers never see half a file.
+        let path = self.path(address);
+        let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &path)
+    }
+
+    fn path(&self, address: &str) -> PathBuf {
+        let name: String = address
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect();
+        self.dir.join(format!("{}.json", name))
+    }
+}
+
+/// `$XDG_CACHE_HOME/boha/balances`, falling back to `$HOME/.cache`.
+pub fn default_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(base.join("boha").join("balances"))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(ttl: Duration) -> BalanceCache {
+        let dir = std::env::temp_dir().join(format!(
+            "boha-cache-test-{}-{}",
+            std::process::id(),
+            ttl.as_secs()
+        ));
+        BalanceCache::new(dir, ttl)
+    }
+
+    #[test]
+    fn roundtrip_within_ttl() {
+        let cache = temp_cache(Duration::from_secs(60));
+        let balance = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 710_000_000,
+            unconfirmed: -1_000,
+        };
+        cache
+            .put("1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU", &balance)
+            .unwrap();
+
+        let cached = cache
+            .get("1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU", Chain::Bitcoin)
+            .unwrap();
+        assert_eq!(cached.confirmed, 710_000_000);
+        assert_eq!(cached.unconfirmed, -1_000);
+        assert!(cache
+            .get("1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU", Chain::Litecoin)
+            .is_none());
+
+        fs::remove_dir_all(cache.dir()).ok();
+    }
+
+    #[test]
+    fn zero_ttl_is_always_stale() {
+        let cache = temp_cache(Duration::ZERO);
+        cache.put("0xabc", &Balance::zero(Chain::Ethereum)).unwrap();
+        assert!(cache.get("0xabc", Chain::Ethereum).is_none());
+
+        fs::remove_dir_all(cache.dir()).ok();
+    }
+}
@@ -0,0 +1,745 @@
+#[cfg(feature = "balance-blocking")]
+mod blocking;
+pub mod cache;
+#[cfg(feature = "balance")]
+mod provider;
+
+#[cfg(feature = "balance-blocking")]
+pub use blocking::fetch_blocking;
+pub use cache::BalanceCache;
+#[cfg(feature = "balance")]
+pub use provider::{electrum_scripthash, BalanceProvider, BitcoinCore, Electrum, Esplora};
+
+use crate::endpoints;
+use boha::Chain;
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BalanceError {
+    #[cfg(feature = "balance")]
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[cfg(feature = "balance-blocking")]
+    #[error("HTTP request failed: {0}")]
+    BlockingRequest(#[from] ureq::Error),
+    #[error("Connection failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("API error: {0}")]
+    Api(String),
+    #[error("Unsupported chain: {0}")]
+    UnsupportedChain(String),
+}
+
+/// Address balance in the chain's base unit (sats, wei, atoms, winston, ...).
+#[derive(Debug, Clone, Copy)]
+pub struct Balance {
+    pub chain: Chain,
+    pub confirmed: u128,
+    pub unconfirmed: i128,
+}
+
+impl Default for Balance {
+    fn default() -> Self {
+        Self::zero(Chain::Bitcoin)
+    }
+}
+
+impl Balance {
+    pub const fn zero(chain: Chain) -> Self {
+        Self {
+            chain,
+            confirmed: 0,
+            unconfirmed: 0,
+        }
+    }
+
+    /// Confirmed balance in whole coins of [`Balance::chain`].
+    pub fn confirmed_native(&self) -> f64 {
+        self.confirmed as f64 / self.scale()
+    }
+
+    /// Confirmed plus pending balance in whole coins of [`Balance::chain`].
+    pub fn total_native(&self) -> f64 {
+        self.total() as f64 / self.scale()
+    }
+
+    fn scale(&self) -> f64 {
+        10f64.powi(i32::from(self.chain.decimals()))
+    }
+
+    /// Whether coins are leaving the address in a transaction that is not
+    /// mined yet.
+    ///
+    /// Backends report pending activity as one net amount, so a larger
+    /// incoming payment in the same mempool window hides the spend.
+    pub const fn has_unconfirmed_spend(&self) -> bool {
+        self.unconfirmed < 0
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn total(&self) -> i128 {
+        self.confirmed as i128 + self.unconfirmed
+    }
+
+    pub fn confirmed_btc(&self) -> f64 {
+        self.confirmed as f64 / 100_000_000.0
+    }
+
+    pub fn total_btc(&self) -> f64 {
+        self.total() as f64 / 100_000_000.0
+    }
+
+    pub fn confirmed_ltc(&self) -> f64 {
+        self.confirmed as f64 / 100_000_000.0
+    }
+
+    pub fn total_ltc(&self) -> f64 {
+        self.total() as f64 / 100_000_000.0
+    }
+
+    pub fn confirmed_eth(&self) -> f64 {
+        self.confirmed as f64 / 1e18
+    }
+
+    pub fn total_eth(&self) -> f64 {
+        self.total() as f64 / 1e18
+    }
+
+    pub fn confirmed_dcr(&self) -> f64 {
+        self.confirmed as f64 / 100_000_000.0
+    }
+
+    pub fn total_dcr(&self) -> f64 {
+        self.total() as f64 / 100_000_000.0
+    }
+
+    pub fn confirmed_ar(&self) -> f64 {
+        self.confirmed as f64 / 1_000_000_000_000.0
+    }
+
+    pub fn total_ar(&self) -> f64 {
+        self.total() as f64 / 1_000_000_000_000.0
+    }
+}
+
+/// Sums balances of several addresses on one chain, e.g. a split bounty.
+impl std::ops::Add for Balance {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        debug_assert_eq!(self.chain, other.chain, "balances on different chains");
+        Self {
+            chain: self.chain,
+            confirmed: self.confirmed + other.confirmed,
+            unconfirmed: self.unconfirmed + other.unconfirmed,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct EsploraAddressResponse {
+    chain_stats: EsploraStats,
+    mempool_stats: EsploraStats,
+}
+
+#[derive(Deserialize)]
+struct EsploraStats {
+    funded_txo_sum: u64,
+    spent_txo_sum: u64,
+}
+
+impl EsploraAddressResponse {
+    fn balance(&self, chain: Chain) -> Balance {
+        Balance {
+            chain,
+            confirmed: u128::from(self.chain_stats.funded_txo_sum)
+                - u128::from(self.chain_stats.spent_txo_sum),
+            unconfirmed: i128::from(self.mempool_stats.funded_txo_sum)
+                - i128::from(self.mempool_stats.spent_txo_sum),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct EtherscanResponse {
+    status: String,
+    message: String,
+    result: String,
+}
+
+#[derive(Deserialize)]
+struct DcrdataAddressTotalsResponse {
+    dcr_unspent: f64,
+}
+
+/// `/dashboards/address/{address}`, keyed by the address as queried.
+#[derive(Deserialize)]
+struct BlockchairDashboardResponse {
+    data: std::collections::HashMap<String, BlockchairDashboard>,
+}
+
+#[derive(Deserialize)]
+struct BlockchairDashboard {
+    address: BlockchairAddress,
+}
+
+#[derive(Deserialize)]
+struct BlockchairAddress {
+    balance: u64,
+}
+
+/// Explorers answer a malformed address with 400 or 422.
+const fn is_invalid_address_status(status: u16) -> bool {
+    matches!(status, 400 | 422)
+}
+
+#[cfg(feature = "balance")]
+async fn fetch_btc(address: &str) -> Result<Balance, BalanceError> {
+    let base_url = endpoints::MEMPOOL.base_url();
+    Esplora::new(base_url, Chain::Bitcoin).fetch(address).await
+}
+
+/// Etherscan when `ETHERSCAN_API_KEY` is set, otherwise the keyless
+/// Blockscout instance (same `module=account&action=balance` API).
+/// Returns the provider name, for errors, and the URL.
+fn eth_request(address: &str) -> (&'static str, String) {
+    dotenvy::dotenv().ok();
+
+    let api_key = std::env::var("ETHERSCAN_API_KEY")
+        .ok()
+        .filter(|key| !key.is_empty());
+    let (provider, url) = if let Some(api_key) = api_key {
+        let base_url = endpoints::ETHERSCAN.base_url();
+        (
+            "Etherscan",
+            format!(
+                "{}/v2/api?chainid=1&module=account&action=balance&address={}&apikey={}",
+                base_url, address, api_key
+            ),
+        )
+    } else {
+        let base_url = endpoints::BLOCKSCOUT.base_url();
+        (
+            "Blockscout",
+            format!(
+                "{}/api?module=account&action=balance&address={}",
+                base_url, address
+            ),
+        )
+    };
+
+    // The API key is the last parameter; keep it out of the log.
+    tracing::debug!(
+        url = url.split("&apikey=").next().unwrap_or_default(),
+        "GET"
+    );
+    (provider, url)
+}
+
+fn eth_balance(provider: &str, response: &EtherscanResponse) -> Result<Balance, BalanceError> {
+    if response.status != "1" {
+        return Err(BalanceError::Api(format!(
+            "{} API error: {}",
+            provider, response.message
+        )));
+    }
+
+    let wei: u128 = response
+        .result
+        .parse()
+        .map_err(|_| BalanceError::Api("Failed to parse balance".into()))?;
+
+    Ok(Balance {
+        chain: Chain::Ethereum,
+        confirmed: wei,
+        unconfirmed: 0,
+    })
+}
+
+#[cfg(feature = "balance")]
+async fn fetch_eth(address: &str) -> Result<Balance, BalanceError> {
+    let (provider, url) = eth_request(address);
+    let response: EtherscanResponse = crate::http::client()
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()
+        .map_err(BalanceError::Request)?
+        .json()
+        .await?;
+    eth_balance(provider, &response)
+}
+
+#[cfg(feature = "balance")]
+async fn fetch_ltc(address: &str) -> Result<Balance, BalanceError> {
+    let base_url = endpoints::LITECOINSPACE.base_url();
+    Esplora::new(base_url, Chain::Litecoin).fetch(address).await
+}
+
+fn dcr_url(address: &str) -> String {
+    let url = format!(
+        "{}/api/address/{}/totals",
+        endpoints::DCRDATA.base_url(),
+        address
+    );
+    tracing::debug!(%url, "GET");
+    url
+}
+
+fn dcr_balance(response: &DcrdataAddressTotalsResponse) -> Result<Balance, BalanceError> {
+    let atoms = (response.dcr_unspent * 100_000_000.0).round();
+    if atoms < 0.0 {
+        return Err(BalanceError::Api(
+            "Unexpected negative balance from dcrdata".into(),
+        ));
+    }
+    let confirmed = atoms as u128;
+
+    Ok(Balance {
+        chain: Chain::Decred,
+        confirmed,
+        unconfirmed: 0,
+    })
+}
+
+/// Turn an error status into [`BalanceError::InvalidAddress`] when the
+/// explorer rejected `address` itself.
+#[cfg(feature = "balance")]
+fn check_status(
+    response: reqwest::Response,
+    address: &str,
+) -> Result<reqwest::Response, BalanceError> {
+    response.error_for_status().map_err(|e| {
+        if e.status()
+            .is_some_and(|status| is_invalid_address_status(status.as_u16()))
+        {
+            BalanceError::InvalidAddress(address.to_string())
+        } else {
+            BalanceError::Request(e)
+        }
+    })
+}
+
+#[cfg(feature = "balance")]
+async fn fetch_dcr(address: &str) -> Result<Balance, BalanceError> {
+    let url = dcr_url(address);
+    let response = crate::http::client().get(&url).send().await?;
+    dcr_balance(&check_status(response, address)?.json().await?)
+}
+
+fn ar_url(address: &str) -> String {
+    let url = format!(
+        "{}/wallet/{}/balance",
+        endpoints::ARWEAVE.base_url(),
+        address
+    );
+    tracing::debug!(%url, "GET");
+    url
+}
+
+fn ar_balance(text: &str) -> Result<Balance, BalanceError> {
+    let winston: u128 = text
+        .trim()
+        .parse()
+        .map_err(|_| BalanceError::Api(format!("Failed to parse Arweave balance: {}", text)))?;
+
+    Ok(Balance {
+        chain: Chain::Arweave,
+        confirmed: winston,
+        unconfirmed: 0,
+    })
+}
+
+#[cfg(feature = "balance")]
+async fn fetch_ar(address: &str) -> Result<Balance, BalanceError> {
+    let url = ar_url(address);
+    let response = crate::http::client().get(&url).send().await?;
+    ar_balance(&check_status(response, address)?.text().await?)
+}
+
+fn blockchair_url(address: &str, chain: Chain) -> Result<String, BalanceError> {
+    let slug = chain
+        .blockchair_slug()
+        .ok_or_else(|| BalanceError::UnsupportedChain(chain.name().to_string()))?;
+    let url = format!(
+        "{}/{}/dashboards/address/{}",
+        endpoints::BLOCKCHAIR.base_url(),
+        slug,
+        address
+    );
+    tracing::debug!(%url, "GET");
+    Ok(url)
+}
+
+/// Blockchair folds mempool activity into one balance, reported here as
+/// confirmed.
+fn blockchair_balance(
+    response: &BlockchairDashboardResponse,
+    chain: Chain,
+) -> Result<Balance, BalanceError> {
+    let dashboard = response
+        .data
+        .values()
+        .next()
+        .ok_or_else(|| BalanceError::Api("Blockchair returned no address data".into()))?;
+
+    Ok(Balance {
+        chain,
+        confirmed: u128::from(dashboard.address.balance),
+        unconfirmed: 0,
+    })
+}
+
+#[cfg(feature = "balance")]
+async fn fetch_blockchair(address: &str, chain: Chain) -> Result<Balance, BalanceError> {
+    let url = blockchair_url(address, chain)?;
+    let response = crate::http::client().get(&url).send().await?;
+    blockchair_balance(&check_status(response, address)?.json().await?, chain)
+}
+
+/// Height of the best block on the public explorer for `chain`; only the
+/// Esplora chains (Bitcoin, Litecoin) report one.
+#[cfg(feature = "balance")]
+pub async fn tip_height(chain: Chain) -> Result<u64, BalanceError> {
+    match chain {
+        Chain::Bitcoin => {
+            Esplora::new(endpoints::MEMPOOL.base_url(), chain)
+                .tip_height()
+                .await
+        }
+        Chain::Litecoin => {
+            Esplora::new(endpoints::LITECOINSPACE.base_url(), chain)
+                .tip_height()
+                .await
+        }
+        _ => Err(BalanceError::UnsupportedChain(chain.name().to_string())),
+    }
+}
+
+#[cfg(feature = "balance")]
+/// Fetch the balance of `address` from the public explorer for `chain`.
+pub async fn fetch(address: &str, chain: Chain) -> Result<Balance, BalanceError> {
+    match chain {
+        Chain::Bitcoin => fetch_btc(address).await,
+        Chain::Ethereum => fetch_eth(address).await,
+        Chain::Litecoin => fetch_ltc(address).await,
+        Chain::Decred => fetch_dcr(address).await,
+        Chain::Arweave => fetch_ar(address).await,
+        Chain::Dogecoin | Chain::BitcoinCash | Chain::Zcash | Chain::Dash => {
+            fetch_blockchair(address, chain).await
+        }
+        _ => Err(BalanceError::UnsupportedChain(chain.name().to_string())),
+    }
+}
+
+#[cfg(feature = "balance")]
+/// Fetch the balance of `address` from an explicit backend.
+pub async fn fetch_with<P: BalanceProvider + Sync>(
+    provider: &P,
+    address: &str,
+) -> Result<Balance, BalanceError> {
+    provider.fetch(address).await
+}
+
+#[cfg(feature = "balance")]
+/// Like [`fetch`], but answers from `cache` while the entry is fresh and
+/// stores successful lookups in it.
+pub async fn fetch_cached(
+    cache: &BalanceCache,
+    address: &str,
+    chain: Chain,
+) -> Result<Balance, BalanceError> {
+    if let Some(balance) = cache.get(address, chain) {
+        tracing::debug!(address, "balance cache hit");
+        return Ok(balance);
+    }
+    let balance = fetch(address, chain).await?;
+    // A cache that can't be written only costs a refetch next time.
+    cache.put(address, &balance).ok();
+    Ok(balance)
+}
+
+#[cfg(feature = "balance")]
+pub async fn fetch_many(addresses: &[(&str, Chain)]) -> Vec<Result<Balance, BalanceError>> {
+    let futures: Vec<_> = addresses
+        .iter()
+        .map(|(addr, chain)| fetch(addr, *chain))
+        .collect();
+    futures::future::join_all(futures).await
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_btc_conversion() {
+        let balance = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 100_000_000,
+            unconfirmed: 50_000_000,
+        };
+
+        assert_eq!(balance.confirmed_btc(), 1.0);
+        assert_eq!(balance.total_btc(), 1.5);
+        assert_eq!(balance.total(), 150_000_000);
+    }
+
+    #[test]
+    fn test_balance_negative_unconfirmed() {
+        let balance = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 100_000_000,
+            unconfirmed: -30_000_000,
+        };
+
+        assert_eq!(balance.total(), 70_000_000);
+        assert_eq!(balance.total_btc(), 0.7);
+        assert!(balance.has_unconfirmed_spend());
+        assert!(!Balance::default().has_unconfirmed_spend());
+    }
+
+    #[test]
+    fn test_balance_add() {
+        let primary = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 100_000_000,
+            unconfirmed: 0,
+        };
+        let extra = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 50_000_000,
+            unconfirmed: -20_000_000,
+        };
+
+        let sum = primary + extra;
+        assert_eq!(sum.confirmed, 150_000_000);
+        assert_eq!(sum.unconfirmed, -20_000_000);
+        assert_eq!(sum.total(), 130_000_000);
+    }
+
+    #[test]
+    fn test_balance_zero() {
+        let balance = Balance::default();
+
+        assert_eq!(balance.confirmed, 0);
+        assert_eq!(balance.unconfirmed, 0);
+        assert_eq!(balance.confirmed_btc(), 0.0);
+        assert_eq!(balance.total_btc(), 0.0);
+        assert_eq!(balance.total(), 0);
+    }
+
+    #[test]
+    fn test_balance_max_btc_supply() {
+        let balance = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 2_100_000_000_000_000,
+            unconfirmed: 0,
+        };
+
+        assert_eq!(balance.confirmed_btc(), 21_000_000.0);
+        assert_eq!(balance.total_btc(), 21_000_000.0);
+    }
+
+    #[test]
+    fn test_balance_negative_total_from_large_pending_outgoing() {
+        let balance = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 100_000_000,
+            unconfirmed: -150_000_000,
+        };
+
+        assert_eq!(balance.total(), -50_000_000);
+        assert_eq!(balance.total_btc(), -0.5);
+    }
+
+    #[test]
+    fn test_balance_native_units_follow_chain() {
+        let btc = Balance {
+            chain: Chain::Bitcoin,
+            confirmed: 150_000_000,
+            unconfirmed: -50_000_000,
+        };
+        assert_eq!(btc.confirmed_native(), 1.5);
+        assert_eq!(btc.total_native(), 1.0);
+
+        let eth = Balance {
+            chain: Chain::Ethereum,
+            confirmed: 2_000_000_000_000_000_000,
+            unconfirmed: 0,
+        };
+        assert_eq!(eth.confirmed_native(), 2.0);
+
+        let ar = Balance {
+            confirmed: 500_000_000_000,
+            ..Balance::zero(Chain::Arweave)
+        };
+        assert_eq!(ar.total_native(), 0.5);
+    }
+
+    #[test]
+    fn test_balance_eth_conversion() {
+        let balance = Balance {
+            chain: Chain::Ethereum,
+            confirmed: 1_000_000_000_000_000_000,
+            unconfirmed: 0,
+        };
+
+        assert_eq!(balance.confirmed_eth(), 1.0);
+        assert_eq!(balance.total_eth(), 1.0);
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_btc_satoshi_genesis_address_has_funds() {
+        let result = fetch("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", Chain::Bitcoin).await;
+        assert!(result.is_ok());
+        let balance = result.unwrap();
+        assert!(balance.confirmed > 0);
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_btc_invalid_address_returns_error() {
+        let result = fetch("invalid_address_xyz", Chain::Bitcoin).await;
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_btc_valid_empty_address() {
+        let result = fetch("1111111111111111111114oLvT2", Chain::Bitcoin).await;
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_many_btc_known_addresses() {
+        let genesis = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa";
+        let satoshi_dice = "1dice8EMZmqKvrGE4Qc9bUFf9PX3xaYDp";
+        let results =
+            fetch_many(&[(genesis, Chain::Bitcoin), (satoshi_dice, Chain::Bitcoin)]).await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_eth_vitalik_address() {
+        let result = fetch(
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            Chain::Ethereum,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_balance_ltc_conversion() {
+        let balance = Balance {
+            chain: Chain::Litecoin,
+            confirmed: 100_000_000,
+            unconfirmed: 0,
+        };
+
+        assert_eq!(balance.confirmed_ltc(), 1.0);
+        assert_eq!(balance.total_ltc(), 1.0);
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_ltc_known_address() {
+        let result = fetch("LVuDpNCSSj6pQ7t9Pv6d6sUkLKoqDEVUnJ", Chain::Litecoin).await;
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_dcr_known_address() {
+        let result = fetch("DsRaAja82UvgnqYaBHYFuyCKURFX2rCyEJ8", Chain::Decred).await;
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_dcr_invalid_address_returns_error() {
+        let result = fetch("invalid_address_xyz", Chain::Decred).await;
+        assert!(matches!(result, Err(BalanceError::InvalidAddress(_))));
+    }
+
+    #[test]
+    fn test_blockchair_dashboard_balance() {
+        let response: BlockchairDashboardResponse = serde_json::from_str(
+            r#"{"data":{"DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE":{"address":{"type":"pubkeyhash","balance":250000000},"transactions":[]}},"context":{"code":200}}"#,
+        )
+        .unwrap();
+        let balance = blockchair_balance(&response, Chain::Dogecoin).unwrap();
+        assert_eq!(balance.chain, Chain::Dogecoin);
+        assert_eq!(balance.confirmed, 250_000_000);
+        assert_eq!(balance.total_native(), 2.5);
+
+        assert!(matches!(
+            blockchair_url("DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE", Chain::Monero),
+            Err(BalanceError::UnsupportedChain(_))
+        ));
+
+        let empty: BlockchairDashboardResponse = serde_json::from_str(r#"{"data":{}}"#).unwrap();
+        assert!(matches!(
+            blockchair_balance(&empty, Chain::Dash),
+            Err(BalanceError::Api(_))
+        ));
+    }
+
+    #[test]
+    fn test_balance_ar_conversion() {
+        let balance = Balance {
+            chain: Chain::Arweave,
+            confirmed: 1_000_000_000_000,
+            unconfirmed: 0,
+        };
+
+        assert_eq!(balance.confirmed_ar(), 1.0);
+        assert_eq!(balance.total_ar(), 1.0);
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_ar_known_address() {
+        let result = fetch(
+            "PbdTDYikdddWfNFlDt2aZokALXKe1mJVSC9TALUBNv8",
+            Chain::Arweave,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    #[ignore = "requires network access"]
+    async fn test_fetch_unsupported_chain() {
+        let result = fetch("some_address", Chain::Monero).await;
+        assert!(matches!(result, Err(BalanceError::UnsupportedChain(_))));
+    }
+
+    #[cfg(feature = "balance")]
+    #[tokio::test]
+    async fn test_tip_height_unsupported_chain() {
+        let result = tip_height(Chain::Ethereum).await;
+        assert!(matches!(result, Err(BalanceError::UnsupportedChain(_))));
+    }
+}
@@ -0,0 +1,75 @@
+//! Public API endpoints and the environment variables that override them.
+
+/// A public API whose base URL can be redirected, e.g. to a local mirror or
+/// a mock server in tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endpoint {
+    /// Environment variable holding the replacement base URL.
+    pub var: &'static str,
+    pub default: &'static str,
+}
+
+impl Endpoint {
+    /// Base URL without a trailing slash, from `var` when set.
+    pub fn base_url(&self) -> String {
+        std::env::var(self.var).map_or_else(
+            |_| self.default.to_string(),
+            |url| url.trim_end_matches('/').to_string(),
+        )
+    }
+}
+
+pub const MEMPOOL: Endpoint = Endpoint {
+    var: "BOHA_MEMPOOL_URL",
+    default: "https://mempool.space",
+};
+
+pub const ETHERSCAN: Endpoint = Endpoint {
+    var: "BOHA_ETHERSCAN_URL",
+    default: "https://api.etherscan.io",
+};
+
+pub const BLOCKSCOUT: Endpoint = Endpoint {
+    var: "BOHA_BLOCKSCOUT_URL",
+    default: "https://eth.blockscout.com",
+};
+
+pub const LITECOINSPACE: Endpoint = Endpoint {
+    var: "BOHA_LITECOINSPACE_URL",
+    default: "https://litecoinspace.org",
+};
+
+pub const DCRDATA: Endpoint = Endpoint {
+    var: "BOHA_DCRDATA_URL",
+    default: "https://dcrdata.decred.org",
+};
+
+pub const ARWEAVE: Endpoint = Endpoint {
+    var: "BOHA_ARWEAVE_URL",
+    default: "https://arweave.net",
+};
+
+/// Dogecoin, Bitcoin Cash, Zcash and Dash, under `/{chain slug}/...`.
+pub const BLOCKCHAIR: Endpoint = Endpoint {
+    var: "BOHA_BLOCKCHAIR_URL",
+    default: "https://api.blockchair.com",
+};
+
+pub const COINGECKO: Endpoint = Endpoint {
+    var: "BOHA_COINGECKO_URL",
+    default: "https://api.coingecko.com",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_override_uses_public_api() {
+        let endpoint = Endpoint {
+            var: "BOHA_TEST_UNSET_ENDPOINT_URL",
+            default: "https://example.org",
+        };
+        assert_eq!(endpoint.base_url(), "https://example.org");
+    }
+}
@@ -0,0 +1,20 @@
+//! Network clients for boha puzzle addresses: live balances (`balance`
+//! feature, or `balance-blocking` for callers without an async runtime) and
+//! fiat prices (`price` feature).
+//!
+//! The async lookups go through the shared `http` client, which carries the
+//! proxy.
+//!
+//! [`endpoints`] is always available so other tools reach the same explorer
+//! APIs, with the same environment overrides, without pulling in an HTTP stack.
+
+pub mod endpoints;
+
+#[cfg(any(feature = "balance", feature = "balance-blocking"))]
+pub mod balance;
+
+#[cfg(any(feature = "balance", feature = "price"))]
+pub mod http;
+
+#[cfg(feature = "price")]
+pub mod price;
@@ -0,0 +1,100 @@
+//! On-disk cache of historical prices, one JSON file per coin, day and
+//! currency.
+//!
+//! A past day's price never changes, so entries don't expire. Days without
+//! a price are cached too, so they aren't asked for again.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceCache {
+    dir: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    rate: Option<f64>,
+}
+
+impl PriceCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Cache under the user's XDG cache directory, if one can be found.
+    pub fn open_default() -> Option<Self> {
+        default_dir().map(Self::new)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cached price of `symbol` on `date`: `Some(None)` if the day is known
+    /// to have no price, `None` if it was never fetched.
+    #[allow(clippy::option_option)]
+    pub fn get(&self, symbol: &str, date: &str, currency: &str) -> Option<Option<f64>> {
+        let text = fs::read_to_string(self.path(symbol, date, currency)).ok()?;
+        let entry: Entry = serde_json::from_str(&text).ok()?;
+        Some(entry.rate)
+    }
+
+    pub fn put(
+        &self,
+        symbol: &str,
+        date: &str,
+        currency: &str,
+        rate: Option<f64>,
+    ) -> io::Result<()> {
+        let json = serde_json::to_string(&Entry { rate }).map_err(io::Error::other)?;
+
+        fs::create_dir_all(&self.dir)?;
+        // Write then rename, so concurrent readers never see half a file.
+        let path = self.path(symbol, date, currency);
+        let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &path)
+    }
+
+    fn path(&self, symbol: &str, date: &str, currency: &str) -> PathBuf {
+        let name: String = format!("{}-{}-{}", symbol, date, currency)
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect();
+        self.dir.join(format!("{}.json", name.to_lowercase()))
+    }
+}
+
+/// `$XDG_CACHE_HOME/boha/prices`, falling back to `$HOME/.cache`.
+pub fn default_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(base.join("boha").join("prices"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remembers_prices_and_missing_days() {
+        let cache = PriceCache::new(
+            std::env::temp_dir().join(format!("boha-price-cache-test-{}", std::process::id())),
+        );
+        assert_eq!(cache.get("BTC", "2013-11-20", "usd"), None);
+
+        cache.put("BTC", "2013-11-20", "usd", Some(550.5)).unwrap();
+        cache.put("AR", "2010-01-01", "usd", None).unwrap();
+        assert_eq!(cache.get("BTC", "2013-11-20", "usd"), Some(Some(550.5)));
+        assert_eq!(cache.get("BTC", "2013-11-20", "eur"), None);
+        assert_eq!(cache.get("AR", "2010-01-01", "usd"), Some(None));
+
+        fs::remove_dir_all(cache.dir()).ok();
+    }
+}
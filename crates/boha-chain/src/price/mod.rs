@@ -0,0 +1,318 @@
+//! Fiat valuation of prizes.
+//!
+//! A [`PriceProvider`] turns currency symbols (`BTC`, `ETH`, `DAI`, ...) into
+//! spot prices in a fiat currency. [`CoinGecko`] is the default source.
+//! Historical prices, which never change, are kept in a [`PriceCache`].
+
+pub mod cache;
+
+pub use cache::PriceCache;
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use boha::Puzzle;
+
+#[derive(Debug, Error)]
+pub enum PriceError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Unsupported currency: {0}")]
+    UnsupportedCurrency(String),
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+}
+
+/// Spot prices of crypto symbols in one fiat currency.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Prices {
+    currency: String,
+    rates: HashMap<String, f64>,
+}
+
+impl Prices {
+    /// `currency` is a lowercase fiat code (`usd`, `eur`, ...).
+    pub fn new(currency: impl Into<String>, rates: HashMap<String, f64>) -> Self {
+        Self {
+            currency: currency.into(),
+            rates,
+        }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Price of one unit of `symbol`, if the provider knows it.
+    pub fn rate(&self, symbol: &str) -> Option<f64> {
+        self.rates.get(symbol).copied()
+    }
+
+    /// `amount` of `symbol` in the fiat currency.
+    pub fn value(&self, amount: f64, symbol: &str) -> Option<f64> {
+        self.rate(symbol).map(|rate| amount * rate)
+    }
+}
+
+/// A source of spot prices.
+pub trait PriceProvider {
+    /// Short human-readable source name, used in error messages.
+    fn name(&self) -> &'static str;
+
+    /// Prices of `symbols` in `currency`. Symbols the provider does not know
+    /// are left out of the result.
+    fn fetch(
+        &self,
+        symbols: &[&str],
+        currency: &str,
+    ) -> impl Future<Output = Result<Prices, PriceError>> + Send;
+
+    /// Price of one `symbol` in `currency` on `date` (`YYYY-MM-DD`), or
+    /// `None` if the provider has no price for that day.
+    fn fetch_historical(
+        &self,
+        symbol: &str,
+        date: &str,
+        currency: &str,
+    ) -> impl Future<Output = Result<Option<f64>, PriceError>> + Send;
+}
+
+/// Prize of a solved puzzle valued at the price of its solve date.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoricalValue {
+    pub currency: String,
+    /// Day the price was taken from (`YYYY-MM-DD`).
+    pub date: String,
+    /// Price of one unit of the prize currency on `date`.
+    pub rate: f64,
+    pub value: f64,
+}
+
+/// CoinGecko `simple/price` API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinGecko {
+    base_url: String,
+}
+
+impl CoinGecko {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl Default for CoinGecko {
+    /// Public API, unless `BOHA_COINGECKO_URL` points elsewhere.
+    fn default() -> Self {
+        Self::new(crate::endpoints::COINGECKO.base_url())
+    }
+}
+
+/// CoinGecko coin id for a currency symbol.
+pub fn coingecko_id(symbol: &str) -> Option<&'static str> {
+    match symbol {
+        "BTC" => Some("bitcoin"),
+        "ETH" => Some("ethereum"),
+        "LTC" => Some("litecoin"),
+        "XMR" => Some("monero"),
+        "DCR" => Some("decred"),
+        "AR" => Some("arweave"),
+        "DOGE" => Some("dogecoin"),
+        "BCH" => Some("bitcoin-cash"),
+        "ZEC" => Some("zcash"),
+        "DASH" => Some("dash"),
+        "DAI" => Some("dai"),
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+struct CoinGeckoHistory {
+    market_data: Option<CoinGeckoMarketData>,
+}
+
+#[derive(Deserialize)]
+struct CoinGeckoMarketData {
+    current_price: HashMap<String, f64>,
+}
+
+impl PriceProvider for CoinGecko {
+    fn name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn fetch(&self, symbols: &[&str], currency: &str) -> Result<Prices, PriceError> {
+        let currency = currency.to_lowercase();
+        let known: Vec<(&str, &str)> = symbols
+            .iter()
+            .filter_map(|symbol| coingecko_id(symbol).map(|id| (*symbol, id)))
+            .collect();
+        if known.is_empty() {
+            return Ok(Prices::new(currency, HashMap::new()));
+        }
+
+        let ids: Vec<&str> = known.iter().map(|(_, id)| *id).collect();
+        let url = format!(
+            "{}/api/v3/simple/price?ids={}&vs_currencies={}",
+            self.base_url,
+            ids.join(","),
+            currency
+        );
+        tracing::debug!(%url, "GET");
+        let response: HashMap<String, HashMap<String, f64>> = crate::http::client()
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let rates: HashMap<String, f64> = known
+            .iter()
+            .filter_map(|(symbol, id)| {
+                let rate = response.get(*id)?.get(&currency)?;
+                Some(((*symbol).to_string(), *rate))
+            })
+            .collect();
+        // CoinGecko answers an unknown fiat code with empty objects.
+        if rates.is_empty() && response.values().all(HashMap::is_empty) {
+            return Err(PriceError::UnsupportedCurrency(currency));
+        }
+        Ok(Prices::new(currency, rates))
+    }
+
+    async fn fetch_historical(
+        &self,
+        symbol: &str,
+        date: &str,
+        currency: &str,
+    ) -> Result<Option<f64>, PriceError> {
+        let Some(id) = coingecko_id(symbol) else {
+            return Ok(None);
+        };
+        // The history endpoint wants DD-MM-YYYY.
+        let (year, month, day) = split_date(date)?;
+        let url = format!(
+            "{}/api/v3/coins/{}/history?date={}-{}-{}&localization=false",
+            self.base_url, id, day, month, year
+        );
+        tracing::debug!(%url, "GET");
+        let response: CoinGeckoHistory = crate::http::client()
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        // No market data means the coin wasn't trading yet.
+        Ok(response
+            .market_data
+            .and_then(|data| data.current_price.get(&currency.to_lowercase()).copied()))
+    }
+}
+
+/// `(year, month, day)` of a `YYYY-MM-DD` date.
+fn split_date(date: &str) -> Result<(&str, &str, &str), PriceError> {
+    let invalid = || PriceError::InvalidDate(date.to_string());
+    let mut parts = date.split('-');
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    let digits = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+    if digits(year, 4) && digits(month, 2) && digits(day, 2) {
+        Ok((year, month, day))
+    } else {
+        Err(invalid())
+    }
+}
+
+/// Prices of `symbols` in `currency` from [`CoinGecko::default`].
+pub async fn fetch(symbols: &[&str], currency: &str) -> Result<Prices, PriceError> {
+    CoinGecko::default().fetch(symbols, currency).await
+}
+
+/// Value of `puzzle`'s prize at its solve date, from [`CoinGecko::default`].
+///
+/// `None` when the puzzle has no prize or solve date, or no price is known
+/// for that day. Prices found (or found missing) are stored in `cache`.
+pub async fn historical_value(
+    puzzle: &Puzzle,
+    currency: &str,
+    cache: Option<&PriceCache>,
+) -> Result<Option<HistoricalValue>, PriceError> {
+    let (Some(prize), Some(solve_date)) = (puzzle.prize, puzzle.solve_date) else {
+        return Ok(None);
+    };
+    let date = format!(
+        "{:04}-{:02}-{:02}",
+        solve_date.year(),
+        solve_date.month(),
+        solve_date.day()
+    );
+    let date = date.as_str();
+    let symbol = puzzle.currency();
+    let currency = currency.to_lowercase();
+
+    let cached = cache.and_then(|c| c.get(symbol, date, &currency));
+    let rate = if let Some(rate) = cached {
+        tracing::debug!(symbol, date, %currency, "price cache hit");
+        rate
+    } else {
+        let rate = CoinGecko::default()
+            .fetch_historical(symbol, date, &currency)
+            .await?;
+        if let Some(cache) = cache {
+            // A cache that can't be written only costs a refetch next time.
+            cache.put(symbol, date, &currency, rate).ok();
+        }
+        rate
+    };
+
+    Ok(rate.map(|rate| HistoricalValue {
+        currency,
+        date: date.to_string(),
+        rate,
+        value: (prize * rate * 100.0).round() / 100.0,
+    }))
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_uses_rate_of_symbol() {
+        let prices = Prices::new("usd", HashMap::from([("BTC".to_string(), 50_000.0)]));
+        assert_eq!(prices.currency(), "usd");
+        assert_eq!(prices.value(0.5, "BTC"), Some(25_000.0));
+        assert_eq!(prices.value(1.0, "ETH"), None);
+    }
+
+    #[test]
+    fn every_chain_has_a_coingecko_id() {
+        for chain in boha::Chain::ALL {
+            assert!(coingecko_id(chain.symbol()).is_some(), "{:?}", chain);
+        }
+        assert_eq!(coingecko_id("DOGE"), Some("dogecoin"));
+        assert_eq!(coingecko_id("BCH"), Some("bitcoin-cash"));
+        assert_eq!(coingecko_id("ZEC"), Some("zcash"));
+        assert_eq!(coingecko_id("DASH"), Some("dash"));
+        assert_eq!(coingecko_id("SOL"), None);
+    }
+
+    #[test]
+    fn split_date_requires_iso_day() {
+        assert_eq!(split_date("2013-11-20").unwrap(), ("2013", "11", "20"));
+        assert!(split_date("2013-11").is_err());
+        assert!(split_date("20-11-2013").is_err());
+        assert!(split_date("2013-11-20-01").is_err());
+    }
+}
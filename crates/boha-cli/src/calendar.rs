@@ -0,0 +1,161 @@
+//! iCalendar (RFC 5545) events for puzzle milestones.
+
+use boha::{Puzzle, Status};
+use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
+
+/// Octets per content line before folding.
+const LINE_LIMIT: usize = 75;
+
+struct Event {
+    uid: String,
+    start: Start,
+    yearly: bool,
+    summary: String,
+    description: String,
+    url: String,
+}
+
+enum Start {
+    At(DateTime<Utc>),
+    /// All-day, for anniversaries.
+    Day(NaiveDateTime),
+}
+
+fn parse(date: &str) -> Option<NaiveDateTime> {
+    date.parse::<boha::DateTime>()
+        .ok()
+        .map(boha::DateTime::naive_datetime)
+}
+
+/// Funding and solve events for `puzzles`; with `anniversaries`, also a
+/// yearly all-day reminder of the funding date for each unsolved puzzle.
+pub fn ics(puzzles: &[&Puzzle], generated: DateTime<Utc>, anniversaries: bool) -> String {
+    let mut events = Vec::new();
+    for puzzle in puzzles {
+        let url = puzzle
+            .source_url
+            .map_or_else(|| puzzle.explorer_url(), str::to_string);
+        let prize = puzzle
+            .prize
+            .map(|p| format!("Prize {} {}. ", p, puzzle.currency()))
+            .unwrap_or_default();
+        let funded = puzzle
+            .start_naive()
+            .or_else(|| puzzle.funding_tx().and_then(|tx| tx.date).and_then(parse));
+
+        if let Some(funded) = funded {
+            events.push(Event {
+                uid: format!("{}-funded@boha", puzzle.uuid),
+                start: Start::At(funded.and_utc()),
+                yearly: false,
+                summary: format!("{} funded", puzzle.id),
+                description: format!("{}Address {}.", prize, puzzle.address.value),
+                url: url.clone(),
+            });
+            if anniversaries && puzzle.status == Status::Unsolved {
+                events.push(Event {
+                    uid: format!("{}-anniversary@boha", puzzle.uuid),
+                    start: Start::Day(
+                        funded
+                            .with_year(funded.year() + 1)
+                            .unwrap_or(funded + chrono::Duration::days(365)),
+                    ),
+                    yearly: true,
+                    summary: format!("{} unsolved since {}", puzzle.id, funded.year()),
+                    description: format!("{}Address {}.", prize, puzzle.address.value),
+                    url: url.clone(),
+                });
+            }
+        }
+
+        if puzzle.status != Status::Unsolved {
+            if let Some(solved) = puzzle.solve_naive() {
+                let after = puzzle
+                    .solve_time_formatted()
+                    .map(|d| format!("Open for {}. ", d))
+                    .unwrap_or_default();
+                events.push(Event {
+                    uid: format!("{}-solved@boha", puzzle.uuid),
+                    start: Start::At(solved.and_utc()),
+                    yearly: false,
+                    summary: format!("{} {}", puzzle.id, puzzle.status),
+                    description: format!("{}{}", prize, after).trim_end().to_string(),
+                    url,
+                });
+            }
+        }
+    }
+
+    let stamp = generated.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//oritwoen//boha//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "X-WR-CALNAME:boha puzzle milestones".to_string(),
+    ];
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        match event.start {
+            Start::At(at) => lines.push(format!("DTSTART:{}", at.format("%Y%m%dT%H%M%SZ"))),
+            Start::Day(day) => lines.push(format!("DTSTART;VALUE=DATE:{}", day.format("%Y%m%d"))),
+        }
+        if event.yearly {
+            lines.push("RRULE:FREQ=YEARLY".to_string());
+        }
+        lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+        if !event.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape(&event.description)));
+        }
+        lines.push(format!("URL:{}", event.url));
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+/// TEXT value escaping.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Split `line` into 75-octet pieces joined by CRLF + space, never inside
+/// a UTF-8 sequence.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + line.len() / LINE_LIMIT * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        // Continuation lines spend one octet on the leading space.
+        if width + c.len_utf8() > LINE_LIMIT {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_lines_fold_at_75_octets() {
+        let line = format!("DESCRIPTION:{}", "é".repeat(60));
+        let folded = fold(&line);
+        assert!(folded.split("\r\n").all(|part| part.len() <= LINE_LIMIT));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn text_is_escaped() {
+        assert_eq!(escape("a, b; c\\d"), "a\\, b\\; c\\\\d");
+    }
+}
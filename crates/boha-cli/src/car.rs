@@ -0,0 +1,328 @@
+//! IPFS UnixFS import and CARv1 output for dataset snapshots.
+//!
+//! Mirrors `ipfs add --cid-version 1` defaults: 256 KiB chunks stored as raw
+//! leaves, balanced layout with up to 174 links per node, so a mirror that
+//! imports the CAR pins the same root CID printed here.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+const CHUNK_SIZE: usize = 256 * 1024;
+const MAX_LINKS: usize = 174;
+
+const CODEC_RAW: u64 = 0x55;
+const CODEC_DAG_PB: u64 = 0x70;
+const MULTIHASH_SHA2_256: u64 = 0x12;
+
+const UNIXFS_DIRECTORY: u64 = 1;
+const UNIXFS_FILE: u64 = 2;
+
+/// CIDv1 with a SHA-256 multihash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cid(Vec<u8>);
+
+impl Cid {
+    fn of(codec: u64, block: &[u8]) -> Self {
+        let mut bytes = Vec::new();
+        put_varint(&mut bytes, 1);
+        put_varint(&mut bytes, codec);
+        put_varint(&mut bytes, MULTIHASH_SHA2_256);
+        put_varint(&mut bytes, 32);
+        bytes.extend_from_slice(&Sha256::digest(block));
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Cid {
+    /// Multibase base32 (`b...`), the form `ipfs` prints for CIDv1.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "b{}", base32_lower(&self.0))
+    }
+}
+
+/// An imported file or directory tree: its root and every block under it.
+pub struct Dag {
+    pub root: Cid,
+    /// Blocks in creation order, children before parents.
+    blocks: Vec<(Cid, Vec<u8>)>,
+}
+
+/// A node as seen from its parent's link.
+struct Node {
+    cid: Cid,
+    /// Cumulative size of the node's block and everything below it.
+    tsize: u64,
+    /// Bytes of file content under the node.
+    filesize: u64,
+}
+
+/// Import the file or directory at `path`.
+pub fn import(path: &Path) -> io::Result<Dag> {
+    let mut blocks = Vec::new();
+    let root = add_path(path, &mut blocks)?.cid;
+    Ok(Dag { root, blocks })
+}
+
+fn add_path(path: &Path, blocks: &mut Vec<(Cid, Vec<u8>)>) -> io::Result<Node> {
+    if !path.is_dir() {
+        return Ok(add_file(&fs::read(path)?, blocks));
+    }
+
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("non UTF-8 file name: {}", name.to_string_lossy()),
+            )
+        })?;
+        entries.push((name, entry.path()));
+    }
+    // dag-pb requires directory links sorted by name bytes.
+    entries.sort();
+
+    let mut links = Vec::with_capacity(entries.len());
+    for (name, child) in entries {
+        links.push((name, add_path(&child, blocks)?));
+    }
+
+    let mut data = Vec::new();
+    put_varint_field(&mut data, 1, UNIXFS_DIRECTORY);
+    let node = pb_node(&links, &data);
+    Ok(push_pb(
+        node,
+        links.iter().map(|(_, n)| n.tsize).sum(),
+        0,
+        blocks,
+    ))
+}
+
+fn add_file(content: &[u8], blocks: &mut Vec<(Cid, Vec<u8>)>) -> Node {
+    let mut level: Vec<Node> = if content.is_empty() {
+        vec![push_raw(Vec::new(), blocks)]
+    } else {
+        content
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| push_raw(chunk.to_vec(), blocks))
+            .collect()
+    };
+
+    while level.len() > 1 {
+        level = level
+            .chunks(MAX_LINKS)
+            .map(|children| {
+                let mut data = Vec::new();
+                put_varint_field(&mut data, 1, UNIXFS_FILE);
+                let filesize = children.iter().map(|c| c.filesize).sum();
+                put_varint_field(&mut data, 3, filesize);
+                for child in children {
+                    put_varint_field(&mut data, 4, child.filesize);
+                }
+                let links: Vec<_> = children.iter().map(|c| (String::new(), c)).collect();
+                let node = pb_node(&links, &data);
+                let tsize = children.iter().map(|c| c.tsize).sum();
+                push_pb(node, tsize, filesize, blocks)
+            })
+            .collect();
+    }
+    level.pop().expect("at least one chunk")
+}
+
+fn push_raw(chunk: Vec<u8>, blocks: &mut Vec<(Cid, Vec<u8>)>) -> Node {
+    let cid = Cid::of(CODEC_RAW, &chunk);
+    let size = chunk.len() as u64;
+    blocks.push((cid.clone(), chunk));
+    Node {
+        cid,
+        tsize: size,
+        filesize: size,
+    }
+}
+
+fn push_pb(
+    block: Vec<u8>,
+    children_tsize: u64,
+    filesize: u64,
+    blocks: &mut Vec<(Cid, Vec<u8>)>,
+) -> Node {
+    let cid = Cid::of(CODEC_DAG_PB, &block);
+    let tsize = block.len() as u64 + children_tsize;
+    blocks.push((cid.clone(), block));
+    Node {
+        cid,
+        tsize,
+        filesize,
+    }
+}
+
+/// dag-pb `PBNode`: links (field 2) come before data (field 1).
+fn pb_node<N: std::borrow::Borrow<Node>>(links: &[(String, N)], data: &[u8]) -> Vec<u8> {
+    let mut node = Vec::new();
+    for (name, child) in links {
+        let child = child.borrow();
+        let mut link = Vec::new();
+        put_bytes_field(&mut link, 1, child.cid.as_bytes());
+        put_bytes_field(&mut link, 2, name.as_bytes());
+        put_varint_field(&mut link, 3, child.tsize);
+        put_bytes_field(&mut node, 2, &link);
+    }
+    put_bytes_field(&mut node, 1, data);
+    node
+}
+
+/// Write `dag` as a CARv1 archive with the root block first.
+pub fn write_car(dag: &Dag, mut out: impl Write) -> io::Result<()> {
+    // dag-cbor {"roots": [CID], "version": 1}
+    let mut root = vec![0x00];
+    root.extend_from_slice(dag.root.as_bytes());
+    let mut header = vec![0xa2, 0x65];
+    header.extend_from_slice(b"roots");
+    header.extend_from_slice(&[0x81, 0xd8, 0x2a]);
+    cbor_bytes_head(&mut header, root.len());
+    header.extend_from_slice(&root);
+    header.push(0x67);
+    header.extend_from_slice(b"version");
+    header.push(0x01);
+
+    let mut prefix = Vec::new();
+    put_varint(&mut prefix, header.len() as u64);
+    out.write_all(&prefix)?;
+    out.write_all(&header)?;
+
+    let root_block = dag.blocks.iter().find(|(cid, _)| *cid == dag.root);
+    let rest = dag.blocks.iter().filter(|(cid, _)| *cid != dag.root);
+    for (cid, block) in root_block.into_iter().chain(rest) {
+        let mut prefix = Vec::new();
+        put_varint(&mut prefix, (cid.as_bytes().len() + block.len()) as u64);
+        out.write_all(&prefix)?;
+        out.write_all(cid.as_bytes())?;
+        out.write_all(block)?;
+    }
+    out.flush()
+}
+
+fn cbor_bytes_head(out: &mut Vec<u8>, len: usize) {
+    match len {
+        0..=23 => out.push(0x40 | len as u8),
+        24..=0xff => out.extend_from_slice(&[0x58, len as u8]),
+        _ => {
+            out.push(0x59);
+            out.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+    }
+}
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn put_varint_field(out: &mut Vec<u8>, field: u64, value: u64) {
+    put_varint(out, field << 3);
+    put_varint(out, value);
+}
+
+fn put_bytes_field(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    put_varint(out, (field << 3) | 2);
+    put_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+/// RFC 4648 base32, lowercase, unpadded.
+fn base32_lower(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+    let mut out = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for byte in bytes {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("boha-car-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn small_file_is_a_raw_leaf() {
+        let dir = temp_dir("raw");
+        let file = dir.join("hello.txt");
+        fs::write(&file, "hello world").unwrap();
+
+        let dag = import(&file).unwrap();
+        assert_eq!(
+            dag.root.to_string(),
+            "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e"
+        );
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn empty_directory_matches_ipfs() {
+        let dir = temp_dir("empty");
+        let dag = import(&dir).unwrap();
+        assert_eq!(
+            dag.root.to_string(),
+            "bafybeiczsscdsbs7ffqz55asqdf3smv6klcw3gofszvwlyarci47bgf354"
+        );
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn large_file_is_chunked_under_a_file_node() {
+        let mut blocks = Vec::new();
+        let content = vec![7u8; CHUNK_SIZE * 2 + 1];
+        let node = add_file(&content, &mut blocks);
+
+        // Two identical full chunks, one short chunk, one parent.
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(node.filesize, content.len() as u64);
+        assert_eq!(node.cid.as_bytes()[1], CODEC_DAG_PB as u8);
+        assert_eq!(
+            node.tsize,
+            blocks.iter().map(|(_, b)| b.len() as u64).sum::<u64>()
+        );
+    }
+
+    #[test]
+    fn car_starts_with_header_and_root_block() {
+        let dir = temp_dir("car");
+        fs::write(dir.join("a.json"), "{}").unwrap();
+        let dag = import(&dir).unwrap();
+
+        let mut car = Vec::new();
+        write_car(&dag, &mut car).unwrap();
+        let header_len = car[0] as usize;
+        assert_eq!(&car[1..3], &[0xa2, 0x65]);
+        let first_block = &car[1 + header_len..];
+        let cid = dag.root.as_bytes();
+        assert_eq!(&first_block[1..=cid.len()], cid);
+        fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,110 @@
+//! Terminal colours that honour `--color`.
+//!
+//! A drop-in for `owo_colors::OwoColorize`: the same method names, but the
+//! styling is skipped when colours are off, so every command follows the
+//! `--color` / `NO_COLOR` decision made once in `main`.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::ValueEnum;
+use owo_colors::Style;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum ColorChoice {
+    /// Colour when stdout is a terminal and NO_COLOR is unset
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Decide for the whole run whether output is coloured; `terminal` says
+/// whether it lands on one.
+pub fn init(choice: ColorChoice, terminal: bool) {
+    let enabled = match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && terminal,
+    };
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `init` turned colours on.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// `value` with `style`, applied only while colours are on.
+pub struct Painted<T> {
+    value: T,
+    style: Style,
+}
+
+impl<T: fmt::Display> fmt::Display for Painted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if enabled() {
+            self.style.style(&self.value).fmt(f)
+        } else {
+            self.value.fmt(f)
+        }
+    }
+}
+
+macro_rules! styles {
+    ($($name:ident),* $(,)?) => {
+        // The whole palette is offered, whether or not a command uses it yet.
+        #[allow(dead_code)]
+        pub trait Colorize: fmt::Display {
+            $(
+                fn $name(&self) -> Painted<&Self> {
+                    Painted { value: self, style: Style::new().$name() }
+                }
+            )*
+        }
+
+        // The whole palette chains, whether or not a command uses it yet.
+        #[allow(dead_code)]
+        impl<T> Painted<T> {
+            $(
+                pub fn $name(mut self) -> Self {
+                    self.style = self.style.$name();
+                    self
+                }
+            )*
+        }
+    };
+}
+
+styles!(
+    red,
+    green,
+    yellow,
+    blue,
+    magenta,
+    cyan,
+    bold,
+    dimmed,
+    bright_black,
+    bright_red,
+    bright_green,
+    bright_yellow,
+    bright_white,
+);
+
+impl<T: fmt::Display + ?Sized> Colorize for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles_follow_the_switch() {
+        ENABLED.store(true, Ordering::Relaxed);
+        assert_eq!("ok".green().bold().to_string(), "\x1b[32;1mok\x1b[0m");
+        ENABLED.store(false, Ordering::Relaxed);
+        assert_eq!("ok".green().bold().to_string(), "ok");
+    }
+}
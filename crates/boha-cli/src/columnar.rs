@@ -0,0 +1,161 @@
+//! Parquet output for `-o parquet`: one typed row per puzzle.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef, BooleanArray, Float64Array, RecordBatch, StringArray, TimestampSecondArray,
+    UInt16Array, UInt32Array, UInt64Array,
+};
+use boha::{DateTime, Puzzle, TransactionType};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+/// Encode `puzzles` as a single-row-group Parquet file.
+///
+/// Nested data is flattened: the key is reduced to its bit range and hex,
+/// the solver to its name and the transaction list to per-type counts.
+/// Dates become UTC second timestamps.
+pub fn to_parquet(puzzles: &[&Puzzle]) -> Result<Vec<u8>, parquet::errors::ParquetError> {
+    let batch = record_batch(puzzles)?;
+    let props = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut bytes = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut bytes, batch.schema(), Some(props))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(bytes)
+}
+
+fn record_batch(puzzles: &[&Puzzle]) -> Result<RecordBatch, arrow_schema::ArrowError> {
+    fn strings(puzzles: &[&Puzzle], f: impl Fn(&Puzzle) -> Option<String>) -> ArrayRef {
+        Arc::new(puzzles.iter().map(|p| f(p)).collect::<StringArray>())
+    }
+    fn timestamps(puzzles: &[&Puzzle], f: impl Fn(&Puzzle) -> Option<DateTime>) -> ArrayRef {
+        let array: TimestampSecondArray = puzzles
+            .iter()
+            .map(|p| f(p).map(|date| date.naive_datetime().and_utc().timestamp()))
+            .collect();
+        Arc::new(array.with_timezone("UTC"))
+    }
+    fn tx_count(puzzles: &[&Puzzle], tx_type: Option<TransactionType>) -> ArrayRef {
+        Arc::new(
+            puzzles
+                .iter()
+                .map(|p| {
+                    let count = p
+                        .transactions
+                        .iter()
+                        .filter(|tx| tx_type.is_none_or(|t| tx.tx_type == t))
+                        .count();
+                    Some(u32::try_from(count).unwrap_or(u32::MAX))
+                })
+                .collect::<UInt32Array>(),
+        )
+    }
+
+    RecordBatch::try_from_iter([
+        ("id", strings(puzzles, |p| Some(p.id.to_string()))),
+        ("uuid", strings(puzzles, |p| Some(p.uuid.to_string()))),
+        (
+            "collection",
+            strings(puzzles, |p| Some(p.collection().to_string())),
+        ),
+        (
+            "chain",
+            strings(puzzles, |p| Some(p.chain.name().to_lowercase())),
+        ),
+        (
+            "address",
+            strings(puzzles, |p| Some(p.address.value.to_string())),
+        ),
+        (
+            "address_kind",
+            strings(puzzles, |p| Some(p.address.kind.to_string())),
+        ),
+        (
+            "hash160",
+            strings(puzzles, |p| p.address.hash160.map(str::to_string)),
+        ),
+        ("status", strings(puzzles, |p| Some(p.status.to_string()))),
+        (
+            "pubkey",
+            strings(puzzles, |p| p.pubkey_str().map(str::to_string)),
+        ),
+        (
+            "key_bits",
+            Arc::new(
+                puzzles
+                    .iter()
+                    .map(|p| p.key.and_then(|k| k.bits))
+                    .collect::<UInt16Array>(),
+            ),
+        ),
+        (
+            "key_hex",
+            strings(puzzles, |p| p.key.and_then(|k| k.hex).map(str::to_string)),
+        ),
+        (
+            "prize",
+            Arc::new(puzzles.iter().map(|p| p.prize).collect::<Float64Array>()),
+        ),
+        (
+            "currency",
+            strings(puzzles, |p| Some(p.currency().to_string())),
+        ),
+        ("start_date", timestamps(puzzles, |p| p.start_date)),
+        ("solve_date", timestamps(puzzles, |p| p.solve_date)),
+        (
+            "solve_time",
+            Arc::new(
+                puzzles
+                    .iter()
+                    .map(|p| p.solve_time)
+                    .collect::<UInt64Array>(),
+            ),
+        ),
+        (
+            "solver",
+            strings(puzzles, |p| {
+                p.solver.as_ref().and_then(|s| s.name).map(str::to_string)
+            }),
+        ),
+        (
+            "superseded_by",
+            strings(puzzles, |p| p.superseded_by.map(str::to_string)),
+        ),
+        (
+            "withdrawn",
+            Arc::new(
+                puzzles
+                    .iter()
+                    .map(|p| Some(p.withdrawn))
+                    .collect::<BooleanArray>(),
+            ),
+        ),
+        (
+            "source_url",
+            strings(puzzles, |p| p.source_url.map(str::to_string)),
+        ),
+        ("transactions", tx_count(puzzles, None)),
+        (
+            "funding_txs",
+            tx_count(puzzles, Some(TransactionType::Funding)),
+        ),
+        (
+            "increase_txs",
+            tx_count(puzzles, Some(TransactionType::Increase)),
+        ),
+        (
+            "decrease_txs",
+            tx_count(puzzles, Some(TransactionType::Decrease)),
+        ),
+        ("sweep_txs", tx_count(puzzles, Some(TransactionType::Sweep))),
+        ("claim_txs", tx_count(puzzles, Some(TransactionType::Claim))),
+        (
+            "pubkey_reveal_txs",
+            tx_count(puzzles, Some(TransactionType::PubkeyReveal)),
+        ),
+    ])
+}
@@ -0,0 +1,308 @@
+//! Cross-collection analyses over the puzzle database.
+
+use crate::color::Colorize;
+use boha::{Puzzle, Solver};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::output::{outln, print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct AnalyzeArgs {
+    #[command(subcommand)]
+    pub target: AnalyzeTarget,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AnalyzeTarget {
+    /// Group solved puzzles by solver across collections
+    Solvers(SolversArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SolversArgs {
+    /// Treat solver records that share a payout address as one entity
+    #[arg(long)]
+    pub link_addresses: bool,
+}
+
+/// One solver, possibly merged from several attribution records.
+#[derive(Debug, Serialize)]
+struct SolverEntity {
+    names: Vec<&'static str>,
+    addresses: Vec<&'static str>,
+    /// Payout addresses that tied separate records together.
+    shared_addresses: Vec<&'static str>,
+    collections: Vec<&'static str>,
+    puzzles: Vec<&'static str>,
+    cross_collection: bool,
+}
+
+#[derive(Tabled)]
+struct SolverTableRow {
+    #[tabled(rename = "Solver")]
+    name: String,
+    #[tabled(rename = "Collections")]
+    collections: String,
+    #[tabled(rename = "Puzzles")]
+    puzzles: usize,
+    #[tabled(rename = "Payout addresses")]
+    addresses: String,
+}
+
+#[derive(Serialize)]
+struct SolverEntityCsvRow {
+    names: String,
+    addresses: String,
+    shared_addresses: String,
+    collections: String,
+    puzzles: String,
+    cross_collection: bool,
+}
+
+pub fn run(ctx: &Context, args: &AnalyzeArgs) {
+    match &args.target {
+        AnalyzeTarget::Solvers(solvers_args) => {
+            let puzzles: Vec<&'static Puzzle> = boha::all().collect();
+            let entities = solver_entities(&puzzles, solvers_args.link_addresses);
+            output_entities(ctx, &entities);
+        }
+    }
+}
+
+/// Union solver records sharing a name and, with `link_addresses`, any
+/// payout address. Entities spanning the most collections come first.
+fn solver_entities(puzzles: &[&'static Puzzle], link_addresses: bool) -> Vec<SolverEntity> {
+    let mut records: Vec<&'static Solver> = Vec::new();
+    let mut solved_by: Vec<(usize, &'static Puzzle)> = Vec::new();
+    for puzzle in puzzles {
+        let Some(solver) = &puzzle.solver else {
+            continue;
+        };
+        let index = records
+            .iter()
+            .position(|r| *r == solver)
+            .unwrap_or_else(|| {
+                records.push(solver);
+                records.len() - 1
+            });
+        solved_by.push((index, puzzle));
+    }
+
+    let mut parent: Vec<usize> = (0..records.len()).collect();
+    for a in 0..records.len() {
+        for b in a + 1..records.len() {
+            let same_name = records[a].name.is_some() && records[a].name == records[b].name;
+            let shared_address = link_addresses
+                && records[a]
+                    .addresses
+                    .iter()
+                    .any(|addr| records[b].addresses.contains(addr));
+            if same_name || shared_address {
+                let (root_a, root_b) = (find(&mut parent, a), find(&mut parent, b));
+                parent[root_b] = root_a;
+            }
+        }
+    }
+
+    let mut roots: Vec<usize> = Vec::new();
+    let mut entities: Vec<SolverEntity> = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        let root = find(&mut parent, index);
+        let slot = roots.iter().position(|r| *r == root).unwrap_or_else(|| {
+            roots.push(root);
+            entities.push(SolverEntity {
+                names: Vec::new(),
+                addresses: Vec::new(),
+                shared_addresses: Vec::new(),
+                collections: Vec::new(),
+                puzzles: Vec::new(),
+                cross_collection: false,
+            });
+            roots.len() - 1
+        });
+        let entity = &mut entities[slot];
+
+        if let Some(name) = record.name {
+            push_unique(&mut entity.names, name);
+        }
+        for address in record.addresses {
+            if entity.addresses.contains(address) {
+                push_unique(&mut entity.shared_addresses, address);
+            } else {
+                entity.addresses.push(address);
+            }
+        }
+        for (_, puzzle) in solved_by.iter().filter(|(i, _)| *i == index) {
+            entity.puzzles.push(puzzle.id);
+            push_unique(&mut entity.collections, puzzle.collection());
+        }
+    }
+
+    for entity in &mut entities {
+        entity.cross_collection = entity.collections.len() > 1;
+    }
+    entities.sort_by(|a, b| {
+        b.collections
+            .len()
+            .cmp(&a.collections.len())
+            .then(b.puzzles.len().cmp(&a.puzzles.len()))
+    });
+    entities
+}
+
+fn find(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+fn push_unique(values: &mut Vec<&'static str>, value: &'static str) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+fn output_entities(ctx: &Context, entities: &[SolverEntity]) {
+    match ctx.output {
+        OutputFormat::Table => {
+            let rows: Vec<SolverTableRow> = entities
+                .iter()
+                .map(|e| SolverTableRow {
+                    name: if e.names.is_empty() {
+                        "Unknown".dimmed().to_string()
+                    } else {
+                        e.names.join(", ")
+                    },
+                    collections: if e.cross_collection {
+                        e.collections.join(", ").bright_yellow().to_string()
+                    } else {
+                        e.collections.join(", ")
+                    },
+                    puzzles: e.puzzles.len(),
+                    addresses: e
+                        .addresses
+                        .iter()
+                        .map(|a| {
+                            if e.shared_addresses.contains(a) {
+                                format!("{} (shared)", a)
+                            } else {
+                                (*a).to_string()
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                })
+                .collect();
+            let table = Table::new(rows).with(Style::rounded()).to_string();
+            outln!(ctx, "{}", table);
+
+            let cross = entities.iter().filter(|e| e.cross_collection).count();
+            outln!(
+                ctx,
+                "\n{} {} solvers, {} across collections",
+                "Total:".dimmed(),
+                entities.len().to_string().bright_white(),
+                cross.to_string().bright_white()
+            );
+        }
+        OutputFormat::Json => {
+            print_json(ctx, entities);
+        }
+        OutputFormat::Jsonl => {
+            for entity in entities {
+                outln!(ctx, "{}", serde_json::to_string(entity).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(entities).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for e in entities {
+                wtr.serialize(SolverEntityCsvRow {
+                    names: e.names.join(";"),
+                    addresses: e.addresses.join(";"),
+                    shared_addresses: e.shared_addresses.join(";"),
+                    collections: e.collections.join(";"),
+                    puzzles: e.puzzles.join(";"),
+                    cross_collection: e.cross_collection,
+                })
+                .unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved_by(puzzle: &'static Puzzle, solver: Solver) -> &'static Puzzle {
+        Box::leak(Box::new(Puzzle {
+            solver: Some(solver),
+            ..puzzle.clone()
+        }))
+    }
+
+    #[test]
+    fn shared_payout_address_links_collections() {
+        let a = solved_by(
+            boha::get("b1000/1").unwrap(),
+            Solver {
+                name: None,
+                addresses: &["1Shared", "1OnlyA"],
+                profiles: &[],
+                announcement_url: None,
+            },
+        );
+        let b = solved_by(
+            boha::get("zden/level_1").unwrap(),
+            Solver {
+                name: Some("alice"),
+                addresses: &["1Shared"],
+                profiles: &[],
+                announcement_url: None,
+            },
+        );
+
+        let unlinked = solver_entities(&[a, b], false);
+        assert_eq!(unlinked.len(), 2);
+        assert!(unlinked.iter().all(|e| !e.cross_collection));
+
+        let linked = solver_entities(&[a, b], true);
+        assert_eq!(linked.len(), 1);
+        assert_eq!(linked[0].names, ["alice"]);
+        assert_eq!(linked[0].collections, ["b1000", "zden"]);
+        assert_eq!(linked[0].shared_addresses, ["1Shared"]);
+        assert!(linked[0].cross_collection);
+    }
+
+    #[test]
+    fn same_name_merges_without_linking() {
+        let solver = Solver {
+            name: Some("bob"),
+            addresses: &[],
+            profiles: &[],
+            announcement_url: None,
+        };
+        let a = solved_by(boha::get("b1000/2").unwrap(), solver.clone());
+        let b = solved_by(
+            boha::get("gsmg").unwrap(),
+            Solver {
+                addresses: &["1Other"],
+                ..solver
+            },
+        );
+
+        let entities = solver_entities(&[a, b], false);
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].puzzles, ["b1000/2", "gsmg"]);
+    }
+}
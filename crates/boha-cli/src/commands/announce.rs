@@ -0,0 +1,325 @@
+//! Solve intake: check a solver's signed announcement, then print the data
+//! patch and a ready-to-post markdown announcement.
+
+use crate::color::Colorize;
+use boha::{DateTime, Puzzle, Status};
+use chrono::{NaiveDateTime, Utc};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+use crate::util::{puzzle_or_exit, shell_quote};
+
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Debug, Args)]
+pub struct AnnounceArgs {
+    /// Puzzle id, or a b1000 puzzle number
+    #[arg(add = ArgValueCandidates::new(super::completions::puzzle_ids))]
+    pub id: String,
+
+    /// Solver name or pseudonym, as it should appear in the data
+    #[arg(long)]
+    pub solver: String,
+
+    /// Solver id in data/solvers.jsonc (default: derived from --solver)
+    #[arg(long)]
+    pub solver_id: Option<String>,
+
+    /// Address the solver swept the prize to
+    #[arg(long)]
+    pub solver_address: Option<String>,
+
+    /// Public announcement of the solve (forum post, tweet), recorded with
+    /// the solver
+    #[arg(long, value_name = "URL", value_parser = parse_announcement_url)]
+    pub announcement_url: Option<String>,
+
+    /// Solve time, UTC "YYYY-MM-DD HH:MM:SS" (default: now)
+    #[arg(long, value_parser = parse_date)]
+    pub date: Option<String>,
+
+    /// Signature over the announcement message by the puzzle address
+    /// (base64 for Bitcoin/Litecoin, hex for Ethereum). Without it, only
+    /// the message to sign is printed.
+    #[arg(long)]
+    pub signature: Option<String>,
+}
+
+#[derive(Serialize)]
+struct MessageOutput {
+    puzzle: &'static str,
+    address: &'static str,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct AnnounceOutput {
+    puzzle: &'static str,
+    address: &'static str,
+    message: String,
+    signature: String,
+    patch: Value,
+    announcement: String,
+}
+
+#[derive(Serialize)]
+struct AnnounceCsvRow<'a> {
+    puzzle: &'a str,
+    address: &'a str,
+    message: &'a str,
+    signature: &'a str,
+}
+
+pub fn run(ctx: &Context, args: &AnnounceArgs) {
+    let puzzle = puzzle_or_exit(&args.id);
+    if puzzle.status != Status::Unsolved {
+        error_exit(format!("{} is already {}", puzzle.id, puzzle.status));
+    }
+
+    let date = args
+        .date
+        .clone()
+        .unwrap_or_else(|| Utc::now().format(DATE_FORMAT).to_string());
+    let message = announcement_message(puzzle, &args.solver, &date);
+
+    let Some(signature) = &args.signature else {
+        print_message(ctx, puzzle, args, &date, message);
+        return;
+    };
+    if let Err(e) =
+        boha::verify::verify_message(puzzle.chain, puzzle.address.value, &message, signature)
+    {
+        error_exit(format!(
+            "Signature does not match {}: {}",
+            puzzle.address.value, e
+        ));
+    }
+
+    let output = AnnounceOutput {
+        puzzle: puzzle.id,
+        address: puzzle.address.value,
+        patch: patch(puzzle, args, &date),
+        announcement: announcement(puzzle, &args.solver, &date, &message, signature),
+        message,
+        signature: signature.clone(),
+    };
+    match ctx.output {
+        OutputFormat::Table => {
+            eprintln!(
+                "{} signed by the key of {}",
+                "✓".green(),
+                puzzle.address.value
+            );
+            outln!(
+                ctx,
+                "{}",
+                format!("# Data patch for data/{}.jsonc", puzzle.collection()).dimmed()
+            );
+            print_json(ctx, &output.patch);
+            outln!(ctx);
+            outln!(ctx, "{}", output.announcement);
+        }
+        OutputFormat::Json => print_json(ctx, &output),
+        OutputFormat::Jsonl => outln!(ctx, "{}", serde_json::to_string(&output).unwrap()),
+        OutputFormat::Yaml => outln!(ctx, "{}", serde_yaml::to_string(&output).unwrap()),
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            wtr.serialize(AnnounceCsvRow {
+                puzzle: output.puzzle,
+                address: output.address,
+                message: &output.message,
+                signature: &output.signature,
+            })
+            .unwrap();
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+/// The exact text the solver signs with the puzzle's key.
+fn announcement_message(puzzle: &Puzzle, solver: &str, date: &str) -> String {
+    format!(
+        "boha: {} ({}) solved by {} on {} UTC",
+        puzzle.id, puzzle.address.value, solver, date
+    )
+}
+
+fn print_message(
+    ctx: &Context,
+    puzzle: &'static Puzzle,
+    args: &AnnounceArgs,
+    date: &str,
+    message: String,
+) {
+    if !matches!(ctx.output, OutputFormat::Table) {
+        let output = MessageOutput {
+            puzzle: puzzle.id,
+            address: puzzle.address.value,
+            message,
+        };
+        crate::output::print_record(ctx, &output, |_, _| {});
+        return;
+    }
+
+    let mut command = vec![
+        "boha".to_string(),
+        "announce".to_string(),
+        puzzle.id.to_string(),
+        "--solver".to_string(),
+        shell_quote(&args.solver),
+        "--date".to_string(),
+        shell_quote(date),
+    ];
+    if let Some(id) = &args.solver_id {
+        command.extend(["--solver-id".to_string(), shell_quote(id)]);
+    }
+    if let Some(address) = &args.solver_address {
+        command.extend(["--solver-address".to_string(), shell_quote(address)]);
+    }
+    command.extend(["--signature".to_string(), "<SIGNATURE>".to_string()]);
+
+    outln!(
+        ctx,
+        "Sign this message with the key of {}:\n",
+        puzzle.address.value
+    );
+    outln!(ctx, "{}\n", message);
+    outln!(ctx, "then run:\n");
+    outln!(ctx, "{}", command.join(" "));
+}
+
+/// Changes for `data/<collection>.jsonc`, plus a `data/solvers.jsonc` entry
+/// when no embedded solver has this name yet.
+fn patch(puzzle: &Puzzle, args: &AnnounceArgs, date: &str) -> Value {
+    let solver_id = args
+        .solver_id
+        .clone()
+        .unwrap_or_else(|| solver_slug(&args.solver));
+
+    let mut set = json!({
+        "status": "solved",
+        "solve_date": date,
+        "solver": solver_id,
+    });
+    if let Some(url) = &args.announcement_url {
+        set["solver"] = json!({ "id": solver_id, "announcement_url": url });
+    }
+    if let Some(solve_time) = puzzle
+        .start_date
+        .and_then(|start| seconds_between(start, date))
+    {
+        set["solve_time"] = json!(solve_time);
+    }
+
+    let known = boha::all()
+        .filter_map(|p| p.solver.as_ref()?.name)
+        .any(|name| name.eq_ignore_ascii_case(&args.solver));
+    let mut patch = json!({
+        "file": format!("data/{}.jsonc", puzzle.collection()),
+        "puzzle": puzzle.id,
+        "address": puzzle.address.value,
+        "set": set,
+    });
+    if !known {
+        patch["solvers"] = json!({
+            solver_id: {
+                "name": args.solver,
+                "addresses": args.solver_address.iter().collect::<Vec<_>>(),
+                "profiles": [],
+            }
+        });
+    }
+    patch
+}
+
+fn announcement(
+    puzzle: &Puzzle,
+    solver: &str,
+    date: &str,
+    message: &str,
+    signature: &str,
+) -> String {
+    let prize = puzzle
+        .prize
+        .map(|prize| format!(", {} {}", prize, puzzle.currency()))
+        .unwrap_or_default();
+    format!(
+        "## {id} solved\n\n\
+         **{solver}** solved [{id}]({url}) (`{address}`{prize}) on {date} UTC.\n\n\
+         Ownership of the key is proven by this message, signed by the puzzle address:\n\n\
+         ```\n{message}\n```\n\n\
+         Signature: `{signature}`\n",
+        id = puzzle.id,
+        url = puzzle.explorer_url(),
+        address = puzzle.address.value,
+    )
+}
+
+fn parse_date(value: &str) -> Result<String, String> {
+    NaiveDateTime::parse_from_str(value, DATE_FORMAT)
+        .map(|date| date.format(DATE_FORMAT).to_string())
+        .map_err(|_| format!("expected \"YYYY-MM-DD HH:MM:SS\", got {}", value))
+}
+
+fn parse_announcement_url(value: &str) -> Result<String, String> {
+    if value.starts_with("https://") {
+        Ok(value.to_string())
+    } else {
+        Err(format!("expected an https:// URL, got {}", value))
+    }
+}
+
+fn seconds_between(start: DateTime, end: &str) -> Option<u64> {
+    let start = start.naive_datetime();
+    let end = NaiveDateTime::parse_from_str(end, DATE_FORMAT).ok()?;
+    u64::try_from((end - start).num_seconds()).ok()
+}
+
+/// `"Jane Doe"` → `"jane_doe"`, the shape of ids in data/solvers.jsonc.
+fn solver_slug(name: &str) -> String {
+    let slug: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    slug.trim_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solver_ids_are_slugs() {
+        assert_eq!(solver_slug("Jane Doe"), "jane_doe");
+        assert_eq!(solver_slug("RetiredCoder!"), "retiredcoder");
+    }
+
+    #[test]
+    fn solve_time_needs_ordered_dates() {
+        assert_eq!(
+            seconds_between(
+                "2024-01-01 00:00:00".parse().unwrap(),
+                "2024-01-02 00:00:01"
+            ),
+            Some(86401)
+        );
+        assert_eq!(
+            seconds_between(
+                "2024-01-02 00:00:00".parse().unwrap(),
+                "2024-01-01 00:00:00"
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Compare stored prizes of unsolved puzzles with live confirmed balances.
+
+use crate::color::Colorize;
+use boha::{Chain, Puzzle, Status};
+use boha_chain::balance::{Balance, BalanceError};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use super::balance::{
+    exit_if_failed, fetch_many, note_snapshot, round_to_chain, snapshot_taken_at, FetchArgs,
+};
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct AuditPrizesArgs {
+    /// Only puzzles from this collection
+    #[arg(long, add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+
+    /// Only puzzles on this chain
+    #[arg(long)]
+    pub chain: Option<Chain>,
+
+    /// Drift (in whole coins) still reported as ok
+    #[arg(long, default_value = "0")]
+    pub tolerance: f64,
+
+    /// Only list puzzles whose balance drifted or could not be fetched
+    #[arg(long)]
+    pub drift_only: bool,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Drift {
+    Ok,
+    Increased,
+    Decreased,
+    Error,
+}
+
+#[derive(Serialize)]
+struct AuditRow {
+    id: &'static str,
+    address: &'static str,
+    symbol: &'static str,
+    prize: f64,
+    confirmed: Option<f64>,
+    /// `confirmed - prize`
+    drift: Option<f64>,
+    status: Drift,
+    error: Option<String>,
+}
+
+#[derive(Tabled)]
+struct AuditTableRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Prize")]
+    prize: String,
+    #[tabled(rename = "Confirmed")]
+    confirmed: String,
+    #[tabled(rename = "Drift")]
+    drift: String,
+    #[tabled(rename = "Status")]
+    status: String,
+}
+
+pub async fn run(ctx: &Context, args: &AuditPrizesArgs) {
+    if !(args.tolerance.is_finite() && args.tolerance >= 0.0) {
+        error_exit("--tolerance must be a non-negative number");
+    }
+
+    let puzzles: Vec<&'static Puzzle> = match &args.collection {
+        Some(name) => collection_or_exit(name, false).all().collect(),
+        None => boha::all().collect(),
+    };
+    // Prizes in tokens (e.g. DAI) can't be compared with the native balance.
+    let puzzles: Vec<_> = puzzles
+        .into_iter()
+        .filter(|p| p.status == Status::Unsolved)
+        .filter(|p| args.chain.is_none_or(|c| p.chain == c))
+        .filter(|p| p.prize.is_some() && p.currency() == p.chain.symbol())
+        .collect();
+
+    let snapshot = snapshot_taken_at(&args.fetch);
+    let rows: Vec<AuditRow> = fetch_many(&puzzles, &args.fetch)
+        .await
+        .into_iter()
+        .map(|(puzzle, result)| audit_row(puzzle, result, args.tolerance))
+        .collect();
+
+    let failed = rows.iter().filter(|r| r.status == Drift::Error).count();
+    let total = rows.len();
+    let shown: Vec<AuditRow> = if args.drift_only {
+        rows.into_iter().filter(|r| r.status != Drift::Ok).collect()
+    } else {
+        rows
+    };
+
+    output_audit(ctx, &shown, total);
+    note_snapshot(snapshot);
+    exit_if_failed(failed, total);
+}
+
+fn audit_row(
+    puzzle: &'static Puzzle,
+    result: Result<Balance, BalanceError>,
+    tolerance: f64,
+) -> AuditRow {
+    let prize = puzzle.prize.expect("only puzzles with a prize are audited");
+    let mut row = AuditRow {
+        id: puzzle.id,
+        address: puzzle.address.value,
+        symbol: puzzle.chain.symbol(),
+        prize,
+        confirmed: None,
+        drift: None,
+        status: Drift::Error,
+        error: None,
+    };
+
+    match result {
+        Ok(balance) => {
+            let confirmed = balance.confirmed_native();
+            let drift = round_to_chain(confirmed - prize, puzzle.chain);
+            row.confirmed = Some(confirmed);
+            row.drift = Some(drift);
+            row.status = if drift.abs() <= tolerance {
+                Drift::Ok
+            } else if drift > 0.0 {
+                Drift::Increased
+            } else {
+                Drift::Decreased
+            };
+        }
+        Err(e) => row.error = Some(e.to_string()),
+    }
+    row
+}
+
+fn output_audit(ctx: &Context, rows: &[AuditRow], audited: usize) {
+    match ctx.output {
+        OutputFormat::Table => {
+            let table_rows: Vec<AuditTableRow> = rows
+                .iter()
+                .map(|r| AuditTableRow {
+                    id: r.id.to_string(),
+                    prize: format!("{:.8} {}", r.prize, r.symbol),
+                    confirmed: r
+                        .confirmed
+                        .map_or_else(|| "-".dimmed().to_string(), |c| format!("{:.8}", c)),
+                    drift: r
+                        .drift
+                        .map_or_else(|| "-".dimmed().to_string(), |d| format!("{:+.8}", d)),
+                    status: match r.status {
+                        Drift::Ok => "ok".green().to_string(),
+                        Drift::Increased => "increased".bright_yellow().to_string(),
+                        Drift::Decreased => "decreased".red().to_string(),
+                        Drift::Error => "error".red().bold().to_string(),
+                    },
+                })
+                .collect();
+            let table = Table::new(table_rows).with(Style::rounded()).to_string();
+            outln!(ctx, "{}", table);
+
+            let count = |status| rows.iter().filter(|r| r.status == status).count();
+            outln!(
+                ctx,
+                "\n{} {} audited, {} increased, {} decreased",
+                "Total:".dimmed(),
+                audited.to_string().bright_white(),
+                count(Drift::Increased),
+                count(Drift::Decreased)
+            );
+        }
+        OutputFormat::Json => {
+            print_json(ctx, rows);
+        }
+        OutputFormat::Jsonl => {
+            for row in rows {
+                outln!(ctx, "{}", serde_json::to_string(row).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(rows).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for row in rows {
+                wtr.serialize(row).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::color::Colorize;
+use boha::Author;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use tabled::{settings::Style, Table};
+
+use crate::context::Context;
+use crate::output::{outln, print_record, KeyValueRow};
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct AuthorArgs {
+    #[arg(add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: String,
+}
+
+pub fn run(ctx: &Context, args: &AuthorArgs) {
+    let author = collection_or_exit(&args.collection, false).author();
+    print_record(ctx, author, print_author_table);
+}
+
+fn print_author_table(ctx: &Context, author: &Author) {
+    let mut rows = vec![];
+
+    rows.push(KeyValueRow {
+        field: "Name".to_string(),
+        value: author.name.map_or_else(
+            || "Anonymous".dimmed().to_string(),
+            |n| n.bright_white().to_string(),
+        ),
+    });
+
+    if !author.aliases.is_empty() {
+        rows.push(KeyValueRow {
+            field: "Aliases".to_string(),
+            value: author.aliases.join(", "),
+        });
+    }
+
+    if !author.addresses.is_empty() {
+        rows.push(KeyValueRow {
+            field: "Addresses".to_string(),
+            value: author.addresses.join(", "),
+        });
+    }
+
+    for profile in author.profiles {
+        rows.push(KeyValueRow {
+            field: profile.name.to_string(),
+            value: profile.url.to_string(),
+        });
+    }
+
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    outln!(ctx, "{}", table);
+}
@@ -0,0 +1,141 @@
+//! Every author with their aliases, profiles, collections and totals.
+
+use crate::color::Colorize;
+use boha::{AuthorStats, Profile};
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use super::stats::{format_prizes, prize_map_json};
+use crate::context::Context;
+use crate::output::{outln, print_json, OutputFormat};
+
+#[derive(Serialize)]
+struct AuthorEntry<'a> {
+    #[serde(flatten)]
+    stats: &'a AuthorStats,
+    aliases: &'static [&'static str],
+    addresses: &'static [&'static str],
+    profiles: &'static [Profile],
+}
+
+#[derive(Tabled)]
+struct AuthorRow {
+    #[tabled(rename = "Author")]
+    author: String,
+    #[tabled(rename = "Collections")]
+    collections: String,
+    #[tabled(rename = "Puzzles")]
+    total: usize,
+    #[tabled(rename = "Unsolved")]
+    unsolved: usize,
+    #[tabled(rename = "Funded")]
+    funded: String,
+    #[tabled(rename = "Outstanding")]
+    outstanding: String,
+    #[tabled(rename = "Profiles")]
+    profiles: String,
+}
+
+#[derive(Serialize)]
+struct AuthorCsvRow {
+    name: Option<&'static str>,
+    aliases: String,
+    collections: String,
+    total: usize,
+    solved: usize,
+    unsolved: usize,
+    claimed: usize,
+    swept: usize,
+    expired: usize,
+    funded_by_chain: String,
+    outstanding_by_chain: String,
+    addresses: String,
+    profiles: String,
+}
+
+pub fn run(ctx: &Context) {
+    let authors = boha::author_stats();
+    let entries: Vec<AuthorEntry> = authors
+        .iter()
+        .map(|stats| AuthorEntry {
+            stats,
+            aliases: stats.author.aliases,
+            addresses: stats.author.addresses,
+            profiles: stats.author.profiles,
+        })
+        .collect();
+
+    match ctx.output {
+        OutputFormat::Table => {
+            let rows: Vec<AuthorRow> = entries.iter().map(row).collect();
+            outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+        }
+        OutputFormat::Json => {
+            print_json(ctx, &entries);
+        }
+        OutputFormat::Jsonl => {
+            for entry in &entries {
+                outln!(ctx, "{}", serde_json::to_string(entry).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(&entries).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for entry in &entries {
+                wtr.serialize(csv_row(entry)).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+fn row(entry: &AuthorEntry) -> AuthorRow {
+    let name = entry.stats.name.unwrap_or("Anonymous").to_string();
+    let author = match entry.aliases {
+        [] => name,
+        aliases => format!("{} ({})", name, aliases.join(", ").dimmed()),
+    };
+    AuthorRow {
+        author,
+        collections: entry.stats.collections.join(", "),
+        total: entry.stats.total,
+        unsolved: entry.stats.unsolved,
+        funded: format_prizes(&entry.stats.funded),
+        outstanding: format_prizes(&entry.stats.outstanding)
+            .bright_yellow()
+            .to_string(),
+        profiles: entry
+            .profiles
+            .iter()
+            .map(|p| p.name)
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+fn csv_row(entry: &AuthorEntry) -> AuthorCsvRow {
+    let stats = entry.stats;
+    AuthorCsvRow {
+        name: stats.name,
+        aliases: entry.aliases.join(";"),
+        collections: stats.collections.join(";"),
+        total: stats.total,
+        solved: stats.solved,
+        unsolved: stats.unsolved,
+        claimed: stats.claimed,
+        swept: stats.swept,
+        expired: stats.expired,
+        funded_by_chain: prize_map_json(&stats.funded),
+        outstanding_by_chain: prize_map_json(&stats.outstanding),
+        addresses: entry.addresses.join(";"),
+        profiles: entry
+            .profiles
+            .iter()
+            .map(|p| p.url)
+            .collect::<Vec<_>>()
+            .join(";"),
+    }
+}
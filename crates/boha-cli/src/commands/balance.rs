@@ -0,0 +1,639 @@
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+use crate::color::Colorize;
+use boha::{Chain, Puzzle, Status};
+use boha_chain::balance::{
+    self, Balance, BalanceCache, BalanceError, BitcoinCore, Electrum, Esplora,
+};
+use clap::{Args, ValueEnum};
+use clap_complete::ArgValueCandidates;
+use futures::StreamExt;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::output::{
+    error_exit, outln, print_json, print_record, CliError, ErrorCode, KeyValueRow, OutputFormat,
+};
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct BalanceArgs {
+    /// Puzzle ID (e.g., b1000/71). Omit when using --all
+    #[arg(
+        required_unless_present = "all",
+        conflicts_with = "all",
+        add = ArgValueCandidates::new(super::completions::puzzle_ids)
+    )]
+    pub id: Option<String>,
+
+    /// Check every puzzle address (narrow down with --collection, --chain, --unsolved)
+    #[arg(long)]
+    pub all: bool,
+
+    /// With --all: only puzzles from this collection
+    #[arg(long, requires = "all", add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+
+    /// With --all: only puzzles on this chain
+    #[arg(long, requires = "all")]
+    pub chain: Option<Chain>,
+
+    /// With --all: only unsolved puzzles
+    #[arg(long, requires = "all")]
+    pub unsolved: bool,
+
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+}
+
+/// Backend, cache and throttling options shared by commands that fetch balances.
+#[derive(Debug, Clone, Args)]
+pub struct FetchArgs {
+    /// Where to query balances (non-default backends serve Bitcoin only; esplora also Litecoin)
+    #[arg(
+        long,
+        value_enum,
+        env = "BOHA_BALANCE_BACKEND",
+        default_value = "default"
+    )]
+    pub backend: Backend,
+
+    /// Backend endpoint: Esplora base URL, Electrum host:port, or bitcoind RPC URL
+    #[arg(long, env = "BOHA_BACKEND_URL")]
+    pub backend_url: Option<String>,
+
+    /// Don't read or write the on-disk balance cache
+    #[arg(long, conflicts_with = "refresh")]
+    pub no_cache: bool,
+
+    /// Ignore cached balances, but cache the fresh ones
+    #[arg(long)]
+    pub refresh: bool,
+
+    /// Seconds a cached balance stays fresh
+    #[arg(
+        long,
+        env = "BOHA_CACHE_TTL",
+        default_value = "300",
+        value_name = "SECS"
+    )]
+    pub cache_ttl: u64,
+
+    /// Maximum number of requests in flight when checking many addresses
+    #[arg(long, default_value = "8", value_parser = clap::value_parser!(u16).range(1..))]
+    pub concurrency: u16,
+
+    /// Maximum requests started per second when checking many addresses
+    #[arg(long, value_name = "PER_SEC")]
+    pub rate: Option<f64>,
+
+    /// Answer from the balances embedded at build time; no network
+    #[cfg(feature = "snapshot")]
+    #[arg(long, conflicts_with_all = ["refresh", "no_cache"])]
+    pub offline: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Backend {
+    /// Public explorer per chain (mempool.space, Etherscan, dcrdata, ...)
+    Default,
+    Mempool,
+    Blockstream,
+    /// Any Esplora instance, requires --backend-url
+    Esplora,
+    /// Electrum server over TCP (default 127.0.0.1:50001)
+    Electrum,
+    /// Bitcoin Core RPC via scantxoutset (default http://127.0.0.1:8332)
+    Bitcoind,
+}
+
+#[derive(Serialize)]
+struct BalanceOutput {
+    address: String,
+    /// Also counted in the totals below.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extra_addresses: Vec<&'static str>,
+    chain: String,
+    confirmed: u128,
+    confirmed_display: f64,
+    unconfirmed: i128,
+    total_display: f64,
+    symbol: String,
+    unit: String,
+    cached: bool,
+    /// When the `--offline` snapshot was taken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct BalanceRow {
+    id: &'static str,
+    address: &'static str,
+    chain: &'static str,
+    status: Status,
+    balance: Option<f64>,
+    symbol: &'static str,
+    prize: Option<f64>,
+    /// `balance - prize`, only when the prize is in the chain's native coin.
+    delta: Option<f64>,
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot: Option<&'static str>,
+}
+
+#[derive(Tabled)]
+struct BalanceTableRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Address")]
+    address: String,
+    #[tabled(rename = "Balance")]
+    balance: String,
+    #[tabled(rename = "Prize")]
+    prize: String,
+    #[tabled(rename = "Delta")]
+    delta: String,
+}
+
+pub async fn run(ctx: &Context, args: &BalanceArgs) {
+    if args.all {
+        run_all(ctx, args).await;
+        return;
+    }
+
+    let id = args.id.as_deref().expect("clap requires id without --all");
+    let puzzle = boha::get(id).unwrap_or_else(|e| CliError::from(e).exit());
+    let snapshot = snapshot_taken_at(&args.fetch);
+    let cache = open_cache(&args.fetch);
+    let (bal, cached) = fetch_balance(puzzle, &args.fetch, cache.as_ref())
+        .await
+        .unwrap_or_else(|e| {
+            let error = if snapshot.is_some() {
+                CliError::new(
+                    ErrorCode::NotFound,
+                    format!("{} is not in the balance snapshot", puzzle.address.value),
+                )
+            } else {
+                balance_error(e)
+            };
+            error.id(puzzle.id).exit()
+        });
+
+    let output = BalanceOutput {
+        address: puzzle.address.value.to_string(),
+        extra_addresses: puzzle.extra_addresses.iter().map(|a| a.value).collect(),
+        chain: bal.chain.name().to_string(),
+        confirmed: bal.confirmed,
+        confirmed_display: bal.confirmed_native(),
+        unconfirmed: bal.unconfirmed,
+        total_display: bal.total_native(),
+        symbol: bal.chain.symbol().to_string(),
+        unit: bal.chain.base_unit().to_string(),
+        cached,
+        snapshot,
+    };
+    print_record(ctx, &output, print_balance_table);
+}
+
+async fn run_all(ctx: &Context, args: &BalanceArgs) {
+    let puzzles: Vec<&'static Puzzle> = match &args.collection {
+        Some(name) => collection_or_exit(name, false).all().collect(),
+        None => boha::all().collect(),
+    };
+    let puzzles: Vec<_> = puzzles
+        .into_iter()
+        .filter(|p| args.chain.is_none_or(|c| p.chain == c))
+        .filter(|p| !args.unsolved || p.status == Status::Unsolved)
+        .collect();
+
+    let snapshot = snapshot_taken_at(&args.fetch);
+    let rows: Vec<BalanceRow> = fetch_many(&puzzles, &args.fetch)
+        .await
+        .into_iter()
+        .map(|(puzzle, result)| BalanceRow {
+            snapshot,
+            ..balance_row(puzzle, result)
+        })
+        .collect();
+
+    let failed = rows.iter().filter(|r| r.error.is_some()).count();
+    output_balance_rows(ctx, &rows);
+    note_snapshot(snapshot);
+    exit_if_failed(failed, rows.len());
+}
+
+/// Fetch balances for `puzzles` under the concurrency and rate limits of
+/// `args`, in input order, with a progress counter on an interactive stderr.
+pub async fn fetch_many(
+    puzzles: &[&'static Puzzle],
+    args: &FetchArgs,
+) -> Vec<(&'static Puzzle, Result<Balance, BalanceError>)> {
+    if let Some(rate) = args.rate {
+        if !(rate.is_finite() && rate > 0.0) {
+            error_exit("--rate must be a positive number");
+        }
+    }
+
+    let total = puzzles.len();
+    let interval = args.rate.map(|rate| Duration::from_secs_f64(1.0 / rate));
+    let start = tokio::time::Instant::now();
+    let show_progress = std::io::stderr().is_terminal();
+    let cache = open_cache(args);
+    let cache = cache.as_ref();
+
+    let mut fetches = futures::stream::iter(puzzles.iter().enumerate())
+        .map(|(i, puzzle)| async move {
+            if let Some(interval) = interval {
+                tokio::time::sleep_until(start + interval.mul_f64(i as f64)).await;
+            }
+            let result = fetch_balance(puzzle, args, cache).await;
+            (*puzzle, result.map(|(balance, _)| balance))
+        })
+        .buffered(usize::from(args.concurrency));
+
+    let mut results = Vec::with_capacity(total);
+    while let Some(result) = fetches.next().await {
+        results.push(result);
+        if show_progress {
+            eprint!(
+                "\r{} {}/{}",
+                "Checking balances".dimmed(),
+                results.len(),
+                total
+            );
+            std::io::stderr().flush().ok();
+        }
+    }
+    if show_progress {
+        eprint!("\r\x1b[2K");
+    }
+    results
+}
+
+/// Warn and exit with the network code when some of `total` lookups failed.
+pub fn exit_if_failed(failed: usize, total: usize) {
+    if failed > 0 {
+        eprintln!(
+            "{} {} of {} balance lookups failed",
+            "Warning:".yellow().bold(),
+            failed,
+            total
+        );
+        crate::output::exit(ErrorCode::Network.exit_code());
+    }
+}
+
+/// Lookup failures are network errors; a bad address or chain is not.
+fn balance_error(error: BalanceError) -> CliError {
+    let code = match error {
+        BalanceError::InvalidAddress(_) | BalanceError::UnsupportedChain(_) => ErrorCode::Error,
+        // Which transport variants exist depends on boha-chain's features.
+        _ => ErrorCode::Network,
+    };
+    CliError::new(code, error)
+}
+
+fn balance_row(puzzle: &'static Puzzle, result: Result<Balance, BalanceError>) -> BalanceRow {
+    let (balance, error) = match result {
+        Ok(balance) => (Some(balance.total_native()), None),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    let native_prize = puzzle
+        .prize
+        .filter(|_| puzzle.currency() == puzzle.chain.symbol());
+
+    BalanceRow {
+        id: puzzle.id,
+        address: puzzle.address.value,
+        chain: puzzle.chain.name(),
+        status: puzzle.status,
+        balance,
+        symbol: puzzle.chain.symbol(),
+        prize: puzzle.prize,
+        delta: balance
+            .zip(native_prize)
+            .map(|(b, p)| round_to_chain(b - p, puzzle.chain)),
+        error,
+        snapshot: None,
+    }
+}
+
+/// Drops float noise below the chain's smallest unit.
+pub fn round_to_chain(amount: f64, chain: Chain) -> f64 {
+    let scale = 10f64.powi(i32::from(chain.decimals()));
+    (amount * scale).round() / scale
+}
+
+fn output_balance_rows(ctx: &Context, rows: &[BalanceRow]) {
+    match ctx.output {
+        OutputFormat::Table => {
+            let table_rows: Vec<BalanceTableRow> = rows
+                .iter()
+                .map(|r| BalanceTableRow {
+                    id: r.id.to_string(),
+                    address: r.address.to_string(),
+                    balance: r.balance.map_or_else(
+                        || "error".red().to_string(),
+                        |b| format!("{:.8} {}", b, r.symbol),
+                    ),
+                    prize: r
+                        .prize
+                        .map_or_else(|| "-".dimmed().to_string(), |p| format!("{:.8}", p)),
+                    delta: r.delta.map_or_else(
+                        || "-".dimmed().to_string(),
+                        |d| {
+                            let delta = format!("{:+.8}", d);
+                            if d < 0.0 {
+                                delta.red().to_string()
+                            } else if d > 0.0 {
+                                delta.green().to_string()
+                            } else {
+                                delta
+                            }
+                        },
+                    ),
+                })
+                .collect();
+            let table = Table::new(table_rows).with(Style::rounded()).to_string();
+            outln!(ctx, "{}", table);
+            outln!(
+                ctx,
+                "\n{} {} addresses",
+                "Total:".dimmed(),
+                rows.len().to_string().bright_white()
+            );
+        }
+        OutputFormat::Json => {
+            print_json(ctx, rows);
+        }
+        OutputFormat::Jsonl => {
+            for row in rows {
+                outln!(ctx, "{}", serde_json::to_string(row).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(rows).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for row in rows {
+                wtr.serialize(row).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+/// When the `--offline` snapshot was taken; exits when `--offline` is
+/// given but this build has no snapshot.
+#[cfg(feature = "snapshot")]
+pub fn snapshot_taken_at(args: &FetchArgs) -> Option<&'static str> {
+    args.offline.then(crate::snapshot::taken_at_or_exit)
+}
+
+#[cfg(not(feature = "snapshot"))]
+pub const fn snapshot_taken_at(_: &FetchArgs) -> Option<&'static str> {
+    None
+}
+
+/// Tell on stderr that balances came from the snapshot, and how old it is.
+#[cfg_attr(not(feature = "snapshot"), allow(unused_variables))]
+pub fn note_snapshot(taken_at: Option<&str>) {
+    #[cfg(feature = "snapshot")]
+    if let Some(taken_at) = taken_at {
+        eprintln!(
+            "{} {}",
+            "Balances from the snapshot of".dimmed(),
+            crate::snapshot::label(taken_at)
+        );
+    }
+}
+
+fn open_cache(args: &FetchArgs) -> Option<BalanceCache> {
+    #[cfg(feature = "snapshot")]
+    if args.offline {
+        return None;
+    }
+    if args.no_cache {
+        return None;
+    }
+    BalanceCache::open_default(Duration::from_secs(args.cache_ttl))
+}
+
+/// Balance summed over all of the puzzle's addresses; the flag is set when
+/// every one of them came from the cache.
+async fn fetch_balance(
+    puzzle: &Puzzle,
+    args: &FetchArgs,
+    cache: Option<&BalanceCache>,
+) -> Result<(Balance, bool), BalanceError> {
+    let mut total = Balance::zero(puzzle.chain);
+    let mut cached = true;
+    for address in puzzle.addresses() {
+        let (balance, hit) = fetch_address_balance(puzzle, address.value, args, cache).await?;
+        total = total + balance;
+        cached &= hit;
+    }
+    Ok((total, cached))
+}
+
+/// Balance of one address from the cache when fresh, otherwise from the
+/// backend; the flag tells which.
+async fn fetch_address_balance(
+    puzzle: &Puzzle,
+    address: &str,
+    args: &FetchArgs,
+    cache: Option<&BalanceCache>,
+) -> Result<(Balance, bool), BalanceError> {
+    #[cfg(feature = "snapshot")]
+    if args.offline {
+        return boha::snapshot::balance(address)
+            .map(|confirmed| {
+                let balance = Balance {
+                    confirmed,
+                    ..Balance::zero(puzzle.chain)
+                };
+                (balance, false)
+            })
+            .ok_or_else(|| {
+                BalanceError::Api(format!("{} is not in the balance snapshot", address))
+            });
+    }
+    if !args.refresh {
+        if let Some(balance) = cache.and_then(|c| c.get(address, puzzle.chain)) {
+            tracing::debug!(id = puzzle.id, address, "balance cache hit");
+            return Ok((balance, true));
+        }
+    }
+
+    let started = std::time::Instant::now();
+    let balance = fetch_from_backend(puzzle, address, args).await;
+    let elapsed_ms = started.elapsed().as_millis();
+    let balance = match balance {
+        Ok(balance) => {
+            tracing::debug!(id = puzzle.id, address, elapsed_ms, "balance fetched");
+            balance
+        }
+        Err(e) => {
+            tracing::info!(id = puzzle.id, address, elapsed_ms, error = %e, "balance lookup failed");
+            return Err(e);
+        }
+    };
+    if let Some(cache) = cache {
+        if let Err(e) = cache.put(address, &balance) {
+            eprintln!(
+                "{} failed to cache balance: {}",
+                "Warning:".yellow().bold(),
+                e
+            );
+        }
+    }
+    Ok((balance, false))
+}
+
+async fn fetch_from_backend(
+    puzzle: &Puzzle,
+    address: &str,
+    args: &FetchArgs,
+) -> Result<Balance, BalanceError> {
+    let url = args.backend_url.as_deref();
+
+    let supported = match args.backend {
+        Backend::Default => true,
+        Backend::Esplora => matches!(puzzle.chain, Chain::Bitcoin | Chain::Litecoin),
+        _ => puzzle.chain == Chain::Bitcoin,
+    };
+    if !supported {
+        return Err(BalanceError::UnsupportedChain(format!(
+            "{} with --backend {}",
+            puzzle.chain.name(),
+            args.backend
+                .to_possible_value()
+                .expect("no skipped variants")
+                .get_name()
+        )));
+    }
+
+    match args.backend {
+        Backend::Default => balance::fetch(address, puzzle.chain).await,
+        Backend::Mempool => {
+            let esplora =
+                url.map_or_else(Esplora::mempool_space, |u| Esplora::new(u, Chain::Bitcoin));
+            balance::fetch_with(&esplora, address).await
+        }
+        Backend::Blockstream => {
+            let esplora =
+                url.map_or_else(Esplora::blockstream, |u| Esplora::new(u, Chain::Bitcoin));
+            balance::fetch_with(&esplora, address).await
+        }
+        Backend::Esplora => {
+            let url = url.unwrap_or_else(|| error_exit("--backend esplora needs --backend-url"));
+            balance::fetch_with(&Esplora::new(url, puzzle.chain), address).await
+        }
+        Backend::Electrum => {
+            let electrum = Electrum::new(url.unwrap_or("127.0.0.1:50001"));
+            balance::fetch_with(&electrum, address).await
+        }
+        Backend::Bitcoind => {
+            let core = BitcoinCore::new(url.unwrap_or("http://127.0.0.1:8332"));
+            balance::fetch_with(&core, address).await
+        }
+    }
+}
+
+/// Best block height of `chain` from the selected backend; Esplora
+/// backends report it, Electrum and bitcoind are not asked.
+pub async fn tip_height(chain: Chain, args: &FetchArgs) -> Result<u64, BalanceError> {
+    let url = args.backend_url.as_deref();
+    let esplora = match args.backend {
+        Backend::Default => return balance::tip_height(chain).await,
+        Backend::Mempool if chain == Chain::Bitcoin => {
+            url.map_or_else(Esplora::mempool_space, |u| Esplora::new(u, chain))
+        }
+        Backend::Blockstream if chain == Chain::Bitcoin => {
+            url.map_or_else(Esplora::blockstream, |u| Esplora::new(u, chain))
+        }
+        Backend::Esplora if matches!(chain, Chain::Bitcoin | Chain::Litecoin) => Esplora::new(
+            url.unwrap_or_else(|| error_exit("--backend esplora needs --backend-url")),
+            chain,
+        ),
+        _ => return Err(BalanceError::UnsupportedChain(chain.name().to_string())),
+    };
+    esplora.tip_height().await
+}
+
+fn print_balance_table(ctx: &Context, balance: &BalanceOutput) {
+    let unit = &balance.unit;
+    let mut rows = vec![
+        KeyValueRow {
+            field: "Address".to_string(),
+            value: balance.address.clone(),
+        },
+        KeyValueRow {
+            field: "Chain".to_string(),
+            value: balance.chain.clone(),
+        },
+        KeyValueRow {
+            field: "Confirmed".to_string(),
+            value: format!(
+                "{} {} ({:.8} {})",
+                balance.confirmed.to_string().green(),
+                unit,
+                balance.confirmed_display,
+                balance.symbol
+            ),
+        },
+        KeyValueRow {
+            field: "Unconfirmed".to_string(),
+            value: if balance.unconfirmed < 0 {
+                format!(
+                    "{} {} {}",
+                    balance.unconfirmed,
+                    unit,
+                    "(unconfirmed spend)".red().bold()
+                )
+            } else if balance.unconfirmed != 0 {
+                format!("{} {}", balance.unconfirmed, unit)
+            } else {
+                "-".dimmed().to_string()
+            },
+        },
+        KeyValueRow {
+            field: "Total".to_string(),
+            value: format!("{:.8} {}", balance.total_display, balance.symbol)
+                .bright_green()
+                .to_string(),
+        },
+    ];
+    for (i, extra) in balance.extra_addresses.iter().enumerate() {
+        rows.insert(
+            1 + i,
+            KeyValueRow {
+                field: "Also".to_string(),
+                value: (*extra).to_string(),
+            },
+        );
+    }
+    if balance.cached {
+        rows.push(KeyValueRow {
+            field: "Source".to_string(),
+            value: "cache (--refresh to refetch)".dimmed().to_string(),
+        });
+    }
+    #[cfg(feature = "snapshot")]
+    if let Some(taken_at) = balance.snapshot {
+        rows.push(KeyValueRow {
+            field: "Source".to_string(),
+            value: format!("snapshot of {}", crate::snapshot::label(taken_at))
+                .yellow()
+                .to_string(),
+        });
+    }
+
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    outln!(ctx, "{}", table);
+}
@@ -0,0 +1,168 @@
+//! Hidden throughput benchmarks, tracked release-to-release.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use crate::color::Colorize;
+use boha::Puzzle;
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use super::search;
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_record, KeyValueRow};
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    #[command(subcommand)]
+    pub target: BenchTarget,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BenchTarget {
+    /// Run the search matcher over a generated query workload
+    Search(SearchBenchArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SearchBenchArgs {
+    /// Number of generated queries per pass
+    #[arg(long, default_value = "1000")]
+    pub queries: usize,
+
+    /// Number of passes over the workload
+    #[arg(long, default_value = "5")]
+    pub iterations: usize,
+
+    /// Workload seed, so runs are comparable
+    #[arg(long, default_value = "1")]
+    pub seed: u64,
+}
+
+#[derive(Serialize)]
+struct SearchBenchReport {
+    puzzles: usize,
+    queries: usize,
+    iterations: usize,
+    matches: usize,
+    elapsed_ms: f64,
+    queries_per_sec: f64,
+    puzzles_per_sec: f64,
+}
+
+pub fn run(ctx: &Context, args: &BenchArgs) {
+    match &args.target {
+        BenchTarget::Search(search_args) => run_search(ctx, search_args),
+    }
+}
+
+fn run_search(ctx: &Context, args: &SearchBenchArgs) {
+    if args.queries == 0 || args.iterations == 0 {
+        error_exit("--queries and --iterations must be at least 1");
+    }
+
+    let puzzles: Vec<&'static Puzzle> = boha::all().collect();
+    let workload = generate_workload(&puzzles, args.queries, args.seed);
+
+    let mut matches = 0;
+    let start = Instant::now();
+    for _ in 0..args.iterations {
+        for (query, exact, case_sensitive) in &workload {
+            let results = search::search(
+                &puzzles,
+                &search::Matcher::text(query, *exact, *case_sensitive),
+                &[],
+            );
+            matches += black_box(results).len();
+        }
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+
+    let total_queries = args.queries * args.iterations;
+    let report = SearchBenchReport {
+        puzzles: puzzles.len(),
+        queries: args.queries,
+        iterations: args.iterations,
+        matches,
+        elapsed_ms: elapsed * 1000.0,
+        queries_per_sec: total_queries as f64 / elapsed,
+        puzzles_per_sec: (total_queries * puzzles.len()) as f64 / elapsed,
+    };
+
+    print_record(ctx, &report, print_search_report_table);
+}
+
+/// Deterministic mix of id, address, hash and miss queries across match modes.
+fn generate_workload(
+    puzzles: &[&'static Puzzle],
+    count: usize,
+    seed: u64,
+) -> Vec<(String, bool, bool)> {
+    // xorshift has a fixed point at zero
+    let mut state = seed.max(1);
+    let mut next = move || {
+        // xorshift64*
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state.wrapping_mul(0x2545_f491_4f6c_dd1d) as usize
+    };
+
+    (0..count)
+        .map(|i| {
+            let puzzle = puzzles[next() % puzzles.len()];
+            let address = puzzle.address.value;
+            let query = match i % 4 {
+                0 => puzzle.name().to_string(),
+                1 => address[..address.len().min(6 + next() % 7)].to_string(),
+                2 => puzzle.address.hash160.map_or_else(
+                    || address.to_string(),
+                    |h| {
+                        let from = next() % (h.len() - 8);
+                        h[from..from + 8].to_string()
+                    },
+                ),
+                _ => format!("zz-miss-{}", next() % 10_000),
+            };
+            let exact = i % 7 == 0;
+            let case_sensitive = i % 5 == 0;
+            (query, exact, case_sensitive)
+        })
+        .collect()
+}
+
+fn print_search_report_table(ctx: &Context, report: &SearchBenchReport) {
+    let rows = vec![
+        KeyValueRow {
+            field: "Puzzles".to_string(),
+            value: report.puzzles.to_string(),
+        },
+        KeyValueRow {
+            field: "Queries".to_string(),
+            value: format!("{} x {}", report.queries, report.iterations),
+        },
+        KeyValueRow {
+            field: "Matches".to_string(),
+            value: report.matches.to_string(),
+        },
+        KeyValueRow {
+            field: "Elapsed".to_string(),
+            value: format!("{:.1} ms", report.elapsed_ms),
+        },
+        KeyValueRow {
+            field: "Queries/s".to_string(),
+            value: format!("{:.0}", report.queries_per_sec)
+                .bright_white()
+                .to_string(),
+        },
+        KeyValueRow {
+            field: "Puzzles/s".to_string(),
+            value: format!("{:.0}", report.puzzles_per_sec),
+        },
+    ];
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    outln!(ctx, "{}", table);
+}
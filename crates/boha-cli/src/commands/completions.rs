@@ -0,0 +1,76 @@
+//! Shell completion scripts.
+//!
+//! The default script calls back into `boha` while completing (clap's
+//! `COMPLETE=<shell> boha` protocol, handled at the top of `main`), so
+//! collection names and puzzle ids complete from the embedded data.
+//! `--static` prints a self-contained script that only knows subcommands
+//! and flags.
+
+use clap::{Args, CommandFactory, ValueEnum};
+use clap_complete::env::{Bash, EnvCompleter, Fish, Powershell, Zsh};
+use clap_complete::CompletionCandidate;
+
+use crate::context::Context;
+use crate::output::error_exit;
+
+/// Environment variable of the completion callback protocol.
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+#[derive(Debug, Args)]
+pub struct CompletionsArgs {
+    pub shell: Shell,
+
+    /// Print a script that does not call back into boha (no puzzle ids)
+    #[arg(long = "static")]
+    pub static_script: bool,
+}
+
+pub fn run(ctx: &Context, args: &CompletionsArgs) {
+    let mut cmd = crate::Cli::command();
+    let mut stdout = &ctx.out;
+    if args.static_script {
+        let shell = match args.shell {
+            Shell::Bash => clap_complete::Shell::Bash,
+            Shell::Zsh => clap_complete::Shell::Zsh,
+            Shell::Fish => clap_complete::Shell::Fish,
+            Shell::Powershell => clap_complete::Shell::PowerShell,
+        };
+        clap_complete::generate(shell, &mut cmd, "boha", &mut stdout);
+        return;
+    }
+    let completer: &dyn EnvCompleter = match args.shell {
+        Shell::Bash => &Bash,
+        Shell::Zsh => &Zsh,
+        Shell::Fish => &Fish,
+        Shell::Powershell => &Powershell,
+    };
+    completer
+        .write_registration(COMPLETE_VAR, "boha", "boha", "boha", &mut stdout)
+        .unwrap_or_else(|e| error_exit(format!("Cannot write completions: {}", e)));
+}
+
+/// Every puzzle id, with its status as the description.
+pub fn puzzle_ids() -> Vec<CompletionCandidate> {
+    boha::all()
+        .map(|p| CompletionCandidate::new(p.id).help(Some(p.status.to_string().into())))
+        .collect()
+}
+
+/// Every collection name, with its puzzle count as the description.
+pub fn collection_names() -> Vec<CompletionCandidate> {
+    boha::collections()
+        .into_iter()
+        .map(|c| {
+            CompletionCandidate::new(c.name())
+                .help(Some(format!("{} puzzles", c.all().count()).into()))
+        })
+        .collect()
+}
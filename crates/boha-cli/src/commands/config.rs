@@ -0,0 +1,172 @@
+//! Read and edit the config file (`~/.config/boha/config.toml`).
+
+use clap::{Args, Subcommand, ValueEnum};
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::color::{ColorChoice, Colorize};
+use crate::config::{self, Kind, Setting, SETTINGS};
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+
+/// Placeholder for secrets withheld from `config list`.
+const REDACTED: &str = "<redacted>";
+
+#[derive(Debug, Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigAction {
+    /// Print the config file path
+    Path,
+
+    /// Every setting with its value and environment variable
+    List {
+        /// Print API keys and tokens (redacted otherwise)
+        #[arg(long)]
+        show_secrets: bool,
+    },
+
+    /// Print the value of KEY
+    Get { key: String },
+
+    /// Set KEY to VALUE (comma-separated for lists)
+    Set { key: String, value: String },
+
+    /// Remove KEY from the file
+    Unset { key: String },
+}
+
+#[derive(Serialize, Tabled)]
+struct SettingRow {
+    #[tabled(rename = "Key")]
+    key: &'static str,
+    #[tabled(skip)]
+    value: Option<String>,
+    #[tabled(rename = "Value")]
+    #[serde(skip)]
+    shown: String,
+    #[tabled(rename = "Environment")]
+    env: &'static str,
+    #[tabled(rename = "Description")]
+    about: &'static str,
+}
+
+pub fn run(ctx: &Context, args: &ConfigArgs) {
+    let path = config::path()
+        .unwrap_or_else(|| error_exit("No config directory; set XDG_CONFIG_HOME or HOME"));
+    if matches!(args.action, ConfigAction::Path) {
+        outln!(ctx, "{}", path.display());
+        return;
+    }
+    let mut doc = ctx.config.clone().unwrap_or_else(|e| error_exit(e));
+
+    match &args.action {
+        ConfigAction::Path => unreachable!("handled above"),
+        ConfigAction::List { show_secrets } => {
+            let rows: Vec<SettingRow> = SETTINGS
+                .iter()
+                .map(|setting| {
+                    let value = config::get(&doc, setting.key).map(|value| {
+                        if setting.kind == Kind::Secret && !show_secrets {
+                            REDACTED.to_string()
+                        } else {
+                            value
+                        }
+                    });
+                    SettingRow {
+                        key: setting.key,
+                        shown: value.clone().unwrap_or_default(),
+                        value,
+                        env: setting.env,
+                        about: setting.about,
+                    }
+                })
+                .collect();
+            match ctx.output {
+                OutputFormat::Table => {
+                    outln!(ctx, "{}", Table::new(&rows).with(Style::rounded()));
+                    outln!(ctx, "{} {}", "File:".dimmed(), path.display());
+                }
+                OutputFormat::Json => print_json(ctx, &rows),
+                OutputFormat::Jsonl => {
+                    for row in &rows {
+                        outln!(ctx, "{}", serde_json::to_string(row).unwrap());
+                    }
+                }
+                OutputFormat::Yaml => outln!(ctx, "{}", serde_yaml::to_string(&rows).unwrap()),
+                OutputFormat::Csv => {
+                    let mut wtr = csv::Writer::from_writer(&ctx.out);
+                    for row in &rows {
+                        wtr.serialize(row).unwrap();
+                    }
+                    wtr.flush().unwrap();
+                }
+                _ => unreachable!("dataset-only formats are rejected in main"),
+            }
+        }
+        ConfigAction::Get { key } => {
+            known(key);
+            match config::get(&doc, key) {
+                Some(value) => outln!(ctx, "{}", value),
+                None => error_exit(format!("{} is not set in {}", key, path.display())),
+            }
+        }
+        ConfigAction::Set { key, value } => {
+            let setting = known(key);
+            check(setting, value).unwrap_or_else(|e| {
+                error_exit(format!("Invalid value for {}: {}", key, e));
+            });
+            config::set(&mut doc, setting, value).unwrap_or_else(|e| error_exit(e));
+            let path = config::save(&doc).unwrap_or_else(|e| error_exit(e));
+            eprintln!("{} {} in {}", "Set".green().bold(), key, path.display());
+        }
+        ConfigAction::Unset { key } => {
+            known(key);
+            if config::unset(&mut doc, key) {
+                let path = config::save(&doc).unwrap_or_else(|e| error_exit(e));
+                eprintln!(
+                    "{} {} from {}",
+                    "Removed".green().bold(),
+                    key,
+                    path.display()
+                );
+            } else {
+                eprintln!("{} is not set", key);
+            }
+        }
+    }
+}
+
+fn known(key: &str) -> &'static Setting {
+    config::setting(key).unwrap_or_else(|| {
+        let keys: Vec<&str> = SETTINGS.iter().map(|s| s.key).collect();
+        error_exit(format!(
+            "Unknown config key {}; known keys: {}",
+            key,
+            keys.join(", ")
+        ))
+    })
+}
+
+/// Catch values the flags would reject now rather than on every later run.
+fn check(setting: &Setting, value: &str) -> Result<(), String> {
+    match setting.key {
+        "output" => match OutputFormat::from_str(value, true)?.dataset_commands() {
+            Some(commands) => Err(format!("-o {} is only supported by {}", value, commands)),
+            None => Ok(()),
+        },
+        "color" => ColorChoice::from_str(value, true).map(drop),
+        #[cfg(feature = "balance")]
+        "balance.backend" => super::balance::Backend::from_str(value, true).map(drop),
+        "watchlist" => value
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .try_for_each(|id| boha::get(id).map(drop).map_err(|e| e.to_string())),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,165 @@
+//! What an arbitrary address is, and whether the dataset knows it.
+
+use crate::color::Colorize;
+use boha::address::{AddressKind, Network};
+use boha::Chain;
+use clap::Args;
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_record, KeyValueRow};
+
+#[derive(Debug, Args)]
+pub struct DecodeArgs {
+    /// Address on any supported chain
+    pub address: String,
+}
+
+#[derive(Serialize)]
+struct DecodeOutput {
+    address: String,
+    chain: Chain,
+    kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network: Option<&'static str>,
+    /// Base58 version byte(s), bech32 human-readable part or CashAddr prefix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prefix: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hash160: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    witness_version: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    witness_program: Option<String>,
+    /// Puzzle with exactly this address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    puzzle: Option<&'static str>,
+    /// Other puzzles whose address commits to the same HASH160.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    same_hash160: Vec<&'static str>,
+}
+
+pub fn run(ctx: &Context, args: &DecodeArgs) {
+    let (chain, kind) =
+        AddressKind::identify(args.address.trim()).unwrap_or_else(|e| error_exit(e));
+    print_record(ctx, &decode(args.address.trim(), chain, kind), print_table);
+}
+
+fn decode(address: &str, chain: Chain, kind: AddressKind) -> DecodeOutput {
+    let payload = hex::encode(kind.payload());
+    let (hash160, witness_version, witness_program) = match kind {
+        AddressKind::P2pkh { .. } | AddressKind::P2sh { .. } => (Some(payload), None, None),
+        AddressKind::P2wpkh { .. } => (Some(payload.clone()), Some(0), Some(payload)),
+        AddressKind::P2wsh { .. } => (None, Some(0), Some(payload)),
+        AddressKind::P2tr { .. } => (None, Some(1), Some(payload)),
+        AddressKind::Ethereum { .. } | AddressKind::Arweave { .. } => (None, None, None),
+    };
+
+    let puzzle = boha::get_by_address(address).ok().map(|p| p.id);
+    let same_hash160 = hash160.as_deref().map_or_else(Vec::new, |hash| {
+        boha::all()
+            .filter(|p| p.address.hash160 == Some(hash) && Some(p.id) != puzzle)
+            .map(|p| p.id)
+            .collect()
+    });
+
+    DecodeOutput {
+        address: address.to_string(),
+        chain,
+        kind: kind.name(),
+        network: network(kind).map(|network| match network {
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+        }),
+        prefix: prefix(chain, kind),
+        hash160,
+        witness_version,
+        witness_program,
+        puzzle,
+        same_hash160,
+    }
+}
+
+/// Version byte(s) of a base58 address, or the HRP of a SegWit one.
+fn prefix(chain: Chain, kind: AddressKind) -> Option<&'static str> {
+    match (chain, kind.name(), network(kind)) {
+        (Chain::Bitcoin, "p2pkh", Some(Network::Mainnet)) => Some("0x00"),
+        (Chain::Bitcoin, "p2pkh", Some(Network::Testnet)) => Some("0x6f"),
+        (Chain::Bitcoin, "p2sh", Some(Network::Mainnet)) => Some("0x05"),
+        (Chain::Bitcoin, "p2sh", Some(Network::Testnet)) => Some("0xc4"),
+        (Chain::Litecoin, "p2pkh", _) => Some("0x30"),
+        (Chain::Litecoin, "p2sh", _) => Some("0x32"),
+        (Chain::Decred, "p2pkh", _) => Some("0x073f"),
+        (Chain::Decred, "p2sh", _) => Some("0x071a"),
+        (Chain::Dogecoin, "p2pkh", _) => Some("0x1e"),
+        (Chain::Dogecoin, "p2sh", _) => Some("0x16"),
+        (Chain::Zcash, "p2pkh", _) => Some("0x1cb8"),
+        (Chain::Zcash, "p2sh", _) => Some("0x1cbd"),
+        (Chain::Dash, "p2pkh", _) => Some("0x4c"),
+        (Chain::Dash, "p2sh", _) => Some("0x10"),
+        // Legacy Bitcoin Cash addresses identify as Bitcoin.
+        (Chain::BitcoinCash, _, _) => Some("bitcoincash"),
+        (Chain::Bitcoin, _, None) => Some("bc"),
+        (Chain::Litecoin, _, None) => Some("ltc"),
+        _ => None,
+    }
+}
+
+/// Network of a base58 address; SegWit and account addresses carry none.
+const fn network(kind: AddressKind) -> Option<Network> {
+    match kind {
+        AddressKind::P2pkh { network, .. } | AddressKind::P2sh { network, .. } => Some(network),
+        _ => None,
+    }
+}
+
+fn print_table(ctx: &Context, output: &DecodeOutput) {
+    let mut rows = vec![
+        KeyValueRow {
+            field: "Address".to_string(),
+            value: output.address.clone(),
+        },
+        KeyValueRow {
+            field: "Chain".to_string(),
+            value: output.chain.name().to_string(),
+        },
+        KeyValueRow {
+            field: "Type".to_string(),
+            value: output.kind.to_uppercase(),
+        },
+    ];
+    let optional = [
+        ("Network", output.network.map(str::to_string)),
+        ("Prefix", output.prefix.map(str::to_string)),
+        ("HASH160", output.hash160.clone()),
+        (
+            "Witness version",
+            output.witness_version.map(|v| v.to_string()),
+        ),
+        ("Witness program", output.witness_program.clone()),
+    ];
+    rows.extend(
+        optional
+            .into_iter()
+            .filter_map(|(field, value)| Some((field, value?)))
+            .map(|(field, value)| KeyValueRow {
+                field: field.to_string(),
+                value,
+            }),
+    );
+    rows.push(KeyValueRow {
+        field: "Puzzle".to_string(),
+        value: output.puzzle.map_or_else(
+            || "not in the dataset".dimmed().to_string(),
+            |id| id.bright_white().to_string(),
+        ),
+    });
+    if !output.same_hash160.is_empty() {
+        rows.push(KeyValueRow {
+            field: "Same HASH160".to_string(),
+            value: output.same_hash160.join(", "),
+        });
+    }
+    outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+}
@@ -0,0 +1,132 @@
+//! Public keys and addresses derived from a private key, WIF or mnemonic.
+
+use crate::color::Colorize;
+use boha::keytools::{self, Derived, DerivedAddress};
+use boha::verify::VerifyError;
+use boha::{Chain, PubkeyFormat};
+use clap::Args;
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_record, KeyValueRow, OutputFormat};
+use crate::qr::{self, QrTarget};
+use crate::util::is_hex_key;
+
+#[derive(Debug, Args)]
+pub struct DeriveArgs {
+    /// Private key: 64-char hex or WIF
+    #[arg(required_unless_present = "mnemonic")]
+    pub key: Option<String>,
+
+    /// BIP39 mnemonic to derive the key from instead
+    #[arg(long, conflicts_with = "key")]
+    pub mnemonic: Option<String>,
+
+    /// BIP32 derivation path for --mnemonic
+    #[arg(long, default_value = "m/44'/0'/0'/0/0", requires = "mnemonic")]
+    pub path: String,
+
+    /// BIP39 passphrase for --mnemonic
+    #[arg(long, default_value = "", requires = "mnemonic")]
+    pub passphrase: String,
+
+    /// Print the first address (or the compressed public key) as a QR code
+    #[arg(long, value_enum, value_name = "VALUE", num_args = 0..=1, default_missing_value = "address")]
+    pub qr: Option<QrTarget>,
+}
+
+#[derive(Serialize)]
+struct DeriveCsvRow<'a> {
+    chain: Chain,
+    kind: &'a str,
+    pubkey_format: Option<PubkeyFormat>,
+    address: &'a str,
+}
+
+pub fn run(ctx: &Context, args: &DeriveArgs) {
+    let derived = match (&args.key, &args.mnemonic) {
+        (_, Some(phrase)) => keytools::key_from_mnemonic(phrase, &args.path, &args.passphrase)
+            .and_then(|key| keytools::derive(&key)),
+        (Some(key), None) => derive_key(key),
+        (None, None) => unreachable!("clap requires a key or --mnemonic"),
+    }
+    .unwrap_or_else(|e| error_exit(e));
+
+    if matches!(ctx.output, OutputFormat::Csv) {
+        let mut wtr = csv::Writer::from_writer(&ctx.out);
+        for address in &derived.addresses {
+            wtr.serialize(DeriveCsvRow {
+                chain: address.chain,
+                kind: address.kind,
+                pubkey_format: address.pubkey_format,
+                address: &address.address,
+            })
+            .unwrap();
+        }
+        wtr.flush().unwrap();
+    } else {
+        print_record(ctx, &derived, print_table);
+    }
+
+    if let Some(target) = args.qr {
+        let value = match target {
+            QrTarget::Address => &derived.addresses[0].address,
+            QrTarget::Pubkey => &derived.compressed_pubkey,
+        };
+        qr::print(ctx, value, !matches!(ctx.output, OutputFormat::Table));
+    }
+}
+
+fn derive_key(key: &str) -> Result<Derived, VerifyError> {
+    if is_hex_key(key) {
+        keytools::derive(&keytools::key_from_hex(key)?)
+    } else {
+        keytools::derive(&keytools::from_wif(key)?.key)
+    }
+}
+
+fn print_table(ctx: &Context, derived: &Derived) {
+    let mut rows = vec![
+        KeyValueRow {
+            field: "Private key".to_string(),
+            value: derived.private_key.clone(),
+        },
+        KeyValueRow {
+            field: "Compressed pubkey".to_string(),
+            value: derived.compressed_pubkey.clone(),
+        },
+        KeyValueRow {
+            field: "HASH160 (compressed)".to_string(),
+            value: derived.hash160_compressed.clone(),
+        },
+        KeyValueRow {
+            field: "Uncompressed pubkey".to_string(),
+            value: derived.uncompressed_pubkey.clone(),
+        },
+        KeyValueRow {
+            field: "HASH160 (uncompressed)".to_string(),
+            value: derived.hash160_uncompressed.clone(),
+        },
+    ];
+    rows.extend(derived.addresses.iter().map(|address| KeyValueRow {
+        field: address_label(address),
+        value: address.address.bright_white().to_string(),
+    }));
+    outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+}
+
+/// `"BTC P2PKH (compressed)"`, or just `"ETH"` for the single Ethereum form.
+fn address_label(address: &DerivedAddress) -> String {
+    let mut label = address.chain.symbol().to_string();
+    if address.kind != "standard" {
+        label.push(' ');
+        label.push_str(&address.kind.to_uppercase());
+    }
+    match address.pubkey_format {
+        Some(PubkeyFormat::Compressed) => label.push_str(" (compressed)"),
+        Some(PubkeyFormat::Uncompressed) => label.push_str(" (uncompressed)"),
+        None => {}
+    }
+    label
+}
@@ -0,0 +1,347 @@
+//! Changes between two export snapshots, or between the data files at a
+//! git ref and the working tree.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::color::Colorize;
+use boha::Collection;
+use clap::Args;
+use serde::Serialize;
+use serde_json::Value;
+use tabled::{settings::Style, Table, Tabled};
+
+use super::import;
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+use crate::util::data_file_id;
+
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    /// Older snapshot (any file `import` reads)
+    #[arg(required_unless_present = "against")]
+    pub old: Option<PathBuf>,
+
+    /// Newer snapshot
+    #[arg(required_unless_present = "against")]
+    pub new: Option<PathBuf>,
+
+    /// Compare data/*.jsonc at this git ref with the working tree; run
+    /// inside a boha checkout
+    #[arg(long, value_name = "REF", conflicts_with_all = ["old", "new"])]
+    pub against: Option<String>,
+}
+
+/// Compared fields and where they live in a puzzle object. Export
+/// snapshots and data files share these names.
+const FIELDS: [(&str, &str); 5] = [
+    ("status", "/status"),
+    ("prize", "/prize"),
+    ("pubkey", "/pubkey/value"),
+    ("start_date", "/start_date"),
+    ("solve_date", "/solve_date"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Kind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Kind {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Changed => "changed",
+        }
+    }
+}
+
+/// One added or removed puzzle, or one changed field.
+#[derive(Serialize)]
+struct Change {
+    id: String,
+    change: Kind,
+    field: Option<&'static str>,
+    old: Value,
+    new: Value,
+}
+
+#[derive(Tabled)]
+struct ChangeTableRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Change")]
+    change: String,
+    #[tabled(rename = "Field")]
+    field: String,
+    #[tabled(rename = "Old")]
+    old: String,
+    #[tabled(rename = "New")]
+    new: String,
+}
+
+/// Puzzles keyed by collection and address, which (unlike the id) survive
+/// renames; the uuid is derived from the same pair.
+type Snapshot = BTreeMap<(String, String), (String, Value)>;
+
+pub fn run(ctx: &Context, args: &DiffArgs) {
+    let (old, new) = match (&args.against, &args.old, &args.new) {
+        (Some(reference), _, _) => (at_ref(reference), working_tree()),
+        (None, Some(old), Some(new)) => (from_file(old), from_file(new)),
+        _ => unreachable!("clap requires OLD and NEW without --against"),
+    };
+    output_changes(ctx, &diff(&old, &new));
+}
+
+fn from_file(path: &Path) -> Snapshot {
+    let collections = import::read_collections(path).unwrap_or_else(|e| error_exit(e));
+    let mut snapshot = Snapshot::new();
+    for (collection, puzzles) in collections {
+        for puzzle in puzzles {
+            let id = puzzle["id"].as_str().unwrap_or_default().to_string();
+            insert(&mut snapshot, &collection, id, puzzle);
+        }
+    }
+    snapshot
+}
+
+/// The data files at `reference`.
+fn at_ref(reference: &str) -> Snapshot {
+    git(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{}^{{commit}}", reference),
+    ])
+    .unwrap_or_else(|_| error_exit(format!("Not a commit in this repository: {}", reference)));
+    data_files(|file| {
+        let object = format!("{}:{}", reference, file);
+        git(&["cat-file", "-e", &object]).ok()?;
+        Some(git(&["show", &object]).unwrap_or_else(|e| error_exit(e)))
+    })
+}
+
+/// The data files as checked out, uncommitted edits included.
+fn working_tree() -> Snapshot {
+    let root = git(&["rev-parse", "--show-toplevel"])
+        .unwrap_or_else(|_| error_exit("--against must be run inside a boha checkout"));
+    let root = PathBuf::from(root.trim_end());
+    data_files(|file| std::fs::read_to_string(root.join(file)).ok())
+}
+
+/// Puzzles of every built-in collection's data file, as returned by
+/// `read`. A file that does not exist contributes no puzzles.
+fn data_files(read: impl Fn(&str) -> Option<String>) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for collection in Collection::ALL {
+        let name = collection.name();
+        let path = format!("data/{}.jsonc", name);
+        let Some(mut text) = read(&path) else {
+            continue;
+        };
+        json_strip_comments::strip(&mut text)
+            .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", path, e)));
+        let file: Value = serde_json::from_str(&text)
+            .unwrap_or_else(|e| error_exit(format!("Cannot parse {}: {}", path, e)));
+
+        let puzzles = match (&file["puzzles"], &file["puzzle"]) {
+            (Value::Array(puzzles), _) => puzzles.clone(),
+            (_, puzzle @ Value::Object(_)) => vec![puzzle.clone()],
+            _ => Vec::new(),
+        };
+        for puzzle in puzzles {
+            let id = data_file_id(name, &puzzle);
+            insert(&mut snapshot, name, id, puzzle);
+        }
+    }
+    snapshot
+}
+
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Cannot run git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    String::from_utf8(output.stdout).map_err(|_| "git printed non-UTF-8 output".to_string())
+}
+
+fn insert(snapshot: &mut Snapshot, collection: &str, id: String, puzzle: Value) {
+    let address = puzzle["address"]["value"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    snapshot.insert((collection.to_string(), address), (id, puzzle));
+}
+
+fn diff(old: &Snapshot, new: &Snapshot) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for (key, (id, puzzle)) in new {
+        let Some((_, before)) = old.get(key) else {
+            changes.push(Change {
+                id: id.clone(),
+                change: Kind::Added,
+                field: None,
+                old: Value::Null,
+                new: Value::Null,
+            });
+            continue;
+        };
+        for (field, pointer) in FIELDS {
+            let was = before.pointer(pointer).unwrap_or(&Value::Null);
+            let is = puzzle.pointer(pointer).unwrap_or(&Value::Null);
+            if !same(was, is) {
+                changes.push(Change {
+                    id: id.clone(),
+                    change: Kind::Changed,
+                    field: Some(field),
+                    old: was.clone(),
+                    new: is.clone(),
+                });
+            }
+        }
+    }
+    for (key, (id, _)) in old {
+        if !new.contains_key(key) {
+            changes.push(Change {
+                id: id.clone(),
+                change: Kind::Removed,
+                field: None,
+                old: Value::Null,
+                new: Value::Null,
+            });
+        }
+    }
+    changes
+}
+
+/// Equal values, with numbers compared by value so `1` matches `1.0`.
+#[allow(clippy::float_cmp)]
+fn same(a: &Value, b: &Value) -> bool {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Scalar as plain text; `None` for null.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn output_changes(ctx: &Context, changes: &[Change]) {
+    match ctx.output {
+        OutputFormat::Table => {
+            if changes.is_empty() {
+                outln!(ctx, "{}", "No changes".dimmed());
+                return;
+            }
+            let rows: Vec<ChangeTableRow> = changes
+                .iter()
+                .map(|c| ChangeTableRow {
+                    id: c.id.clone(),
+                    change: match c.change {
+                        Kind::Added => c.change.name().green().to_string(),
+                        Kind::Removed => c.change.name().red().to_string(),
+                        Kind::Changed => c.change.name().bright_yellow().to_string(),
+                    },
+                    field: c.field.unwrap_or_default().to_string(),
+                    old: text(&c.old).unwrap_or_else(|| "-".dimmed().to_string()),
+                    new: text(&c.new).unwrap_or_else(|| "-".dimmed().to_string()),
+                })
+                .collect();
+            let table = Table::new(rows).with(Style::rounded()).to_string();
+            outln!(ctx, "{}", table);
+
+            let count = |kind| changes.iter().filter(|c| c.change == kind).count();
+            outln!(
+                ctx,
+                "\n{} {} added, {} removed, {} changed fields",
+                "Total:".dimmed(),
+                count(Kind::Added),
+                count(Kind::Removed),
+                count(Kind::Changed)
+            );
+        }
+        OutputFormat::Json => {
+            print_json(ctx, changes);
+        }
+        OutputFormat::Jsonl => {
+            for change in changes {
+                outln!(ctx, "{}", serde_json::to_string(change).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(changes).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            wtr.write_record(["id", "change", "field", "old", "new"])
+                .unwrap();
+            for c in changes {
+                wtr.write_record([
+                    c.id.clone(),
+                    c.change.name().to_string(),
+                    c.field.unwrap_or_default().to_string(),
+                    text(&c.old).unwrap_or_default(),
+                    text(&c.new).unwrap_or_default(),
+                ])
+                .unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn snapshot(puzzles: &[Value]) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        for puzzle in puzzles {
+            let id = puzzle["id"].as_str().unwrap().to_string();
+            insert(&mut snapshot, "b1000", id, puzzle.clone());
+        }
+        snapshot
+    }
+
+    #[test]
+    fn reports_field_changes_and_membership() {
+        let old = snapshot(&[
+            json!({"id": "b1000/71", "address": {"value": "1a"}, "status": "unsolved", "prize": 7.1}),
+            json!({"id": "b1000/72", "address": {"value": "1b"}, "status": "unsolved"}),
+        ]);
+        let new = snapshot(&[
+            json!({"id": "b1000/71", "address": {"value": "1a"}, "status": "solved", "prize": 7.1,
+                   "pubkey": {"value": "02ab", "format": "compressed"}}),
+            json!({"id": "b1000/73", "address": {"value": "1c"}, "status": "unsolved"}),
+        ]);
+        let changes: Vec<(String, Kind, Option<&str>)> = diff(&old, &new)
+            .into_iter()
+            .map(|c| (c.id, c.change, c.field))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("b1000/71".to_string(), Kind::Changed, Some("status")),
+                ("b1000/71".to_string(), Kind::Changed, Some("pubkey")),
+                ("b1000/73".to_string(), Kind::Added, None),
+                ("b1000/72".to_string(), Kind::Removed, None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Expected brute-force time for a puzzle at a given search rate.
+
+use crate::color::Colorize;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_record, KeyValueRow};
+use crate::util::puzzle_or_exit;
+
+#[derive(Debug, Args)]
+pub struct EstimateArgs {
+    /// Puzzle id, or a b1000 puzzle number
+    #[arg(add = ArgValueCandidates::new(super::completions::puzzle_ids))]
+    pub id: String,
+
+    /// Search rate, e.g. 1.2Gkeys/s, 850M or 3e9 (k, M, G, T, P, E prefixes)
+    #[arg(long, value_parser = parse_rate)]
+    pub rate: f64,
+}
+
+#[derive(Serialize)]
+struct EstimateOutput {
+    puzzle: &'static str,
+    keys: String,
+    keys_per_second: f64,
+    exhaust_secs: f64,
+    half_secs: f64,
+    exhaust: String,
+    half: String,
+}
+
+pub fn run(ctx: &Context, args: &EstimateArgs) {
+    let puzzle = puzzle_or_exit(&args.id);
+    let effort = puzzle
+        .estimated_effort(args.rate)
+        .unwrap_or_else(|| error_exit(format!("{} has no known key range", puzzle.id)));
+
+    let output = EstimateOutput {
+        puzzle: puzzle.id,
+        keys: effort.keys.to_string(),
+        keys_per_second: effort.keys_per_second,
+        exhaust_secs: effort.exhaust_secs,
+        half_secs: effort.half_secs,
+        exhaust: effort.exhaust_formatted(),
+        half: effort.half_formatted(),
+    };
+    print_record(ctx, &output, print_table);
+}
+
+fn print_table(ctx: &Context, output: &EstimateOutput) {
+    let rows = vec![
+        KeyValueRow {
+            field: "Puzzle".to_string(),
+            value: output.puzzle.bright_white().to_string(),
+        },
+        KeyValueRow {
+            field: "Keys".to_string(),
+            value: output.keys.clone(),
+        },
+        KeyValueRow {
+            field: "Rate".to_string(),
+            value: format_rate(output.keys_per_second),
+        },
+        KeyValueRow {
+            field: "50% chance".to_string(),
+            value: output.half.clone(),
+        },
+        KeyValueRow {
+            field: "Full range".to_string(),
+            value: output.exhaust.clone(),
+        },
+    ];
+    outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+}
+
+const PREFIXES: [(&str, f64); 6] = [
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+    ("T", 1e12),
+    ("P", 1e15),
+    ("E", 1e18),
+];
+
+/// Parse a `--rate` value: a number with an optional SI prefix and an
+/// optional `keys/s` unit. `m` is mega; nobody searches millikeys.
+pub fn parse_rate(value: &str) -> Result<f64, String> {
+    let lower = value.trim().to_ascii_lowercase();
+    let number = lower.strip_suffix("/s").unwrap_or(&lower);
+    let number = number
+        .strip_suffix("keys")
+        .or_else(|| number.strip_suffix("key"))
+        .unwrap_or(number)
+        .trim_end();
+
+    let (number, scale) = PREFIXES
+        .iter()
+        .find_map(|(prefix, scale)| {
+            number
+                .strip_suffix(prefix.to_ascii_lowercase().as_str())
+                .map(|n| (n, *scale))
+        })
+        .unwrap_or((number, 1.0));
+
+    let rate = number
+        .parse::<f64>()
+        .ok()
+        .map(|n| n * scale)
+        .filter(|rate| rate.is_finite() && *rate > 0.0);
+    rate.ok_or_else(|| format!("expected a positive rate like 1.2Gkeys/s, got {}", value))
+}
+
+/// `rate` with the largest SI prefix that keeps it at or above 1.
+fn format_rate(rate: f64) -> String {
+    PREFIXES
+        .iter()
+        .rev()
+        .find(|(_, scale)| rate >= *scale)
+        .map_or_else(
+            || format!("{} keys/s", trim_decimals(rate)),
+            |(prefix, scale)| format!("{}{} keys/s", trim_decimals(rate / scale), prefix),
+        )
+}
+
+/// Two decimals at most, without trailing zeros.
+fn trim_decimals(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates_accept_prefixes_and_units() {
+        assert_eq!(parse_rate("1.2Gkeys/s"), Ok(1.2e9));
+        assert_eq!(parse_rate("850M"), Ok(850e6));
+        assert_eq!(parse_rate("3e9"), Ok(3e9));
+        assert_eq!(parse_rate("500 keys/s"), Ok(500.0));
+        assert_eq!(parse_rate("2k/s"), Ok(2000.0));
+        assert!(parse_rate("0").is_err());
+        assert!(parse_rate("fast").is_err());
+    }
+
+    #[test]
+    fn rates_print_with_prefixes() {
+        assert_eq!(format_rate(1.2e9), "1.2G keys/s");
+        assert_eq!(format_rate(2000.0), "2k keys/s");
+        assert_eq!(format_rate(500.0), "500 keys/s");
+        assert_eq!(format_rate(1.1e9 / 3.0), "366.67M keys/s");
+    }
+}
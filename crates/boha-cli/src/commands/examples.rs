@@ -0,0 +1,277 @@
+//! Runnable example invocations, built from puzzles in the embedded dataset.
+//!
+//! Ids are looked up at run time rather than written down, so the examples
+//! keep working as puzzles get solved. `tests/cli.rs` runs every one of them.
+
+use crate::color::Colorize;
+use boha::{b1000, Puzzle, Status};
+use clap::Args;
+use serde::Serialize;
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+use crate::util::shell_quote;
+
+#[derive(Debug, Args)]
+pub struct ExamplesArgs {
+    /// Only examples for this command (e.g. range, grep-scripts)
+    pub command: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Example {
+    command: &'static str,
+    description: &'static str,
+    /// Arguments after `boha`, unquoted.
+    args: Vec<String>,
+    /// The invocation as typed into a shell.
+    line: String,
+}
+
+pub fn run(ctx: &Context, args: &ExamplesArgs) {
+    let mut examples = examples();
+    if let Some(command) = &args.command {
+        examples.retain(|e| e.command == command);
+        if examples.is_empty() {
+            let mut known: Vec<&str> = self::examples().iter().map(|e| e.command).collect();
+            known.dedup();
+            error_exit(format!(
+                "No examples for '{}'. Commands with examples: {}",
+                command,
+                known.join(", ")
+            ));
+        }
+    }
+
+    match ctx.output {
+        OutputFormat::Table => {
+            for (i, example) in examples.iter().enumerate() {
+                if i > 0 {
+                    outln!(ctx);
+                }
+                outln!(ctx, "{}", format!("# {}", example.description).dimmed());
+                outln!(ctx, "{}", example.line);
+            }
+        }
+        OutputFormat::Json => {
+            print_json(ctx, &examples);
+        }
+        OutputFormat::Jsonl => {
+            for example in &examples {
+                outln!(ctx, "{}", serde_json::to_string(example).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(&examples).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            wtr.write_record(["command", "description", "line"])
+                .unwrap();
+            for example in &examples {
+                wtr.write_record([example.command, example.description, &example.line])
+                    .unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+/// Every example, grouped by command in `--help` order. Examples whose
+/// puzzle no longer exists in the data are left out.
+fn examples() -> Vec<Example> {
+    let open = b1000::unsolved().next();
+    let open_with_pubkey = b1000::with_pubkey().find(|p| p.status == Status::Unsolved);
+    let solved = b1000::solved()
+        .filter(|p| p.key.is_some_and(|k| k.hex.is_some()))
+        .last();
+    let scripted = boha::all().find(|p| p.address.redeem_script.is_some());
+
+    let mut examples = vec![
+        example(
+            "list",
+            "Unsolved b1000 puzzles",
+            &["list", "b1000", "--unsolved"],
+        ),
+        example(
+            "list",
+            "Unsolved puzzles with a revealed public key",
+            &["list", "b1000", "--unsolved", "--with-pubkey"],
+        ),
+    ];
+    if let Some(p) = solved {
+        examples.push(example(
+            "show",
+            "Details of a solved puzzle, private key included",
+            &["show", p.id, "--show-key"],
+        ));
+        examples.push(example(
+            "show",
+            "The same puzzle with its transactions summarised in one line",
+            &["show", p.id, "--no-transactions"],
+        ));
+    }
+    examples.push(example(
+        "stats",
+        "Totals across every collection",
+        &["stats"],
+    ));
+    examples.push(example(
+        "stats",
+        "Counts and prizes per author",
+        &["stats", "--by-author"],
+    ));
+    if let Some(number) = open.and_then(puzzle_number) {
+        examples.push(example(
+            "range",
+            "Key range of the lowest unsolved b1000 puzzle",
+            &["range", number],
+        ));
+        examples.push(example(
+            "range",
+            "The same range split into 4 parts as KeyHunt arguments",
+            &["range", number, "--split", "4", "--format", "keyhunt"],
+        ));
+        examples.push(example(
+            "range",
+            "Five random keys from that range, e.g. to test a solver pipeline",
+            &["range", number, "--random", "5"],
+        ));
+    }
+    if let Some(p) = open_with_pubkey {
+        examples.push(example(
+            "pubkey",
+            "Kangaroo input for a puzzle with a revealed public key",
+            &["pubkey", p.id, "--kangaroo"],
+        ));
+    }
+    if let Some(p) = open {
+        examples.push(example(
+            "solver-config",
+            "BitCrack arguments for an unsolved puzzle",
+            &["solver-config", p.id, "--tool", "bitcrack"],
+        ));
+        examples.push(example(
+            "estimate",
+            "How long that puzzle takes at 1.2 billion keys per second",
+            &["estimate", p.id, "--rate", "1.2Gkeys/s"],
+        ));
+    }
+    examples.push(example(
+        "rank",
+        "The ten unsolved puzzles paying the most per day of search at 1.2 billion keys/s",
+        &["rank", "--rate", "1.2Gkeys/s", "--limit", "10"],
+    ));
+    examples.push(example("author", "Who funded b1000", &["author", "b1000"]));
+    examples.push(example(
+        "authors",
+        "Every author with their collections and prize totals",
+        &["authors"],
+    ));
+    if let Some(p) = open {
+        examples.push(example(
+            "search",
+            "Puzzles matching the start of an address",
+            &["search", &p.address.value[..8], "--limit", "5"],
+        ));
+        examples.push(example(
+            "decode",
+            "Type and HASH160 of an address, and which puzzle it is",
+            &["decode", p.address.value],
+        ));
+    }
+    if scripted.is_some() {
+        examples.push(example(
+            "grep-scripts",
+            "Redeem scripts that hash with SHA-1",
+            &["grep-scripts", "OP_SHA1 * OP_SHA1"],
+        ));
+    }
+    if let Some(p) = solved {
+        examples.push(example(
+            "verify",
+            "Check that a solved key derives its address",
+            &["verify", p.id],
+        ));
+    }
+    examples.push(example(
+        "derive",
+        "Every address of private key 1",
+        &[
+            "derive",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ],
+    ));
+    examples.push(example(
+        "wif",
+        "The same key as a testnet WIF",
+        &[
+            "wif",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "--network",
+            "testnet",
+        ],
+    ));
+    examples.push(example(
+        "export",
+        "Unsolved b1000 puzzles as compact JSON",
+        &["export", "b1000", "--unsolved", "--compact"],
+    ));
+    examples.push(example(
+        "metrics",
+        "Prometheus gauges for one collection",
+        &["metrics", "b1000"],
+    ));
+    examples.push(example(
+        "analyze",
+        "Solvers grouped across collections",
+        &["analyze", "solvers", "--link-addresses"],
+    ));
+    examples
+}
+
+fn example(command: &'static str, description: &'static str, args: &[&str]) -> Example {
+    let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+    let line = std::iter::once("boha".to_string())
+        .chain(args.iter().map(|a| shell_quote(a)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    Example {
+        command,
+        description,
+        args,
+        line,
+    }
+}
+
+/// The number in a b1000 id (`"b1000/71"` → `"71"`).
+fn puzzle_number(puzzle: &Puzzle) -> Option<&'static str> {
+    puzzle.id.strip_prefix("b1000/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_quote_patterns() {
+        let e = example("grep-scripts", "", &["grep-scripts", "OP_SHA1 * OP_SHA1"]);
+        assert_eq!(e.line, "boha grep-scripts 'OP_SHA1 * OP_SHA1'");
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+        assert_eq!(shell_quote("b1000/71"), "b1000/71");
+    }
+
+    #[test]
+    fn examples_are_grouped_by_command() {
+        let examples = examples();
+        let mut seen: Vec<&str> = Vec::new();
+        for e in &examples {
+            if seen.last() != Some(&e.command) {
+                assert!(!seen.contains(&e.command), "{} is split up", e.command);
+                seen.push(e.command);
+            }
+            assert_eq!(e.args[0], e.command);
+        }
+    }
+}
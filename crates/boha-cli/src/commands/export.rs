@@ -0,0 +1,728 @@
+use boha::{Assets, Author, Puzzle, Stats, Status};
+use chrono::Utc;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use super::list::DateArgs;
+use crate::calendar;
+use crate::compression::{decompress_auto, Compression, Encoder};
+use crate::context::Context;
+use crate::feed;
+use crate::output::{self, error_exit, out, outln, OutputFormat};
+use crate::report::{self, Report, Section};
+use crate::util::collection_or_exit;
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// Collections to export (default: all)
+    #[arg(add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collections: Vec<String>,
+
+    /// Exclude author information
+    #[arg(long)]
+    pub no_authors: bool,
+
+    /// Exclude statistics
+    #[arg(long)]
+    pub no_stats: bool,
+
+    /// Force compact JSON output
+    #[arg(long)]
+    pub compact: bool,
+
+    /// Export only unsolved puzzles
+    #[arg(long, conflicts_with = "solved")]
+    pub unsolved: bool,
+
+    /// Export only solved puzzles
+    #[arg(long, conflicts_with = "unsolved")]
+    pub solved: bool,
+
+    #[command(flatten)]
+    pub dates: DateArgs,
+
+    /// Write one file per collection (like `data/`) plus an index.json
+    /// manifest into DIR; each file is replaced atomically
+    #[arg(long, value_name = "DIR", visible_alias = "split-dir")]
+    pub split_per_collection: Option<PathBuf>,
+
+    /// Only export puzzles added or changed since the export that wrote this index.json
+    #[arg(long, value_name = "MANIFEST")]
+    pub since: Option<PathBuf>,
+
+    /// Copy referenced asset files into DIR as `<sha256>.<ext>` and point
+    /// the exported asset paths at the copies
+    #[arg(long, value_name = "DIR")]
+    pub with_assets: Option<PathBuf>,
+
+    /// The `assets/` tree of a boha checkout to copy from
+    #[arg(
+        long,
+        value_name = "DIR",
+        default_value = "assets",
+        requires = "with_assets"
+    )]
+    pub assets_from: PathBuf,
+
+    /// Emit a Bitcoin Core `importdescriptors` request for the Bitcoin
+    /// puzzles instead of the dataset
+    #[arg(
+        long,
+        conflicts_with_all = ["split_per_collection", "since", "with_assets"]
+    )]
+    pub descriptors: bool,
+
+    /// Print the JSON Schema of the `-o json` export and exit
+    #[arg(
+        long,
+        conflicts_with_all = ["split_per_collection", "since", "with_assets", "descriptors"]
+    )]
+    pub schema: bool,
+
+    /// With `-o ics`, add a yearly reminder of the funding date for each
+    /// unsolved puzzle
+    #[arg(long)]
+    pub anniversaries: bool,
+
+    /// Compress the output as it is written
+    #[arg(long, value_name = "FORMAT", conflicts_with_all = ["gzip", "zstd"])]
+    pub compress: Option<Compression>,
+
+    /// Same as `--compress gzip`
+    #[arg(long, conflicts_with = "zstd")]
+    pub gzip: bool,
+
+    /// Same as `--compress zstd`
+    #[arg(long)]
+    pub zstd: bool,
+
+    /// Add the prize value at solve date to solved puzzles
+    #[cfg(feature = "price")]
+    #[arg(long)]
+    pub with_historical_value: bool,
+
+    /// Fiat currency for --with-historical-value
+    #[cfg(feature = "price")]
+    #[arg(long, value_name = "CODE", default_value = "usd", value_parser = crate::util::parse_currency, requires = "with_historical_value")]
+    pub currency: String,
+}
+
+impl ExportArgs {
+    const fn compression(&self) -> Option<Compression> {
+        if self.gzip {
+            Some(Compression::Gzip)
+        } else if self.zstd {
+            Some(Compression::Zstd)
+        } else {
+            self.compress
+        }
+    }
+}
+
+/// Version of the JSON export layout, described by `export --schema`.
+///
+/// Changes within a version are additive only: fields are never renamed,
+/// removed or reshaped. Keys are written in declaration order.
+pub const SCHEMA_VERSION: u32 = 1;
+
+const SCHEMA: &str = include_str!("../../schemas/export.schema.json");
+
+#[derive(Serialize)]
+struct CollectionExport {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<&'static Author>,
+    puzzles: Vec<ExportPuzzle>,
+}
+
+#[derive(Serialize)]
+struct ExportPuzzle {
+    #[serde(flatten)]
+    puzzle: &'static Puzzle,
+    #[cfg(feature = "price")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    historical_value: Option<boha_chain::price::HistoricalValue>,
+}
+
+#[derive(Serialize)]
+struct ManifestEntry {
+    name: &'static str,
+    file: String,
+    puzzles: usize,
+    sha256: String,
+}
+
+#[derive(Serialize)]
+struct Manifest<'a> {
+    schema_version: u32,
+    version: &'static str,
+    exported_at: &'a str,
+    format: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compression: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<&'a Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<&'a Delta>,
+    collections: Vec<ManifestEntry>,
+    /// SHA-256 of every selected puzzle's compact JSON, including ones left
+    /// out by `--since`, so the next incremental export can diff against it.
+    puzzle_hashes: &'a BTreeMap<&'static str, String>,
+}
+
+/// The part of a previous manifest `--since` needs.
+#[derive(Deserialize)]
+struct PreviousManifest {
+    exported_at: String,
+    puzzle_hashes: Option<HashMap<String, String>>,
+}
+
+/// What an incremental export is relative to.
+#[derive(Serialize)]
+struct Delta {
+    /// `exported_at` of the manifest passed to `--since`.
+    exported_at: String,
+    /// Puzzles in that export which are no longer selected.
+    removed: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ExportData {
+    schema_version: u32,
+    version: &'static str,
+    exported_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<Delta>,
+    collections: Vec<CollectionExport>,
+    #[serde(skip)]
+    puzzle_hashes: BTreeMap<&'static str, String>,
+}
+
+pub fn run(ctx: &Context, args: &ExportArgs) {
+    use std::collections::HashSet;
+
+    if args.schema {
+        out!(ctx, "{}", SCHEMA);
+        return;
+    }
+
+    let mut seen = HashSet::new();
+    #[allow(clippy::useless_let_if_seq)]
+    let mut collections_to_export = Vec::new();
+
+    if args.collections.is_empty() {
+        collections_to_export = boha::collections();
+    } else {
+        for collection in &args.collections {
+            if collection == "all" {
+                collections_to_export = boha::collections();
+                break;
+            }
+
+            let canonical = collection_or_exit(collection, true);
+
+            if seen.insert(canonical.name()) {
+                collections_to_export.push(canonical);
+            }
+        }
+    }
+
+    let mut filter = boha::Filter::new();
+    args.dates.apply(&mut filter);
+
+    let mut export_collections = Vec::new();
+
+    for collection in collections_to_export {
+        let name = collection.name();
+        let author = Some(collection.author());
+
+        // Apply status and date filtering
+        #[cfg_attr(not(feature = "price"), allow(clippy::needless_collect))]
+        let filtered: Vec<&'static Puzzle> = collection
+            .all()
+            .filter(|p| !args.unsolved || p.status == Status::Unsolved)
+            .filter(|p| !args.solved || p.status == Status::Solved)
+            .filter(|p| filter.matches(p))
+            .collect();
+
+        #[cfg(feature = "price")]
+        let mut historical_values = if args.with_historical_value {
+            let solved: Vec<_> = filtered
+                .iter()
+                .copied()
+                .filter(|p| p.status == Status::Solved)
+                .collect();
+            solved
+                .iter()
+                .map(|p| p.id)
+                .zip(crate::util::historical_values_or_exit(
+                    &solved,
+                    &args.currency,
+                ))
+                .collect()
+        } else {
+            std::collections::HashMap::new()
+        };
+
+        export_collections.push(CollectionExport {
+            name,
+            author: if args.no_authors { None } else { author },
+            puzzles: filtered
+                .into_iter()
+                .map(|puzzle| ExportPuzzle {
+                    puzzle,
+                    #[cfg(feature = "price")]
+                    historical_value: historical_values.remove(puzzle.id).flatten(),
+                })
+                .collect(),
+        });
+    }
+
+    if args.descriptors {
+        output_descriptors(ctx, &export_collections, args.compact);
+        return;
+    }
+
+    let stats = if args.no_stats {
+        None
+    } else {
+        let mut stats = boha::Stats::default();
+        for collection in &export_collections {
+            for ExportPuzzle { puzzle, .. } in &collection.puzzles {
+                stats.total += 1;
+                match puzzle.status {
+                    Status::Solved => stats.solved += 1,
+                    Status::Unsolved => stats.unsolved += 1,
+                    Status::Claimed => stats.claimed += 1,
+                    Status::Swept => stats.swept += 1,
+                    Status::Expired => stats.expired += 1,
+                }
+                if puzzle.has_pubkey() {
+                    stats.with_pubkey += 1;
+                }
+                if let Some(prize) = puzzle.prize {
+                    let currency = puzzle.currency().to_string();
+                    *stats.total_prize.entry(currency.clone()).or_insert(0.0) += prize;
+                    if puzzle.status == Status::Unsolved {
+                        *stats.unsolved_prize.entry(currency).or_insert(0.0) += prize;
+                    }
+                }
+            }
+        }
+        Some(stats)
+    };
+
+    let puzzle_hashes: BTreeMap<&'static str, String> = export_collections
+        .iter()
+        .flat_map(|c| &c.puzzles)
+        .map(|p| (p.puzzle.id, puzzle_hash(p.puzzle)))
+        .collect();
+
+    let since = args.since.as_deref().map(|path| {
+        let delta = keep_changed(&mut export_collections, &puzzle_hashes, path);
+        let changed: usize = export_collections.iter().map(|c| c.puzzles.len()).sum();
+        eprintln!(
+            "{} changed, {} removed since {}",
+            changed,
+            delta.removed.len(),
+            delta.exported_at
+        );
+        delta
+    });
+
+    if let Some(dir) = &args.with_assets {
+        let base = args.split_per_collection.as_deref();
+        bundle_assets(&mut export_collections, &args.assets_from, dir, base);
+    }
+
+    let export_data = ExportData {
+        schema_version: SCHEMA_VERSION,
+        version: boha::version::FULL_VERSION,
+        exported_at: Utc::now().to_rfc3339(),
+        stats,
+        since,
+        collections: export_collections,
+        puzzle_hashes,
+    };
+
+    let format = if matches!(ctx.output, OutputFormat::Table) {
+        OutputFormat::Json
+    } else {
+        ctx.output
+    };
+    if args.anniversaries && !matches!(format, OutputFormat::Ics) {
+        error_exit("--anniversaries requires -o ics");
+    }
+
+    if let Some(dir) = &args.split_per_collection {
+        write_split(dir, &export_data, format, args.compact, args.compression());
+    } else {
+        output_export(
+            ctx,
+            &export_data,
+            format,
+            args.compact,
+            args.anniversaries,
+            args.compression(),
+        );
+    }
+}
+
+/// One entry of a Bitcoin Core `importdescriptors` request.
+#[derive(Serialize)]
+struct ImportDescriptor {
+    desc: String,
+    /// Unix time to rescan from; 0 rescans the whole chain.
+    timestamp: i64,
+    label: &'static str,
+}
+
+fn output_descriptors(ctx: &Context, collections: &[CollectionExport], compact: bool) {
+    let requests: Vec<ImportDescriptor> = collections
+        .iter()
+        .flat_map(|c| &c.puzzles)
+        .filter_map(|ExportPuzzle { puzzle, .. }| {
+            Some(ImportDescriptor {
+                desc: puzzle.descriptor()?,
+                timestamp: rescan_from(puzzle),
+                label: puzzle.id,
+            })
+        })
+        .collect();
+
+    if requests.is_empty() {
+        error_exit("No Bitcoin puzzles selected; descriptors are Bitcoin-only");
+    }
+    let json = if !compact && ctx.out.is_terminal() {
+        serde_json::to_string_pretty(&requests).unwrap()
+    } else {
+        serde_json::to_string(&requests).unwrap()
+    };
+    outln!(ctx, "{}", json);
+}
+
+/// Earliest recorded date of a puzzle, so the rescan sees its funding.
+fn rescan_from(puzzle: &Puzzle) -> i64 {
+    puzzle
+        .transactions
+        .iter()
+        .filter_map(|tx| tx.date?.parse::<boha::DateTime>().ok())
+        .chain(puzzle.start_date)
+        .map(|date| date.naive_datetime().and_utc().timestamp())
+        .min()
+        .unwrap_or(0)
+}
+
+fn puzzle_hash(puzzle: &Puzzle) -> String {
+    hex::encode(Sha256::digest(serde_json::to_vec(puzzle).unwrap()))
+}
+
+/// Drop puzzles whose hash matches the manifest at `path`, and collections
+/// left empty. Returns what the export is now relative to.
+fn keep_changed(
+    collections: &mut Vec<CollectionExport>,
+    hashes: &BTreeMap<&'static str, String>,
+    path: &Path,
+) -> Delta {
+    let raw = std::fs::read(path)
+        .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", path.display(), e)));
+    let bytes = decompress_auto(raw)
+        .unwrap_or_else(|e| error_exit(format!("Cannot decompress {}: {}", path.display(), e)));
+    let previous: PreviousManifest = serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+        error_exit(format!(
+            "{} is not an export manifest: {}",
+            path.display(),
+            e
+        ))
+    });
+    let Some(previous_hashes) = previous.puzzle_hashes else {
+        error_exit(format!(
+            "{} has no puzzle_hashes; it predates incremental exports",
+            path.display()
+        ));
+    };
+
+    for collection in collections.iter_mut() {
+        collection
+            .puzzles
+            .retain(|p| previous_hashes.get(p.puzzle.id) != hashes.get(p.puzzle.id));
+    }
+
+    // Only report removals from collections this export covers.
+    let exported: Vec<&str> = collections.iter().map(|c| c.name).collect();
+    let mut removed: Vec<String> = previous_hashes
+        .into_keys()
+        .filter(|id| !hashes.contains_key(id.as_str()))
+        .filter(|id| exported.contains(&id.split('/').next().unwrap_or(id)))
+        .collect();
+    removed.sort();
+
+    collections.retain(|c| !c.puzzles.is_empty());
+    Delta {
+        exported_at: previous.exported_at,
+        removed,
+    }
+}
+
+/// Copy every asset of the exported puzzles into `dir`, named by content
+/// hash so identical files are stored once, and rewrite the puzzles' asset
+/// paths to `<dir>/<sha256>.<ext>`, relative to `base` when `dir` is inside it.
+fn bundle_assets(
+    collections: &mut [CollectionExport],
+    source: &Path,
+    dir: &Path,
+    base: Option<&Path>,
+) {
+    std::fs::create_dir_all(dir)
+        .unwrap_or_else(|e| error_exit(format!("Cannot create {}: {}", dir.display(), e)));
+
+    let mut bundled: HashMap<PathBuf, &'static str> = HashMap::new();
+    let mut bytes_written = 0;
+    for entry in collections.iter_mut().flat_map(|c| &mut c.puzzles) {
+        let Some(assets) = entry.puzzle.assets else {
+            continue;
+        };
+        let mut bundle = |relative: &str| {
+            let path = source.join(entry.puzzle.collection()).join(relative);
+            if let Some(bundled_path) = bundled.get(&path) {
+                return *bundled_path;
+            }
+            let contents = std::fs::read(&path).unwrap_or_else(|e| {
+                error_exit(format!(
+                    "Cannot read asset {} of {}: {} (use --assets-from to point at a boha checkout's assets/)",
+                    path.display(),
+                    entry.puzzle.id,
+                    e
+                ))
+            });
+            let mut name = hex::encode(Sha256::digest(&contents));
+            if let Some(ext) = path.extension() {
+                name = format!("{}.{}", name, ext.to_string_lossy());
+            }
+            let target = dir.join(&name);
+            if !target.exists() {
+                write_file(&target, &contents);
+                bytes_written += contents.len();
+            }
+            // The export is built once per process, so leaking the few
+            // rewritten paths is simpler than owning them.
+            let shown = base
+                .and_then(|base| target.strip_prefix(base).ok())
+                .unwrap_or(&target);
+            let bundled_path: &'static str = shown.to_string_lossy().into_owned().leak();
+            bundled.insert(path, bundled_path);
+            bundled_path
+        };
+
+        let rewritten = Assets {
+            puzzle: assets.puzzle.map(&mut bundle),
+            solver: assets.solver.map(&mut bundle),
+            hints: assets
+                .hints
+                .iter()
+                .map(|hint| bundle(hint))
+                .collect::<Vec<_>>()
+                .leak(),
+            source_url: assets.source_url,
+        };
+        entry.puzzle = Box::leak(Box::new(Puzzle {
+            assets: Some(rewritten),
+            ..entry.puzzle.clone()
+        }));
+    }
+
+    let files: std::collections::HashSet<_> = bundled.values().collect();
+    eprintln!(
+        "Bundled {} assets ({} bytes written) into {}",
+        files.len(),
+        bytes_written,
+        dir.display()
+    );
+}
+
+fn write_split(
+    dir: &Path,
+    data: &ExportData,
+    format: OutputFormat,
+    compact: bool,
+    compression: Option<Compression>,
+) {
+    let format_name = match format {
+        OutputFormat::Json => "json",
+        OutputFormat::Jsonl => "jsonl",
+        _ => error_exit("--split-per-collection supports json and jsonl output"),
+    };
+    let extension = compression.map_or_else(
+        || format_name.to_string(),
+        |c| format!("{}.{}", format_name, c.extension()),
+    );
+
+    std::fs::create_dir_all(dir)
+        .unwrap_or_else(|e| error_exit(format!("Cannot create {}: {}", dir.display(), e)));
+
+    let mut entries = Vec::new();
+    for collection in &data.collections {
+        let mut encoder = Encoder::new(Vec::new(), compression)
+            .unwrap_or_else(|e| error_exit(format!("Compression failed: {}", e)));
+        let written = if matches!(format, OutputFormat::Jsonl) {
+            write_jsonl(&mut encoder, collection.puzzles.iter())
+        } else {
+            write_json(&mut encoder, collection, !compact)
+        };
+        let bytes = written
+            .and_then(|()| encoder.finish())
+            .unwrap_or_else(|e| error_exit(format!("Compression failed: {}", e)));
+        let file = format!("{}.{}", collection.name, extension);
+        write_file(&dir.join(&file), &bytes);
+        entries.push(ManifestEntry {
+            name: collection.name,
+            file,
+            puzzles: collection.puzzles.len(),
+            sha256: hex::encode(Sha256::digest(&bytes)),
+        });
+    }
+
+    let manifest = Manifest {
+        schema_version: SCHEMA_VERSION,
+        version: data.version,
+        exported_at: &data.exported_at,
+        format: format_name,
+        compression: compression.map(Compression::extension),
+        stats: data.stats.as_ref(),
+        since: data.since.as_ref(),
+        collections: entries,
+        puzzle_hashes: &data.puzzle_hashes,
+    };
+    write_file(
+        &dir.join("index.json"),
+        (serde_json::to_string_pretty(&manifest).unwrap() + "\n").as_bytes(),
+    );
+
+    eprintln!(
+        "Wrote {} collections to {}",
+        manifest.collections.len(),
+        dir.display()
+    );
+}
+
+fn write_json(out: &mut impl Write, value: &impl Serialize, pretty: bool) -> io::Result<()> {
+    if pretty {
+        serde_json::to_writer_pretty(&mut *out, value)?;
+    } else {
+        serde_json::to_writer(&mut *out, value)?;
+    }
+    out.write_all(b"\n")
+}
+
+fn write_jsonl<'a>(
+    out: &mut impl Write,
+    puzzles: impl Iterator<Item = &'a ExportPuzzle>,
+) -> io::Result<()> {
+    for puzzle in puzzles {
+        serde_json::to_writer(&mut *out, puzzle)?;
+        out.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &[u8]) {
+    crate::util::write_atomic(path, contents)
+        .unwrap_or_else(|e| error_exit(format!("Cannot write {}: {}", path.display(), e)));
+}
+
+fn report(data: &ExportData) -> Report<'_> {
+    Report {
+        version: data.version,
+        exported_at: &data.exported_at,
+        stats: data.stats.as_ref(),
+        sections: data
+            .collections
+            .iter()
+            .map(|c| Section {
+                name: c.name,
+                author: c.author,
+                puzzles: c.puzzles.iter().map(|p| p.puzzle).collect(),
+            })
+            .collect(),
+    }
+}
+
+fn output_export(
+    ctx: &Context,
+    data: &ExportData,
+    format: OutputFormat,
+    compact: bool,
+    anniversaries: bool,
+    compression: Option<Compression>,
+) {
+    let puzzles = || -> Vec<&Puzzle> {
+        data.collections
+            .iter()
+            .flat_map(|c| &c.puzzles)
+            .map(|p| p.puzzle)
+            .collect()
+    };
+    match format {
+        OutputFormat::Table => {
+            eprintln!("Table format not supported for export. Use 'boha list' for table output.");
+            crate::output::exit(1);
+        }
+        OutputFormat::Yaml => {
+            eprintln!("YAML format not supported for export. Use 'boha list -o yaml' instead.");
+            crate::output::exit(1);
+        }
+        OutputFormat::Csv => {
+            eprintln!("CSV format not supported for export. Use 'boha list -o csv' instead.");
+            crate::output::exit(1);
+        }
+        #[cfg(feature = "parquet")]
+        OutputFormat::Parquet => {
+            if compression.is_some() {
+                error_exit("Parquet is compressed internally; drop --compress");
+            }
+            crate::commands::list::write_parquet(ctx, &puzzles());
+            return;
+        }
+        _ => {}
+    }
+
+    let terminal = ctx.out.is_terminal();
+    if compression.is_some() && terminal {
+        error_exit("Refusing to write compressed data to a terminal; redirect stdout to a file");
+    }
+    let stdout = io::BufWriter::new(&ctx.out);
+    let mut out = Encoder::new(stdout, compression)
+        .unwrap_or_else(|e| error_exit(format!("Cannot write export: {}", e)));
+    let written = match format {
+        OutputFormat::Json => match output::query() {
+            Some(query) => write_json(
+                &mut out,
+                &query.apply(&serde_json::to_value(data).unwrap()),
+                !compact && terminal,
+            ),
+            None => write_json(&mut out, data, !compact && terminal),
+        },
+        OutputFormat::Jsonl => {
+            write_jsonl(&mut out, data.collections.iter().flat_map(|c| &c.puzzles))
+        }
+        OutputFormat::Markdown => out.write_all(report::markdown(&report(data)).as_bytes()),
+        OutputFormat::Html => out.write_all(report::html(&report(data)).as_bytes()),
+        OutputFormat::Rss => out.write_all(feed::rss(&puzzles(), Utc::now()).as_bytes()),
+        OutputFormat::Ics => {
+            out.write_all(calendar::ics(&puzzles(), Utc::now(), anniversaries).as_bytes())
+        }
+        _ => unreachable!("rejected above"),
+    };
+    written
+        .and_then(|()| out.finish())
+        .and_then(|mut stdout| stdout.flush())
+        .unwrap_or_else(|e| error_exit(format!("Cannot write export: {}", e)));
+}
@@ -0,0 +1,208 @@
+//! Search stored redeem scripts by opcode sequence or raw hex.
+
+use boha::script::{self, Instruction};
+use boha::Puzzle;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct GrepScriptsArgs {
+    /// Opcodes in order (e.g. "OP_SHA1 OP_SWAP OP_SHA1"); `*` matches any one
+    /// element, hex matches a data push
+    pub pattern: String,
+
+    /// Match PATTERN as a hex fragment of the raw script instead
+    #[arg(long)]
+    pub hex: bool,
+
+    /// Only puzzles from this collection
+    #[arg(long, add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+}
+
+#[derive(Serialize, Tabled)]
+struct ScriptMatch {
+    #[tabled(rename = "ID")]
+    id: &'static str,
+    #[tabled(rename = "Address")]
+    address: &'static str,
+    #[tabled(rename = "Script")]
+    asm: String,
+    #[tabled(skip)]
+    script: &'static str,
+}
+
+/// One element of an opcode pattern.
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Any,
+    Opcode(u8),
+    Push(Vec<u8>),
+}
+
+impl Token {
+    fn matches(&self, instruction: &Instruction) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Opcode(op) => instruction.data.is_none() && instruction.opcode == *op,
+            Self::Push(data) => instruction.data == Some(data.as_slice()),
+        }
+    }
+}
+
+pub fn run(ctx: &Context, args: &GrepScriptsArgs) {
+    let matcher = if args.hex {
+        let fragment = args.pattern.trim().to_ascii_lowercase();
+        if fragment.is_empty() || hex::decode(&fragment).is_err() {
+            error_exit(format!(
+                "'{}' is not a whole number of hex bytes",
+                args.pattern
+            ));
+        }
+        Matcher::Hex(fragment)
+    } else {
+        Matcher::Opcodes(parse_pattern(&args.pattern).unwrap_or_else(|e| error_exit(e)))
+    };
+
+    let puzzles: Vec<&'static Puzzle> = match &args.collection {
+        Some(name) => collection_or_exit(name, false).all().collect(),
+        None => boha::all().collect(),
+    };
+    let hits: Vec<ScriptMatch> = puzzles
+        .into_iter()
+        .filter_map(|puzzle| {
+            let script = puzzle.address.redeem_script?.script;
+            let bytes = hex::decode(script).ok()?;
+            let instructions = script::parse(&bytes).ok()?;
+            matcher
+                .is_match(script, &instructions)
+                .then(|| ScriptMatch {
+                    id: puzzle.id,
+                    address: puzzle.address.value,
+                    asm: script::to_asm(script).unwrap_or_default(),
+                    script,
+                })
+        })
+        .collect();
+
+    match ctx.output {
+        OutputFormat::Table => {
+            if hits.is_empty() {
+                outln!(ctx, "No scripts match.");
+            } else {
+                outln!(ctx, "{}", Table::new(&hits).with(Style::rounded()));
+            }
+        }
+        OutputFormat::Json => {
+            print_json(ctx, &hits);
+        }
+        OutputFormat::Jsonl => {
+            for m in &hits {
+                outln!(ctx, "{}", serde_json::to_string(m).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(&hits).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for m in &hits {
+                wtr.serialize(m).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+enum Matcher {
+    Hex(String),
+    Opcodes(Vec<Token>),
+}
+
+impl Matcher {
+    fn is_match(&self, script_hex: &str, instructions: &[Instruction]) -> bool {
+        match self {
+            // Only byte-aligned hits count; an odd offset would straddle bytes.
+            Self::Hex(fragment) => script_hex
+                .match_indices(fragment.as_str())
+                .any(|(i, _)| i % 2 == 0),
+            Self::Opcodes(tokens) => instructions
+                .windows(tokens.len())
+                .any(|window| tokens.iter().zip(window).all(|(t, i)| t.matches(i))),
+        }
+    }
+}
+
+fn parse_pattern(pattern: &str) -> Result<Vec<Token>, String> {
+    let tokens: Vec<Token> = pattern
+        .split_whitespace()
+        .map(|word| {
+            if word == "*" {
+                Ok(Token::Any)
+            } else if let Some(op) = script::opcode_by_name(word) {
+                Ok(Token::Opcode(op))
+            } else if let Ok(data) = hex::decode(word) {
+                Ok(Token::Push(data))
+            } else {
+                Err(format!("Unknown opcode: {}", word))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    if tokens.is_empty() {
+        return Err("Empty pattern".to_string());
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, script_hex: &str) -> bool {
+        let bytes = hex::decode(script_hex).unwrap();
+        let instructions = script::parse(&bytes).unwrap();
+        Matcher::Opcodes(parse_pattern(pattern).unwrap()).is_match(script_hex, &instructions)
+    }
+
+    #[test]
+    fn opcode_sequences_must_be_contiguous() {
+        let sha1 = "6e879169a77ca787";
+        assert!(matches("OP_SHA1 OP_SWAP OP_SHA1", sha1));
+        assert!(matches("sha1 * sha1 equal", sha1));
+        assert!(!matches("OP_SHA1 OP_SHA1", sha1));
+        assert!(!matches("OP_HASH160", sha1));
+    }
+
+    #[test]
+    fn hex_tokens_match_pushes() {
+        let p2pkh = "76a914751e76e8199196d454941c45d1b3a323f1433bd688ac";
+        assert!(matches(
+            "OP_HASH160 751e76e8199196d454941c45d1b3a323f1433bd6",
+            p2pkh
+        ));
+        assert!(!matches("OP_HASH160 751e76e8", p2pkh));
+    }
+
+    #[test]
+    fn hex_fragments_are_byte_aligned() {
+        let matcher = Matcher::Hex("7ca7".to_string());
+        assert!(matcher.is_match("6e879169a77ca787", &[]));
+        assert!(!Matcher::Hex("e879".to_string()).is_match("6e879169a77ca787", &[]));
+    }
+
+    #[test]
+    fn unknown_words_are_rejected() {
+        assert_eq!(
+            parse_pattern("OP_SHA1 OP_BOGUS"),
+            Err("Unknown opcode: OP_BOGUS".to_string())
+        );
+        assert!(parse_pattern("  ").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! A puzzle's on-chain timeline: funding, top-ups, claims and sweeps.
+
+use boha::{Amount, Puzzle, TransactionType};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use super::show::{format_amount, format_transaction_type};
+use crate::context::Context;
+use crate::output::{outln, print_json, CliError, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct HistoryArgs {
+    /// Puzzle id (e.g. b1000/66)
+    #[arg(add = ArgValueCandidates::new(super::completions::puzzle_ids))]
+    pub id: String,
+}
+
+#[derive(Serialize)]
+struct HistoryEntry {
+    date: Option<&'static str>,
+    #[serde(rename = "type")]
+    tx_type: TransactionType,
+    amount: Option<f64>,
+    /// `amount` in base units (sats, wei, ...), exact.
+    native_amount: Option<Amount>,
+    currency: &'static str,
+    txid: Option<&'static str>,
+    explorer_url: Option<String>,
+}
+
+#[derive(Tabled)]
+struct HistoryRow {
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "Type")]
+    tx_type: String,
+    #[tabled(rename = "Amount")]
+    amount: String,
+    #[tabled(rename = "Transaction")]
+    link: String,
+}
+
+pub fn run(ctx: &Context, args: &HistoryArgs) {
+    let puzzle = boha::get(&args.id).unwrap_or_else(|e| CliError::from(e).exit());
+    let entries = entries(puzzle);
+
+    match ctx.output {
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                outln!(ctx, "No transactions recorded for {}.", puzzle.id);
+            } else {
+                outln!(ctx, "{}", Table::new(rows(&entries)).with(Style::rounded()));
+            }
+        }
+        OutputFormat::Json => {
+            print_json(ctx, &entries);
+        }
+        OutputFormat::Jsonl => {
+            for entry in &entries {
+                outln!(ctx, "{}", serde_json::to_string(entry).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(&entries).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for entry in &entries {
+                wtr.serialize(entry).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+fn entries(puzzle: &Puzzle) -> Vec<HistoryEntry> {
+    puzzle
+        .transactions
+        .iter()
+        .map(|tx| HistoryEntry {
+            date: tx.date,
+            tx_type: tx.tx_type,
+            amount: tx.amount,
+            native_amount: tx.native_amount(),
+            currency: puzzle.currency(),
+            txid: tx.txid,
+            explorer_url: tx.txid.map(|txid| puzzle.chain.tx_explorer_url(txid)),
+        })
+        .collect()
+}
+
+fn rows(entries: &[HistoryEntry]) -> Vec<HistoryRow> {
+    entries
+        .iter()
+        .map(|entry| HistoryRow {
+            date: entry.date.unwrap_or("-").to_string(),
+            tx_type: format_transaction_type(entry.tx_type),
+            amount: entry.native_amount.map_or_else(
+                || "-".to_string(),
+                |amount| format_amount(amount, entry.currency),
+            ),
+            link: entry
+                .explorer_url
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_link_each_txid_on_the_puzzle_chain() {
+        let puzzle = boha::get("b1000/66").unwrap();
+        let entries = entries(puzzle);
+        assert_eq!(entries.len(), puzzle.transactions.len());
+        let claim = entries
+            .iter()
+            .find(|e| e.tx_type == TransactionType::Claim)
+            .unwrap();
+        let url = claim.explorer_url.as_deref().unwrap();
+        assert!(url.starts_with("https://mempool.space/tx/"));
+        assert!(url.ends_with(claim.txid.unwrap()));
+        assert_eq!(claim.currency, "BTC");
+    }
+}
@@ -0,0 +1,396 @@
+//! Read files produced by `boha export`, compressed or not, and check
+//! `data/*.jsonc` source files before a rebuild.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::color::Colorize;
+use boha::{validate, Chain, PubkeyFormat, Status};
+use clap::Args;
+use serde::Serialize;
+use serde_json::Value;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::commands::export::SCHEMA_VERSION;
+use crate::compression::{decompress_auto, Compression};
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, ErrorCode, KeyValueRow, OutputFormat};
+use crate::util::data_file_id;
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    /// Export file (json or jsonl, optionally gzip/zstd compressed); `-` reads stdin
+    pub file: PathBuf,
+
+    /// FILE is a `data/<collection>.jsonc` source file: report what the
+    /// build would reject (status, pubkey, hash160, dates) instead of
+    /// summarizing it
+    #[arg(long)]
+    pub check: bool,
+}
+
+/// An export in any of the shapes `export` writes.
+struct Imported {
+    /// The decoded document; a JSON array for JSONL input.
+    document: Value,
+    version: Option<String>,
+    exported_at: Option<String>,
+    compression: Option<Compression>,
+    /// `(collection, puzzles)` in file order.
+    collections: Vec<(String, Vec<Value>)>,
+}
+
+pub fn run(ctx: &Context, args: &ImportArgs) {
+    if args.check {
+        check(ctx, &args.file);
+        return;
+    }
+    let raw = read_input(&args.file)
+        .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", args.file.display(), e)));
+    let imported = parse(raw).unwrap_or_else(|e| error_exit(e));
+
+    match ctx.output {
+        OutputFormat::Table => print_summary(ctx, &imported),
+        OutputFormat::Json => {
+            print_json(ctx, &imported.document);
+        }
+        OutputFormat::Jsonl => {
+            for (_, puzzles) in &imported.collections {
+                for puzzle in puzzles {
+                    outln!(ctx, "{}", serde_json::to_string(puzzle).unwrap());
+                }
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(
+                ctx,
+                "{}",
+                serde_yaml::to_string(&imported.document).unwrap()
+            );
+        }
+        OutputFormat::Csv => {
+            error_exit("CSV format not supported for import. Use json, jsonl or yaml.");
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+/// Puzzles of any export file, grouped by collection.
+pub fn read_collections(path: &Path) -> Result<Vec<(String, Vec<Value>)>, String> {
+    let raw = read_input(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    Ok(parse(raw)?.collections)
+}
+
+fn read_input(path: &Path) -> std::io::Result<Vec<u8>> {
+    if path.as_os_str() == "-" {
+        let mut buf = Vec::new();
+        std::io::stdin().read_to_end(&mut buf)?;
+        Ok(buf)
+    } else {
+        std::fs::read(path)
+    }
+}
+
+fn parse(raw: Vec<u8>) -> Result<Imported, String> {
+    let compression = Compression::detect(&raw);
+    let bytes = decompress_auto(raw).map_err(|e| format!("Cannot decompress input: {}", e))?;
+    let text = String::from_utf8(bytes).map_err(|_| "Input is not UTF-8 text".to_string())?;
+
+    let document = match serde_json::from_str::<Value>(&text) {
+        Ok(value) => value,
+        Err(_) => Value::Array(parse_jsonl(&text)?),
+    };
+
+    if let Some(schema_version) = document.get("schema_version").and_then(Value::as_u64) {
+        if schema_version > u64::from(SCHEMA_VERSION) {
+            return Err(format!(
+                "Export schema version {} is newer than this boha understands ({}); upgrade boha",
+                schema_version, SCHEMA_VERSION
+            ));
+        }
+    }
+
+    let str_field = |key: &str| document.get(key).and_then(Value::as_str).map(String::from);
+    let collections = if let Some(collections) = document.get("collections") {
+        // Full export
+        collections
+            .as_array()
+            .ok_or("`collections` is not an array")?
+            .iter()
+            .map(collection_entry)
+            .collect::<Result<_, _>>()?
+    } else if document.get("puzzles").is_some() {
+        // One file of --split-per-collection
+        vec![collection_entry(&document)?]
+    } else if let Some(puzzles) = document.as_array() {
+        // JSONL export or `list -o json`
+        group_by_collection(puzzles)
+    } else {
+        return Err("Not a boha export: expected `collections`, `puzzles` or a list".into());
+    };
+
+    Ok(Imported {
+        version: str_field("version"),
+        exported_at: str_field("exported_at"),
+        document,
+        compression,
+        collections,
+    })
+}
+
+fn parse_jsonl(text: &str) -> Result<Vec<Value>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| format!("Invalid JSON on line {}: {}", i + 1, e))
+        })
+        .collect()
+}
+
+fn collection_entry(value: &Value) -> Result<(String, Vec<Value>), String> {
+    let name = value
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or("collection without a `name`")?;
+    let puzzles = value
+        .get("puzzles")
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("collection {} has no `puzzles` array", name))?;
+    Ok((name.to_string(), puzzles.clone()))
+}
+
+fn group_by_collection(puzzles: &[Value]) -> Vec<(String, Vec<Value>)> {
+    let mut groups: Vec<(String, Vec<Value>)> = Vec::new();
+    for puzzle in puzzles {
+        let id = puzzle.get("id").and_then(Value::as_str).unwrap_or("");
+        let collection = id.split('/').next().unwrap_or(id);
+        match groups.iter_mut().find(|(name, _)| name == collection) {
+            Some((_, group)) => group.push(puzzle.clone()),
+            None => groups.push((collection.to_string(), vec![puzzle.clone()])),
+        }
+    }
+    groups
+}
+
+fn print_summary(ctx: &Context, imported: &Imported) {
+    let total: usize = imported.collections.iter().map(|(_, p)| p.len()).sum();
+    let mut rows = vec![
+        KeyValueRow {
+            field: "Source version".to_string(),
+            value: imported
+                .version
+                .clone()
+                .unwrap_or_else(|| "-".dimmed().to_string()),
+        },
+        KeyValueRow {
+            field: "Exported at".to_string(),
+            value: imported
+                .exported_at
+                .clone()
+                .unwrap_or_else(|| "-".dimmed().to_string()),
+        },
+        KeyValueRow {
+            field: "Compression".to_string(),
+            value: imported
+                .compression
+                .map_or_else(|| "none".to_string(), |c| c.extension().to_string()),
+        },
+        KeyValueRow {
+            field: "Puzzles".to_string(),
+            value: total.to_string().bright_white().to_string(),
+        },
+    ];
+    for (name, puzzles) in &imported.collections {
+        rows.push(KeyValueRow {
+            field: format!("  {}", name),
+            value: puzzles.len().to_string(),
+        });
+    }
+
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    outln!(ctx, "{}", table);
+}
+
+/// A field of a data file entry that the build would reject.
+#[derive(Serialize, Tabled)]
+struct Problem {
+    #[tabled(rename = "Puzzle")]
+    id: String,
+    #[tabled(rename = "Field")]
+    field: String,
+    #[tabled(rename = "Problem")]
+    problem: String,
+}
+
+#[derive(Serialize)]
+struct CheckReport {
+    file: String,
+    puzzles: usize,
+    problems: Vec<Problem>,
+}
+
+fn check(ctx: &Context, path: &Path) {
+    let raw = read_input(path)
+        .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", path.display(), e)));
+    let mut text = String::from_utf8(raw)
+        .unwrap_or_else(|_| error_exit(format!("{} is not UTF-8 text", path.display())));
+    json_strip_comments::strip(&mut text)
+        .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", path.display(), e)));
+    let file: Value = serde_json::from_str(&text)
+        .unwrap_or_else(|e| error_exit(format!("{} is not valid JSON: {}", path.display(), e)));
+
+    // Ids are built from the collection, which the file is named after.
+    let collection = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .filter(|stem| *stem != "-")
+        .unwrap_or("stdin");
+    let puzzles = match (&file["puzzles"], &file["puzzle"]) {
+        (Value::Array(puzzles), _) => puzzles.iter().collect(),
+        (_, puzzle @ Value::Object(_)) => vec![puzzle],
+        _ => error_exit("Not a collection file: expected `puzzles` or `puzzle`"),
+    };
+
+    let report = CheckReport {
+        file: path.display().to_string(),
+        puzzles: puzzles.len(),
+        problems: puzzles
+            .iter()
+            .flat_map(|puzzle| {
+                let id = data_file_id(collection, puzzle);
+                check_puzzle(puzzle)
+                    .into_iter()
+                    .map(move |(field, problem)| Problem {
+                        id: id.clone(),
+                        field,
+                        problem,
+                    })
+            })
+            .collect(),
+    };
+
+    match ctx.output {
+        OutputFormat::Table => {
+            if report.problems.is_empty() {
+                outln!(
+                    ctx,
+                    "{} {} puzzles in {} pass",
+                    "✓".green().bold(),
+                    report.puzzles,
+                    report.file
+                );
+            } else {
+                let table = Table::new(&report.problems)
+                    .with(Style::rounded())
+                    .to_string();
+                outln!(ctx, "{}", table);
+                outln!(
+                    ctx,
+                    "{} {} problems in {} puzzles",
+                    "✗".red().bold(),
+                    report.problems.len(),
+                    report.puzzles
+                );
+            }
+        }
+        OutputFormat::Json => print_json(ctx, &report),
+        OutputFormat::Jsonl => {
+            for problem in &report.problems {
+                outln!(ctx, "{}", serde_json::to_string(problem).unwrap());
+            }
+        }
+        OutputFormat::Yaml => outln!(ctx, "{}", serde_yaml::to_string(&report).unwrap()),
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for problem in &report.problems {
+                wtr.serialize(problem).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+
+    if !report.problems.is_empty() {
+        crate::output::exit(ErrorCode::CheckFailed.exit_code());
+    }
+}
+
+/// `(field, problem)` for every rule `puzzle` breaks.
+fn check_puzzle(puzzle: &Value) -> Vec<(String, String)> {
+    let mut problems = Vec::new();
+    let mut report = |field: &str, problem: String| problems.push((field.to_string(), problem));
+
+    match puzzle["status"].as_str() {
+        Some(status) => match status.parse::<Status>() {
+            Ok(parsed) if parsed.to_string() == status => {}
+            Ok(parsed) => report("status", format!("must be lowercase: {}", parsed)),
+            Err(e) => report("status", e),
+        },
+        None => report("status", "missing".to_string()),
+    }
+
+    let chain = match puzzle["chain"].as_str() {
+        None => Some(Chain::Bitcoin),
+        Some(chain) => chain.parse().map_err(|e| report("chain", e)).ok(),
+    };
+    let address = &puzzle["address"];
+    match (
+        address["value"].as_str(),
+        address["hash160"].as_str(),
+        chain,
+    ) {
+        (None, _, _) => report("address.value", "missing".to_string()),
+        (Some(value), Some(hash160), Some(chain)) => {
+            if let Err(e) = validate::hash160(value, chain, hash160) {
+                report("address.hash160", e.to_string());
+            }
+        }
+        _ => {}
+    }
+
+    let pubkey = &puzzle["pubkey"];
+    if !pubkey.is_null() {
+        let format = match pubkey["format"].as_str() {
+            Some("compressed") => Some(PubkeyFormat::Compressed),
+            Some("uncompressed") => Some(PubkeyFormat::Uncompressed),
+            other => {
+                report(
+                    "pubkey.format",
+                    format!(
+                        "expected compressed or uncompressed, got {}",
+                        other.unwrap_or("nothing")
+                    ),
+                );
+                None
+            }
+        };
+        match (pubkey["value"].as_str(), format) {
+            (None, _) => report("pubkey.value", "missing".to_string()),
+            (Some(value), Some(format)) => {
+                if let Err(e) = validate::pubkey(value, format) {
+                    report("pubkey.value", e.to_string());
+                }
+            }
+            (Some(_), None) => {}
+        }
+    }
+
+    if let Err(e) = validate::dates(puzzle["start_date"].as_str(), puzzle["solve_date"].as_str()) {
+        let field = match &e {
+            validate::ValidationError::DateFormat { field, .. } => field,
+            _ => "solve_date",
+        };
+        report(field, e.to_string());
+    }
+    if let Some(transactions) = puzzle["transactions"].as_array() {
+        for (i, tx) in transactions.iter().enumerate() {
+            if let Some(date) = tx["date"].as_str() {
+                if let Err(e) = validate::date("date", date) {
+                    report(&format!("transactions[{}].date", i), e.to_string());
+                }
+            }
+        }
+    }
+    problems
+}
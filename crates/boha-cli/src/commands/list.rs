@@ -0,0 +1,480 @@
+use std::cmp::Ordering;
+
+use crate::color::Colorize;
+use boha::{Chain, DateTime, Filter, Puzzle, Status};
+use clap::{Args, ValueEnum};
+use clap_complete::ArgValueCandidates;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+use tabled::{builder::Builder, settings::Style};
+
+use crate::context::Context;
+use crate::markdown;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+use crate::template::TemplateArgs;
+use crate::util::{collection_or_exit, status_colored};
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Args)]
+pub struct ListArgs {
+    #[arg(default_value = "all", add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: String,
+
+    #[arg(long)]
+    pub unsolved: bool,
+
+    #[arg(long)]
+    pub solved: bool,
+
+    #[arg(long, name = "with-pubkey")]
+    pub with_pubkey: bool,
+
+    #[arg(long, name = "with-transactions")]
+    pub with_transactions: bool,
+
+    #[arg(long)]
+    pub chain: Option<Chain>,
+
+    #[command(flatten)]
+    pub bounds: BoundsArgs,
+
+    #[command(flatten)]
+    pub dates: DateArgs,
+
+    /// Also list superseded and withdrawn entries (hidden by default)
+    #[arg(long)]
+    pub include_superseded: bool,
+
+    /// Columns to print, in order, for table, CSV, JSON and YAML output
+    /// (default: id,chain,address,status,prize; JSON/YAML: whole puzzles)
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        conflicts_with_all = ["template", "template_file"]
+    )]
+    pub columns: Option<Vec<Column>>,
+
+    /// Sort by this field; puzzles without it come last
+    #[arg(long, value_enum)]
+    pub sort: Option<SortKey>,
+
+    /// Sort descending
+    #[arg(long, requires = "sort")]
+    pub desc: bool,
+
+    #[command(flatten)]
+    pub template: TemplateArgs,
+}
+
+/// Prize and key-size bounds shared by `list` and `search`.
+#[derive(Debug, Clone, Args)]
+pub struct BoundsArgs {
+    /// Only puzzles with at least this prize, in the puzzle's own currency
+    #[arg(long, value_name = "AMOUNT")]
+    pub min_prize: Option<f64>,
+
+    /// Only puzzles with at most this prize, in the puzzle's own currency
+    #[arg(long, value_name = "AMOUNT")]
+    pub max_prize: Option<f64>,
+
+    /// Only puzzles whose key has at least this many bits
+    #[arg(long, value_name = "BITS")]
+    pub min_bits: Option<u16>,
+
+    /// Only puzzles whose key has at most this many bits
+    #[arg(long, value_name = "BITS")]
+    pub max_bits: Option<u16>,
+}
+
+impl BoundsArgs {
+    /// The bounds as a library [`Filter`], exiting on an empty range.
+    pub fn filter(&self) -> Filter {
+        if let (Some(min), Some(max)) = (self.min_prize, self.max_prize) {
+            if min > max {
+                error_exit("--min-prize is above --max-prize");
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_bits, self.max_bits) {
+            if min > max {
+                error_exit("--min-bits is above --max-bits");
+            }
+        }
+        let mut filter = Filter::new();
+        filter.min_prize = self.min_prize;
+        filter.max_prize = self.max_prize;
+        filter.min_bits = self.min_bits;
+        filter.max_bits = self.max_bits;
+        filter
+    }
+}
+
+/// Funding and solve date bounds shared by `list` and `export`.
+///
+/// Dates may be cut short: `--solved-after 2024 --solved-before 2024` is
+/// everything solved in 2024, `--funded-before 2015-06` everything funded
+/// by the end of June 2015.
+#[derive(Debug, Clone, Args)]
+pub struct DateArgs {
+    /// Only puzzles funded on or after this date (YYYY[-MM[-DD[ HH:MM:SS]]])
+    #[arg(long, value_name = "DATE", value_parser = DateTime::floor)]
+    pub funded_after: Option<DateTime>,
+
+    /// Only puzzles funded on or before this date
+    #[arg(long, value_name = "DATE", value_parser = DateTime::ceil)]
+    pub funded_before: Option<DateTime>,
+
+    /// Only puzzles solved on or after this date
+    #[arg(long, value_name = "DATE", value_parser = DateTime::floor)]
+    pub solved_after: Option<DateTime>,
+
+    /// Only puzzles solved on or before this date
+    #[arg(long, value_name = "DATE", value_parser = DateTime::ceil)]
+    pub solved_before: Option<DateTime>,
+}
+
+impl DateArgs {
+    /// Add the date bounds to `filter`, exiting on an empty range.
+    pub fn apply(&self, filter: &mut Filter) {
+        if let (Some(after), Some(before)) = (self.funded_after, self.funded_before) {
+            if after > before {
+                error_exit("--funded-after is after --funded-before");
+            }
+        }
+        if let (Some(after), Some(before)) = (self.solved_after, self.solved_before) {
+            if after > before {
+                error_exit("--solved-after is after --solved-before");
+            }
+        }
+        filter.funded_after = self.funded_after;
+        filter.funded_before = self.funded_before;
+        filter.solved_after = self.solved_after;
+        filter.solved_before = self.solved_before;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub enum Column {
+    Id,
+    Uuid,
+    Chain,
+    Address,
+    Kind,
+    Hash160,
+    Status,
+    Prize,
+    Currency,
+    Bits,
+    Pubkey,
+    StartDate,
+    SolveDate,
+    SolveTime,
+    Solver,
+    Description,
+}
+
+/// Table cells wrap descriptions to this many columns.
+const DESCRIPTION_WIDTH: usize = 40;
+
+const DEFAULT_COLUMNS: [Column; 5] = [
+    Column::Id,
+    Column::Chain,
+    Column::Address,
+    Column::Status,
+    Column::Prize,
+];
+
+impl Column {
+    /// Key in JSON and YAML, heading in CSV.
+    fn key(self) -> &'static str {
+        match self {
+            Column::Id => "id",
+            Column::Uuid => "uuid",
+            Column::Chain => "chain",
+            Column::Address => "address",
+            Column::Kind => "kind",
+            Column::Hash160 => "hash160",
+            Column::Status => "status",
+            Column::Prize => "prize",
+            Column::Currency => "currency",
+            Column::Bits => "bits",
+            Column::Pubkey => "pubkey",
+            Column::StartDate => "start_date",
+            Column::SolveDate => "solve_date",
+            Column::SolveTime => "solve_time",
+            Column::Solver => "solver",
+            Column::Description => "description",
+        }
+    }
+
+    fn heading(self) -> &'static str {
+        match self {
+            Column::Id => "ID",
+            Column::Uuid => "UUID",
+            Column::Chain => "Chain",
+            Column::Address => "Address",
+            Column::Kind => "Kind",
+            Column::Hash160 => "HASH160",
+            Column::Status => "Status",
+            Column::Prize => "Prize",
+            Column::Currency => "Currency",
+            Column::Bits => "Bits",
+            Column::Pubkey => "Pubkey",
+            Column::StartDate => "Start Date",
+            Column::SolveDate => "Solve Date",
+            Column::SolveTime => "Solve Time",
+            Column::Solver => "Solver",
+            Column::Description => "Description",
+        }
+    }
+
+    /// Machine-readable value; solve time in seconds.
+    fn value(self, p: &Puzzle) -> Value {
+        let text = |s: Option<&str>| s.map_or(Value::Null, Value::from);
+        match self {
+            Column::Id => p.id.into(),
+            Column::Uuid => p.uuid.into(),
+            Column::Chain => serde_json::to_value(p.chain).unwrap(),
+            Column::Address => p.address.value.into(),
+            Column::Kind => p.address.kind.into(),
+            Column::Hash160 => text(p.address.hash160),
+            Column::Status => p.status.to_string().into(),
+            Column::Prize => p.prize.map_or(Value::Null, Value::from),
+            Column::Currency => p.currency().into(),
+            Column::Bits => p
+                .key
+                .as_ref()
+                .and_then(|k| k.bits)
+                .map_or(Value::Null, Value::from),
+            Column::Pubkey => text(p.pubkey.as_ref().map(|k| k.value)),
+            Column::StartDate => p.start_date.map_or(Value::Null, |d| d.to_string().into()),
+            Column::SolveDate => p.solve_date.map_or(Value::Null, |d| d.to_string().into()),
+            Column::SolveTime => p.solve_time.map_or(Value::Null, Value::from),
+            Column::Solver => text(p.solver.as_ref().and_then(|s| s.name)),
+            Column::Description => text(p.description),
+        }
+    }
+
+    /// Table cell, formatted for reading.
+    fn cell(self, p: &Puzzle) -> String {
+        let missing = || "-".dimmed().to_string();
+        match self {
+            Column::Chain => p.chain.symbol().to_string(),
+            Column::Status => match (p.superseded_by, p.withdrawn) {
+                (Some(by), _) => format!(
+                    "{} {}",
+                    status_colored(p.status),
+                    format!("(superseded by {})", by).dimmed()
+                ),
+                (None, true) => format!("{} {}", status_colored(p.status), "(withdrawn)".dimmed()),
+                (None, false) => status_colored(p.status),
+            },
+            Column::Prize => p
+                .prize
+                .map_or_else(missing, |v| format!("{:.4} {}", v, p.currency())),
+            Column::SolveTime => p.solve_time_formatted().unwrap_or_else(missing),
+            Column::Description => p
+                .description
+                .map_or_else(missing, |d| markdown::render(d, DESCRIPTION_WIDTH)),
+            _ => text(&self.value(p)).unwrap_or_else(missing),
+        }
+    }
+}
+
+/// Scalar as plain text; `None` for null.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub enum SortKey {
+    Prize,
+    Bits,
+    StartDate,
+    SolveDate,
+    SolveTime,
+}
+
+impl SortKey {
+    fn compare(self, a: &Puzzle, b: &Puzzle) -> Option<Ordering> {
+        let bits = |p: &Puzzle| p.key.as_ref().and_then(|k| k.bits);
+        match self {
+            SortKey::Prize => a.prize?.partial_cmp(&b.prize?),
+            SortKey::Bits => Some(bits(a)?.cmp(&bits(b)?)),
+            SortKey::StartDate => Some(a.start_date?.cmp(&b.start_date?)),
+            SortKey::SolveDate => Some(a.solve_date?.cmp(&b.solve_date?)),
+            SortKey::SolveTime => Some(a.solve_time?.cmp(&b.solve_time?)),
+        }
+    }
+
+    fn has(self, p: &Puzzle) -> bool {
+        self.compare(p, p).is_some()
+    }
+}
+
+/// Stable sort by `key`; puzzles without the field keep their order at the end.
+fn sort(puzzles: &mut [&Puzzle], key: SortKey, desc: bool) {
+    puzzles.sort_by(|a, b| match (key.has(a), key.has(b)) {
+        (true, true) => {
+            let order = key.compare(a, b).unwrap_or(Ordering::Equal);
+            if desc {
+                order.reverse()
+            } else {
+                order
+            }
+        }
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
+    });
+}
+
+/// The selected columns of one puzzle, serialized in column order.
+struct Row<'a> {
+    puzzle: &'a Puzzle,
+    columns: &'a [Column],
+}
+
+impl Serialize for Row<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for column in self.columns {
+            map.serialize_entry(column.key(), &column.value(self.puzzle))?;
+        }
+        map.end()
+    }
+}
+
+pub fn run(ctx: &Context, args: &ListArgs) {
+    let puzzles: Vec<&Puzzle> = if args.collection == "all" {
+        boha::all().collect()
+    } else {
+        collection_or_exit(&args.collection, true).all().collect()
+    };
+
+    let mut filter = args.bounds.filter();
+    args.dates.apply(&mut filter);
+    filter.chain = args.chain;
+    let mut filtered: Vec<_> = puzzles
+        .into_iter()
+        .filter(|p| filter.matches(p))
+        .filter(|p| !args.unsolved || p.status == Status::Unsolved)
+        .filter(|p| !args.solved || p.status == Status::Solved)
+        .filter(|p| !args.with_pubkey || p.pubkey.is_some())
+        .filter(|p| !args.with_transactions || p.has_transactions())
+        .filter(|p| args.include_superseded || !p.is_superseded())
+        .collect();
+    if let Some(key) = args.sort {
+        sort(&mut filtered, key, args.desc);
+    }
+
+    if let Some(template) = args.template.template() {
+        for puzzle in &filtered {
+            template.print(ctx, puzzle);
+        }
+        return;
+    }
+    // --solved lists used to show how long each puzzle stayed open.
+    let default_columns: Vec<Column> = if args.solved {
+        [DEFAULT_COLUMNS.as_slice(), &[Column::SolveTime]].concat()
+    } else {
+        DEFAULT_COLUMNS.to_vec()
+    };
+    output_puzzles(ctx, &filtered, args.columns.as_deref(), &default_columns);
+}
+
+/// Print `puzzles` with the `selected` columns, or for table and CSV
+/// output `default_columns` and for JSON and YAML whole puzzles.
+fn output_puzzles(
+    ctx: &Context,
+    puzzles: &[&Puzzle],
+    selected: Option<&[Column]>,
+    default_columns: &[Column],
+) {
+    let columns = selected.unwrap_or(default_columns);
+    let rows: Vec<Row> = puzzles
+        .iter()
+        .map(|puzzle| Row { puzzle, columns })
+        .collect();
+    match ctx.output {
+        OutputFormat::Table => {
+            let mut builder = Builder::default();
+            builder.push_record(columns.iter().map(|c| c.heading()));
+            for puzzle in puzzles {
+                builder.push_record(columns.iter().map(|c| c.cell(puzzle)));
+            }
+            let mut table = builder.build();
+            table.with(Style::rounded());
+            outln!(ctx, "{}", table);
+            outln!(
+                ctx,
+                "\n{} {} puzzles",
+                "Total:".dimmed(),
+                puzzles.len().to_string().bright_white()
+            );
+        }
+        OutputFormat::Json if selected.is_some() => print_json(ctx, &rows),
+        OutputFormat::Json => print_json(ctx, puzzles),
+        OutputFormat::Jsonl if selected.is_some() => {
+            for row in &rows {
+                outln!(ctx, "{}", serde_json::to_string(row).unwrap());
+            }
+        }
+        OutputFormat::Jsonl => {
+            for p in puzzles {
+                outln!(ctx, "{}", serde_json::to_string(p).unwrap());
+            }
+        }
+        OutputFormat::Yaml if selected.is_some() => {
+            outln!(ctx, "{}", serde_yaml::to_string(&rows).unwrap());
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(puzzles).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            wtr.write_record(columns.iter().map(|c| c.key())).unwrap();
+            for puzzle in puzzles {
+                wtr.write_record(
+                    columns
+                        .iter()
+                        .map(|c| text(&c.value(puzzle)).unwrap_or_default()),
+                )
+                .unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        #[cfg(feature = "parquet")]
+        OutputFormat::Parquet if selected.is_some() => {
+            error_exit("--columns does not apply to -o parquet, which has a fixed schema")
+        }
+        #[cfg(feature = "parquet")]
+        OutputFormat::Parquet => write_parquet(ctx, puzzles),
+        OutputFormat::Markdown | OutputFormat::Html | OutputFormat::Rss | OutputFormat::Ics => {
+            unreachable!("dataset-only formats are rejected in main")
+        }
+    }
+}
+
+/// Parquet is binary, so it never goes to a terminal.
+#[cfg(feature = "parquet")]
+pub fn write_parquet(ctx: &Context, puzzles: &[&Puzzle]) {
+    use std::io::Write;
+
+    if ctx.out.is_terminal() {
+        error_exit("Refusing to write Parquet to a terminal; redirect stdout to a file");
+    }
+    let bytes = crate::columnar::to_parquet(puzzles)
+        .unwrap_or_else(|e| error_exit(format!("Cannot encode Parquet: {}", e)));
+    let mut stdout = &ctx.out;
+    stdout
+        .write_all(&bytes)
+        .and_then(|()| stdout.flush())
+        .unwrap_or_else(|e| error_exit(format!("Cannot write Parquet: {}", e)));
+}
@@ -0,0 +1,444 @@
+//! Model Context Protocol server, so assistants can query the dataset with
+//! tool calls instead of being fed pasted JSON dumps.
+//!
+//! Speaks the MCP stdio transport: one JSON-RPC 2.0 message per line on
+//! stdin and stdout. Nothing else may be written to stdout while serving.
+
+use std::io::{BufRead, Write};
+
+use boha::{Chain, Collection, Puzzle, Status};
+use serde_json::{json, Map, Value};
+
+use super::search::{search, Matcher};
+use super::show::redact_secrets;
+
+/// Newest first; offered when the client asks for a version we do not know.
+const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+const DEFAULT_LIMIT: u64 = 50;
+
+pub fn run() {
+    let mut stdout = std::io::stdout().lock();
+    for line in std::io::stdin().lock().lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_line(&line) {
+            // The client hung up; nothing left to serve.
+            if writeln!(stdout, "{}", response)
+                .and_then(|()| stdout.flush())
+                .is_err()
+            {
+                break;
+            }
+        }
+    }
+}
+
+/// Reply to one incoming message, or `None` for notifications.
+fn handle_line(line: &str) -> Option<Value> {
+    let message: Value = match serde_json::from_str(line) {
+        Ok(message) => message,
+        Err(e) => return Some(error(&Value::Null, PARSE_ERROR, e.to_string())),
+    };
+    if !message.is_object() {
+        return Some(error(
+            &Value::Null,
+            INVALID_REQUEST,
+            "expected a JSON-RPC request object",
+        ));
+    }
+    let id = message.get("id").cloned();
+    let Some(method) = message.get("method").and_then(Value::as_str) else {
+        return id.map(|id| error(&id, INVALID_REQUEST, "missing method"));
+    };
+    let id = id?;
+    let params = message.get("params").unwrap_or(&Value::Null);
+    Some(match handle(method, params) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => error(&id, code, message),
+    })
+}
+
+fn error(id: &Value, code: i64, message: impl Into<String>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message.into() },
+    })
+}
+
+fn handle(method: &str, params: &Value) -> Result<Value, (i64, String)> {
+    match method {
+        "initialize" => Ok(initialize(params)),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tools() })),
+        "tools/call" => call(params),
+        _ => Err((METHOD_NOT_FOUND, format!("unknown method: {}", method))),
+    }
+}
+
+fn initialize(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = PROTOCOL_VERSIONS
+        .into_iter()
+        .find(|v| Some(*v) == requested)
+        .unwrap_or(PROTOCOL_VERSIONS[0]);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": "boha", "version": env!("CARGO_PKG_VERSION") },
+        "instructions": "Read-only access to the boha dataset of crypto puzzles, bounties \
+            and challenges. Puzzle ids look like `b1000/66` or `gsmg`. Private \
+            keys of solved puzzles are redacted unless `show_key` is set.",
+    })
+}
+
+fn tools() -> Value {
+    let collections: Vec<&str> = boha::collections()
+        .into_iter()
+        .map(Collection::name)
+        .collect();
+    json!([
+        {
+            "name": "get_puzzle",
+            "description": "Full record of one puzzle by id (e.g. `b1000/66`, `gsmg`) or uuid.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string", "description": "Puzzle id or uuid" },
+                    "show_key": { "type": "boolean", "description": "Include private key material of solved puzzles" },
+                },
+                "required": ["id"],
+            },
+        },
+        {
+            "name": "get_puzzle_by_address",
+            "description": "Full record of the puzzle whose address matches exactly.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "address": { "type": "string", "description": "Puzzle address" },
+                    "show_key": { "type": "boolean", "description": "Include private key material of solved puzzles" },
+                },
+                "required": ["address"],
+            },
+        },
+        {
+            "name": "search_puzzles",
+            "description": "Search ids, addresses, names and other text fields, best matches first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string" },
+                    "collection": { "type": "string", "enum": collections },
+                    "exact": { "type": "boolean", "description": "Require an exact field match" },
+                    "limit": { "type": "integer", "minimum": 1, "default": DEFAULT_LIMIT },
+                },
+                "required": ["query"],
+            },
+        },
+        {
+            "name": "list_puzzles",
+            "description": "Puzzles filtered by collection, status and chain.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "collection": { "type": "string", "enum": collections },
+                    "status": { "type": "string", "enum": ["solved", "unsolved", "claimed", "swept", "expired"] },
+                    "chain": { "type": "string", "description": "Chain name, e.g. bitcoin or ethereum" },
+                    "include_superseded": { "type": "boolean", "description": "Include superseded and withdrawn entries" },
+                    "limit": { "type": "integer", "minimum": 1, "default": DEFAULT_LIMIT },
+                },
+            },
+        },
+        {
+            "name": "get_stats",
+            "description": "Dataset totals: puzzle counts by status and prize sums per currency.",
+            "inputSchema": { "type": "object", "properties": {} },
+        },
+    ])
+}
+
+fn call(params: &Value) -> Result<Value, (i64, String)> {
+    let name = params
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| (INVALID_PARAMS, "missing tool name".to_string()))?;
+    let empty = Map::new();
+    let args = params
+        .get("arguments")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    // Bad arguments and unknown puzzles are tool errors rather than protocol
+    // errors, so the model sees the message and can retry.
+    let outcome = match name {
+        "get_puzzle" => get_puzzle(args),
+        "get_puzzle_by_address" => get_puzzle_by_address(args),
+        "search_puzzles" => search_puzzles(args),
+        "list_puzzles" => list_puzzles(args),
+        "get_stats" => serde_json::to_value(boha::stats()).map_err(|e| e.to_string()),
+        _ => return Err((INVALID_PARAMS, format!("unknown tool: {}", name))),
+    };
+    let (text, is_error) = match outcome {
+        Ok(value) => (serde_json::to_string_pretty(&value).unwrap(), false),
+        Err(message) => (message, true),
+    };
+    Ok(json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    }))
+}
+
+type Args = Map<String, Value>;
+
+fn str_arg<'a>(args: &'a Args, name: &str) -> Result<Option<&'a str>, String> {
+    match args.get(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(_) => Err(format!("`{}` must be a string", name)),
+    }
+}
+
+fn required_str<'a>(args: &'a Args, name: &str) -> Result<&'a str, String> {
+    str_arg(args, name)?.ok_or_else(|| format!("missing `{}`", name))
+}
+
+fn bool_arg(args: &Args, name: &str) -> Result<bool, String> {
+    match args.get(name) {
+        None | Some(Value::Null) => Ok(false),
+        Some(Value::Bool(b)) => Ok(*b),
+        Some(_) => Err(format!("`{}` must be a boolean", name)),
+    }
+}
+
+fn limit_arg(args: &Args) -> Result<usize, String> {
+    match args.get("limit") {
+        None | Some(Value::Null) => Ok(DEFAULT_LIMIT as usize),
+        Some(value) => value
+            .as_u64()
+            .filter(|n| *n > 0)
+            .map(|n| n as usize)
+            .ok_or_else(|| "`limit` must be a positive integer".to_string()),
+    }
+}
+
+fn collection_arg(args: &Args) -> Result<Vec<&'static Puzzle>, String> {
+    Ok(match str_arg(args, "collection")? {
+        None | Some("all") => boha::all().collect(),
+        Some(name) => Collection::parse(name)
+            .map_err(|_| format!("unknown collection: {}", name))?
+            .all()
+            .collect(),
+    })
+}
+
+fn puzzle_value(puzzle: &Puzzle, show_key: bool) -> Value {
+    let puzzle = if show_key {
+        puzzle.clone()
+    } else {
+        redact_secrets(puzzle)
+    };
+    serde_json::to_value(puzzle).unwrap()
+}
+
+fn get_puzzle(args: &Args) -> Result<Value, String> {
+    let id = required_str(args, "id")?;
+    let puzzle = boha::get(id)
+        .or_else(|e| boha::get_by_uuid(id).map_err(|_| e))
+        .map_err(|e| e.to_string())?;
+    Ok(puzzle_value(puzzle, bool_arg(args, "show_key")?))
+}
+
+fn get_puzzle_by_address(args: &Args) -> Result<Value, String> {
+    let puzzle = boha::get_by_address(required_str(args, "address")?).map_err(|e| e.to_string())?;
+    Ok(puzzle_value(puzzle, bool_arg(args, "show_key")?))
+}
+
+fn search_puzzles(args: &Args) -> Result<Value, String> {
+    let query = required_str(args, "query")?;
+    if query.trim().is_empty() {
+        return Err("`query` cannot be empty".to_string());
+    }
+    let puzzles = collection_arg(args)?;
+    let limit = limit_arg(args)?;
+    let results = search(
+        &puzzles,
+        &Matcher::text(query, bool_arg(args, "exact")?, false),
+        &[],
+    );
+
+    let total = results.len();
+    let results: Vec<Value> = results
+        .iter()
+        .take(limit)
+        .map(|result| {
+            let mut value = puzzle_value(result.puzzle, false);
+            value["matched_fields"] = json!(result.matched_fields);
+            value
+        })
+        .collect();
+    Ok(json!({ "total": total, "results": results }))
+}
+
+fn list_puzzles(args: &Args) -> Result<Value, String> {
+    let status = str_arg(args, "status")?
+        .map(|s| {
+            s.parse::<Status>()
+                .map_err(|_| format!("unknown status: {}", s))
+        })
+        .transpose()?;
+    let chain = str_arg(args, "chain")?
+        .map(|s| {
+            s.parse::<Chain>()
+                .map_err(|_| format!("unknown chain: {}", s))
+        })
+        .transpose()?;
+    let include_superseded = bool_arg(args, "include_superseded")?;
+    let limit = limit_arg(args)?;
+
+    let matching: Vec<&Puzzle> = collection_arg(args)?
+        .into_iter()
+        .filter(|p| status.is_none_or(|s| p.status == s))
+        .filter(|p| chain.is_none_or(|c| p.chain == c))
+        .filter(|p| include_superseded || !p.is_superseded())
+        .collect();
+    let puzzles: Vec<Value> = matching
+        .iter()
+        .take(limit)
+        .map(|p| puzzle_value(p, false))
+        .collect();
+    Ok(json!({ "total": matching.len(), "puzzles": puzzles }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, params: &Value) -> Value {
+        let line = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        handle_line(&line.to_string()).expect("requests get a response")
+    }
+
+    fn call_tool(name: &str, arguments: &Value) -> (Value, bool) {
+        let response = request(
+            "tools/call",
+            &json!({ "name": name, "arguments": arguments }),
+        );
+        let result = &response["result"];
+        let text = result["content"][0]["text"].as_str().unwrap();
+        let is_error = result["isError"].as_bool().unwrap();
+        let value = serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.into()));
+        (value, is_error)
+    }
+
+    #[test]
+    fn initialize_negotiates_protocol_version() {
+        let known = request("initialize", &json!({ "protocolVersion": "2024-11-05" }));
+        assert_eq!(known["result"]["protocolVersion"], "2024-11-05");
+        assert_eq!(known["result"]["serverInfo"]["name"], "boha");
+
+        let unknown = request("initialize", &json!({ "protocolVersion": "1999-01-01" }));
+        assert_eq!(unknown["result"]["protocolVersion"], PROTOCOL_VERSIONS[0]);
+    }
+
+    #[test]
+    fn notifications_get_no_reply() {
+        let line = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        assert!(handle_line(line).is_none());
+    }
+
+    #[test]
+    fn protocol_errors_use_json_rpc_codes() {
+        assert_eq!(handle_line("{").unwrap()["error"]["code"], PARSE_ERROR);
+        assert_eq!(
+            request("resources/list", &json!({}))["error"]["code"],
+            METHOD_NOT_FOUND
+        );
+        assert_eq!(
+            request("tools/call", &json!({ "name": "drop_tables" }))["error"]["code"],
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn tools_list_names_every_tool() {
+        let response = request("tools/list", &json!({}));
+        let names: Vec<&str> = response["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "get_puzzle",
+                "get_puzzle_by_address",
+                "search_puzzles",
+                "list_puzzles",
+                "get_stats"
+            ]
+        );
+    }
+
+    #[test]
+    fn get_puzzle_redacts_keys_unless_asked() {
+        let (puzzle, is_error) = call_tool("get_puzzle", &json!({ "id": "b1000/1" }));
+        assert!(!is_error);
+        assert_eq!(puzzle["id"], "b1000/1");
+        assert_eq!(puzzle["key"]["hex"], "<redacted>");
+
+        let (puzzle, _) = call_tool("get_puzzle", &json!({ "id": "b1000/1", "show_key": true }));
+        assert_ne!(puzzle["key"]["hex"], "<redacted>");
+
+        let uuid = puzzle["uuid"].as_str().unwrap();
+        let (by_uuid, _) = call_tool("get_puzzle", &json!({ "id": uuid }));
+        assert_eq!(by_uuid["id"], "b1000/1");
+    }
+
+    #[test]
+    fn unknown_puzzle_is_a_tool_error() {
+        let (message, is_error) = call_tool("get_puzzle", &json!({ "id": "nope/1" }));
+        assert!(is_error);
+        assert!(message.as_str().unwrap().contains("nope/1"));
+
+        let (_, is_error) = call_tool("get_puzzle", &json!({}));
+        assert!(is_error);
+    }
+
+    #[test]
+    fn search_and_list_report_totals_before_limit() {
+        let (found, _) = call_tool(
+            "search_puzzles",
+            &json!({ "query": "bitcoin", "collection": "b1000", "limit": 2 }),
+        );
+        assert_eq!(found["results"].as_array().unwrap().len(), 2);
+        assert!(found["total"].as_u64().unwrap() > 2);
+        assert!(found["results"][0]["matched_fields"].is_array());
+
+        let (listed, _) = call_tool(
+            "list_puzzles",
+            &json!({ "collection": "b1000", "status": "unsolved", "limit": 1 }),
+        );
+        let unsolved = boha::b1000::all()
+            .filter(|p| p.status == Status::Unsolved)
+            .count();
+        assert_eq!(listed["total"], unsolved);
+        assert_eq!(listed["puzzles"][0]["status"], "unsolved");
+    }
+
+    #[test]
+    fn get_stats_matches_library() {
+        let (stats, is_error) = call_tool("get_stats", &json!({}));
+        assert!(!is_error);
+        assert_eq!(stats["total"], boha::stats().total);
+    }
+}
@@ -0,0 +1,390 @@
+//! Prometheus text exposition of dataset gauges, printed once or served over HTTP.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+use boha::{DateTime, Puzzle, Status};
+use chrono::Utc;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+
+#[cfg(feature = "balance")]
+use super::balance::{fetch_many, FetchArgs};
+use crate::context::Context;
+use crate::feed;
+use crate::output::{error_exit, out, OutputFormat};
+use crate::util::collection_or_exit;
+
+const STATUSES: [Status; 5] = [
+    Status::Solved,
+    Status::Unsolved,
+    Status::Claimed,
+    Status::Swept,
+    Status::Expired,
+];
+
+#[derive(Debug, Args)]
+pub struct MetricsArgs {
+    /// Only puzzles from this collection (default: all)
+    #[arg(add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+
+    /// Serve the metrics at http://ADDR/metrics instead of printing them
+    /// once, and an RSS feed of recent solves at /feed.xml
+    #[arg(long, value_name = "ADDR")]
+    pub listen: Option<SocketAddr>,
+
+    /// Add the live balance of every unsolved puzzle address
+    #[cfg(feature = "balance")]
+    #[arg(long)]
+    pub balances: bool,
+
+    /// Seconds between balance refreshes while serving
+    #[cfg(feature = "balance")]
+    #[arg(long, default_value = "300", value_name = "SECS", requires = "balances", value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+
+    #[cfg(feature = "balance")]
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+}
+
+/// One balance poll over the unsolved puzzles.
+#[cfg(feature = "balance")]
+struct Balances {
+    /// Unix time the poll finished.
+    refreshed: i64,
+    /// Confirmed plus pending balance in whole coins.
+    values: Vec<(&'static Puzzle, f64)>,
+    errors: usize,
+}
+
+pub fn run(ctx: &Context, args: &MetricsArgs) {
+    if !matches!(ctx.output, OutputFormat::Table) {
+        error_exit("metrics writes the Prometheus text format; drop -o");
+    }
+
+    let puzzles: Vec<&'static Puzzle> = match &args.collection {
+        Some(name) => collection_or_exit(name, false).all().collect(),
+        None => boha::all().collect(),
+    };
+
+    #[cfg(feature = "balance")]
+    let balances = args
+        .balances
+        .then(|| std::sync::Arc::new(std::sync::Mutex::new(poll_balances(&puzzles, &args.fetch))));
+    let exposition = || {
+        #[allow(unused_mut)]
+        let mut out = render(&puzzles);
+        #[cfg(feature = "balance")]
+        if let Some(balances) = &balances {
+            out.push_str(&render_balances(&balances.lock().unwrap()));
+        }
+        out
+    };
+
+    let Some(addr) = args.listen else {
+        out!(ctx, "{}", exposition());
+        return;
+    };
+
+    #[cfg(feature = "balance")]
+    if let Some(shared) = &balances {
+        let (shared, puzzles) = (shared.clone(), puzzles.clone());
+        let fetch = FetchArgs {
+            refresh: true,
+            ..args.fetch.clone()
+        };
+        let interval = std::time::Duration::from_secs(args.interval);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(interval);
+            let fresh = poll_balances(&puzzles, &fetch);
+            *shared.lock().unwrap() = fresh;
+        });
+    }
+
+    let listener = TcpListener::bind(addr)
+        .unwrap_or_else(|e| error_exit(format!("Cannot listen on {}: {}", addr, e)));
+    eprintln!(
+        "Serving metrics on http://{}/metrics",
+        listener.local_addr().unwrap()
+    );
+    for stream in listener.incoming().flatten() {
+        // A client that hangs up mid-request must not stop the exporter.
+        respond(&stream, exposition, || feed::rss(&puzzles, Utc::now())).ok();
+    }
+}
+
+#[cfg(feature = "balance")]
+fn poll_balances(puzzles: &[&'static Puzzle], fetch: &FetchArgs) -> Balances {
+    use crate::color::Colorize;
+
+    let unsolved: Vec<&'static Puzzle> = puzzles
+        .iter()
+        .copied()
+        .filter(|p| p.status == Status::Unsolved)
+        .collect();
+    // The lookups get their own thread and runtime, so this never blocks
+    // inside another runtime.
+    let results = std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("failed to start async runtime")
+                    .block_on(fetch_many(&unsolved, fetch))
+            })
+            .join()
+            .expect("balance lookup thread panicked")
+    });
+
+    let mut balances = Balances {
+        refreshed: chrono::Utc::now().timestamp(),
+        values: Vec::new(),
+        errors: 0,
+    };
+    for (puzzle, result) in results {
+        match result {
+            Ok(balance) => balances.values.push((puzzle, balance.total_native())),
+            Err(e) => {
+                eprintln!("{} {}: {}", "Warning:".yellow().bold(), puzzle.id, e);
+                balances.errors += 1;
+            }
+        }
+    }
+    balances
+}
+
+fn respond(
+    mut stream: &TcpStream,
+    metrics: impl FnOnce() -> String,
+    feed: impl FnOnce() -> String,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let path = request_line.split_whitespace().nth(1).unwrap_or("");
+    let (status, content_type, body) = match path {
+        "/metrics" => (
+            "200 OK",
+            "text/plain; version=0.0.4; charset=utf-8",
+            metrics(),
+        ),
+        "/feed.xml" => ("200 OK", "application/rss+xml; charset=utf-8", feed()),
+        _ => (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "See /metrics or /feed.xml\n".to_string(),
+        ),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Dataset gauges for `puzzles`.
+fn render(puzzles: &[&'static Puzzle]) -> String {
+    let mut out = String::new();
+
+    let mut counts: BTreeMap<&str, [usize; STATUSES.len()]> = BTreeMap::new();
+    let mut unsolved_prize: BTreeMap<(String, &str), f64> = BTreeMap::new();
+    let mut last_solve: BTreeMap<&str, i64> = BTreeMap::new();
+    for puzzle in puzzles {
+        let slot = STATUSES.iter().position(|s| *s == puzzle.status).unwrap();
+        counts.entry(puzzle.collection()).or_default()[slot] += 1;
+        if puzzle.status == Status::Unsolved {
+            if let Some(prize) = puzzle.prize {
+                let key = (puzzle.chain.name().to_lowercase(), puzzle.currency());
+                *unsolved_prize.entry(key).or_insert(0.0) += prize;
+            }
+        }
+        if let Some(time) = puzzle.solve_date.map(timestamp) {
+            let latest = last_solve.entry(puzzle.collection()).or_insert(time);
+            *latest = (*latest).max(time);
+        }
+    }
+
+    header(
+        &mut out,
+        "boha_info",
+        "Build and data version of the exporter.",
+    );
+    sample(
+        &mut out,
+        "boha_info",
+        &[
+            ("version", env!("CARGO_PKG_VERSION")),
+            ("data", boha::version::DATA_HASH),
+        ],
+        1,
+    );
+
+    header(
+        &mut out,
+        "boha_puzzles",
+        "Puzzles by collection and status.",
+    );
+    for (collection, counts) in &counts {
+        for (status, count) in STATUSES.iter().zip(counts) {
+            let status = status.to_string();
+            sample(
+                &mut out,
+                "boha_puzzles",
+                &[("collection", collection), ("status", &status)],
+                count,
+            );
+        }
+    }
+
+    header(
+        &mut out,
+        "boha_unsolved_prize",
+        "Prize of unsolved puzzles, in whole units of the prize currency.",
+    );
+    for ((chain, currency), prize) in &unsolved_prize {
+        sample(
+            &mut out,
+            "boha_unsolved_prize",
+            &[("chain", chain), ("currency", currency)],
+            prize,
+        );
+    }
+
+    header(
+        &mut out,
+        "boha_last_solve_timestamp_seconds",
+        "Unix time of the most recent solve per collection.",
+    );
+    for (collection, time) in &last_solve {
+        sample(
+            &mut out,
+            "boha_last_solve_timestamp_seconds",
+            &[("collection", collection)],
+            time,
+        );
+    }
+
+    out
+}
+
+/// Per-address gauges from the last balance poll.
+#[cfg(feature = "balance")]
+fn render_balances(balances: &Balances) -> String {
+    let mut out = String::new();
+    header(
+        &mut out,
+        "boha_puzzle_balance",
+        "Confirmed plus pending balance of unsolved puzzle addresses, in whole coins.",
+    );
+    for (puzzle, balance) in &balances.values {
+        let chain = puzzle.chain.name().to_lowercase();
+        sample(
+            &mut out,
+            "boha_puzzle_balance",
+            &[
+                ("id", puzzle.id),
+                ("address", puzzle.address.value),
+                ("chain", &chain),
+                ("symbol", puzzle.chain.symbol()),
+            ],
+            balance,
+        );
+    }
+    header(
+        &mut out,
+        "boha_balance_errors",
+        "Balance lookups that failed in the last poll.",
+    );
+    sample(&mut out, "boha_balance_errors", &[], balances.errors);
+    header(
+        &mut out,
+        "boha_balance_refresh_timestamp_seconds",
+        "Unix time of the last balance poll.",
+    );
+    sample(
+        &mut out,
+        "boha_balance_refresh_timestamp_seconds",
+        &[],
+        balances.refreshed,
+    );
+    out
+}
+
+fn header(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+}
+
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: impl std::fmt::Display) {
+    out.push_str(name);
+    if !labels.is_empty() {
+        let labels: Vec<String> = labels
+            .iter()
+            .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+            .collect();
+        let _ = write!(out, "{{{}}}", labels.join(","));
+    }
+    let _ = writeln!(out, " {}", value);
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Seconds since the epoch; dataset dates are UTC.
+fn timestamp(date: DateTime) -> i64 {
+    date.naive_datetime().and_utc().timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exposition_counts_statuses_per_collection() {
+        let puzzles: Vec<_> = boha::b1000::all().collect();
+        let text = render(&puzzles);
+        let solved = puzzles
+            .iter()
+            .filter(|p| p.status == Status::Solved)
+            .count();
+        assert!(text.contains(&format!(
+            "boha_puzzles{{collection=\"b1000\",status=\"solved\"}} {}\n",
+            solved
+        )));
+        assert!(text.contains("boha_puzzles{collection=\"b1000\",status=\"expired\"} 0\n"));
+        assert!(text.contains("boha_unsolved_prize{chain=\"bitcoin\",currency=\"BTC\"} "));
+        assert!(text.contains("# TYPE boha_last_solve_timestamp_seconds gauge\n"));
+        assert!(!text.contains("boha_puzzle_balance"));
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        let mut out = String::new();
+        sample(&mut out, "m", &[("l", "a\"b\\c\nd")], 1);
+        assert_eq!(out, "m{l=\"a\\\"b\\\\c\\nd\"} 1\n");
+    }
+
+    #[test]
+    fn solve_dates_are_utc() {
+        assert_eq!(timestamp("1970-01-02 00:00:01".parse().unwrap()), 86_401);
+        assert_eq!(timestamp(DateTime::floor("1970-01-02").unwrap()), 86_400);
+    }
+}
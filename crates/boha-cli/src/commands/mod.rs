@@ -0,0 +1,48 @@
+//! One module per subcommand. Each exposes an `Args` struct and a `run` entry point.
+
+pub mod analyze;
+pub mod announce;
+#[cfg(feature = "balance")]
+pub mod audit_prizes;
+pub mod author;
+pub mod authors;
+#[cfg(feature = "balance")]
+pub mod balance;
+pub mod bench;
+pub mod completions;
+pub mod config;
+pub mod decode;
+pub mod derive;
+pub mod diff;
+pub mod estimate;
+pub mod examples;
+pub mod export;
+pub mod grep_scripts;
+pub mod history;
+pub mod import;
+pub mod list;
+#[cfg(feature = "mcp")]
+pub mod mcp;
+pub mod metrics;
+pub mod progress;
+pub mod pubkey;
+#[cfg(feature = "publish")]
+pub mod publish;
+pub mod random;
+pub mod range;
+pub mod rank;
+pub mod search;
+pub mod show;
+pub mod site;
+pub mod solver_config;
+pub mod stats;
+pub mod timeline;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "examples-data")]
+pub mod tutorial;
+pub mod validate;
+pub mod verify;
+#[cfg(feature = "balance")]
+pub mod watch;
+pub mod wif;
@@ -0,0 +1,247 @@
+//! Track which parts of a puzzle's key range have been searched.
+
+use crate::color::Colorize;
+use boha::progress::{Coverage, Progress, ProgressStore};
+use boha::{keymath, KeyRange, Puzzle};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, print_record, KeyValueRow, OutputFormat};
+use crate::util::puzzle_or_exit;
+
+#[derive(Debug, Args)]
+pub struct ProgressArgs {
+    #[command(subcommand)]
+    pub action: ProgressAction,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProgressAction {
+    /// Record START..=END (hex) as searched
+    Add {
+        /// Puzzle id, or a b1000 puzzle number
+        puzzle: String,
+        start: String,
+        end: String,
+    },
+    /// Coverage of one puzzle, or of every puzzle with recorded progress
+    Show { puzzle: Option<String> },
+    /// Forget the ranges recorded for a puzzle
+    Clear { puzzle: String },
+}
+
+#[derive(Serialize)]
+struct CoverageOutput {
+    puzzle: &'static str,
+    start: String,
+    end: String,
+    scanned: String,
+    total: String,
+    percent: f64,
+    ranges: Vec<RangeRow>,
+}
+
+#[derive(Serialize, Tabled)]
+struct RangeRow {
+    #[tabled(rename = "Start")]
+    start: String,
+    #[tabled(rename = "End")]
+    end: String,
+    #[tabled(rename = "Keys")]
+    keys: String,
+}
+
+#[derive(Serialize, Tabled)]
+struct SummaryRow {
+    #[tabled(rename = "Puzzle")]
+    puzzle: String,
+    #[tabled(rename = "Ranges")]
+    ranges: usize,
+    #[tabled(rename = "Scanned")]
+    scanned: String,
+    #[tabled(skip)]
+    percent: f64,
+    #[tabled(rename = "Covered")]
+    #[serde(skip)]
+    covered: String,
+}
+
+pub fn run(ctx: &Context, args: &ProgressArgs) {
+    let store = ProgressStore::open_default()
+        .unwrap_or_else(|| error_exit("No state directory; set XDG_STATE_HOME or HOME"));
+    let mut progress = store
+        .load()
+        .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", store.path().display(), e)));
+
+    match &args.action {
+        ProgressAction::Add { puzzle, start, end } => {
+            let puzzle = puzzle_or_exit(puzzle);
+            let key_range = key_range(puzzle);
+            let (Some(start), Some(end)) =
+                (keymath::parse_hex_key(start), keymath::parse_hex_key(end))
+            else {
+                error_exit("START and END must be hex keys");
+            };
+            let range =
+                KeyRange::new(start, end).unwrap_or_else(|| error_exit("START is above END"));
+            if range.end() < key_range.start() || range.start() > key_range.end() {
+                error_exit(format!(
+                    "{:#x} is outside the key range of {} ({:#x})",
+                    range, puzzle.id, key_range
+                ));
+            }
+            progress.add(puzzle.id, &range);
+            save(&store, &progress);
+            if matches!(ctx.output, OutputFormat::Table) {
+                outln!(ctx, "Recorded {:#x} for {}", range, puzzle.id);
+            }
+            show_one(ctx, &progress, puzzle);
+        }
+        ProgressAction::Show { puzzle: Some(id) } => show_one(ctx, &progress, puzzle_or_exit(id)),
+        ProgressAction::Show { puzzle: None } => show_all(ctx, &progress),
+        ProgressAction::Clear { puzzle } => {
+            let puzzle = puzzle_or_exit(puzzle);
+            let removed = progress.clear(puzzle.id);
+            save(&store, &progress);
+            eprintln!("Removed {} range(s) for {}", removed, puzzle.id);
+        }
+    }
+}
+
+fn key_range(puzzle: &Puzzle) -> KeyRange {
+    puzzle
+        .key_range_big()
+        .unwrap_or_else(|| error_exit(format!("{} has no known key range", puzzle.id)))
+}
+
+fn save(store: &ProgressStore, progress: &Progress) {
+    if let Err(e) = store.save(progress) {
+        error_exit(format!("Cannot write {}: {}", store.path().display(), e));
+    }
+}
+
+fn show_one(ctx: &Context, progress: &Progress, puzzle: &'static Puzzle) {
+    let key_range = key_range(puzzle);
+    let coverage = progress.coverage(puzzle.id, &key_range);
+    let output = CoverageOutput {
+        puzzle: puzzle.id,
+        start: format!("0x{:x}", key_range.start()),
+        end: format!("0x{:x}", key_range.end()),
+        scanned: coverage.scanned.to_string(),
+        total: coverage.total.to_string(),
+        percent: coverage.percent,
+        ranges: range_rows(&coverage),
+    };
+    print_record(ctx, &output, print_coverage_table);
+}
+
+fn range_rows(coverage: &Coverage) -> Vec<RangeRow> {
+    coverage
+        .ranges
+        .iter()
+        .map(|range| RangeRow {
+            start: format!("0x{:x}", range.start()),
+            end: format!("0x{:x}", range.end()),
+            keys: range.size().to_string(),
+        })
+        .collect()
+}
+
+fn print_coverage_table(ctx: &Context, output: &CoverageOutput) {
+    let rows = vec![
+        KeyValueRow {
+            field: "Puzzle".to_string(),
+            value: output.puzzle.bright_white().to_string(),
+        },
+        KeyValueRow {
+            field: "Range".to_string(),
+            value: format!("{}:{}", output.start, output.end),
+        },
+        KeyValueRow {
+            field: "Scanned".to_string(),
+            value: format!("{} of {} keys", output.scanned, output.total),
+        },
+        KeyValueRow {
+            field: "Covered".to_string(),
+            value: format_percent(output.percent),
+        },
+    ];
+    outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+    if !output.ranges.is_empty() {
+        outln!(ctx, "{}", Table::new(&output.ranges).with(Style::rounded()));
+    }
+}
+
+fn show_all(ctx: &Context, progress: &Progress) {
+    let rows: Vec<SummaryRow> = progress
+        .puzzle_ids()
+        .filter_map(|id| {
+            let puzzle = boha::get(id).ok()?;
+            let coverage = progress.coverage(id, &puzzle.key_range_big()?);
+            Some(SummaryRow {
+                puzzle: id.to_string(),
+                ranges: progress.entries(id).len(),
+                scanned: coverage.scanned.to_string(),
+                percent: coverage.percent,
+                covered: format_percent(coverage.percent),
+            })
+        })
+        .collect();
+
+    match ctx.output {
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                outln!(
+                    ctx,
+                    "No progress recorded. Add some with `boha progress add`."
+                );
+            } else {
+                outln!(ctx, "{}", Table::new(&rows).with(Style::rounded()));
+            }
+        }
+        OutputFormat::Json => {
+            print_json(ctx, &rows);
+        }
+        OutputFormat::Jsonl => {
+            for row in &rows {
+                outln!(ctx, "{}", serde_json::to_string(row).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(&rows).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for row in &rows {
+                wtr.serialize(row).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+/// Readable at any scale: fixed point down to 0.01%, scientific below.
+fn format_percent(percent: f64) -> String {
+    if percent == 0.0 {
+        "0%".to_string()
+    } else if percent >= 0.01 {
+        format!("{:.2}%", percent)
+    } else {
+        format!("{:.3e}%", percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percents_stay_readable() {
+        assert_eq!(format_percent(0.0), "0%");
+        assert_eq!(format_percent(12.345), "12.35%");
+        assert_eq!(format_percent(0.000_012_3), "1.230e-5%");
+    }
+}
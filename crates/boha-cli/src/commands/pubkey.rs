@@ -0,0 +1,169 @@
+//! Revealed public keys, optionally as input for Pollard's kangaroo solvers.
+
+use crate::color::Colorize;
+use boha::{keymath, Puzzle};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_record, CliError, KeyValueRow};
+
+#[derive(Debug, Args)]
+pub struct PubkeyArgs {
+    /// Puzzle id (e.g. b1000/135)
+    #[arg(add = ArgValueCandidates::new(super::completions::puzzle_ids))]
+    pub id: String,
+
+    /// Add the key range and the range-shifted point, as JeanLucPons' Kangaroo reads them
+    #[arg(long)]
+    pub kangaroo: bool,
+}
+
+#[derive(Serialize)]
+struct PubkeyOutput {
+    puzzle: &'static str,
+    compressed: String,
+    uncompressed: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<String>,
+    /// `end − start`: the upper bound once the range is moved to start at 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shifted_end: Option<String>,
+    /// `P − start·G`; absent when the private key is the range start itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shifted_pubkey: Option<String>,
+}
+
+pub fn run(ctx: &Context, args: &PubkeyArgs) {
+    let puzzle = boha::get(&args.id).unwrap_or_else(|e| CliError::from(e).exit());
+    let output = build(puzzle, args.kangaroo).unwrap_or_else(|e| error_exit(e));
+    if args.kangaroo {
+        print_record(ctx, &output, print_kangaroo);
+    } else {
+        print_record(ctx, &output, print_table);
+    }
+}
+
+fn build(puzzle: &'static Puzzle, kangaroo: bool) -> Result<PubkeyOutput, String> {
+    let pubkey = puzzle
+        .pubkey
+        .ok_or_else(|| format!("{} has no revealed public key", puzzle.id))?;
+    let point = keymath::parse_pubkey(pubkey.value)
+        .ok_or_else(|| format!("{} stores an invalid public key", puzzle.id))?;
+
+    let mut output = PubkeyOutput {
+        puzzle: puzzle.id,
+        compressed: keymath::compressed_hex(&point),
+        uncompressed: keymath::uncompressed_hex(&point),
+        start: None,
+        end: None,
+        shifted_end: None,
+        shifted_pubkey: None,
+    };
+    if kangaroo {
+        let range = puzzle
+            .key_range_big()
+            .ok_or_else(|| format!("{} has no known key range", puzzle.id))?;
+        output.shifted_end = Some(format!("{:x}", range.size() - 1u32));
+        output.shifted_pubkey =
+            keymath::shift(&point, range.start()).map(|p| keymath::compressed_hex(&p));
+        output.start = Some(format!("{:x}", range.start()));
+        output.end = Some(format!("{:x}", range.end()));
+    }
+    Ok(output)
+}
+
+fn print_table(ctx: &Context, output: &PubkeyOutput) {
+    let rows = vec![
+        KeyValueRow {
+            field: "Puzzle".to_string(),
+            value: output.puzzle.bright_white().to_string(),
+        },
+        KeyValueRow {
+            field: "Compressed".to_string(),
+            value: output.compressed.clone(),
+        },
+        KeyValueRow {
+            field: "Uncompressed".to_string(),
+            value: output.uncompressed.clone(),
+        },
+    ];
+    outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+}
+
+/// Kangaroo's input file: range start, range end, then one key per line.
+fn print_kangaroo(ctx: &Context, output: &PubkeyOutput) {
+    let (Some(start), Some(end), Some(shifted_end)) =
+        (&output.start, &output.end, &output.shifted_end)
+    else {
+        return;
+    };
+    outln!(ctx, "{}", format!("# {} input", output.puzzle).dimmed());
+    outln!(ctx, "{}\n{}\n{}", start, end, output.compressed);
+
+    outln!(ctx);
+    match &output.shifted_pubkey {
+        Some(shifted) => {
+            outln!(
+                ctx,
+                "{}",
+                format!("# Range-shifted: add {} to the key found", start).dimmed()
+            );
+            outln!(ctx, "0\n{}\n{}", shifted_end, shifted);
+        }
+        None => outln!(
+            ctx,
+            "{}",
+            format!("# The private key is the range start, {}", start).dimmed()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn kangaroo_shift_matches_solved_key() {
+        // b1000/130 was solved; its key minus the range start must be the
+        // discrete log of the shifted point.
+        let puzzle = boha::get("b1000/130").unwrap();
+        let output = build(puzzle, true).unwrap();
+        assert_eq!(
+            output.start.as_deref(),
+            Some("200000000000000000000000000000000")
+        );
+        assert_eq!(
+            output.shifted_end.as_deref(),
+            Some("1ffffffffffffffffffffffffffffffff")
+        );
+
+        let key = BigUint::parse_bytes(puzzle.key.unwrap().hex.unwrap().as_bytes(), 16).unwrap();
+        let range = puzzle.key_range_big().unwrap();
+        let expected = keymath::mul_generator(&(key - range.start())).unwrap();
+        assert_eq!(
+            output.shifted_pubkey.unwrap(),
+            keymath::compressed_hex(&expected)
+        );
+    }
+
+    #[test]
+    fn uncompressed_pubkeys_are_compressed() {
+        let output = build(boha::get("zden/level_4").unwrap(), false).unwrap();
+        assert_eq!(output.compressed.len(), 66);
+        assert!(output.start.is_none());
+    }
+
+    #[test]
+    fn requires_revealed_pubkey() {
+        let Err(err) = build(boha::get("b1000/71").unwrap(), true) else {
+            panic!("b1000/71 has no public key");
+        };
+        assert!(err.contains("no revealed public key"));
+    }
+}
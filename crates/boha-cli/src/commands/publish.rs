@@ -0,0 +1,162 @@
+//! Content-addressed artifacts for mirroring an export snapshot.
+//!
+//! Only files are produced; pinning the CAR or seeding the torrent is left
+//! to the mirror.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use clap::{ArgGroup, Args};
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use crate::car;
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_record, KeyValueRow};
+use crate::torrent;
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("artifact").required(true).multiple(true).args(["car", "torrent"])))]
+pub struct PublishArgs {
+    /// Export file or `--split-per-collection` directory to publish
+    pub path: PathBuf,
+
+    /// Write the IPFS UnixFS DAG of PATH as a CARv1 archive to FILE
+    #[arg(long, value_name = "FILE")]
+    pub car: Option<PathBuf>,
+
+    /// Write a BitTorrent v1 metainfo file for PATH to FILE
+    #[arg(long, value_name = "FILE")]
+    pub torrent: Option<PathBuf>,
+
+    /// Tracker announce URL for --torrent (repeatable; the first is primary)
+    #[arg(long, value_name = "URL", requires = "torrent")]
+    pub tracker: Vec<String>,
+
+    /// Torrent piece length in KiB (power of two)
+    #[arg(
+        long,
+        value_name = "KIB",
+        default_value_t = torrent::DEFAULT_PIECE_LENGTH / 1024,
+        value_parser = parse_piece_length,
+        requires = "torrent"
+    )]
+    pub piece_length: usize,
+}
+
+#[derive(Serialize)]
+struct PublishOutput {
+    path: String,
+    files: usize,
+    bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    car: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    info_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    magnet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    torrent: Option<String>,
+}
+
+fn parse_piece_length(s: &str) -> Result<usize, String> {
+    let kib: usize = s.parse().map_err(|_| format!("invalid number: {}", s))?;
+    if kib < 16 || !kib.is_power_of_two() {
+        return Err("must be a power of two of at least 16".to_string());
+    }
+    Ok(kib)
+}
+
+pub fn run(ctx: &Context, args: &PublishArgs) {
+    if !args.path.exists() {
+        error_exit(format!("{} does not exist", args.path.display()));
+    }
+    let (files, bytes) = tally(&args.path)
+        .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", args.path.display(), e)));
+
+    let mut output = PublishOutput {
+        path: args.path.display().to_string(),
+        files,
+        bytes,
+        cid: None,
+        car: None,
+        info_hash: None,
+        magnet: None,
+        torrent: None,
+    };
+
+    if let Some(car_path) = &args.car {
+        let dag = car::import(&args.path)
+            .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", args.path.display(), e)));
+        File::create(car_path)
+            .and_then(|file| car::write_car(&dag, BufWriter::new(file)))
+            .unwrap_or_else(|e| error_exit(format!("Cannot write {}: {}", car_path.display(), e)));
+        output.cid = Some(dag.root.to_string());
+        output.car = Some(car_path.display().to_string());
+    }
+
+    if let Some(torrent_path) = &args.torrent {
+        let torrent = torrent::create(&args.path, args.piece_length * 1024, &args.tracker)
+            .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", args.path.display(), e)));
+        std::fs::write(torrent_path, &torrent.metainfo).unwrap_or_else(|e| {
+            error_exit(format!("Cannot write {}: {}", torrent_path.display(), e))
+        });
+        output.info_hash = Some(hex::encode(torrent.info_hash));
+        output.magnet = Some(torrent.magnet());
+        output.torrent = Some(torrent_path.display().to_string());
+    }
+
+    print_record(ctx, &output, print_table);
+}
+
+/// Number of files and total bytes under `path`.
+fn tally(path: &Path) -> std::io::Result<(usize, u64)> {
+    if !path.is_dir() {
+        return Ok((1, path.metadata()?.len()));
+    }
+    let mut total = (0, 0);
+    for entry in std::fs::read_dir(path)? {
+        let (files, bytes) = tally(&entry?.path())?;
+        total.0 += files;
+        total.1 += bytes;
+    }
+    Ok(total)
+}
+
+fn print_table(ctx: &Context, output: &PublishOutput) {
+    let mut rows = vec![
+        KeyValueRow {
+            field: "Path".to_string(),
+            value: output.path.clone(),
+        },
+        KeyValueRow {
+            field: "Files".to_string(),
+            value: output.files.to_string(),
+        },
+        KeyValueRow {
+            field: "Bytes".to_string(),
+            value: output.bytes.to_string(),
+        },
+    ];
+    let optional = [
+        ("CID", &output.cid),
+        ("CAR", &output.car),
+        ("Info hash", &output.info_hash),
+        ("Magnet", &output.magnet),
+        ("Torrent", &output.torrent),
+    ];
+    for (field, value) in optional {
+        if let Some(value) = value {
+            rows.push(KeyValueRow {
+                field: field.to_string(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    outln!(ctx, "{}", table);
+}
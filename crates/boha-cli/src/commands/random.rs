@@ -0,0 +1,62 @@
+//! One puzzle drawn at random from those matching the filters, shown as
+//! `show` would.
+
+use boha::{Chain, Puzzle, Status};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+
+use super::list::BoundsArgs;
+use super::show::{self, ShowOptions};
+use crate::context::Context;
+use crate::output::{fail, ErrorCode};
+use crate::util::collection_or_exit;
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Args)]
+pub struct RandomArgs {
+    /// Collection to draw from (default: all)
+    #[arg(long, add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+
+    #[arg(long, conflicts_with = "solved")]
+    pub unsolved: bool,
+
+    #[arg(long)]
+    pub solved: bool,
+
+    #[arg(long)]
+    pub with_pubkey: bool,
+
+    #[arg(long)]
+    pub chain: Option<Chain>,
+
+    #[command(flatten)]
+    pub bounds: BoundsArgs,
+
+    #[command(flatten)]
+    pub show: ShowOptions,
+}
+
+pub fn run(ctx: &Context, args: &RandomArgs) {
+    let mut filter = args.bounds.filter();
+    filter.chain = args.chain;
+    if args.unsolved {
+        filter.status = Some(Status::Unsolved);
+    } else if args.solved {
+        filter.status = Some(Status::Solved);
+    }
+
+    let puzzles: Vec<&'static Puzzle> = match args.collection.as_deref() {
+        Some("all") | None => boha::all().collect(),
+        Some(collection) => collection_or_exit(collection, true).all().collect(),
+    };
+    let candidates = puzzles
+        .into_iter()
+        .filter(|p| !p.is_superseded())
+        .filter(|p| !args.with_pubkey || p.has_pubkey());
+    let Some(puzzle) = filter.random(candidates) else {
+        fail(ErrorCode::NotFound, "No puzzles match the filters");
+    };
+
+    show::show(ctx, &[puzzle], false, &args.show);
+}
@@ -0,0 +1,215 @@
+use crate::color::Colorize;
+use boha::{b1000, KeyRange};
+use clap::{Args, ValueEnum};
+use num_bigint::BigUint;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::output::{
+    error_exit, fail, outln, print_record, print_rows, CliError, ErrorCode, KeyValueRow,
+    OutputFormat,
+};
+
+/// Most sub-ranges `--split` prints; more is a typo, not a work plan.
+const MAX_SPLIT: u64 = 100_000;
+
+/// Most keys `--random` draws.
+const MAX_SAMPLES: u64 = 100_000;
+
+#[derive(Debug, Args)]
+pub struct RangeArgs {
+    pub puzzle_number: u32,
+
+    /// Divide the range into N contiguous sub-ranges for distributed search
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..=MAX_SPLIT))]
+    pub split: Option<u64>,
+
+    /// Print each range as arguments for a key search tool
+    #[arg(long, value_enum, value_name = "TOOL")]
+    pub format: Option<ToolFormat>,
+
+    /// Print N uniformly random keys from the range (samples, not solutions)
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = ["split", "format"],
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=MAX_SAMPLES)
+    )]
+    pub random: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ToolFormat {
+    /// `--keyspace START:END`
+    Bitcrack,
+    /// `-r START:END`
+    Keyhunt,
+    /// `--keyspace START:END` (VanitySearch forks with range support)
+    Vanitysearch,
+}
+
+impl ToolFormat {
+    /// The tool's arguments restricting the search to `range`.
+    pub fn range_args(self, range: &KeyRange) -> [String; 2] {
+        let flag = match self {
+            Self::Bitcrack | Self::Vanitysearch => "--keyspace",
+            Self::Keyhunt => "-r",
+        };
+        [flag.to_string(), format!("{:x}", range)]
+    }
+}
+
+#[derive(Serialize, Tabled)]
+struct SplitRow {
+    #[tabled(rename = "Part")]
+    part: u64,
+    #[tabled(rename = "Start")]
+    start: String,
+    #[tabled(rename = "End")]
+    end: String,
+    #[tabled(rename = "Keys")]
+    keys: String,
+}
+
+#[derive(Serialize, Tabled)]
+struct SampleRow {
+    #[tabled(rename = "Sample")]
+    sample: usize,
+    #[tabled(rename = "Key")]
+    key: String,
+}
+
+#[derive(Serialize)]
+struct RangeOutput {
+    puzzle: u32,
+    start: String,
+    end: String,
+    address: Option<String>,
+    pubkey: Option<String>,
+}
+
+pub fn run(ctx: &Context, args: &RangeArgs) {
+    let p = b1000::get(args.puzzle_number).unwrap_or_else(|e| CliError::from(e).exit());
+    let range = p.key_range_big().expect("b1000 puzzles always have bits");
+
+    if args.split.is_some() || args.format.is_some() {
+        if let Some(split) = args
+            .split
+            .filter(|&split| range.size() < BigUint::from(split))
+        {
+            fail(
+                ErrorCode::Usage,
+                format!(
+                    "--split {} exceeds the {} keys of puzzle {}",
+                    split,
+                    range.size(),
+                    args.puzzle_number
+                ),
+            );
+        }
+        let ranges = b1000::split_range(args.puzzle_number, args.split.unwrap_or(1));
+        match args.format {
+            Some(tool) => print_tool_args(ctx, &ranges, tool),
+            None => print_split(ctx, &ranges),
+        }
+        return;
+    }
+
+    if let Some(n) = args.random {
+        print_samples(ctx, args.puzzle_number, &range.random(n));
+        return;
+    }
+
+    let range = RangeOutput {
+        puzzle: args.puzzle_number,
+        start: format!("0x{:x}", range.start()),
+        end: format!("0x{:x}", range.end()),
+        address: Some(p.address.value.to_string()),
+        pubkey: p.pubkey.map(|pk| pk.value.to_string()),
+    };
+    print_record(ctx, &range, print_range_table);
+}
+
+fn print_tool_args(ctx: &Context, ranges: &[KeyRange], tool: ToolFormat) {
+    if !matches!(ctx.output, OutputFormat::Table) {
+        error_exit("--format prints plain tool arguments; drop -o");
+    }
+    for range in ranges {
+        outln!(ctx, "{}", tool.range_args(range).join(" "));
+    }
+}
+
+fn print_split(ctx: &Context, ranges: &[KeyRange]) {
+    let rows: Vec<SplitRow> = ranges
+        .iter()
+        .zip(1..)
+        .map(|(range, part)| SplitRow {
+            part,
+            start: format!("0x{:x}", range.start()),
+            end: format!("0x{:x}", range.end()),
+            keys: range.size().to_string(),
+        })
+        .collect();
+
+    print_rows(ctx, &rows);
+}
+
+fn print_samples(ctx: &Context, puzzle: u32, keys: &[BigUint]) {
+    let rows: Vec<SampleRow> = keys
+        .iter()
+        .zip(1..)
+        .map(|(key, sample)| SampleRow {
+            sample,
+            key: format!("{:064x}", key),
+        })
+        .collect();
+
+    if matches!(ctx.output, OutputFormat::Table) {
+        outln!(
+            ctx,
+            "{}",
+            format!(
+                "# {} random keys from puzzle {}'s range: samples, not solutions",
+                rows.len(),
+                puzzle
+            )
+            .dimmed()
+        );
+    }
+    print_rows(ctx, &rows);
+}
+
+fn print_range_table(ctx: &Context, range: &RangeOutput) {
+    let mut rows = vec![
+        KeyValueRow {
+            field: "Puzzle".to_string(),
+            value: range.puzzle.to_string().bright_white().to_string(),
+        },
+        KeyValueRow {
+            field: "Start".to_string(),
+            value: range.start.clone(),
+        },
+        KeyValueRow {
+            field: "End".to_string(),
+            value: range.end.clone(),
+        },
+    ];
+
+    if let Some(addr) = &range.address {
+        rows.push(KeyValueRow {
+            field: "Address".to_string(),
+            value: addr.clone(),
+        });
+    }
+
+    if let Some(pk) = &range.pubkey {
+        rows.push(KeyValueRow {
+            field: "Pubkey".to_string(),
+            value: pk.clone(),
+        });
+    }
+
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    outln!(ctx, "{}", table);
+}
@@ -0,0 +1,161 @@
+//! Unsolved puzzles ordered by expected prize per day of brute-force search.
+
+use boha::{Puzzle, Status};
+use clap::Args;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::commands::estimate::parse_rate;
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct RankArgs {
+    /// Search rate, e.g. 1.2Gkeys/s, 850M or 3e9
+    #[arg(long, value_parser = parse_rate)]
+    pub rate: f64,
+
+    /// Price of one prize coin in USD, to rank in dollars per day
+    #[arg(long, value_name = "USD")]
+    pub price: Option<f64>,
+
+    /// Show only the top N puzzles
+    #[arg(long, value_name = "N")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Tabled)]
+struct RankRow {
+    #[tabled(rename = "#")]
+    rank: usize,
+    #[tabled(rename = "Puzzle")]
+    puzzle: &'static str,
+    #[tabled(rename = "Bits")]
+    bits: u16,
+    #[tabled(skip)]
+    prize: f64,
+    #[tabled(skip)]
+    currency: &'static str,
+    #[tabled(rename = "Prize")]
+    #[serde(skip)]
+    prize_display: String,
+    #[tabled(rename = "Pubkey")]
+    #[serde(skip)]
+    pubkey_display: &'static str,
+    #[tabled(skip)]
+    has_pubkey: bool,
+    #[tabled(rename = "50% chance")]
+    half: String,
+    /// Prize times the share of the range searched per day.
+    #[tabled(skip)]
+    per_day: f64,
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usd_per_day: Option<f64>,
+    #[tabled(rename = "Expected per day")]
+    #[serde(skip)]
+    per_day_display: String,
+}
+
+pub fn run(ctx: &Context, args: &RankArgs) {
+    if args
+        .price
+        .is_some_and(|price| !price.is_finite() || price <= 0.0)
+    {
+        error_exit("--price must be a positive number");
+    }
+
+    let mut rows: Vec<RankRow> = boha::all()
+        .filter(|p| p.status == Status::Unsolved && !p.is_superseded())
+        .filter_map(|p| row(p, args))
+        .collect();
+    rows.sort_by(|a, b| b.per_day.total_cmp(&a.per_day));
+    if let Some(limit) = args.limit {
+        rows.truncate(limit);
+    }
+    for (i, row) in rows.iter_mut().enumerate() {
+        row.rank = i + 1;
+    }
+
+    match ctx.output {
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                outln!(ctx, "No unsolved puzzles with a known key range.");
+            } else {
+                outln!(ctx, "{}", Table::new(&rows).with(Style::rounded()));
+                if rows.iter().any(|r| r.has_pubkey) {
+                    outln!(
+                        ctx,
+                        "Puzzles with a public key fall to Kangaroo/BSGS far faster than this."
+                    );
+                }
+            }
+        }
+        OutputFormat::Json => {
+            print_json(ctx, &rows);
+        }
+        OutputFormat::Jsonl => {
+            for row in &rows {
+                outln!(ctx, "{}", serde_json::to_string(row).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(&rows).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for row in &rows {
+                wtr.serialize(row).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+fn row(puzzle: &'static Puzzle, args: &RankArgs) -> Option<RankRow> {
+    let prize = puzzle.prize.filter(|prize| *prize > 0.0)?;
+    let bits = puzzle.key?.bits?;
+    let effort = puzzle.estimated_effort(args.rate)?;
+    let per_day = prize * 86400.0 / effort.exhaust_secs;
+    let usd_per_day = args.price.map(|price| per_day * price);
+    let per_day_display = usd_per_day.map_or_else(
+        || format!("{} {}", format_amount(per_day), puzzle.currency()),
+        |usd| format!("${}", format_amount(usd)),
+    );
+
+    Some(RankRow {
+        rank: 0,
+        puzzle: puzzle.id,
+        bits,
+        prize,
+        currency: puzzle.currency(),
+        prize_display: format!("{} {}", prize, puzzle.currency()),
+        pubkey_display: if puzzle.has_pubkey() { "yes" } else { "" },
+        has_pubkey: puzzle.has_pubkey(),
+        half: effort.half_formatted(),
+        per_day,
+        usd_per_day,
+        per_day_display,
+    })
+}
+
+/// Fixed point for everyday amounts, scientific for the vanishing ones.
+fn format_amount(amount: f64) -> String {
+    if amount >= 0.01 {
+        format!("{:.2}", amount)
+    } else {
+        format!("{:.3e}", amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_stay_readable() {
+        assert_eq!(format_amount(12.345), "12.35");
+        assert_eq!(format_amount(0.000_012_3), "1.230e-5");
+    }
+}
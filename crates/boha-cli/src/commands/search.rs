@@ -0,0 +1,552 @@
+use crate::color::Colorize;
+use boha::{Puzzle, Status};
+use clap::{Args, ValueEnum};
+use clap_complete::ArgValueCandidates;
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use super::list::BoundsArgs;
+use crate::context::Context;
+use crate::output::{error_exit, fail, outln, print_json, ErrorCode, OutputFormat};
+use crate::template::TemplateArgs;
+use crate::util::{collection_or_exit, status_colored};
+
+#[derive(Debug, Args)]
+pub struct SearchArgs {
+    /// Search query (required)
+    pub query: String,
+
+    /// Require exact match
+    #[arg(long, conflicts_with = "regex")]
+    pub exact: bool,
+
+    /// Treat the query as a regular expression, e.g. '^1P'
+    #[arg(long)]
+    pub regex: bool,
+
+    /// Only search these fields (comma-separated); default: every text field
+    #[arg(long, value_delimiter = ',', value_name = "FIELD")]
+    pub field: Vec<SearchField>,
+
+    /// Case-sensitive search
+    #[arg(long)]
+    pub case_sensitive: bool,
+
+    /// Limit number of results
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Filter by collection
+    #[arg(long, add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+
+    #[command(flatten)]
+    pub bounds: BoundsArgs,
+
+    #[command(flatten)]
+    pub template: TemplateArgs,
+}
+
+#[derive(Serialize)]
+pub struct SearchResult {
+    #[serde(flatten)]
+    pub puzzle: &'static Puzzle,
+    pub matched_fields: Vec<&'static str>,
+    #[serde(skip)] // Internal only - used for sorting, not exposed in output
+    relevance_score: usize,
+}
+
+#[derive(Tabled)]
+struct SearchTableRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Chain")]
+    chain: String,
+    #[tabled(rename = "Address")]
+    address: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Matched")]
+    matched: String,
+}
+
+#[derive(Serialize)]
+struct SearchCsvRow {
+    id: String,
+    chain: String,
+    address: String,
+    status: String,
+    matched_fields: String, // semicolon-separated
+}
+
+/// Fields `--field` can restrict a search to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub enum SearchField {
+    /// Full puzzle id, collection included (b1000/66)
+    Id,
+    /// Puzzle name within its collection (66)
+    Name,
+    Address,
+    Pubkey,
+    /// P2SH redeem script hex
+    RedeemScript,
+}
+
+impl SearchField {
+    fn label(self) -> &'static str {
+        match self {
+            SearchField::Id => "id",
+            SearchField::Name => "name",
+            SearchField::Address => "address.value",
+            SearchField::Pubkey => "pubkey.value",
+            SearchField::RedeemScript => "address.redeem_script.script",
+        }
+    }
+
+    fn value(self, puzzle: &Puzzle) -> Option<&'static str> {
+        match self {
+            SearchField::Id => Some(puzzle.id),
+            SearchField::Name => puzzle.id.split_once('/').map(|(_, name)| name),
+            SearchField::Address => Some(puzzle.address.value),
+            SearchField::Pubkey => puzzle.pubkey_str(),
+            SearchField::RedeemScript => puzzle.address.redeem_script.map(|r| r.script),
+        }
+    }
+}
+
+/// How a query is matched against a field.
+pub enum Matcher {
+    /// Substring (or whole value with `exact`), lowercased unless
+    /// `case_sensitive`.
+    Text {
+        query: String,
+        exact: bool,
+        case_sensitive: bool,
+    },
+    Regex(Regex),
+}
+
+impl Matcher {
+    pub fn text(query: &str, exact: bool, case_sensitive: bool) -> Self {
+        Matcher::Text {
+            query: if case_sensitive {
+                query.to_string()
+            } else {
+                query.to_lowercase()
+            },
+            exact,
+            case_sensitive,
+        }
+    }
+
+    pub fn regex(pattern: &str, case_sensitive: bool) -> Result<Self, regex::Error> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(!case_sensitive)
+            .build()
+            .map(Matcher::Regex)
+    }
+
+    /// Byte position of the first match in `haystack`.
+    fn find(&self, haystack: &str) -> Option<usize> {
+        match self {
+            Matcher::Text {
+                query,
+                exact,
+                case_sensitive,
+            } => {
+                let folded;
+                let haystack = if *case_sensitive {
+                    haystack
+                } else {
+                    folded = haystack.to_lowercase();
+                    &folded
+                };
+                if *exact {
+                    (haystack == query).then_some(0)
+                } else {
+                    haystack.find(query.as_str())
+                }
+            }
+            Matcher::Regex(regex) => regex.find(haystack).map(|m| m.start()),
+        }
+    }
+
+    /// Whether unrestricted searches compare against the full id rather
+    /// than just the name, i.e. the query mentions a collection.
+    fn wants_full_id(&self) -> bool {
+        match self {
+            Matcher::Text { query, exact, .. } => *exact || query.contains('/'),
+            Matcher::Regex(regex) => regex.as_str().contains('/'),
+        }
+    }
+}
+
+fn puzzle_matches(
+    puzzle: &Puzzle,
+    matcher: &Matcher,
+    fields: &[SearchField],
+) -> Option<(Vec<&'static str>, usize)> {
+    let mut matched_fields: Vec<&'static str> = Vec::new();
+    let mut first_match_position: Option<usize> = None;
+    let mut first_match_field_rank: Option<usize> = None;
+
+    let mut record_match = |label: &'static str, position: usize, field_rank: usize| {
+        matched_fields.push(label);
+        if first_match_position.is_none() {
+            first_match_position = Some(position);
+            first_match_field_rank = Some(field_rank);
+        }
+    };
+
+    let matches_in = |haystack: &str| matcher.find(haystack);
+
+    if !fields.is_empty() {
+        for (rank, field) in fields.iter().enumerate() {
+            if let Some(position) = field.value(puzzle).and_then(matches_in) {
+                record_match(field.label(), position, rank);
+            } else if matches!(field, SearchField::Address) {
+                if let Some(position) = puzzle
+                    .extra_addresses
+                    .iter()
+                    .find_map(|address| matches_in(address.value))
+                {
+                    record_match("extra_addresses.value", position, rank);
+                }
+            }
+        }
+        return score(
+            puzzle,
+            matched_fields,
+            first_match_position,
+            first_match_field_rank,
+        );
+    }
+
+    let id_haystack = if matcher.wants_full_id() {
+        puzzle.id
+    } else {
+        puzzle
+            .id
+            .split_once('/')
+            .map_or(puzzle.id, |(_, rest)| rest)
+    };
+
+    if let Some(position) = matches_in(id_haystack) {
+        record_match("id", position, 0);
+    }
+
+    if let Some(position) = matches_in(puzzle.address.value) {
+        record_match("address.value", position, 1);
+    }
+
+    if let Some(hash160) = puzzle.address.hash160 {
+        if let Some(position) = matches_in(hash160) {
+            record_match("address.hash160", position, 2);
+        }
+    }
+
+    if let Some(witness_program) = puzzle.address.witness_program {
+        if let Some(position) = matches_in(witness_program) {
+            record_match("address.witness_program", position, 3);
+        }
+    }
+
+    for address in puzzle.extra_addresses {
+        if let Some(position) = matches_in(address.value) {
+            record_match("extra_addresses.value", position, 1);
+            break;
+        }
+    }
+
+    if let Some(pubkey) = puzzle.pubkey {
+        if let Some(position) = matches_in(pubkey.value) {
+            record_match("pubkey.value", position, 4);
+        }
+    }
+
+    if let Some(key) = puzzle.key {
+        if let Some(hex) = key.hex {
+            if let Some(position) = matches_in(hex) {
+                record_match("key.hex", position, 5);
+            }
+        }
+
+        if let Some(wif) = key.wif {
+            if let Some(encrypted) = wif.encrypted {
+                if let Some(position) = matches_in(encrypted) {
+                    record_match("key.wif.encrypted", position, 6);
+                }
+            }
+            if let Some(decrypted) = wif.decrypted {
+                if let Some(position) = matches_in(decrypted) {
+                    record_match("key.wif.decrypted", position, 7);
+                }
+            }
+            if let Some(passphrase) = wif.passphrase {
+                if let Some(position) = matches_in(passphrase) {
+                    record_match("key.wif.passphrase", position, 7);
+                }
+            }
+            if let Some(salt) = wif.salt {
+                if let Some(position) = matches_in(salt) {
+                    record_match("key.wif.salt", position, 7);
+                }
+            }
+        }
+
+        if let Some(seed) = key.seed {
+            if let Some(phrase) = seed.phrase {
+                if let Some(position) = matches_in(phrase) {
+                    record_match("key.seed.phrase", position, 8);
+                }
+            }
+        }
+
+        if let Some(mini) = key.mini {
+            if let Some(position) = matches_in(mini) {
+                record_match("key.mini", position, 9);
+            }
+        }
+    }
+
+    if let Some(solver) = &puzzle.solver {
+        if let Some(name) = solver.name {
+            if let Some(position) = matches_in(name) {
+                record_match("solver.name", position, 10);
+            }
+        }
+
+        for addr in solver.addresses {
+            if let Some(position) = matches_in(addr) {
+                record_match("solver.addresses", position, 11);
+                break;
+            }
+        }
+    }
+
+    for tx in puzzle.transactions {
+        if let Some(txid) = tx.txid {
+            if let Some(position) = matches_in(txid) {
+                record_match("transactions.txid", position, 12);
+                break;
+            }
+        }
+    }
+
+    if let Some(position) = matches_in(puzzle.chain.name()) {
+        record_match("chain", position, 13);
+    }
+
+    if let Some(position) = matches_in(puzzle.chain.symbol()) {
+        record_match("chain.symbol", position, 14);
+    }
+
+    if let Some(currency) = puzzle.currency {
+        if let Some(position) = matches_in(currency) {
+            record_match("currency", position, 15);
+        }
+    }
+
+    score(
+        puzzle,
+        matched_fields,
+        first_match_position,
+        first_match_field_rank,
+    )
+}
+
+fn score(
+    puzzle: &Puzzle,
+    matched_fields: Vec<&'static str>,
+    first_match_position: Option<usize>,
+    first_match_field_rank: Option<usize>,
+) -> Option<(Vec<&'static str>, usize)> {
+    if matched_fields.is_empty() {
+        return None;
+    }
+
+    let position = first_match_position.expect("matched_fields is non-empty");
+    let field_rank = first_match_field_rank.expect("matched_fields is non-empty");
+
+    let rank_score = 1_000_000usize.saturating_sub(field_rank * 50_000);
+    let position_score = 10_000usize.saturating_sub(position);
+    let id_len_bonus = if field_rank == 0 {
+        100usize.saturating_mul(10_000usize.saturating_sub(puzzle.id.len()))
+    } else {
+        0
+    };
+
+    let score = rank_score + position_score + id_len_bonus;
+
+    Some((matched_fields, score))
+}
+
+/// Match `puzzles` against `matcher`, best matches first. Empty `fields`
+/// searches every text field.
+pub fn search(
+    puzzles: &[&'static Puzzle],
+    matcher: &Matcher,
+    fields: &[SearchField],
+) -> Vec<SearchResult> {
+    let mut results: Vec<SearchResult> = puzzles
+        .iter()
+        .filter_map(|&p| {
+            puzzle_matches(p, matcher, fields).map(|(matched_fields, relevance_score)| {
+                SearchResult {
+                    puzzle: p,
+                    matched_fields,
+                    relevance_score,
+                }
+            })
+        })
+        .collect();
+
+    results.sort_by(|a, b| {
+        b.relevance_score
+            .cmp(&a.relevance_score)
+            .then_with(|| a.puzzle.id.cmp(b.puzzle.id))
+    });
+
+    results
+}
+
+fn output_search_results(ctx: &Context, results: &[SearchResult], query: &str) {
+    match ctx.output {
+        OutputFormat::Table => {
+            if results.is_empty() {
+                fail(
+                    ErrorCode::NotFound,
+                    format!("No puzzles found matching '{}'", query),
+                );
+            }
+
+            let rows: Vec<SearchTableRow> = results
+                .iter()
+                .map(|r| SearchTableRow {
+                    id: r.puzzle.id.to_string(),
+                    chain: r.puzzle.chain.symbol().to_string(),
+                    address: r.puzzle.address.value.to_string(),
+                    status: status_colored(r.puzzle.status),
+                    matched: r.matched_fields.join(", "),
+                })
+                .collect();
+
+            let mut table = Table::new(rows);
+            table.with(Style::rounded());
+            outln!(ctx, "{}", table);
+            outln!(
+                ctx,
+                "\n{} {} results",
+                "Total:".dimmed(),
+                results.len().to_string().bright_white()
+            );
+        }
+        OutputFormat::Json => {
+            if results.is_empty() {
+                outln!(ctx, "[]");
+            } else {
+                print_json(ctx, results);
+            }
+        }
+        OutputFormat::Jsonl => {
+            for r in results {
+                outln!(ctx, "{}", serde_json::to_string(r).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            if results.is_empty() {
+                outln!(ctx, "[]");
+            } else {
+                outln!(ctx, "{}", serde_yaml::to_string(results).unwrap());
+            }
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+
+            if results.is_empty() {
+                wtr.write_record(["id", "chain", "address", "status", "matched_fields"])
+                    .unwrap();
+            } else {
+                for r in results {
+                    let status = match r.puzzle.status {
+                        Status::Solved => "solved",
+                        Status::Unsolved => "unsolved",
+                        Status::Claimed => "claimed",
+                        Status::Swept => "swept",
+                        Status::Expired => "expired",
+                    };
+
+                    wtr.serialize(SearchCsvRow {
+                        id: r.puzzle.id.to_string(),
+                        chain: r.puzzle.chain.symbol().to_string(),
+                        address: r.puzzle.address.value.to_string(),
+                        status: status.to_string(),
+                        matched_fields: r.matched_fields.join(";"),
+                    })
+                    .unwrap();
+                }
+            }
+
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+pub fn run(ctx: &Context, args: &SearchArgs) {
+    let query = args.query.as_str();
+    if query.trim().is_empty() {
+        error_exit("Search query cannot be empty");
+    }
+
+    let filter = args.bounds.filter();
+    let puzzles: Vec<&'static Puzzle> = match args.collection.as_deref() {
+        Some("all") | None => boha::all().filter(|p| filter.matches(p)).collect(),
+        Some(collection) => collection_or_exit(collection, true)
+            .all()
+            .filter(|p| filter.matches(p))
+            .collect(),
+    };
+
+    let matcher = if args.regex {
+        Matcher::regex(query, args.case_sensitive)
+            .unwrap_or_else(|e| error_exit(format!("Invalid --regex query: {}", e)))
+    } else {
+        Matcher::text(query, args.exact, args.case_sensitive)
+    };
+    let mut results = search(&puzzles, &matcher, &args.field);
+
+    if let Some(limit) = args.limit {
+        results.truncate(limit);
+    }
+
+    if let Some(template) = args.template.template() {
+        for result in &results {
+            template.print(ctx, result);
+        }
+        return;
+    }
+    output_search_results(ctx, &results, query);
+}
+
+#[cfg(all(test, feature = "examples-data"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_addresses_match() {
+        let puzzle = boha::get("example/2").unwrap();
+        let matcher = Matcher::Text {
+            query: "mudnu2zljxjmx".to_string(),
+            exact: false,
+            case_sensitive: false,
+        };
+        for fields in [&[][..], &[SearchField::Address]] {
+            let results = search(&[puzzle], &matcher, fields);
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].matched_fields, ["extra_addresses.value"]);
+        }
+    }
+}
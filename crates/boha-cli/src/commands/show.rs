@@ -0,0 +1,792 @@
+use std::io::{self, IsTerminal, Write};
+
+use crate::color::Colorize;
+use boha::{script, Amount, Key, PubkeyFormat, Puzzle, Status, TransactionType};
+use clap::Args;
+#[cfg(feature = "clipboard")]
+use clap::ValueEnum;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use crate::context::Context;
+use crate::markdown;
+use crate::output::{
+    error_exit, fail, outln, print_record, section, CliError, ErrorCode, KeyValueRow, OutputFormat,
+};
+use crate::qr::{self, QrTarget};
+use crate::template::TemplateArgs;
+
+/// Column the description is wrapped to in the detail table.
+const DESCRIPTION_WIDTH: usize = 60;
+
+/// Placeholder for secrets withheld from output.
+const REDACTED: &str = "<redacted>";
+
+#[derive(Debug, Args)]
+pub struct ShowArgs {
+    /// Puzzle ids, or inclusive ranges of numbered ones (b1000/60..70)
+    #[arg(required = true, value_name = "ID", add = ArgValueCandidates::new(super::completions::puzzle_ids))]
+    pub ids: Vec<String>,
+
+    #[command(flatten)]
+    pub options: ShowOptions,
+}
+
+/// How `show` renders puzzles; shared with `random`.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Args)]
+pub struct ShowOptions {
+    /// Summarise the transactions in one line instead of listing each with
+    /// its explorer link
+    #[arg(long)]
+    pub no_transactions: bool,
+
+    #[arg(long)]
+    pub open: bool,
+
+    /// Print private key material without asking (redacted otherwise)
+    #[arg(long)]
+    pub show_key: bool,
+
+    /// Copy a value to the system clipboard
+    #[cfg(feature = "clipboard")]
+    #[arg(long, value_enum)]
+    pub copy: Option<CopyTarget>,
+
+    /// Print the address (or public key) as a QR code
+    #[arg(long, value_enum, value_name = "VALUE", num_args = 0..=1, default_missing_value = "address")]
+    pub qr: Option<QrTarget>,
+
+    /// Value the prize in this fiat currency (e.g. usd, eur)
+    #[cfg(feature = "price")]
+    #[arg(long, value_name = "CODE", value_parser = crate::util::parse_currency)]
+    pub currency: Option<String>,
+
+    /// Value the prize at the price of its solve date (in --currency, default usd)
+    #[cfg(feature = "price")]
+    #[arg(long)]
+    pub with_historical_value: bool,
+
+    #[command(flatten)]
+    pub template: TemplateArgs,
+}
+
+/// Prize in a fiat currency, added to the output with `--currency`.
+#[cfg(feature = "price")]
+#[derive(Serialize)]
+struct PrizeFiat {
+    currency: String,
+    /// Price of one unit of the prize currency.
+    rate: f64,
+    value: f64,
+}
+
+/// The puzzle plus values computed for display.
+#[derive(Serialize)]
+struct ShowRecord<'a> {
+    #[serde(flatten)]
+    puzzle: &'a Puzzle,
+    /// Redeem script disassembled, for script-hash puzzles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script_asm: Option<String>,
+    #[cfg(feature = "price")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prize_fiat: Option<PrizeFiat>,
+    #[cfg(feature = "price")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    historical_value: Option<boha_chain::price::HistoricalValue>,
+}
+
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CopyTarget {
+    Address,
+    Pubkey,
+    /// Key range as `0x<start>:0x<end>`
+    Range,
+}
+
+pub fn run(ctx: &Context, args: &ShowArgs) {
+    let puzzles: Vec<&'static Puzzle> = args.ids.iter().flat_map(|id| resolve(id)).collect();
+    // A list in, a list out: structured output is an array unless a
+    // single plain id was asked for.
+    let many = args.ids.len() > 1 || args.ids.iter().any(|id| id.contains(".."));
+    show(ctx, &puzzles, many, &args.options);
+}
+
+/// Render `puzzles`, as an array in JSON and YAML when `many`.
+pub fn show(ctx: &Context, puzzles: &[&'static Puzzle], many: bool, args: &ShowOptions) {
+    #[cfg(feature = "clipboard")]
+    if args.copy.is_some() && puzzles.len() > 1 {
+        error_exit("--copy needs a single puzzle");
+    }
+
+    let qr_values: Vec<Option<String>> = puzzles
+        .iter()
+        .map(|puzzle| args.qr.map(|target| qr_value(puzzle, target)))
+        .collect();
+
+    let template = args.template.template();
+    // Templates feed scripts, so they never prompt.
+    let interactive = template.is_none() && matches!(ctx.output, OutputFormat::Table);
+    if !interactive {
+        for value in qr_values.iter().flatten() {
+            qr::print(ctx, value, true);
+        }
+    }
+    let shown: Vec<(&Puzzle, Puzzle, bool)> = puzzles
+        .iter()
+        .map(|&puzzle| {
+            if args.open {
+                open_asset(puzzle);
+            }
+            #[cfg(feature = "clipboard")]
+            if let Some(target) = args.copy {
+                copy_to_clipboard(puzzle, target);
+            }
+            let reveal = args.show_key
+                || (has_secret(puzzle) && interactive && confirm_reveal(ctx, puzzle.id));
+            let shown = if reveal {
+                puzzle.clone()
+            } else {
+                redact_secrets(puzzle)
+            };
+            (puzzle, shown, reveal)
+        })
+        .collect();
+
+    let records: Vec<ShowRecord> = shown
+        .iter()
+        .map(|(puzzle, shown, _)| ShowRecord {
+            puzzle: shown,
+            script_asm: script_asm(puzzle),
+            #[cfg(feature = "price")]
+            prize_fiat: args
+                .currency
+                .as_deref()
+                .and_then(|currency| prize_fiat(puzzle, currency)),
+            #[cfg(feature = "price")]
+            historical_value: if args.with_historical_value {
+                historical_value(puzzle, args.currency.as_deref().unwrap_or("usd"))
+            } else {
+                None
+            },
+        })
+        .collect();
+
+    if let Some(template) = template {
+        for record in &records {
+            template.print(ctx, record);
+        }
+        return;
+    }
+    if many && matches!(ctx.output, OutputFormat::Json | OutputFormat::Yaml) {
+        print_record(ctx, &records, |_, _| {});
+        return;
+    }
+    for (i, record) in records.iter().enumerate() {
+        print_record(ctx, record, |ctx, r| {
+            if i > 0 {
+                outln!(ctx);
+            }
+            #[cfg(feature = "price")]
+            let prize_rows = fiat_rows(r);
+            #[cfg(not(feature = "price"))]
+            let prize_rows = Vec::new();
+            print_puzzle_detail_table(ctx, r.puzzle, !args.no_transactions, prize_rows);
+            if let Some(value) = &qr_values[i] {
+                qr::print(ctx, value, false);
+            }
+        });
+    }
+
+    if matches!(ctx.output, OutputFormat::Table) {
+        for (puzzle, _, reveal) in &shown {
+            if !reveal && has_secret(puzzle) {
+                eprintln!(
+                    "{} private key of {} hidden, pass --show-key to reveal",
+                    "Note:".yellow().bold(),
+                    puzzle.id
+                );
+            }
+        }
+    }
+}
+
+/// The puzzle `id` names, or every existing puzzle of a `collection/a..b`
+/// range; exits when there is none.
+fn resolve(id: &str) -> Vec<&'static Puzzle> {
+    let Some((start, end)) = id.split_once("..") else {
+        return vec![boha::get(id).unwrap_or_else(|e| CliError::from(e).exit())];
+    };
+    let Some((collection, first)) = start.split_once('/') else {
+        error_exit(format!("Invalid range {}: expected collection/N..M", id));
+    };
+    let last = end
+        .strip_prefix(collection)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .unwrap_or(end);
+    let (Ok(first), Ok(last)) = (first.parse::<u32>(), last.parse::<u32>()) else {
+        error_exit(format!("Invalid range {}: expected collection/N..M", id));
+    };
+    if first > last {
+        error_exit(format!("Invalid range {}: {} is above {}", id, first, last));
+    }
+    let puzzles: Vec<_> = (first..=last)
+        .filter_map(|n| boha::get(&format!("{}/{}", collection, n)).ok())
+        .collect();
+    if puzzles.is_empty() {
+        fail(ErrorCode::NotFound, format!("No puzzles in range {}", id));
+    }
+    puzzles
+}
+
+fn open_asset(puzzle: &Puzzle) {
+    if let Some(url) = puzzle.asset_url() {
+        if let Err(e) = open::that(&url) {
+            eprintln!("{} Failed to open URL: {}", "Warning:".yellow().bold(), e);
+        }
+    } else {
+        eprintln!(
+            "{} No asset available for {}",
+            "Warning:".yellow().bold(),
+            puzzle.id
+        );
+    }
+}
+
+fn script_asm(puzzle: &Puzzle) -> Option<String> {
+    script::to_asm(puzzle.address.redeem_script?.script).ok()
+}
+
+/// Fiat value of the prize, or `None` (with a note) when there is nothing to value.
+#[cfg(feature = "price")]
+fn prize_fiat(puzzle: &Puzzle, currency: &str) -> Option<PrizeFiat> {
+    let prize = puzzle.prize?;
+    let symbol = puzzle.currency();
+    let prices = crate::util::prices_or_exit(&[symbol], currency);
+    let Some(rate) = prices.rate(symbol) else {
+        eprintln!(
+            "{} no {} price available for {}",
+            "Note:".yellow().bold(),
+            prices.currency(),
+            symbol
+        );
+        return None;
+    };
+    Some(PrizeFiat {
+        currency: prices.currency().to_string(),
+        rate,
+        value: (prize * rate * 100.0).round() / 100.0,
+    })
+}
+
+/// Prize value at solve date, or `None` (with a note) when unknown.
+#[cfg(feature = "price")]
+fn historical_value(
+    puzzle: &'static Puzzle,
+    currency: &str,
+) -> Option<boha_chain::price::HistoricalValue> {
+    if puzzle.prize.is_none() || puzzle.solve_date.is_none() {
+        eprintln!(
+            "{} {} has no prize or solve date to value",
+            "Note:".yellow().bold(),
+            puzzle.id
+        );
+        return None;
+    }
+    let value = crate::util::historical_values_or_exit(&[puzzle], currency)
+        .pop()
+        .flatten();
+    if value.is_none() {
+        eprintln!(
+            "{} no {} price of {} available for the solve date",
+            "Note:".yellow().bold(),
+            currency,
+            puzzle.currency()
+        );
+    }
+    value
+}
+
+#[cfg(feature = "price")]
+fn fiat_rows(record: &ShowRecord) -> Vec<KeyValueRow> {
+    let mut rows = Vec::new();
+    if let Some(fiat) = &record.prize_fiat {
+        rows.push(KeyValueRow {
+            field: "Prize value".to_string(),
+            value: format!("{:.2} {}", fiat.value, fiat.currency.to_uppercase()),
+        });
+    }
+    if let Some(historical) = &record.historical_value {
+        rows.push(KeyValueRow {
+            field: "Value at solve".to_string(),
+            value: format!(
+                "{:.2} {} ({})",
+                historical.value,
+                historical.currency.to_uppercase(),
+                historical.date
+            ),
+        });
+    }
+    rows
+}
+
+#[cfg(feature = "clipboard")]
+fn copy_to_clipboard(puzzle: &Puzzle, target: CopyTarget) {
+    let (label, value) = match target {
+        CopyTarget::Address => ("address", Some(puzzle.address.value.to_string())),
+        CopyTarget::Pubkey => ("public key", puzzle.pubkey_str().map(str::to_string)),
+        CopyTarget::Range => (
+            "key range",
+            puzzle.key_range_big().map(|range| format!("{:#x}", range)),
+        ),
+    };
+
+    let Some(value) = value else {
+        CliError::new(
+            ErrorCode::NoKey,
+            format!("No {} available for {}", label, puzzle.id),
+        )
+        .id(puzzle.id)
+        .exit()
+    };
+
+    match arboard::Clipboard::new().and_then(|mut cb| cb.set_text(value)) {
+        Ok(()) => eprintln!("{} {} to clipboard", "Copied".green().bold(), label),
+        Err(e) => eprintln!(
+            "{} Failed to copy to clipboard: {}",
+            "Warning:".yellow().bold(),
+            e
+        ),
+    }
+}
+
+/// What `--qr` encodes for `puzzle`; exits when it has no such value.
+fn qr_value(puzzle: &Puzzle, target: QrTarget) -> String {
+    let value = match target {
+        QrTarget::Address => Some(puzzle.address.value),
+        QrTarget::Pubkey => puzzle.pubkey_str(),
+    };
+    value.map_or_else(
+        || {
+            CliError::new(
+                ErrorCode::NoKey,
+                format!("No {} available for {}", target.label(), puzzle.id),
+            )
+            .id(puzzle.id)
+            .exit()
+        },
+        str::to_string,
+    )
+}
+
+/// Whether the puzzle carries key material that spends (or spent) its funds.
+fn has_secret(p: &Puzzle) -> bool {
+    p.key.is_some_and(|k| {
+        k.hex.is_some()
+            || k.mini.is_some()
+            || k.seed.is_some_and(|s| s.phrase.is_some())
+            || k.wif
+                .is_some_and(|w| w.decrypted.is_some() || w.passphrase.is_some())
+    })
+}
+
+/// Ask on an interactive terminal; anything but "y"/"yes" keeps the key hidden.
+fn confirm_reveal(ctx: &Context, id: &str) -> bool {
+    if !io::stdin().is_terminal() || !ctx.out.is_terminal() {
+        return false;
+    }
+
+    print!("Reveal private key for {}? [y/N] ", id.cyan());
+    io::stdout().flush().ok();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).ok();
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Copy of `p` with private key material replaced by [`REDACTED`].
+pub fn redact_secrets(p: &Puzzle) -> Puzzle {
+    let redact = |v: Option<&'static str>| v.map(|_| REDACTED);
+    Puzzle {
+        key: p.key.map(|k| Key {
+            hex: redact(k.hex),
+            mini: redact(k.mini),
+            wif: k.wif.map(|w| boha::Wif {
+                decrypted: redact(w.decrypted),
+                passphrase: redact(w.passphrase),
+                ..w
+            }),
+            seed: k.seed.map(|s| boha::Seed {
+                phrase: redact(s.phrase),
+                ..s
+            }),
+            ..k
+        }),
+        ..p.clone()
+    }
+}
+
+pub fn format_transaction_type(tx_type: TransactionType) -> String {
+    match tx_type {
+        TransactionType::Funding => "Funding".blue().to_string(),
+        TransactionType::Increase => "Increase".green().to_string(),
+        TransactionType::Decrease => "Decrease".yellow().to_string(),
+        TransactionType::Sweep => "Sweep".red().to_string(),
+        TransactionType::Claim => "Claim".cyan().to_string(),
+        TransactionType::PubkeyReveal => "PubkeyReveal".magenta().to_string(),
+    }
+}
+
+/// `amount` padded to the usual eight decimals, but never rounded: wei and
+/// piconero amounts keep every significant digit.
+pub fn format_amount(amount: Amount, currency: &str) -> String {
+    let exact = amount.to_string();
+    let places = exact
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len());
+    format!("{:.*} {}", places.max(8), amount, currency)
+}
+
+/// `prize_rows` (e.g. fiat values) are shown right after the prize.
+pub fn print_puzzle_detail_table(
+    ctx: &Context,
+    p: &Puzzle,
+    show_transactions: bool,
+    prize_rows: Vec<KeyValueRow>,
+) {
+    let rows = detail_rows(p, show_transactions, prize_rows);
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    outln!(ctx, "{}", table);
+}
+
+/// The field/value rows of the `show` table, section headers included.
+pub fn detail_rows(
+    p: &Puzzle,
+    show_transactions: bool,
+    prize_rows: Vec<KeyValueRow>,
+) -> Vec<KeyValueRow> {
+    let status_colored = match p.status {
+        Status::Solved => "Solved".green().to_string(),
+        Status::Unsolved => "Unsolved".yellow().to_string(),
+        Status::Claimed => "Claimed".cyan().to_string(),
+        Status::Swept => "Swept".red().to_string(),
+        Status::Expired => "Expired".dimmed().to_string(),
+    };
+
+    let mut rows = vec![
+        KeyValueRow {
+            field: "ID".to_string(),
+            value: p.id.to_string().bright_white().to_string(),
+        },
+        KeyValueRow {
+            field: "UUID".to_string(),
+            value: p.uuid.to_string(),
+        },
+        KeyValueRow {
+            field: "Chain".to_string(),
+            value: p.chain.name().to_string(),
+        },
+        KeyValueRow {
+            field: "Status".to_string(),
+            value: status_colored,
+        },
+    ];
+
+    if let Some(prize) = p.prize {
+        rows.push(KeyValueRow {
+            field: "Prize".to_string(),
+            value: format!("{} {}", prize, p.currency())
+                .bright_green()
+                .to_string(),
+        });
+        rows.extend(prize_rows);
+    }
+
+    if let Some(url) = p.source_url {
+        rows.push(KeyValueRow {
+            field: "Source".to_string(),
+            value: url.to_string(),
+        });
+    }
+
+    if let Some(description) = p.description {
+        rows.push(KeyValueRow {
+            field: "About".to_string(),
+            value: markdown::render(description, DESCRIPTION_WIDTH),
+        });
+    }
+
+    rows.push(section("Address"));
+    rows.push(KeyValueRow {
+        field: "  Value".to_string(),
+        value: p.address.value.to_string(),
+    });
+    rows.push(KeyValueRow {
+        field: "  Type".to_string(),
+        value: p.address.kind.to_uppercase(),
+    });
+    if let Some(hash160) = p.address.hash160 {
+        rows.push(KeyValueRow {
+            field: "  HASH160".to_string(),
+            value: hash160.to_string(),
+        });
+    }
+    if let Some(rs) = &p.address.redeem_script {
+        rows.push(KeyValueRow {
+            field: "  Redeem Script".to_string(),
+            value: rs.script.to_string(),
+        });
+        if let Some(asm) = script_asm(p) {
+            rows.push(KeyValueRow {
+                field: "  Script".to_string(),
+                value: asm,
+            });
+        }
+        rows.push(KeyValueRow {
+            field: "  Script Hash".to_string(),
+            value: rs.hash.to_string(),
+        });
+    }
+    for (i, extra) in p.extra_addresses.iter().enumerate() {
+        let field = if i == 0 { "  Also" } else { "" };
+        rows.push(KeyValueRow {
+            field: field.to_string(),
+            value: format!("{} ({})", extra.value, extra.kind.to_uppercase()),
+        });
+    }
+
+    if let Some(pubkey) = &p.pubkey {
+        rows.push(section("Public Key"));
+        rows.push(KeyValueRow {
+            field: "  Key".to_string(),
+            value: pubkey.value.to_string(),
+        });
+        rows.push(KeyValueRow {
+            field: "  Format".to_string(),
+            value: match pubkey.format {
+                PubkeyFormat::Compressed => "compressed",
+                PubkeyFormat::Uncompressed => "uncompressed",
+            }
+            .to_string(),
+        });
+    }
+
+    if let Some(key) = &p.key {
+        if key.is_known() {
+            rows.push(section("Private Key"));
+            if let Some(hex) = key.hex {
+                rows.push(KeyValueRow {
+                    field: "  Hex".to_string(),
+                    value: hex.to_string().bright_red().to_string(),
+                });
+            }
+            if let Some(wif) = key.wif {
+                if let Some(encrypted) = wif.encrypted {
+                    rows.push(KeyValueRow {
+                        field: "  WIF (encrypted)".to_string(),
+                        value: encrypted.to_string().bright_red().to_string(),
+                    });
+                }
+                if let Some(decrypted) = wif.decrypted {
+                    rows.push(KeyValueRow {
+                        field: "  WIF".to_string(),
+                        value: decrypted.to_string().bright_red().to_string(),
+                    });
+                }
+                if let Some(passphrase) = wif.passphrase {
+                    rows.push(KeyValueRow {
+                        field: "  Passphrase".to_string(),
+                        value: passphrase.to_string().bright_red().to_string(),
+                    });
+                }
+                if let Some(salt) = wif.salt {
+                    rows.push(KeyValueRow {
+                        field: "  Salt".to_string(),
+                        value: salt.to_string().bright_red().to_string(),
+                    });
+                }
+            }
+            if let Some(seed) = &key.seed {
+                if let Some(phrase) = seed.phrase {
+                    rows.push(KeyValueRow {
+                        field: "  Seed".to_string(),
+                        value: phrase.to_string().bright_red().to_string(),
+                    });
+                }
+                if let Some(path) = seed.path {
+                    rows.push(KeyValueRow {
+                        field: "  Seed Path".to_string(),
+                        value: path.to_string(),
+                    });
+                }
+                if let Some(xpub) = seed.xpub {
+                    rows.push(KeyValueRow {
+                        field: "  Xpub".to_string(),
+                        value: xpub.to_string(),
+                    });
+                }
+            }
+            if let Some(mini) = key.mini {
+                rows.push(KeyValueRow {
+                    field: "  Mini".to_string(),
+                    value: mini.to_string().bright_red().to_string(),
+                });
+            }
+        }
+
+        if let Some(bits) = key.bits {
+            rows.push(section("Key Range"));
+            rows.push(KeyValueRow {
+                field: "  Bits".to_string(),
+                value: bits.to_string(),
+            });
+            if let Some(range) = p.key_range_big() {
+                rows.push(KeyValueRow {
+                    field: "  Min".to_string(),
+                    value: format!("0x{:x}", range.start()),
+                });
+                rows.push(KeyValueRow {
+                    field: "  Max".to_string(),
+                    value: format!("0x{:x}", range.end()),
+                });
+            }
+        }
+    }
+
+    if p.start_date.is_some() || p.solve_date.is_some() || p.solve_time.is_some() {
+        rows.push(section("Timeline"));
+        if let Some(date) = p.start_date {
+            rows.push(KeyValueRow {
+                field: "  Funded".to_string(),
+                value: date.to_string(),
+            });
+        }
+        if let Some(date) = p.solve_date {
+            rows.push(KeyValueRow {
+                field: "  Solved".to_string(),
+                value: date.to_string(),
+            });
+        }
+        if let Some(formatted) = p.solve_time_formatted() {
+            rows.push(KeyValueRow {
+                field: "  Duration".to_string(),
+                value: formatted,
+            });
+        }
+    }
+
+    if let Some(txid) = p.claim_txid() {
+        rows.push(section("Claim"));
+        rows.push(KeyValueRow {
+            field: "  TX".to_string(),
+            value: txid.to_string(),
+        });
+        rows.push(KeyValueRow {
+            field: "  Explorer".to_string(),
+            value: p.chain.tx_explorer_url(txid),
+        });
+    }
+
+    if let Some(solver) = &p.solver {
+        if solver.name.is_some()
+            || !solver.addresses.is_empty()
+            || solver.announcement_url.is_some()
+        {
+            rows.push(section("Solver"));
+            if let Some(name) = solver.name {
+                rows.push(KeyValueRow {
+                    field: "  Name".to_string(),
+                    value: name.bright_white().to_string(),
+                });
+            }
+            for (i, addr) in solver.addresses.iter().enumerate() {
+                let field = if i == 0 { "  Address" } else { "" };
+                rows.push(KeyValueRow {
+                    field: field.to_string(),
+                    value: addr.to_string(),
+                });
+            }
+            for profile in solver.profiles {
+                rows.push(KeyValueRow {
+                    field: format!("  {}", profile.name),
+                    value: profile.url.to_string(),
+                });
+            }
+            if let Some(url) = solver.announcement_url {
+                rows.push(KeyValueRow {
+                    field: "  Announced".to_string(),
+                    value: url.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(assets) = &p.assets {
+        rows.push(section("Assets"));
+        if let Some(path) = p.asset_path() {
+            rows.push(KeyValueRow {
+                field: "  Path".to_string(),
+                value: path,
+            });
+        }
+        if let Some(url) = p.asset_url() {
+            rows.push(KeyValueRow {
+                field: "  URL".to_string(),
+                value: url,
+            });
+        }
+        if let Some(solver_path) = assets.solver {
+            rows.push(KeyValueRow {
+                field: "  Solver".to_string(),
+                value: format!("assets/{}/{}", p.collection(), solver_path),
+            });
+        }
+        if !assets.hints.is_empty() {
+            for (i, hint) in assets.hints.iter().enumerate() {
+                let field = if i == 0 { "  Hints" } else { "" };
+                rows.push(KeyValueRow {
+                    field: field.to_string(),
+                    value: format!("assets/{}/{}", p.collection(), hint),
+                });
+            }
+        }
+        if let Some(source) = assets.source_url {
+            rows.push(KeyValueRow {
+                field: "  Source".to_string(),
+                value: source.to_string(),
+            });
+        }
+    }
+
+    if show_transactions && !p.transactions.is_empty() {
+        rows.push(section("Transactions"));
+        for tx in p.transactions {
+            let value = [
+                Some(tx.date.unwrap_or("-").to_string()),
+                tx.native_amount().map(|a| format_amount(a, p.currency())),
+                tx.txid.map(|txid| p.chain.tx_explorer_url(txid)),
+            ];
+            rows.push(KeyValueRow {
+                field: format!("  {}", format_transaction_type(tx.tx_type)),
+                value: value.into_iter().flatten().collect::<Vec<_>>().join(" "),
+            });
+        }
+    } else if let Some(last) = p.transactions.last() {
+        rows.push(section("Transactions"));
+        rows.push(KeyValueRow {
+            field: "  Recorded".to_string(),
+            value: format!(
+                "{}, last {} {} {}",
+                p.transactions.len(),
+                format_transaction_type(last.tx_type),
+                last.date.unwrap_or("-"),
+                format!("(boha history {})", p.id).dimmed()
+            ),
+        });
+    }
+
+    rows
+}
@@ -0,0 +1,299 @@
+//! Static dashboard of the embedded dataset.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use boha::{script, Collection, Puzzle, Status, TransactionType};
+use chrono::Utc;
+use clap::Args;
+
+use crate::context::Context;
+use crate::output::error_exit;
+use crate::report::{self, html_escape};
+
+const LAYOUT: &str = include_str!("../../templates/site/layout.html");
+
+#[derive(Debug, Args)]
+pub struct SiteArgs {
+    /// Directory to write the site into (created if missing)
+    #[arg(long, value_name = "DIR", default_value = "public")]
+    pub out: PathBuf,
+}
+
+/// Page location and rendering context shared by every page.
+struct Page<'a> {
+    generated: &'a str,
+    /// Prefix from the page back to the site root, `""` or `"../"`.
+    root: &'static str,
+    breadcrumbs: Vec<(String, String)>,
+}
+
+impl Page<'_> {
+    fn render(&self, title: &str, content: &str) -> String {
+        let mut breadcrumbs = String::new();
+        for (href, text) in &self.breadcrumbs {
+            write!(
+                breadcrumbs,
+                " / <a href=\"{}\">{}</a>",
+                html_escape(href),
+                html_escape(text)
+            )
+            .unwrap();
+        }
+        LAYOUT
+            .replace("{{title}}", &html_escape(title))
+            .replace("{{root}}", self.root)
+            .replace("{{breadcrumbs}}", &breadcrumbs)
+            .replace("{{generated}}", self.generated)
+            .replace("{{version}}", &html_escape(boha::version::FULL_VERSION))
+            .replace("{{content}}", content)
+    }
+}
+
+pub fn run(_ctx: &Context, args: &SiteArgs) {
+    let generated = Utc::now().format("%Y-%m-%d %H:%M UTC").to_string();
+    let collections = boha::collections();
+
+    write(&args.out.join("style.css"), report::STYLE);
+    let root = Page {
+        generated: &generated,
+        root: "",
+        breadcrumbs: Vec::new(),
+    };
+    write(
+        &args.out.join("index.html"),
+        &root.render("Crypto puzzles and bounties", &index(&collections)),
+    );
+
+    let mut pages = 1;
+    for collection in &collections {
+        let dir = args.out.join(collection.name());
+        let page = Page {
+            generated: &generated,
+            root: "../",
+            breadcrumbs: vec![("index.html".to_string(), collection.name().to_string())],
+        };
+        write(
+            &dir.join("index.html"),
+            &page.render(collection.name(), &collection_page(*collection)),
+        );
+        for puzzle in collection.all() {
+            write(
+                &dir.join(page_file(puzzle)),
+                &page.render(puzzle.id, &puzzle_page(puzzle)),
+            );
+        }
+        pages += 1 + collection.all().len();
+    }
+
+    eprintln!("Wrote {} pages to {}", pages, args.out.display());
+}
+
+fn write(path: &Path, contents: &str) {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .unwrap_or_else(|e| error_exit(format!("Cannot create {}: {}", dir.display(), e)));
+    }
+    std::fs::write(path, contents)
+        .unwrap_or_else(|e| error_exit(format!("Cannot write {}: {}", path.display(), e)));
+}
+
+/// File of a puzzle page inside its collection directory. Single-puzzle
+/// collections (`gsmg`, `bitaps`) have no name part, so use the collection.
+fn page_file(puzzle: &Puzzle) -> String {
+    let name = puzzle.name();
+    format!(
+        "{}.html",
+        if name.is_empty() {
+            puzzle.collection()
+        } else {
+            name
+        }
+    )
+}
+
+fn index(collections: &[Collection]) -> String {
+    let mut out = String::new();
+    report::html_summary(&mut out, &boha::stats());
+
+    writeln!(out, "<h2>Collections</h2>\n<table>").unwrap();
+    report::html_row(
+        &mut out,
+        "th",
+        [
+            "Collection",
+            "Author",
+            "Puzzles",
+            "Unsolved",
+            "Unsolved prize",
+        ]
+        .map(String::from),
+    );
+    for collection in collections {
+        let puzzles: Vec<&Puzzle> = collection.all().collect();
+        let unsolved: Vec<&&Puzzle> = puzzles
+            .iter()
+            .filter(|p| p.status == Status::Unsolved)
+            .collect();
+        let mut prize: HashMap<String, f64> = HashMap::new();
+        for p in &unsolved {
+            *prize.entry(p.currency().to_string()).or_default() += p.prize.unwrap_or(0.0);
+        }
+        report::html_row(
+            &mut out,
+            "td",
+            [
+                format!(
+                    "<a href=\"{0}/index.html\">{0}</a>",
+                    html_escape(collection.name())
+                ),
+                html_escape(collection.author().name.unwrap_or("Anonymous")),
+                puzzles.len().to_string(),
+                unsolved.len().to_string(),
+                html_escape(&report::prizes(&prize)),
+            ],
+        );
+    }
+    writeln!(out, "</table>").unwrap();
+    out
+}
+
+fn collection_page(collection: Collection) -> String {
+    let mut out = String::new();
+    report::html_author(&mut out, collection.author());
+    let puzzles: Vec<&'static Puzzle> = collection.all().collect();
+    report::html_puzzle_table(&mut out, &puzzles, |p| Some(page_file(p)));
+    out
+}
+
+fn puzzle_page(puzzle: &Puzzle) -> String {
+    let mut rows: Vec<(&str, String)> = vec![
+        (
+            "Status",
+            format!(
+                "<span class=\"{0}\">{0}</span>",
+                html_escape(&puzzle.status.to_string())
+            ),
+        ),
+        ("Chain", html_escape(puzzle.chain.name())),
+        (
+            "Address",
+            format!(
+                "<a class=\"mono\" href=\"{}\">{}</a> ({})",
+                html_escape(&puzzle.explorer_url()),
+                html_escape(puzzle.address.value),
+                html_escape(puzzle.address.kind)
+            ),
+        ),
+    ];
+    if let Some(hash160) = puzzle.address.hash160 {
+        rows.push(("HASH160", mono(hash160)));
+    }
+    if let Some(pubkey) = puzzle.pubkey_str() {
+        rows.push(("Public key", mono(pubkey)));
+    }
+    if let Some(redeem_script) = puzzle.address.redeem_script {
+        rows.push(("Redeem script", mono(redeem_script.script)));
+        if let Ok(asm) = script::to_asm(redeem_script.script) {
+            rows.push(("Script", mono(&asm)));
+        }
+    }
+    if let Some(prize) = puzzle.prize {
+        rows.push((
+            "Prize",
+            format!("{} {}", prize, html_escape(puzzle.currency())),
+        ));
+    }
+    if let Some(bits) = puzzle.key.and_then(|k| k.bits) {
+        rows.push(("Key bits", bits.to_string()));
+    }
+    if let Some(range) = puzzle.key_range_big() {
+        rows.push((
+            "Key range",
+            mono(&format!("0x{:x} – 0x{:x}", range.start(), range.end())),
+        ));
+    }
+    if let Some(hex) = puzzle.key.and_then(|k| k.hex) {
+        rows.push(("Private key", mono(hex)));
+    }
+    if let Some(date) = puzzle.start_date {
+        rows.push(("Funded", date.to_string()));
+    }
+    if let Some(date) = puzzle.solve_date {
+        rows.push(("Solved", date.to_string()));
+    }
+    if let Some(duration) = puzzle.solve_time_formatted() {
+        rows.push(("Time to solve", html_escape(&duration)));
+    }
+    if let Some(name) = puzzle.solver.as_ref().and_then(|s| s.name) {
+        rows.push(("Solver", html_escape(name)));
+    }
+    if let Some(url) = puzzle.solver.as_ref().and_then(|s| s.announcement_url) {
+        rows.push((
+            "Announcement",
+            format!("<a href=\"{0}\">{0}</a>", html_escape(url)),
+        ));
+    }
+    if let Some(by) = puzzle.superseded_by {
+        rows.push(("Superseded by", html_escape(by)));
+    }
+    if let Some(url) = puzzle.source_url {
+        rows.push((
+            "Source",
+            format!("<a href=\"{0}\">{0}</a>", html_escape(url)),
+        ));
+    }
+
+    let mut out = String::from("<table>\n");
+    for (field, value) in rows {
+        writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", field, value).unwrap();
+    }
+    writeln!(out, "</table>").unwrap();
+
+    if puzzle.has_transactions() {
+        writeln!(out, "<h2>Transactions</h2>\n<table>").unwrap();
+        report::html_row(
+            &mut out,
+            "th",
+            ["Type", "Date", "Transaction", "Amount"].map(String::from),
+        );
+        for tx in puzzle.transactions {
+            report::html_row(
+                &mut out,
+                "td",
+                [
+                    transaction_type(tx.tx_type).to_string(),
+                    tx.date.map(html_escape).unwrap_or_default(),
+                    tx.txid.map_or_else(String::new, |txid| {
+                        format!(
+                            "<a class=\"mono\" href=\"{}\">{}</a>",
+                            html_escape(&puzzle.chain.tx_explorer_url(txid)),
+                            html_escape(txid)
+                        )
+                    }),
+                    tx.native_amount()
+                        .map(|a| format!("{} {}", a, html_escape(puzzle.currency())))
+                        .unwrap_or_default(),
+                ],
+            );
+        }
+        writeln!(out, "</table>").unwrap();
+    }
+    out
+}
+
+fn mono(text: &str) -> String {
+    format!("<span class=\"mono\">{}</span>", html_escape(text))
+}
+
+const fn transaction_type(tx_type: TransactionType) -> &'static str {
+    match tx_type {
+        TransactionType::Funding => "Funding",
+        TransactionType::Increase => "Increase",
+        TransactionType::Decrease => "Decrease",
+        TransactionType::Sweep => "Sweep",
+        TransactionType::Claim => "Claim",
+        TransactionType::PubkeyReveal => "Pubkey reveal",
+    }
+}
@@ -0,0 +1,228 @@
+//! Ready-to-run arguments for the key search tools people point at puzzles.
+
+use std::path::{Path, PathBuf};
+
+use crate::color::Colorize;
+use boha::{PubkeyFormat, Puzzle};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+
+use super::range::ToolFormat;
+use crate::context::Context;
+use crate::output::{error_exit, out, outln, print_record, CliError};
+
+#[derive(Debug, Args)]
+pub struct SolverConfigArgs {
+    /// Puzzle id (e.g. b1000/71)
+    #[arg(add = ArgValueCandidates::new(super::completions::puzzle_ids))]
+    pub id: String,
+
+    /// Search tool to configure
+    #[arg(long, value_enum)]
+    pub tool: ToolFormat,
+
+    /// Write input files the tool needs into DIR (printed otherwise)
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct SolverConfig {
+    puzzle: &'static str,
+    tool: &'static str,
+    /// `command` split into arguments, for callers that spawn it directly.
+    args: Vec<String>,
+    command: String,
+    files: Vec<InputFile>,
+}
+
+#[derive(Serialize)]
+struct InputFile {
+    path: String,
+    contents: String,
+    written: bool,
+}
+
+pub fn run(ctx: &Context, args: &SolverConfigArgs) {
+    let puzzle = boha::get(&args.id).unwrap_or_else(|e| CliError::from(e).exit());
+    let mut config =
+        build(puzzle, args.tool, args.dir.as_deref()).unwrap_or_else(|e| error_exit(e));
+
+    if let Some(dir) = &args.dir {
+        std::fs::create_dir_all(dir)
+            .unwrap_or_else(|e| error_exit(format!("Cannot create {}: {}", dir.display(), e)));
+        for file in &mut config.files {
+            std::fs::write(&file.path, &file.contents)
+                .unwrap_or_else(|e| error_exit(format!("Cannot write {}: {}", file.path, e)));
+            file.written = true;
+        }
+    }
+
+    print_record(ctx, &config, print_table);
+}
+
+fn build(
+    puzzle: &'static Puzzle,
+    tool: ToolFormat,
+    dir: Option<&Path>,
+) -> Result<SolverConfig, String> {
+    let range = puzzle
+        .key_range_big()
+        .ok_or_else(|| format!("{} has no known key range", puzzle.id))?;
+    if puzzle.address.kind != "p2pkh" {
+        return Err(format!(
+            "{} is a {} address; search tools only target P2PKH",
+            puzzle.id, puzzle.address.kind
+        ));
+    }
+    let uncompressed = puzzle
+        .pubkey
+        .is_some_and(|pk| pk.format == PubkeyFormat::Uncompressed);
+    let file_path = |ext: &str| {
+        let name = format!("{}.{}", puzzle.id.replace('/', "_"), ext);
+        match dir {
+            Some(dir) => dir.join(name).display().to_string(),
+            None => name,
+        }
+    };
+
+    let mut files = Vec::new();
+    let (program, mut argv) = match tool {
+        ToolFormat::Bitcrack => (
+            "cuBitCrack",
+            vec![if uncompressed { "-u" } else { "-c" }.to_string()],
+        ),
+        ToolFormat::Vanitysearch => (
+            "VanitySearch",
+            if uncompressed {
+                vec!["-u".to_string()]
+            } else {
+                Vec::new()
+            },
+        ),
+        ToolFormat::Keyhunt => {
+            // A known public key allows baby-step giant-step, far faster than
+            // hashing every key in the range.
+            let (mode, target, ext) = match puzzle.pubkey {
+                Some(pk) => ("bsgs", pk.value, "pub"),
+                None => ("address", puzzle.address.value, "txt"),
+            };
+            let path = file_path(ext);
+            files.push(InputFile {
+                path: path.clone(),
+                contents: format!("{}\n", target),
+                written: false,
+            });
+            let mut argv = vec!["-m".to_string(), mode.to_string(), "-f".to_string(), path];
+            if mode == "address" {
+                let look = if uncompressed {
+                    "uncompress"
+                } else {
+                    "compress"
+                };
+                argv.extend(["-l".to_string(), look.to_string()]);
+            }
+            ("keyhunt", argv)
+        }
+    };
+    argv.extend(tool.range_args(&range));
+    if !matches!(tool, ToolFormat::Keyhunt) {
+        argv.push(puzzle.address.value.to_string());
+    }
+
+    let command = std::iter::once(program.to_string())
+        .chain(argv.iter().map(|a| shell_quote(a)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    argv.insert(0, program.to_string());
+    Ok(SolverConfig {
+        puzzle: puzzle.id,
+        tool: program,
+        args: argv,
+        command,
+        files,
+    })
+}
+
+fn shell_quote(arg: &str) -> String {
+    if arg
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b"-_./:=".contains(&b))
+    {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+fn print_table(ctx: &Context, config: &SolverConfig) {
+    outln!(ctx, "{}", config.command);
+    for file in &config.files {
+        if file.written {
+            eprintln!("{} {}", "Wrote".dimmed(), file.path);
+        } else {
+            outln!(ctx, "\n{} {}", "#".dimmed(), file.path.dimmed());
+            out!(ctx, "{}", file.contents);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitcrack_targets_address_in_range() {
+        let config = build(boha::get("b1000/71").unwrap(), ToolFormat::Bitcrack, None).unwrap();
+        assert_eq!(
+            config.command,
+            "cuBitCrack -c --keyspace 400000000000000000:7fffffffffffffffff \
+             1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU"
+        );
+        assert!(config.files.is_empty());
+    }
+
+    #[test]
+    fn keyhunt_prefers_bsgs_with_pubkey() {
+        let config = build(boha::get("b1000/135").unwrap(), ToolFormat::Keyhunt, None).unwrap();
+        assert_eq!(
+            &config.args[..5],
+            ["keyhunt", "-m", "bsgs", "-f", "b1000_135.pub"]
+        );
+        assert_eq!(
+            config.files[0].contents,
+            format!(
+                "{}\n",
+                boha::get("b1000/135").unwrap().pubkey_str().unwrap()
+            )
+        );
+
+        let config = build(boha::get("b1000/71").unwrap(), ToolFormat::Keyhunt, None).unwrap();
+        assert_eq!(config.args[2], "address");
+        assert!(config
+            .command
+            .contains("-l compress -r 400000000000000000:"));
+    }
+
+    #[test]
+    fn files_land_in_dir() {
+        let dir = Path::new("out dir");
+        let config = build(
+            boha::get("b1000/71").unwrap(),
+            ToolFormat::Keyhunt,
+            Some(dir),
+        )
+        .unwrap();
+        assert_eq!(config.files[0].path, "out dir/b1000_71.txt");
+        assert!(config.command.contains("-f 'out dir/b1000_71.txt'"));
+    }
+
+    #[test]
+    fn rejects_puzzles_without_range() {
+        let Err(err) = build(boha::get("gsmg").unwrap(), ToolFormat::Bitcrack, None) else {
+            panic!("gsmg has no key range");
+        };
+        assert!(err.contains("key range"));
+    }
+}
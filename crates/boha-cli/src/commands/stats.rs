@@ -0,0 +1,542 @@
+use crate::color::Colorize;
+use boha::{AuthorStats, Stats, STATS_SCHEMA_VERSION};
+use clap::Args;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::output::{outln, print_json, print_record, KeyValueRow, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    /// Emit an older JSON/YAML layout (1 = unversioned, pre-schema_version)
+    #[arg(long, value_name = "VERSION", value_parser = clap::value_parser!(u32).range(1..=i64::from(STATS_SCHEMA_VERSION)))]
+    pub compat: Option<u32>,
+
+    /// Aggregate counts and prize totals per author across collections
+    #[arg(long, conflicts_with = "compat")]
+    pub by_author: bool,
+
+    /// Value prize totals in this fiat currency (e.g. usd, eur)
+    #[cfg(feature = "price")]
+    #[arg(long, value_name = "CODE", value_parser = crate::util::parse_currency, conflicts_with_all = ["compat", "by_author"])]
+    pub currency: Option<String>,
+
+    /// Add the confirmed balance of unsolved addresses from the embedded snapshot
+    #[cfg(feature = "snapshot")]
+    #[arg(long, conflicts_with_all = ["compat", "by_author"])]
+    #[cfg_attr(feature = "price", arg(conflicts_with = "currency"))]
+    pub with_balances: bool,
+}
+
+/// Confirmed balances of unsolved puzzle addresses from the embedded
+/// snapshot, added to the output with `--with-balances`.
+#[cfg(feature = "snapshot")]
+#[derive(Serialize)]
+struct SnapshotStats {
+    taken_at: &'static str,
+    age_days: i64,
+    /// Unsolved puzzles with a balance in the snapshot.
+    addresses: usize,
+    /// Unsolved puzzles the snapshot has no balance for.
+    missing: usize,
+    /// Whole coins per currency symbol.
+    unsolved_balance: BTreeMap<String, f64>,
+}
+
+#[cfg(feature = "snapshot")]
+impl SnapshotStats {
+    fn new<'a>(
+        taken_at: &'static str,
+        puzzles: impl Iterator<Item = &'a boha::Puzzle>,
+        balance: impl Fn(&str) -> Option<u128>,
+    ) -> Self {
+        let mut stats = Self {
+            taken_at,
+            age_days: crate::snapshot::age_days(taken_at),
+            addresses: 0,
+            missing: 0,
+            unsolved_balance: BTreeMap::new(),
+        };
+        for puzzle in puzzles.filter(|p| p.status == boha::Status::Unsolved) {
+            let Some(confirmed) = balance(puzzle.address.value) else {
+                stats.missing += 1;
+                continue;
+            };
+            stats.addresses += 1;
+            *stats
+                .unsolved_balance
+                .entry(puzzle.chain.symbol().to_string())
+                .or_default() += confirmed as f64 / 10f64.powi(i32::from(puzzle.chain.decimals()));
+        }
+        stats
+    }
+}
+
+#[cfg(feature = "snapshot")]
+#[derive(Serialize)]
+struct StatsWithBalances<'a> {
+    #[serde(flatten)]
+    stats: &'a Stats,
+    balances: SnapshotStats,
+}
+
+/// Prize totals in a fiat currency, added to the output with `--currency`.
+/// Currencies without a known price are left out.
+#[cfg(feature = "price")]
+#[derive(Serialize)]
+struct FiatStats {
+    currency: String,
+    /// Price of one coin per currency symbol.
+    rates: BTreeMap<String, f64>,
+    total_prize: BTreeMap<String, f64>,
+    unsolved_prize: BTreeMap<String, f64>,
+    total: f64,
+    unsolved: f64,
+}
+
+#[cfg(feature = "price")]
+impl FiatStats {
+    fn new(stats: &Stats, prices: &boha_chain::price::Prices) -> Self {
+        let value = |map: &HashMap<String, f64>| -> BTreeMap<String, f64> {
+            map.iter()
+                .filter_map(|(symbol, amount)| {
+                    Some((symbol.clone(), round_cents(prices.value(*amount, symbol)?)))
+                })
+                .collect()
+        };
+        let total_prize = value(&stats.total_prize);
+        let unsolved_prize = value(&stats.unsolved_prize);
+        Self {
+            currency: prices.currency().to_string(),
+            rates: stats
+                .total_prize
+                .keys()
+                .filter_map(|symbol| Some((symbol.clone(), prices.rate(symbol)?)))
+                .collect(),
+            total: round_cents(total_prize.values().sum()),
+            unsolved: round_cents(unsolved_prize.values().sum()),
+            total_prize,
+            unsolved_prize,
+        }
+    }
+}
+
+#[cfg(feature = "price")]
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(feature = "price")]
+#[derive(Serialize)]
+struct StatsWithFiat<'a> {
+    #[serde(flatten)]
+    stats: &'a Stats,
+    fiat: FiatStats,
+}
+
+/// Schema version 1: no `schema_version`, prize maps only list currencies
+/// that have prizes, in arbitrary order.
+#[derive(Serialize)]
+struct StatsV1<'a> {
+    total: usize,
+    solved: usize,
+    unsolved: usize,
+    claimed: usize,
+    swept: usize,
+    expired: usize,
+    with_pubkey: usize,
+    total_prize: &'a HashMap<String, f64>,
+    unsolved_prize: &'a HashMap<String, f64>,
+}
+
+impl<'a> StatsV1<'a> {
+    const fn from_stats(stats: &'a Stats) -> Self {
+        Self {
+            total: stats.total,
+            solved: stats.solved,
+            unsolved: stats.unsolved,
+            claimed: stats.claimed,
+            swept: stats.swept,
+            expired: stats.expired,
+            with_pubkey: stats.with_pubkey,
+            total_prize: &stats.total_prize,
+            unsolved_prize: &stats.unsolved_prize,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct StatsCsvRow {
+    total: usize,
+    solved: usize,
+    unsolved: usize,
+    claimed: usize,
+    swept: usize,
+    with_pubkey: usize,
+    total_prize_by_chain: String,
+    unsolved_prize_by_chain: String,
+    total_prize_btc: f64,
+    total_prize_eth: f64,
+    total_prize_ltc: f64,
+    total_prize_xmr: f64,
+    total_prize_dcr: f64,
+    total_prize_ar: f64,
+    unsolved_prize_btc: f64,
+    unsolved_prize_eth: f64,
+    unsolved_prize_ltc: f64,
+    unsolved_prize_xmr: f64,
+    unsolved_prize_dcr: f64,
+    unsolved_prize_ar: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fiat_currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_prize_fiat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unsolved_prize_fiat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    balances_taken_at: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unsolved_balance_by_chain: Option<String>,
+}
+
+impl StatsCsvRow {
+    fn from_stats(stats: &Stats) -> Self {
+        fn get_prize(map: &HashMap<String, f64>, symbol: &str) -> f64 {
+            *map.get(symbol).unwrap_or(&0.0)
+        }
+
+        Self {
+            total: stats.total,
+            solved: stats.solved,
+            unsolved: stats.unsolved,
+            claimed: stats.claimed,
+            swept: stats.swept,
+            with_pubkey: stats.with_pubkey,
+            total_prize_by_chain: prize_map_json(&stats.total_prize),
+            unsolved_prize_by_chain: prize_map_json(&stats.unsolved_prize),
+            total_prize_btc: get_prize(&stats.total_prize, "BTC"),
+            total_prize_eth: get_prize(&stats.total_prize, "ETH"),
+            total_prize_ltc: get_prize(&stats.total_prize, "LTC"),
+            total_prize_xmr: get_prize(&stats.total_prize, "XMR"),
+            total_prize_dcr: get_prize(&stats.total_prize, "DCR"),
+            total_prize_ar: get_prize(&stats.total_prize, "AR"),
+            unsolved_prize_btc: get_prize(&stats.unsolved_prize, "BTC"),
+            unsolved_prize_eth: get_prize(&stats.unsolved_prize, "ETH"),
+            unsolved_prize_ltc: get_prize(&stats.unsolved_prize, "LTC"),
+            unsolved_prize_xmr: get_prize(&stats.unsolved_prize, "XMR"),
+            unsolved_prize_dcr: get_prize(&stats.unsolved_prize, "DCR"),
+            unsolved_prize_ar: get_prize(&stats.unsolved_prize, "AR"),
+            fiat_currency: None,
+            total_prize_fiat: None,
+            unsolved_prize_fiat: None,
+            balances_taken_at: None,
+            unsolved_balance_by_chain: None,
+        }
+    }
+}
+
+pub(super) fn prize_map_json(map: &HashMap<String, f64>) -> String {
+    let out: BTreeMap<&String, &f64> = map.iter().collect();
+    serde_json::to_string(&out).expect("serialize prize map")
+}
+
+#[derive(Tabled)]
+struct AuthorStatsTableRow {
+    #[tabled(rename = "Author")]
+    author: String,
+    #[tabled(rename = "Collections")]
+    collections: String,
+    #[tabled(rename = "Puzzles")]
+    total: usize,
+    #[tabled(rename = "Unsolved")]
+    unsolved: usize,
+    #[tabled(rename = "Funded")]
+    funded: String,
+    #[tabled(rename = "Claimed back")]
+    claimed_back: String,
+    #[tabled(rename = "Outstanding")]
+    outstanding: String,
+}
+
+#[derive(Serialize)]
+struct AuthorStatsCsvRow {
+    name: Option<&'static str>,
+    collections: String,
+    total: usize,
+    solved: usize,
+    unsolved: usize,
+    claimed: usize,
+    swept: usize,
+    expired: usize,
+    funded_by_chain: String,
+    claimed_back_by_chain: String,
+    outstanding_by_chain: String,
+}
+
+/// `"1.00 BTC, 5.00 ETH"`, skipping currencies with nothing in them.
+pub(super) fn format_prizes(map: &HashMap<String, f64>) -> String {
+    let mut prizes: Vec<_> = map.iter().filter(|(_, amount)| **amount > 0.0).collect();
+    if prizes.is_empty() {
+        return "-".to_string();
+    }
+    prizes.sort_by_key(|(symbol, _)| symbol.as_str());
+    prizes
+        .iter()
+        .map(|(symbol, amount)| format!("{:.2} {}", amount, symbol))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn run(ctx: &Context, args: &StatsArgs) {
+    if args.by_author {
+        output_author_stats(ctx, &boha::author_stats());
+        return;
+    }
+
+    let stats = boha::stats();
+
+    #[cfg(feature = "price")]
+    if let Some(currency) = &args.currency {
+        let symbols: Vec<&str> = stats.total_prize.keys().map(String::as_str).collect();
+        let fiat = FiatStats::new(&stats, &crate::util::prices_or_exit(&symbols, currency));
+        output_stats_with_fiat(ctx, &stats, fiat);
+        return;
+    }
+
+    #[cfg(feature = "snapshot")]
+    if args.with_balances {
+        let balances = SnapshotStats::new(
+            crate::snapshot::taken_at_or_exit(),
+            boha::all(),
+            boha::snapshot::balance,
+        );
+        output_stats_with_balances(ctx, &stats, balances);
+        return;
+    }
+
+    output_stats(ctx, &stats, args.compat);
+}
+
+#[cfg(feature = "snapshot")]
+fn output_stats_with_balances(ctx: &Context, stats: &Stats, balances: SnapshotStats) {
+    if matches!(ctx.output, OutputFormat::Csv) {
+        let row = StatsCsvRow {
+            balances_taken_at: Some(balances.taken_at),
+            unsolved_balance_by_chain: Some(
+                serde_json::to_string(&balances.unsolved_balance).expect("serialize balances"),
+            ),
+            ..StatsCsvRow::from_stats(stats)
+        };
+        print_record(ctx, &row, |_, _| {});
+        return;
+    }
+
+    print_record(ctx, &StatsWithBalances { stats, balances }, |ctx, s| {
+        let mut rows = stats_table_rows(s.stats);
+        for (symbol, amount) in &s.balances.unsolved_balance {
+            rows.push(KeyValueRow {
+                field: format!("Unsolved balance {}", symbol),
+                value: format!("{:.2}", amount).bright_yellow().to_string(),
+            });
+        }
+        rows.push(KeyValueRow {
+            field: "Balances as of".to_string(),
+            value: crate::snapshot::label(s.balances.taken_at)
+                .yellow()
+                .to_string(),
+        });
+        if s.balances.missing > 0 {
+            rows.push(KeyValueRow {
+                field: "Not in snapshot".to_string(),
+                value: format!("{} unsolved", s.balances.missing)
+                    .dimmed()
+                    .to_string(),
+            });
+        }
+        let table = Table::new(rows).with(Style::rounded()).to_string();
+        outln!(ctx, "{}", table);
+    });
+}
+
+#[cfg(feature = "price")]
+fn output_stats_with_fiat(ctx: &Context, stats: &Stats, fiat: FiatStats) {
+    if matches!(ctx.output, OutputFormat::Csv) {
+        let row = StatsCsvRow {
+            fiat_currency: Some(fiat.currency.clone()),
+            total_prize_fiat: Some(fiat.total),
+            unsolved_prize_fiat: Some(fiat.unsolved),
+            ..StatsCsvRow::from_stats(stats)
+        };
+        print_record(ctx, &row, |_, _| {});
+        return;
+    }
+
+    print_record(ctx, &StatsWithFiat { stats, fiat }, |ctx, s| {
+        let mut rows = stats_table_rows(s.stats);
+        let code = s.fiat.currency.to_uppercase();
+        rows.push(KeyValueRow {
+            field: format!("Total value ({})", code),
+            value: format!("{:.2}", s.fiat.total),
+        });
+        rows.push(KeyValueRow {
+            field: format!("Unsolved value ({})", code),
+            value: format!("{:.2}", s.fiat.unsolved)
+                .bright_yellow()
+                .to_string(),
+        });
+        let table = Table::new(rows).with(Style::rounded()).to_string();
+        outln!(ctx, "{}", table);
+    });
+}
+
+pub fn output_stats(ctx: &Context, stats: &Stats, compat: Option<u32>) {
+    match (ctx.output, compat) {
+        (OutputFormat::Csv, _) => {
+            print_record(ctx, &StatsCsvRow::from_stats(stats), |_, _| {});
+        }
+        (_, Some(1)) => {
+            print_record(ctx, &StatsV1::from_stats(stats), |ctx, _| {
+                print_stats_table(ctx, stats);
+            });
+        }
+        _ => print_record(ctx, stats, print_stats_table),
+    }
+}
+
+fn print_stats_table(ctx: &Context, stats: &Stats) {
+    let table = Table::new(stats_table_rows(stats))
+        .with(Style::rounded())
+        .to_string();
+    outln!(ctx, "{}", table);
+}
+
+fn stats_table_rows(stats: &Stats) -> Vec<KeyValueRow> {
+    let mut rows = vec![
+        KeyValueRow {
+            field: "Total puzzles".to_string(),
+            value: stats.total.to_string().bright_white().to_string(),
+        },
+        KeyValueRow {
+            field: "Solved".to_string(),
+            value: stats.solved.to_string().green().to_string(),
+        },
+        KeyValueRow {
+            field: "Unsolved".to_string(),
+            value: stats.unsolved.to_string().yellow().to_string(),
+        },
+        KeyValueRow {
+            field: "Claimed".to_string(),
+            value: stats.claimed.to_string().cyan().to_string(),
+        },
+        KeyValueRow {
+            field: "Swept".to_string(),
+            value: stats.swept.to_string().red().to_string(),
+        },
+        KeyValueRow {
+            field: "With public key".to_string(),
+            value: stats.with_pubkey.to_string(),
+        },
+    ];
+
+    let mut total_prizes: Vec<_> = stats.total_prize.iter().collect();
+    total_prizes.sort_by_key(|(symbol, _)| symbol.as_str());
+    for (symbol, amount) in total_prizes {
+        rows.push(KeyValueRow {
+            field: format!("Total {}", symbol),
+            value: format!("{:.2}", amount),
+        });
+    }
+
+    let mut unsolved_prizes: Vec<_> = stats.unsolved_prize.iter().collect();
+    unsolved_prizes.sort_by_key(|(symbol, _)| symbol.as_str());
+    for (symbol, amount) in unsolved_prizes {
+        rows.push(KeyValueRow {
+            field: format!("Unsolved {}", symbol),
+            value: format!("{:.2}", amount).bright_yellow().to_string(),
+        });
+    }
+
+    rows
+}
+
+fn output_author_stats(ctx: &Context, authors: &[AuthorStats]) {
+    match ctx.output {
+        OutputFormat::Table => {
+            let rows: Vec<AuthorStatsTableRow> = authors
+                .iter()
+                .map(|a| AuthorStatsTableRow {
+                    author: a.name.unwrap_or("Anonymous").to_string(),
+                    collections: a.collections.join(", "),
+                    total: a.total,
+                    unsolved: a.unsolved,
+                    funded: format_prizes(&a.funded),
+                    claimed_back: format_prizes(&a.claimed_back),
+                    outstanding: format_prizes(&a.outstanding).bright_yellow().to_string(),
+                })
+                .collect();
+            let table = Table::new(rows).with(Style::rounded()).to_string();
+            outln!(ctx, "{}", table);
+        }
+        OutputFormat::Json => {
+            print_json(ctx, authors);
+        }
+        OutputFormat::Jsonl => {
+            for a in authors {
+                outln!(ctx, "{}", serde_json::to_string(a).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(authors).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for a in authors {
+                wtr.serialize(AuthorStatsCsvRow {
+                    name: a.name,
+                    collections: a.collections.join(";"),
+                    total: a.total,
+                    solved: a.solved,
+                    unsolved: a.unsolved,
+                    claimed: a.claimed,
+                    swept: a.swept,
+                    expired: a.expired,
+                    funded_by_chain: prize_map_json(&a.funded),
+                    claimed_back_by_chain: prize_map_json(&a.claimed_back),
+                    outstanding_by_chain: prize_map_json(&a.outstanding),
+                })
+                .unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+#[cfg(all(test, feature = "snapshot"))]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_stats_sum_unsolved_balances() {
+        let b71 = boha::get("b1000/71").unwrap().address.value;
+        let b66 = boha::get("b1000/66").unwrap().address.value;
+        let balance = |address: &str| match address {
+            a if a == b71 => Some(710_000_000),
+            a if a == b66 => Some(1),
+            _ => None,
+        };
+        let stats = SnapshotStats::new("2026-01-01 00:00:00", boha::b1000::all(), balance);
+        assert_eq!(stats.addresses, 1);
+        assert_eq!(stats.unsolved_balance["BTC"], 7.1);
+        assert_eq!(
+            stats.missing,
+            boha::b1000::all()
+                .filter(|p| p.status == boha::Status::Unsolved)
+                .count()
+                - 1
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Recent funding, key reveals, claims and sweeps across the dataset.
+
+use crate::color::Colorize;
+use boha::{Amount, DateTime, Event, EventKind, Puzzle};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use super::show::format_amount;
+use crate::context::Context;
+use crate::output::{outln, print_json, OutputFormat};
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct TimelineArgs {
+    #[arg(default_value = "all", add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: String,
+
+    /// Show at most this many events, newest first (0 for all)
+    #[arg(short = 'n', long, default_value = "20", value_name = "N")]
+    pub limit: usize,
+
+    /// Only events on or after this date (YYYY[-MM[-DD[ HH:MM:SS]]])
+    #[arg(long, value_name = "DATE", value_parser = DateTime::floor)]
+    pub since: Option<DateTime>,
+}
+
+#[derive(Serialize)]
+struct TimelineEntry {
+    puzzle: &'static str,
+    kind: EventKind,
+    date: DateTime,
+    amount: Option<f64>,
+    /// `amount` in base units (sats, wei, ...), exact.
+    native_amount: Option<Amount>,
+    currency: &'static str,
+    txid: Option<&'static str>,
+    explorer_url: Option<String>,
+}
+
+#[derive(Tabled)]
+struct TimelineRow {
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "Puzzle")]
+    puzzle: String,
+    #[tabled(rename = "Event")]
+    kind: String,
+    #[tabled(rename = "Amount")]
+    amount: String,
+}
+
+pub fn run(ctx: &Context, args: &TimelineArgs) {
+    let puzzles: Vec<&'static Puzzle> = if args.collection == "all" {
+        boha::all().collect()
+    } else {
+        collection_or_exit(&args.collection, true).all().collect()
+    };
+    let entries = recent(&puzzles, args.since, args.limit);
+
+    match ctx.output {
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                outln!(ctx, "No events.");
+            } else {
+                let rows: Vec<TimelineRow> = entries.iter().map(row).collect();
+                outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+            }
+        }
+        OutputFormat::Json => {
+            print_json(ctx, &entries);
+        }
+        OutputFormat::Jsonl => {
+            for entry in &entries {
+                outln!(ctx, "{}", serde_json::to_string(entry).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(&entries).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for entry in &entries {
+                wtr.serialize(entry).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+/// Dated events of `puzzles` from `since` on, newest first. Undated
+/// events can't be placed and are left out; so are superseded puzzles.
+fn recent(
+    puzzles: &[&'static Puzzle],
+    since: Option<DateTime>,
+    limit: usize,
+) -> Vec<TimelineEntry> {
+    let mut entries: Vec<TimelineEntry> = puzzles
+        .iter()
+        .filter(|p| !p.is_superseded())
+        .flat_map(|&puzzle| {
+            puzzle
+                .timeline()
+                .into_iter()
+                .filter_map(move |event| entry(puzzle, event))
+        })
+        .filter(|entry| since.is_none_or(|since| entry.date >= since))
+        .collect();
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.date));
+    if limit > 0 {
+        entries.truncate(limit);
+    }
+    entries
+}
+
+fn entry(puzzle: &'static Puzzle, event: Event) -> Option<TimelineEntry> {
+    Some(TimelineEntry {
+        puzzle: puzzle.id,
+        kind: event.kind,
+        date: event.date?,
+        amount: event.amount,
+        native_amount: event.native_amount,
+        currency: puzzle.currency(),
+        txid: event.txid,
+        explorer_url: event.txid.map(|txid| puzzle.chain.tx_explorer_url(txid)),
+    })
+}
+
+fn row(entry: &TimelineEntry) -> TimelineRow {
+    let kind = entry.kind.to_string();
+    TimelineRow {
+        date: entry.date.to_string(),
+        puzzle: entry.puzzle.to_string(),
+        kind: match entry.kind {
+            EventKind::Funded => kind.blue().to_string(),
+            EventKind::Increased => kind.green().to_string(),
+            EventKind::Decreased => kind.yellow().to_string(),
+            EventKind::PubkeyRevealed => kind.magenta().to_string(),
+            EventKind::Claimed => kind.cyan().to_string(),
+            EventKind::Swept => kind.red().to_string(),
+        },
+        amount: entry.native_amount.map_or_else(
+            || "-".to_string(),
+            |amount| format_amount(amount, entry.currency),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newest_first_and_limited() {
+        let puzzles: Vec<&'static Puzzle> = boha::b1000::all().collect();
+        let entries = recent(&puzzles, None, 5);
+        assert_eq!(entries.len(), 5);
+        assert!(entries.windows(2).all(|w| w[0].date >= w[1].date));
+    }
+
+    #[test]
+    fn since_drops_older_events() {
+        let puzzles = [boha::get("b1000/66").unwrap()];
+        let since = DateTime::floor("2024-09").unwrap();
+        let entries = recent(&puzzles, Some(since), 0);
+        let kinds: Vec<EventKind> = entries.iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, [EventKind::Claimed, EventKind::Increased]);
+    }
+}
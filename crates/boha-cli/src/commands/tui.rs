@@ -0,0 +1,394 @@
+//! Terminal browser: a searchable puzzle list beside the `show` details of
+//! the selected puzzle, with status filters on hotkeys.
+
+use std::io::{self, IsTerminal};
+
+use boha::{Puzzle, Status};
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use super::search::{search, Matcher};
+use super::show::{detail_rows, redact_secrets};
+use crate::output::error_exit;
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct TuiArgs {
+    /// Collection to browse (default: all)
+    #[arg(add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+
+    /// Show private key material in the detail pane (redacted otherwise)
+    #[arg(long)]
+    pub show_key: bool,
+}
+
+/// Status filter hotkeys, in the order the header lists them.
+const STATUS_KEYS: [(char, Status); 5] = [
+    ('u', Status::Unsolved),
+    ('s', Status::Solved),
+    ('c', Status::Claimed),
+    ('w', Status::Swept),
+    ('e', Status::Expired),
+];
+
+const HELP: &str = "↑↓/jk move  g/G first/last  / search  u s c w e toggle status  a all statuses  PgUp/PgDn scroll details  q quit";
+
+pub fn run(args: &TuiArgs) {
+    if !io::stdout().is_terminal() || !io::stdin().is_terminal() {
+        error_exit("boha tui needs an interactive terminal");
+    }
+    let puzzles: Vec<&'static Puzzle> = match args.collection.as_deref() {
+        Some("all") | None => boha::all().collect(),
+        Some(collection) => collection_or_exit(collection, true).all().collect(),
+    };
+    let puzzles = puzzles.into_iter().filter(|p| !p.is_superseded()).collect();
+    let mut app = App::new(puzzles, args.show_key);
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+    if let Err(e) = result {
+        error_exit(format!("Terminal error: {}", e));
+    }
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle(key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+struct App {
+    puzzles: Vec<&'static Puzzle>,
+    /// Statuses toggled on; none means every status.
+    statuses: Vec<Status>,
+    query: String,
+    searching: bool,
+    visible: Vec<&'static Puzzle>,
+    list: ListState,
+    detail_scroll: u16,
+    show_key: bool,
+}
+
+impl App {
+    fn new(puzzles: Vec<&'static Puzzle>, show_key: bool) -> Self {
+        let mut app = Self {
+            puzzles,
+            statuses: Vec::new(),
+            query: String::new(),
+            searching: false,
+            visible: Vec::new(),
+            list: ListState::default(),
+            detail_scroll: 0,
+            show_key,
+        };
+        app.refresh();
+        app
+    }
+
+    fn selected(&self) -> Option<&'static Puzzle> {
+        self.list
+            .selected()
+            .and_then(|i| self.visible.get(i).copied())
+    }
+
+    /// Recompute the visible puzzles, keeping the selection when it is
+    /// still listed. A query orders by search relevance.
+    fn refresh(&mut self) {
+        let previous = self.selected().map(|p| p.id);
+        let filtered: Vec<&'static Puzzle> = self
+            .puzzles
+            .iter()
+            .copied()
+            .filter(|p| self.statuses.is_empty() || self.statuses.contains(&p.status))
+            .collect();
+        self.visible = if self.query.trim().is_empty() {
+            filtered
+        } else {
+            search(&filtered, &Matcher::text(&self.query, false, false), &[])
+                .into_iter()
+                .map(|r| r.puzzle)
+                .collect()
+        };
+        let index = previous
+            .and_then(|id| self.visible.iter().position(|p| p.id == id))
+            .or_else(|| (!self.visible.is_empty()).then_some(0));
+        if index != self.list.selected() {
+            self.detail_scroll = 0;
+        }
+        self.list.select(index);
+    }
+
+    fn select(&mut self, index: usize) {
+        if !self.visible.is_empty() {
+            self.list.select(Some(index.min(self.visible.len() - 1)));
+            self.detail_scroll = 0;
+        }
+    }
+
+    /// Apply a key press; `false` quits.
+    fn handle(&mut self, key: KeyEvent) -> bool {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return false;
+        }
+        if self.searching {
+            match key.code {
+                KeyCode::Enter => self.searching = false,
+                KeyCode::Esc => {
+                    self.searching = false;
+                    self.query.clear();
+                }
+                KeyCode::Backspace => {
+                    self.query.pop();
+                }
+                KeyCode::Char(c) => self.query.push(c),
+                _ => return true,
+            }
+            self.refresh();
+            return true;
+        }
+
+        let current = self.list.selected().unwrap_or(0);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('/') => self.searching = true,
+            KeyCode::Down | KeyCode::Char('j') => self.select(current + 1),
+            KeyCode::Up | KeyCode::Char('k') => self.select(current.saturating_sub(1)),
+            KeyCode::Home | KeyCode::Char('g') => self.select(0),
+            KeyCode::End | KeyCode::Char('G') => self.select(usize::MAX),
+            KeyCode::PageDown => self.detail_scroll = self.detail_scroll.saturating_add(10),
+            KeyCode::PageUp => self.detail_scroll = self.detail_scroll.saturating_sub(10),
+            KeyCode::Char('a') => {
+                self.statuses.clear();
+                self.refresh();
+            }
+            KeyCode::Char(c) => {
+                if let Some(&(_, status)) = STATUS_KEYS.iter().find(|(k, _)| *k == c) {
+                    if let Some(i) = self.statuses.iter().position(|s| *s == status) {
+                        self.statuses.remove(i);
+                    } else {
+                        self.statuses.push(status);
+                    }
+                    self.refresh();
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [list_area, detail_area] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(body);
+
+        frame.render_widget(Paragraph::new(self.header()), header);
+
+        let items: Vec<ListItem> = self
+            .visible
+            .iter()
+            .map(|p| {
+                let prize = p
+                    .prize
+                    .map(|v| format!("{} {}", v, p.currency()))
+                    .unwrap_or_default();
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{:<24} ", p.id)),
+                    Span::styled(format!("{:<9} ", p.status), status_style(p.status)),
+                    Span::raw(prize),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(format!(" Puzzles ({}) ", self.visible.len())))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("▸ ");
+        frame.render_stateful_widget(list, list_area, &mut self.list);
+
+        let detail = Paragraph::new(self.detail_lines())
+            .block(Block::bordered().title(" Details "))
+            .wrap(Wrap { trim: false })
+            .scroll((self.detail_scroll, 0));
+        frame.render_widget(detail, detail_area);
+
+        frame.render_widget(
+            Paragraph::new(HELP).style(Style::new().fg(Color::DarkGray)),
+            footer,
+        );
+    }
+
+    fn header(&self) -> Line<'static> {
+        let mut spans = vec![Span::styled(
+            "Search: ",
+            Style::new().add_modifier(Modifier::BOLD),
+        )];
+        spans.push(Span::raw(self.query.clone()));
+        if self.searching {
+            spans.push(Span::styled(
+                "█",
+                Style::new().add_modifier(Modifier::SLOW_BLINK),
+            ));
+        }
+        spans.push(Span::raw("   "));
+        for (key, status) in STATUS_KEYS {
+            let style = if self.statuses.contains(&status) {
+                status_style(status).add_modifier(Modifier::REVERSED)
+            } else {
+                Style::new().fg(Color::DarkGray)
+            };
+            spans.push(Span::styled(format!("[{}] {}", key, status), style));
+            spans.push(Span::raw(" "));
+        }
+        Line::from(spans)
+    }
+
+    /// The `show` rows of the selected puzzle, with terminal colours
+    /// replaced by styles.
+    fn detail_lines(&self) -> Vec<Line<'static>> {
+        let Some(puzzle) = self.selected() else {
+            return vec![Line::raw("No puzzles match")];
+        };
+        let shown = if self.show_key {
+            puzzle.clone()
+        } else {
+            redact_secrets(puzzle)
+        };
+        let rows: Vec<(String, String)> = detail_rows(&shown, true, Vec::new())
+            .into_iter()
+            .map(|r| (strip_ansi(&r.field), strip_ansi(&r.value)))
+            .collect();
+        let width = rows
+            .iter()
+            .map(|(field, _)| field.chars().count())
+            .max()
+            .unwrap_or(0);
+        rows.into_iter()
+            .map(|(field, value)| {
+                if field.starts_with('▸') {
+                    Line::styled(
+                        field,
+                        Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    let style = if field == "Status" {
+                        status_style(puzzle.status)
+                    } else {
+                        Style::new()
+                    };
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:<width$}  ", field, width = width),
+                            Style::new().fg(Color::DarkGray),
+                        ),
+                        Span::styled(value, style),
+                    ])
+                }
+            })
+            .collect()
+    }
+}
+
+/// Same colours as the `show` table.
+fn status_style(status: Status) -> Style {
+    let color = match status {
+        Status::Solved => Color::Green,
+        Status::Unsolved => Color::Yellow,
+        Status::Claimed => Color::Cyan,
+        Status::Swept => Color::Red,
+        Status::Expired => Color::DarkGray,
+    };
+    Style::new().fg(color)
+}
+
+/// `text` without ANSI SGR sequences (`ESC [ ... m`).
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn press(app: &mut App, code: KeyCode) -> bool {
+        app.handle(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn status_toggles_and_search_narrow_the_list() {
+        let mut app = App::new(boha::b1000::all().collect(), false);
+        let total = app.visible.len();
+
+        press(&mut app, KeyCode::Char('u'));
+        assert!(app.visible.iter().all(|p| p.status == Status::Unsolved));
+        press(&mut app, KeyCode::Char('s'));
+        assert!(app
+            .visible
+            .iter()
+            .all(|p| matches!(p.status, Status::Unsolved | Status::Solved)));
+        press(&mut app, KeyCode::Char('a'));
+        assert_eq!(app.visible.len(), total);
+
+        press(&mut app, KeyCode::Char('/'));
+        for c in "71".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.selected().unwrap().id, "b1000/71");
+        assert!(!press(&mut app, KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn detail_pane_shows_the_selected_puzzle() {
+        let mut app = App::new(vec![boha::get("b1000/66").unwrap()], false);
+        let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(screen.contains("13zb1hQbWVsc2S7ZTZnP2G4undNNpdh5so"));
+        assert!(screen.contains("<redacted>"));
+        assert!(!screen.contains('\x1b'));
+    }
+
+    #[test]
+    fn ansi_sequences_are_stripped() {
+        assert_eq!(strip_ansi("\x1b[32mSolved\x1b[39m"), "Solved");
+    }
+}
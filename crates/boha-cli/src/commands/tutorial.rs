@@ -0,0 +1,198 @@
+//! Guided walkthrough of show/range/verify on the fake `example` collection.
+
+use std::io::{self, BufRead, Write};
+
+use crate::color::Colorize;
+use boha::{Key, KeyRange, PubkeyFormat, Puzzle};
+use num_bigint::BigUint;
+use tabled::{settings::Style, Table};
+
+use crate::commands::show::print_puzzle_detail_table;
+use crate::context::Context;
+use crate::output::{CliError, KeyValueRow};
+
+/// Wrong answers allowed per question before the answer is revealed.
+const MAX_ATTEMPTS: usize = 5;
+
+/// Puzzle used throughout: unsolved in the data, but its key (0x7) is known.
+const TARGET: u32 = 3;
+
+pub fn run(ctx: &Context) {
+    let puzzle = boha::example::get(TARGET).unwrap_or_else(|e| CliError::from(e).exit());
+    let mut input = io::stdin().lock();
+
+    intro();
+    step_show(ctx, &mut input, puzzle);
+    let range = step_range(&mut input, puzzle);
+    step_verify(&mut input, puzzle, &range);
+    drop(input);
+    outro();
+}
+
+fn intro() {
+    println!("{}", "Welcome to the boha tutorial!".bold());
+    println!(
+        "We'll use the {} collection: fake Bitcoin testnet puzzles with known keys,",
+        "example".cyan()
+    );
+    println!("so nothing here touches a real bounty. Press Ctrl-D at any prompt to quit.\n");
+}
+
+fn step_show(ctx: &Context, input: &mut impl BufRead, puzzle: &Puzzle) {
+    heading(1, "Inspect a puzzle");
+    command(&format!("boha show {}", puzzle.id));
+    // Hide the key: finding it is step 3.
+    let unsolved = Puzzle {
+        key: puzzle.key.map(|k| Key {
+            hex: None,
+            wif: None,
+            ..k
+        }),
+        ..puzzle.clone()
+    };
+    print_puzzle_detail_table(ctx, &unsolved, false, Vec::new());
+    println!();
+
+    ask(
+        input,
+        "Which address does the puzzle's key unlock? Paste it:",
+        puzzle.address.value,
+        |answer| answer == puzzle.address.value,
+    );
+}
+
+fn step_range(input: &mut impl BufRead, puzzle: &Puzzle) -> KeyRange {
+    heading(2, "Narrow the search");
+    let bits = puzzle.key.and_then(|k| k.bits).unwrap_or_default();
+    let range = puzzle
+        .key_range_big()
+        .expect("example puzzles always have bits");
+
+    println!(
+        "The key has {} bits, so it lies in [2^{}, 2^{} - 1].",
+        bits.to_string().cyan(),
+        bits - 1,
+        bits
+    );
+    println!("For b1000 puzzles the same table comes from `boha range <number>`.");
+    let rows = vec![
+        KeyValueRow {
+            field: "Start".to_string(),
+            value: format!("0x{:x}", range.start()),
+        },
+        KeyValueRow {
+            field: "End".to_string(),
+            value: format!("0x{:x}", range.end()),
+        },
+    ];
+    println!("{}\n", Table::new(rows).with(Style::rounded()));
+
+    let count = range.size();
+    ask(
+        input,
+        "How many candidate keys are in this range?",
+        &count.to_string(),
+        |answer| parse_number(answer).as_ref() == Some(&count),
+    );
+
+    range
+}
+
+fn step_verify(input: &mut impl BufRead, puzzle: &Puzzle, range: &KeyRange) {
+    heading(3, "Find and verify the key");
+    println!("Try candidates from the range (decimal or 0x-hex); each guess is checked");
+    println!("by deriving its address, exactly like `boha verify` does.\n");
+
+    let answer = puzzle.key.and_then(|k| k.hex).unwrap_or_default();
+    let address = puzzle.address.value;
+
+    ask(input, "Enter a private key:", answer, |guess| {
+        let Some(key) = parse_number(guess) else {
+            println!("  {} not a number", "✗".red());
+            return false;
+        };
+        if !range.contains_key(&key) {
+            println!("  {} {} is outside the range", "✗".red(), guess);
+            return false;
+        }
+        match boha::verify::verify_bitcoin_address(
+            &format!("{:064x}", key),
+            address,
+            PubkeyFormat::Compressed,
+        ) {
+            Ok(_) => true,
+            Err(boha::verify::VerifyError::Mismatch { derived, .. }) => {
+                println!("  {} derives {}, not the target", "✗".red(), derived);
+                false
+            }
+            Err(e) => {
+                println!("  {} {}", "✗".red(), e);
+                false
+            }
+        }
+    });
+
+    command(&format!("boha verify {}", puzzle.id));
+    println!(
+        "{} Private key verified for {}",
+        "✓".green().bold(),
+        puzzle.id.cyan()
+    );
+    println!("  Address: {}\n", address);
+}
+
+fn outro() {
+    println!(
+        "{}",
+        "That's the whole loop: inspect, narrow, verify.".bold()
+    );
+    println!("Real puzzles work the same way, just with far larger ranges:");
+    command("boha list b1000 --unsolved");
+    command("boha show b1000/71");
+    command("boha verify --all");
+}
+
+fn heading(step: u8, title: &str) {
+    println!(
+        "{} {}",
+        format!("Step {}/3:", step).bold().cyan(),
+        title.bold()
+    );
+}
+
+fn command(line: &str) {
+    println!("  {} {}", "$".bright_black(), line.green());
+}
+
+/// Prompt until `check` accepts an answer, revealing `answer` after
+/// [`MAX_ATTEMPTS`] misses. Exits on end of input.
+fn ask(input: &mut impl BufRead, question: &str, answer: &str, check: impl Fn(&str) -> bool) {
+    for attempt in 1..=MAX_ATTEMPTS {
+        print!("{} {} ", "?".yellow().bold(), question);
+        io::stdout().flush().ok();
+
+        let mut line = String::new();
+        if input.read_line(&mut line).unwrap_or(0) == 0 {
+            println!();
+            eprintln!("Tutorial aborted.");
+            std::process::exit(1);
+        }
+
+        if check(line.trim()) {
+            println!("  {} Correct!\n", "✓".green().bold());
+            return;
+        }
+        if attempt < MAX_ATTEMPTS {
+            println!("  Not quite, try again.");
+        }
+    }
+    println!("  The answer was {}. Moving on.\n", answer.cyan());
+}
+
+fn parse_number(s: &str) -> Option<BigUint> {
+    let s = s.trim();
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+        None => BigUint::parse_bytes(s.as_bytes(), 10),
+    }
+}
@@ -0,0 +1,181 @@
+//! Dataset integrity rules over the embedded puzzles, the same checks the
+//! test suite runs.
+
+use crate::color::Colorize;
+use boha::validate::{self, RuleReport};
+use boha::Puzzle;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::output::{outln, print_json, ErrorCode, OutputFormat};
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct ValidateArgs {
+    /// Collection to check (default: all)
+    #[arg(add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    id: &'static str,
+    problem: String,
+}
+
+#[derive(Serialize)]
+struct RuleResult {
+    rule: &'static str,
+    description: &'static str,
+    checked: usize,
+    passed: bool,
+    failures: Vec<Failure>,
+}
+
+impl From<RuleReport> for RuleResult {
+    fn from(report: RuleReport) -> Self {
+        Self {
+            rule: report.rule.name(),
+            description: report.rule.description(),
+            checked: report.checked,
+            passed: report.passed(),
+            failures: report
+                .failures
+                .into_iter()
+                .map(|(id, e)| Failure {
+                    id,
+                    problem: e.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ValidateReport {
+    puzzles: usize,
+    passed: bool,
+    rules: Vec<RuleResult>,
+}
+
+#[derive(Tabled)]
+struct RuleTableRow {
+    #[tabled(rename = "Rule")]
+    rule: &'static str,
+    #[tabled(rename = "Checks")]
+    description: &'static str,
+    #[tabled(rename = "Checked")]
+    checked: usize,
+    #[tabled(rename = "Failed")]
+    failed: usize,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+#[derive(Tabled)]
+struct FailureTableRow {
+    #[tabled(rename = "Puzzle")]
+    id: &'static str,
+    #[tabled(rename = "Rule")]
+    rule: &'static str,
+    #[tabled(rename = "Problem")]
+    problem: String,
+}
+
+pub fn run(ctx: &Context, args: &ValidateArgs) {
+    let puzzles: Vec<&Puzzle> = match args.collection.as_deref() {
+        None | Some("all") => boha::all().collect(),
+        Some(name) => collection_or_exit(name, true).all().collect(),
+    };
+    let rules: Vec<RuleResult> = validate::validate(puzzles.iter().copied())
+        .into_iter()
+        .map(RuleResult::from)
+        .collect();
+    let report = ValidateReport {
+        puzzles: puzzles.len(),
+        passed: rules.iter().all(|r| r.passed),
+        rules,
+    };
+
+    match ctx.output {
+        OutputFormat::Table => output_table(ctx, &report),
+        OutputFormat::Json => print_json(ctx, &report),
+        OutputFormat::Jsonl => {
+            for rule in &report.rules {
+                outln!(ctx, "{}", serde_json::to_string(rule).unwrap());
+            }
+        }
+        OutputFormat::Yaml => outln!(ctx, "{}", serde_yaml::to_string(&report).unwrap()),
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            wtr.write_record(["rule", "checked", "failed", "passed"])
+                .unwrap();
+            for rule in &report.rules {
+                wtr.write_record([
+                    rule.rule.to_string(),
+                    rule.checked.to_string(),
+                    rule.failures.len().to_string(),
+                    rule.passed.to_string(),
+                ])
+                .unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+
+    if !report.passed {
+        crate::output::exit(ErrorCode::CheckFailed.exit_code());
+    }
+}
+
+fn output_table(ctx: &Context, report: &ValidateReport) {
+    let rows: Vec<RuleTableRow> = report
+        .rules
+        .iter()
+        .map(|r| RuleTableRow {
+            rule: r.rule,
+            description: r.description,
+            checked: r.checked,
+            failed: r.failures.len(),
+            result: if r.passed {
+                "pass".green().to_string()
+            } else {
+                "FAIL".red().bold().to_string()
+            },
+        })
+        .collect();
+    outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+
+    let failures: Vec<FailureTableRow> = report
+        .rules
+        .iter()
+        .flat_map(|r| {
+            r.failures.iter().map(|f| FailureTableRow {
+                id: f.id,
+                rule: r.rule,
+                problem: f.problem.clone(),
+            })
+        })
+        .collect();
+    if failures.is_empty() {
+        outln!(
+            ctx,
+            "{} {} puzzles pass every rule",
+            "✓".green().bold(),
+            report.puzzles
+        );
+    } else {
+        outln!(ctx, "{}", Table::new(&failures).with(Style::rounded()));
+        outln!(
+            ctx,
+            "{} {} failures in {} puzzles",
+            "✗".red().bold(),
+            failures.len(),
+            report.puzzles
+        );
+    }
+}
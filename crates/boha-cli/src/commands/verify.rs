@@ -0,0 +1,421 @@
+use std::path::PathBuf;
+
+use crate::color::Colorize;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::context::Context;
+use crate::output::{outln, print_json, print_record, CliError, ErrorCode, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Puzzle ID (e.g., b1000/66, gsmg, bitaps). Omit when using --all
+    #[arg(add = ArgValueCandidates::new(super::completions::puzzle_ids))]
+    pub id: Option<String>,
+
+    /// Verify all puzzles with private keys or redeem scripts
+    #[arg(long)]
+    pub all: bool,
+
+    /// Quiet mode - no output, exit code only
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Check stored public keys against addresses (no private key needed)
+    #[arg(long)]
+    pub pubkey_only: bool,
+
+    /// Check a hash-collision claim: two files whose contents collide
+    #[arg(
+        long,
+        num_args = 2,
+        value_names = ["A", "B"],
+        requires = "id",
+        conflicts_with_all = ["all", "pubkey_only"]
+    )]
+    pub preimages: Option<Vec<PathBuf>>,
+}
+
+#[derive(Serialize, Tabled)]
+struct VerifyOutput {
+    id: String,
+    verified: bool,
+    #[tabled(skip)]
+    private_key: Option<String>,
+    expected_address: String,
+    #[tabled(skip)]
+    derived_address: Option<String>,
+    #[tabled(skip)]
+    error: Option<String>,
+}
+
+type Verifier = fn(&boha::Puzzle) -> Result<boha::verify::VerifyResult, boha::verify::VerifyError>;
+
+pub fn run(ctx: &Context, args: &VerifyArgs) {
+    let verifier: Verifier = if args.pubkey_only {
+        boha::verify::verify_puzzle_pubkey
+    } else {
+        boha::verify::verify_puzzle
+    };
+
+    if let (Some(id), Some(preimages)) = (&args.id, &args.preimages) {
+        verify_collision(ctx, id, preimages, args.quiet);
+    } else if args.all {
+        verify_all(ctx, verifier, args.pubkey_only, args.quiet);
+    } else if let Some(id) = &args.id {
+        verify_single(ctx, id, verifier, args.pubkey_only, args.quiet);
+    } else {
+        crate::output::fail(
+            ErrorCode::Usage,
+            "Either provide a puzzle ID or use --all flag",
+        );
+    }
+}
+
+/// Exit with `code`, reporting `error` unless `--quiet`.
+fn exit(quiet: bool, error: CliError) -> ! {
+    if quiet {
+        crate::output::exit(error.code.exit_code());
+    }
+    error.exit()
+}
+
+fn verify_single(ctx: &Context, id: &str, verifier: Verifier, pubkey_only: bool, quiet: bool) {
+    use boha::verify;
+
+    let puzzle = boha::get(id).unwrap_or_else(|e| exit(quiet, e.into()));
+
+    let private_key = if pubkey_only {
+        None
+    } else {
+        puzzle.key.as_ref().and_then(|k| k.hex.map(str::to_string))
+    };
+
+    let output = match verifier(puzzle) {
+        Ok(result) => VerifyOutput {
+            id: result.id,
+            verified: true,
+            private_key: result.private_key,
+            expected_address: result.expected_address,
+            derived_address: result.derived_address,
+            error: None,
+        },
+        Err(verify::VerifyError::NoPrivateKey) => exit(
+            quiet,
+            CliError::new(
+                ErrorCode::NoKey,
+                format!("Puzzle '{}' has no private key", id),
+            )
+            .id(id),
+        ),
+        Err(verify::VerifyError::NoPublicKey) => exit(
+            quiet,
+            CliError::new(
+                ErrorCode::NoKey,
+                format!("Puzzle '{}' has no public key", id),
+            )
+            .id(id),
+        ),
+        Err(verify::VerifyError::InvalidKey(ref msg)) => {
+            exit(quiet, CliError::new(ErrorCode::NoKey, msg).id(id))
+        }
+        Err(
+            verify::VerifyError::UnverifiableKey(ref msg)
+            | verify::VerifyError::UnsupportedChain(ref msg),
+        ) => {
+            let output = VerifyOutput {
+                id: id.to_string(),
+                verified: false,
+                private_key,
+                expected_address: puzzle.address.value.to_string(),
+                derived_address: None,
+                error: Some(msg.clone()),
+            };
+            if !quiet {
+                output_verify(ctx, &output, pubkey_only);
+            }
+            crate::output::exit(ErrorCode::NoKey.exit_code());
+        }
+        Err(e) => {
+            let output = VerifyOutput {
+                id: id.to_string(),
+                verified: false,
+                private_key,
+                expected_address: puzzle.address.value.to_string(),
+                derived_address: None,
+                error: Some(e.to_string()),
+            };
+            if !quiet {
+                output_verify(ctx, &output, pubkey_only);
+            }
+            crate::output::exit(ErrorCode::CheckFailed.exit_code());
+        }
+    };
+
+    if !quiet {
+        output_verify(ctx, &output, pubkey_only);
+    }
+}
+
+#[derive(Serialize)]
+struct CollisionOutput {
+    id: String,
+    verified: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+fn verify_collision(ctx: &Context, id: &str, preimages: &[PathBuf], quiet: bool) {
+    use boha::verify::{self, VerifyError};
+
+    let puzzle = boha::get(id).unwrap_or_else(|e| exit(quiet, e.into()));
+
+    let contents: Vec<Vec<u8>> = preimages
+        .iter()
+        .map(|path| {
+            std::fs::read(path).unwrap_or_else(|e| {
+                exit(
+                    quiet,
+                    CliError::new(
+                        ErrorCode::Error,
+                        format!("Cannot read {}: {}", path.display(), e),
+                    ),
+                )
+            })
+        })
+        .collect();
+
+    let (output, code) = match verify::verify_collision(puzzle, &contents[0], &contents[1]) {
+        Ok(digest) => (
+            CollisionOutput {
+                id: puzzle.id.to_string(),
+                verified: true,
+                digest: Some(digest),
+                error: None,
+            },
+            None,
+        ),
+        Err(e) => {
+            let code = match e {
+                VerifyError::UnverifiableKey(_) | VerifyError::InvalidKey(_) => ErrorCode::NoKey,
+                _ => ErrorCode::CheckFailed,
+            };
+            (
+                CollisionOutput {
+                    id: puzzle.id.to_string(),
+                    verified: false,
+                    digest: None,
+                    error: Some(e.to_string()),
+                },
+                Some(code),
+            )
+        }
+    };
+
+    if !quiet {
+        print_record(ctx, &output, |ctx, output| {
+            if output.verified {
+                outln!(
+                    ctx,
+                    "{} Collision verified for {}",
+                    "✓".green().bold(),
+                    output.id.cyan()
+                );
+                outln!(
+                    ctx,
+                    "  Digest: {}",
+                    output.digest.as_deref().unwrap_or_default()
+                );
+            } else {
+                outln!(
+                    ctx,
+                    "{} Verification failed for {}",
+                    "✗".red().bold(),
+                    output.id.cyan()
+                );
+                if let Some(ref error) = output.error {
+                    outln!(ctx, "  Error: {}", error.red());
+                }
+            }
+        });
+    }
+    if let Some(code) = code {
+        crate::output::exit(code.exit_code());
+    }
+}
+
+fn output_verify(ctx: &Context, result: &VerifyOutput, pubkey_only: bool) {
+    let subject = if pubkey_only {
+        "Public key"
+    } else if result.verified && result.private_key.is_none() {
+        "Redeem script"
+    } else {
+        "Private key"
+    };
+    print_record(ctx, result, |ctx, result| {
+        if result.verified {
+            outln!(
+                ctx,
+                "{} {} verified for {}",
+                "✓".green().bold(),
+                subject,
+                result.id.cyan()
+            );
+            outln!(ctx, "  Address: {}", result.expected_address);
+        } else {
+            outln!(
+                ctx,
+                "{} Verification failed for {}",
+                "✗".red().bold(),
+                result.id.cyan()
+            );
+            outln!(ctx, "  Expected: {}", result.expected_address);
+            if let Some(ref derived) = result.derived_address {
+                outln!(ctx, "  Derived:  {}", derived);
+            }
+            if let Some(ref error) = result.error {
+                outln!(ctx, "  Error: {}", error.red());
+            }
+        }
+    });
+}
+
+#[derive(Serialize)]
+struct VerifySummary<'a> {
+    total: usize,
+    verified: usize,
+    failed: usize,
+    skipped: usize,
+    results: &'a [VerifyOutput],
+}
+
+fn verify_all(ctx: &Context, verifier: Verifier, pubkey_only: bool, quiet: bool) {
+    use boha::verify;
+
+    let mut results = Vec::new();
+    let mut verified_count = 0;
+    let mut failed_count = 0;
+    let mut skipped_count = 0;
+
+    let puzzles: Vec<&boha::Puzzle> = boha::all().collect();
+    let outcomes = verify::verify_puzzles(&puzzles, verifier);
+
+    for (puzzle, outcome) in puzzles.iter().zip(outcomes) {
+        match outcome {
+            Ok(result) => {
+                verified_count += 1;
+                results.push(VerifyOutput {
+                    id: result.id,
+                    verified: true,
+                    private_key: result.private_key,
+                    expected_address: result.expected_address,
+                    derived_address: result.derived_address,
+                    error: None,
+                });
+            }
+            Err(e) if e.is_skipped() => {
+                skipped_count += 1;
+            }
+            Err(e) => {
+                failed_count += 1;
+                results.push(VerifyOutput {
+                    id: puzzle.id.to_string(),
+                    verified: false,
+                    private_key: if pubkey_only {
+                        None
+                    } else {
+                        puzzle.key.as_ref().and_then(|k| k.hex.map(str::to_string))
+                    },
+                    expected_address: puzzle.address.value.to_string(),
+                    derived_address: None,
+                    error: Some(e.to_string()),
+                });
+            }
+        }
+    }
+
+    let summary = VerifySummary {
+        total: puzzles.len(),
+        verified: verified_count,
+        failed: failed_count,
+        skipped: skipped_count,
+        results: &results,
+    };
+
+    if !quiet {
+        match ctx.output {
+            OutputFormat::Table => {
+                outln!(
+                    ctx,
+                    "\n{} Verification Summary",
+                    "━".repeat(50).bright_black()
+                );
+                outln!(
+                    ctx,
+                    "  {} {} verified",
+                    "✓".green().bold(),
+                    verified_count.to_string().green()
+                );
+                if failed_count > 0 {
+                    outln!(
+                        ctx,
+                        "  {} {} failed",
+                        "✗".red().bold(),
+                        failed_count.to_string().red()
+                    );
+                }
+                if skipped_count > 0 {
+                    outln!(
+                        ctx,
+                        "  {} {} skipped (no key)",
+                        "○".yellow(),
+                        skipped_count.to_string().yellow()
+                    );
+                }
+                outln!(ctx, "{}\n", "━".repeat(50).bright_black());
+
+                if failed_count > 0 {
+                    outln!(ctx, "{}", "Failed verifications:".red().bold());
+                    for result in &results {
+                        if !result.verified {
+                            outln!(
+                                ctx,
+                                "  {} {} - {}",
+                                "✗".red().bold(),
+                                result.id.cyan(),
+                                result.error.as_deref().unwrap_or("Unknown error")
+                            );
+                        }
+                    }
+                }
+            }
+            OutputFormat::Json => {
+                print_json(ctx, &summary);
+            }
+            OutputFormat::Jsonl => {
+                for result in &results {
+                    outln!(ctx, "{}", serde_json::to_string(result).unwrap());
+                }
+            }
+            OutputFormat::Yaml => {
+                outln!(ctx, "{}", serde_yaml::to_string(&summary).unwrap());
+            }
+            OutputFormat::Csv => {
+                let mut wtr = csv::Writer::from_writer(&ctx.out);
+                for result in &results {
+                    wtr.serialize(result).unwrap();
+                }
+                wtr.flush().unwrap();
+            }
+            _ => unreachable!("dataset-only formats are rejected in main"),
+        }
+    }
+
+    if failed_count > 0 {
+        crate::output::exit(ErrorCode::CheckFailed.exit_code());
+    }
+}
@@ -0,0 +1,693 @@
+//! Poll unsolved puzzle addresses and report balance changes as they happen.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use crate::color::Colorize;
+use boha::{Chain, Puzzle, Status};
+use boha_chain::balance::{Balance, BalanceError};
+use chrono::Utc;
+use clap::Args;
+use clap_complete::ArgValueCandidates;
+use serde::Serialize;
+
+use super::balance::{fetch_many, round_to_chain, tip_height, FetchArgs};
+use crate::context::Context;
+use crate::notify::Notifier;
+use crate::output::{error_exit, outln, CliError, OutputFormat};
+use crate::util::collection_or_exit;
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// Only puzzles from this collection (default: all)
+    #[arg(add = ArgValueCandidates::new(super::completions::collection_names))]
+    pub collection: Option<String>,
+
+    /// Only puzzles on this chain
+    #[arg(long)]
+    pub chain: Option<Chain>,
+
+    /// Watch these puzzles (any status) when no collection is given
+    #[arg(
+        long = "id",
+        value_name = "ID",
+        env = "BOHA_WATCHLIST",
+        value_delimiter = ',',
+        add = ArgValueCandidates::new(super::completions::puzzle_ids)
+    )]
+    pub ids: Vec<String>,
+
+    /// Seconds between polls
+    #[arg(long, default_value = "60", value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+
+    /// Stop after this many polls (default: run until interrupted)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub polls: Option<u64>,
+
+    /// Re-check mined changes until they are this many blocks deep and report
+    /// `event_reverted` if a reorg drops them (0 to disable)
+    #[arg(long, default_value = "6", value_name = "BLOCKS")]
+    pub reorg_depth: u64,
+
+    /// Hold mined funding and spend events until this many blocks deep,
+    /// reporting them as `pending` in the meantime
+    #[arg(long, default_value = "1", value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub confirmations: u64,
+
+    /// POST funding and spend events as JSON to URL (repeatable)
+    #[arg(
+        long,
+        value_name = "URL",
+        env = "BOHA_NOTIFY_URL",
+        value_delimiter = ','
+    )]
+    pub notify_url: Vec<String>,
+
+    /// Telegram bot token for funding and spend alerts
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        env = "BOHA_TELEGRAM_TOKEN",
+        hide_env_values = true,
+        requires = "telegram_chat"
+    )]
+    pub telegram_token: Option<String>,
+
+    /// Telegram chat id (or @channel) that receives the alerts
+    #[arg(
+        long,
+        value_name = "CHAT",
+        env = "BOHA_TELEGRAM_CHAT",
+        requires = "telegram_token"
+    )]
+    pub telegram_chat: Option<String>,
+
+    // Watch always refetches; fresh balances are still written to the cache
+    // unless --no-cache is given.
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum EventKind {
+    /// A transaction spending from the address is in the mempool.
+    UnconfirmedSpend,
+    /// The address received coins.
+    Funded,
+    /// Coins left the address.
+    Spent,
+    /// A pending change was mined; the total stayed the same.
+    Confirmed,
+    /// A mined change is still short of `--confirmations`.
+    Pending,
+    /// A reorg dropped an earlier mined change from the chain.
+    EventReverted,
+}
+
+impl EventKind {
+    const fn label(self) -> &'static str {
+        match self {
+            Self::UnconfirmedSpend => "unconfirmed spend",
+            Self::Funded => "funded",
+            Self::Spent => "spent",
+            Self::Confirmed => "confirmed",
+            Self::Pending => "pending",
+            Self::EventReverted => "reverted by reorg",
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+struct WatchEvent {
+    time: String,
+    id: &'static str,
+    address: &'static str,
+    symbol: &'static str,
+    kind: EventKind,
+    /// Whether the change is still unconfirmed.
+    pending: bool,
+    /// Chain height when the change was first seen mined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<u64>,
+    /// Blocks on top of the change, counting its own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirmations: Option<u64>,
+    /// The event a `pending` one turns into once deep enough.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    awaiting: Option<EventKind>,
+    previous: f64,
+    current: f64,
+    change: f64,
+}
+
+pub async fn run(ctx: &Context, args: &WatchArgs) {
+    if matches!(ctx.output, OutputFormat::Yaml | OutputFormat::Csv) {
+        error_exit("watch streams events; use table, json or jsonl output");
+    }
+    #[cfg(feature = "snapshot")]
+    if args.fetch.offline {
+        crate::output::fail(
+            crate::output::ErrorCode::Usage,
+            "watch polls live balances; --offline is not supported",
+        );
+    }
+
+    let puzzles: Vec<&'static Puzzle> = match &args.collection {
+        Some(name) => collection_or_exit(name, false)
+            .all()
+            .filter(|p| p.status == Status::Unsolved)
+            .collect(),
+        None if !args.ids.is_empty() => args
+            .ids
+            .iter()
+            .map(|id| boha::get(id).unwrap_or_else(|e| CliError::from(e).exit()))
+            .collect(),
+        None => boha::all()
+            .filter(|p| p.status == Status::Unsolved)
+            .collect(),
+    };
+    let puzzles: Vec<_> = puzzles
+        .into_iter()
+        .filter(|p| args.chain.is_none_or(|c| p.chain == c))
+        .collect();
+    if puzzles.is_empty() {
+        error_exit("No unsolved puzzles to watch");
+    }
+
+    let fetch = FetchArgs {
+        refresh: true,
+        ..args.fetch.clone()
+    };
+    if args.confirmations > 1 {
+        let chains: HashSet<Chain> = puzzles.iter().map(|p| p.chain).collect();
+        for chain in chains {
+            if let Err(BalanceError::UnsupportedChain(_)) = tip_height(chain, &fetch).await {
+                crate::output::fail(
+                    crate::output::ErrorCode::Usage,
+                    format!(
+                    "--confirmations needs block heights, which this backend does not report for {}",
+                        chain.name()
+                    ),
+                );
+            }
+        }
+    }
+    let notifier = Notifier::new(
+        ctx.client.clone(),
+        args.notify_url.clone(),
+        args.telegram_token.clone().zip(args.telegram_chat.clone()),
+    );
+    eprintln!(
+        "{} {} addresses every {}s",
+        "Watching".bold(),
+        puzzles.len(),
+        args.interval
+    );
+    if !notifier.is_empty() {
+        eprintln!("Alerts go to {} notification target(s)", notifier.targets());
+    }
+
+    let mut last: HashMap<&'static str, Balance> = HashMap::new();
+    let mut tracked: HashMap<&'static str, Tracked> = HashMap::new();
+    let mut poll = 0;
+    loop {
+        let started = std::time::Instant::now();
+        let results = fetch_many(&puzzles, &fetch).await;
+        tracing::info!(
+            poll = poll + 1,
+            addresses = results.len(),
+            failed = results.iter().filter(|(_, r)| r.is_err()).count(),
+            elapsed_ms = started.elapsed().as_millis(),
+            "poll finished"
+        );
+        let tips = tip_heights(&results, &last, &tracked, &fetch).await;
+        for (puzzle, result) in results {
+            match result {
+                Ok(balance) => {
+                    // A spend already waiting in the mempool on the first poll
+                    // is reported against the settled balance.
+                    let previous = last.insert(puzzle.id, balance).unwrap_or(Balance {
+                        unconfirmed: 0,
+                        ..balance
+                    });
+                    let tip = tips.get(&puzzle.chain).copied();
+                    for event in observe(
+                        puzzle,
+                        &mut tracked,
+                        &previous,
+                        &balance,
+                        tip,
+                        args.confirmations,
+                        args.reorg_depth,
+                    ) {
+                        print_event(ctx, &event);
+                        notify(&notifier, &event).await;
+                    }
+                }
+                Err(e) => eprintln!("{} {}: {}", "Warning:".yellow().bold(), puzzle.id, e),
+            }
+        }
+
+        poll += 1;
+        if args.polls.is_some_and(|polls| poll >= polls) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(args.interval)).await;
+    }
+}
+
+/// A mined balance change, re-checked each poll until it is buried deep
+/// enough that a reorg is no longer expected to drop it.
+struct Tracked {
+    /// Confirmed balance before the change.
+    before: u128,
+    /// Chain height when the change was first seen mined; `None` when the
+    /// backend reports no heights, and the change is then tracked until the
+    /// next one replaces it.
+    height: Option<u64>,
+    /// The event held back until `--confirmations` is reached.
+    held: Option<WatchEvent>,
+}
+
+impl Tracked {
+    /// Blocks on top of the change's block, counting that block.
+    fn depth(&self, tip: Option<u64>) -> Option<u64> {
+        Some(tip?.saturating_sub(self.height?) + 1)
+    }
+}
+
+/// Tip heights of the chains with a mined change this poll or an earlier
+/// one still being re-checked. Chains without a height source are left out.
+async fn tip_heights(
+    results: &[(&'static Puzzle, Result<Balance, BalanceError>)],
+    last: &HashMap<&'static str, Balance>,
+    tracked: &HashMap<&'static str, Tracked>,
+    fetch: &FetchArgs,
+) -> HashMap<Chain, u64> {
+    let chains: HashSet<Chain> = results
+        .iter()
+        .filter(|(puzzle, result)| {
+            tracked.contains_key(puzzle.id)
+                || result.as_ref().is_ok_and(|balance| {
+                    last.get(puzzle.id)
+                        .is_some_and(|previous| previous.confirmed != balance.confirmed)
+                })
+        })
+        .map(|(puzzle, _)| puzzle.chain)
+        .collect();
+
+    let mut tips = HashMap::new();
+    for chain in chains {
+        match tip_height(chain, fetch).await {
+            Ok(height) => {
+                tips.insert(chain, height);
+            }
+            Err(BalanceError::UnsupportedChain(_)) => {}
+            Err(e) => eprintln!(
+                "{} {} tip height: {}",
+                "Warning:".yellow().bold(),
+                chain.name(),
+                e
+            ),
+        }
+    }
+    tips
+}
+
+/// The events for `puzzle` this poll: a reorg undoing a tracked change, a
+/// held event reaching `confirmations`, and whatever [`detect`] finds.
+/// Mined changes are tracked until both `confirmations` and `reorg_depth`
+/// blocks deep.
+fn observe(
+    puzzle: &'static Puzzle,
+    tracked: &mut HashMap<&'static str, Tracked>,
+    previous: &Balance,
+    current: &Balance,
+    tip: Option<u64>,
+    confirmations: u64,
+    reorg_depth: u64,
+) -> Vec<WatchEvent> {
+    let mut events = Vec::new();
+    if let Some(entry) = tracked.get_mut(puzzle.id) {
+        if current.confirmed == entry.before {
+            let height = entry.height;
+            tracked.remove(puzzle.id);
+            return vec![WatchEvent {
+                height,
+                ..event(puzzle, EventKind::EventReverted, previous, current)
+            }];
+        }
+        // A change first seen while the tip was unknown counts from the
+        // first height we get.
+        entry.height = entry.height.or(tip);
+        let depth = entry.depth(tip);
+        if depth.is_some_and(|depth| depth >= confirmations) {
+            if let Some(held) = entry.held.take() {
+                events.push(WatchEvent {
+                    time: Utc::now().to_rfc3339(),
+                    confirmations: depth,
+                    ..held
+                });
+            }
+        }
+        if entry.held.is_none() && depth.is_some_and(|depth| depth >= reorg_depth) {
+            tracked.remove(puzzle.id);
+        }
+    }
+
+    let Some(mut event) = detect(puzzle, previous, current) else {
+        return events;
+    };
+    if current.confirmed != previous.confirmed {
+        event.height = tip;
+        event.confirmations = tip.map(|_| 1);
+        // A newer mined change settles one still waiting for its depth.
+        if let Some(held) = tracked.remove(puzzle.id).and_then(|entry| entry.held) {
+            events.push(held);
+        }
+        let held = (confirmations > 1).then(|| {
+            let pending = WatchEvent {
+                kind: EventKind::Pending,
+                awaiting: Some(event.kind),
+                ..event.clone()
+            };
+            std::mem::replace(&mut event, pending)
+        });
+        if reorg_depth > 0 || held.is_some() {
+            tracked.insert(
+                puzzle.id,
+                Tracked {
+                    before: previous.confirmed,
+                    height: tip,
+                    held,
+                },
+            );
+        }
+    }
+    events.push(event);
+    events
+}
+
+/// The event between two balances of `puzzle`'s address, if anything moved.
+fn detect(puzzle: &'static Puzzle, previous: &Balance, current: &Balance) -> Option<WatchEvent> {
+    let (before, after) = (previous.total(), current.total());
+    let kind = if current.has_unconfirmed_spend() && current.unconfirmed < previous.unconfirmed {
+        EventKind::UnconfirmedSpend
+    } else if after > before {
+        EventKind::Funded
+    } else if after < before {
+        EventKind::Spent
+    } else if current.confirmed != previous.confirmed {
+        EventKind::Confirmed
+    } else {
+        return None;
+    };
+    Some(event(puzzle, kind, previous, current))
+}
+
+fn event(
+    puzzle: &'static Puzzle,
+    kind: EventKind,
+    previous: &Balance,
+    current: &Balance,
+) -> WatchEvent {
+    WatchEvent {
+        time: Utc::now().to_rfc3339(),
+        id: puzzle.id,
+        address: puzzle.address.value,
+        symbol: puzzle.chain.symbol(),
+        kind,
+        pending: current.unconfirmed != 0,
+        height: None,
+        confirmations: None,
+        awaiting: None,
+        previous: previous.total_native(),
+        current: current.total_native(),
+        change: round_to_chain(
+            current.total_native() - previous.total_native(),
+            puzzle.chain,
+        ),
+    }
+}
+
+/// Push funding, spend and reorg events, mempool, pending or mined;
+/// confirmations only settle an earlier alert.
+async fn notify(notifier: &Notifier, event: &WatchEvent) {
+    let settles = event.awaiting.unwrap_or(event.kind) == EventKind::Confirmed;
+    if notifier.is_empty() || settles {
+        return;
+    }
+    for error in notifier.send(event, &alert_text(event)).await {
+        eprintln!("{} {}", "Warning:".yellow().bold(), error);
+    }
+}
+
+/// `pending spent`, `funded`, ... for a table line or alert.
+fn kind_text(event: &WatchEvent) -> String {
+    match event.awaiting {
+        Some(awaiting) => format!("{} {}", event.kind.label(), awaiting.label()),
+        None => event.kind.label().to_string(),
+    }
+}
+
+fn alert_text(event: &WatchEvent) -> String {
+    format!(
+        "boha: {} {} {:+.8} {} ({:.8} -> {:.8}){}\n{}",
+        event.id,
+        kind_text(event),
+        event.change,
+        event.symbol,
+        event.previous,
+        event.current,
+        if event.pending { " pending" } else { "" },
+        event.address
+    )
+}
+
+fn print_event(ctx: &Context, event: &WatchEvent) {
+    match ctx.output {
+        OutputFormat::Table => {
+            let text = kind_text(event);
+            let kind = match event.kind {
+                EventKind::UnconfirmedSpend => text.magenta().bold().to_string(),
+                EventKind::Funded => text.green().bold().to_string(),
+                EventKind::Spent => text.red().bold().to_string(),
+                EventKind::Confirmed => text.cyan().to_string(),
+                EventKind::Pending => text.blue().to_string(),
+                EventKind::EventReverted => text.yellow().bold().to_string(),
+            };
+            outln!(
+                ctx,
+                "{} {} {} {:+.8} {} ({:.8} -> {:.8}){}",
+                event.time.dimmed(),
+                event.id.bright_white(),
+                kind,
+                event.change,
+                event.symbol,
+                event.previous,
+                event.current,
+                if event.pending { " pending" } else { "" }
+            );
+        }
+        _ => outln!(ctx, "{}", serde_json::to_string(event).unwrap()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn btc(confirmed: u128, unconfirmed: i128) -> Balance {
+        Balance {
+            confirmed,
+            unconfirmed,
+            ..Balance::zero(Chain::Bitcoin)
+        }
+    }
+
+    #[test]
+    fn classifies_balance_changes() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let kind = |a, b| detect(puzzle, &a, &b).map(|e| (e.kind, e.pending));
+
+        assert_eq!(kind(btc(100, 0), btc(100, 0)), None);
+        assert_eq!(
+            kind(btc(100, 0), btc(100, -100)),
+            Some((EventKind::UnconfirmedSpend, true))
+        );
+        assert_eq!(
+            kind(btc(100, -40), btc(100, -100)),
+            Some((EventKind::UnconfirmedSpend, true))
+        );
+        assert_eq!(
+            kind(btc(100, 0), btc(100, 30)),
+            Some((EventKind::Funded, true))
+        );
+        assert_eq!(
+            kind(btc(100, -100), btc(0, 0)),
+            Some((EventKind::Confirmed, false))
+        );
+        assert_eq!(
+            kind(btc(0, 0), btc(50, 0)),
+            Some((EventKind::Funded, false))
+        );
+    }
+
+    #[test]
+    fn alert_text_names_puzzle_and_address() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let event = detect(
+            puzzle,
+            &btc(150_000_000, 0),
+            &btc(150_000_000, -150_000_000),
+        )
+        .unwrap();
+        assert_eq!(
+            alert_text(&event),
+            "boha: gsmg unconfirmed spend -1.50000000 BTC (1.50000000 -> 0.00000000) pending\n1GSMG1JC9wtdSwfwApgj2xcmJPAwx7prBe"
+        );
+    }
+
+    #[test]
+    fn reports_mined_change_dropped_by_reorg() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let mut tracked = HashMap::new();
+
+        let spent = observe(
+            puzzle,
+            &mut tracked,
+            &btc(100, 0),
+            &btc(0, 0),
+            Some(800),
+            1,
+            6,
+        );
+        assert_eq!(spent[0].kind, EventKind::Spent);
+        assert_eq!(spent[0].height, Some(800));
+
+        let reverted = observe(
+            puzzle,
+            &mut tracked,
+            &btc(0, 0),
+            &btc(100, 0),
+            Some(801),
+            1,
+            6,
+        );
+        assert_eq!(reverted.len(), 1);
+        assert_eq!(reverted[0].kind, EventKind::EventReverted);
+        assert_eq!(reverted[0].height, Some(800));
+        assert!(tracked.is_empty());
+    }
+
+    #[test]
+    fn stops_tracking_once_buried() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let mut tracked = HashMap::new();
+
+        observe(
+            puzzle,
+            &mut tracked,
+            &btc(100, 0),
+            &btc(0, 0),
+            Some(800),
+            1,
+            6,
+        );
+        assert!(observe(
+            puzzle,
+            &mut tracked,
+            &btc(0, 0),
+            &btc(0, 0),
+            Some(804),
+            1,
+            6
+        )
+        .is_empty());
+        assert!(tracked.contains_key("gsmg"));
+        assert!(observe(
+            puzzle,
+            &mut tracked,
+            &btc(0, 0),
+            &btc(0, 0),
+            Some(805),
+            1,
+            6
+        )
+        .is_empty());
+        assert!(tracked.is_empty());
+
+        observe(puzzle, &mut tracked, &btc(0, 0), &btc(100, 0), None, 1, 0);
+        assert!(tracked.is_empty());
+    }
+
+    #[test]
+    fn holds_mined_change_until_confirmed() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let mut tracked = HashMap::new();
+        let mut poll = |previous, current, tip| {
+            observe(puzzle, &mut tracked, &previous, &current, Some(tip), 3, 0)
+                .into_iter()
+                .map(|e| (e.kind, e.awaiting, e.confirmations))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            poll(btc(100, 0), btc(0, 0), 800),
+            [(EventKind::Pending, Some(EventKind::Spent), Some(1))]
+        );
+        assert!(poll(btc(0, 0), btc(0, 0), 801).is_empty());
+        assert_eq!(
+            poll(btc(0, 0), btc(0, 0), 802),
+            [(EventKind::Spent, None, Some(3))]
+        );
+        assert!(poll(btc(0, 0), btc(0, 0), 803).is_empty());
+        assert!(tracked.is_empty());
+    }
+
+    #[test]
+    fn pending_change_can_be_reverted() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let mut tracked = HashMap::new();
+
+        observe(
+            puzzle,
+            &mut tracked,
+            &btc(0, 0),
+            &btc(100, 0),
+            Some(800),
+            3,
+            0,
+        );
+        let events = observe(
+            puzzle,
+            &mut tracked,
+            &btc(100, 0),
+            &btc(0, 0),
+            Some(801),
+            3,
+            0,
+        );
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, EventKind::EventReverted);
+        assert!(tracked.is_empty());
+    }
+
+    #[test]
+    fn alert_text_names_pending_kind() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let mut tracked = HashMap::new();
+        let events = observe(puzzle, &mut tracked, &btc(0, 0), &btc(100, 0), None, 2, 0);
+        assert!(alert_text(&events[0]).starts_with("boha: gsmg pending funded +0.00000100 BTC"));
+    }
+
+    #[test]
+    fn change_is_in_whole_coins() {
+        let puzzle = boha::get("gsmg").unwrap();
+        let event = detect(puzzle, &btc(150_000_000, 0), &btc(0, 0)).unwrap();
+        assert_eq!(event.symbol, "BTC");
+        assert!((event.change + 1.5).abs() < f64::EPSILON);
+    }
+}
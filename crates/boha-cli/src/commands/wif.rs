@@ -0,0 +1,112 @@
+//! Conversion between hex private keys and WIF.
+
+use crate::color::Colorize;
+use boha::address::Network;
+use boha::keytools;
+use boha::PubkeyFormat;
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use tabled::{settings::Style, Table};
+
+use crate::context::Context;
+use crate::output::{error_exit, outln, print_record, KeyValueRow};
+use crate::util::is_hex_key;
+
+#[derive(Debug, Args)]
+pub struct WifArgs {
+    /// Private key: 64-char hex, or a WIF to decode
+    pub key: String,
+
+    /// Network of the WIF to write (default: the input WIF's, else mainnet)
+    #[arg(long, value_enum)]
+    pub network: Option<WifNetwork>,
+
+    /// Write a WIF for the uncompressed public key (default: the input
+    /// WIF's encoding, else compressed)
+    #[arg(long, conflicts_with = "compressed")]
+    pub uncompressed: bool,
+
+    /// Write a WIF for the compressed public key
+    #[arg(long)]
+    pub compressed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WifNetwork {
+    Mainnet,
+    Testnet,
+}
+
+impl From<WifNetwork> for Network {
+    fn from(network: WifNetwork) -> Self {
+        match network {
+            WifNetwork::Mainnet => Self::Mainnet,
+            WifNetwork::Testnet => Self::Testnet,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WifOutput {
+    hex: String,
+    wif: String,
+    network: &'static str,
+    compressed: bool,
+}
+
+pub fn run(ctx: &Context, args: &WifArgs) {
+    let (key, pubkey_format, network) = if is_hex_key(&args.key) {
+        let key = keytools::key_from_hex(&args.key).unwrap_or_else(|e| error_exit(e));
+        (key, PubkeyFormat::Compressed, Network::Mainnet)
+    } else {
+        let decoded = keytools::from_wif(&args.key).unwrap_or_else(|e| error_exit(e));
+        (decoded.key, decoded.pubkey_format, decoded.network)
+    };
+
+    let network = args.network.map_or(network, Network::from);
+    let pubkey_format = if args.uncompressed {
+        PubkeyFormat::Uncompressed
+    } else if args.compressed {
+        PubkeyFormat::Compressed
+    } else {
+        pubkey_format
+    };
+
+    let output = WifOutput {
+        hex: hex::encode(key.to_bytes()),
+        wif: keytools::to_wif(&key, pubkey_format, network),
+        network: match network {
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+        },
+        compressed: pubkey_format == PubkeyFormat::Compressed,
+    };
+    print_record(ctx, &output, print_table);
+}
+
+fn print_table(ctx: &Context, output: &WifOutput) {
+    let rows = vec![
+        KeyValueRow {
+            field: "Hex".to_string(),
+            value: output.hex.clone(),
+        },
+        KeyValueRow {
+            field: "WIF".to_string(),
+            value: output.wif.bright_white().to_string(),
+        },
+        KeyValueRow {
+            field: "Network".to_string(),
+            value: output.network.to_string(),
+        },
+        KeyValueRow {
+            field: "Public key".to_string(),
+            value: if output.compressed {
+                "compressed"
+            } else {
+                "uncompressed"
+            }
+            .to_string(),
+        },
+    ];
+    outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+}
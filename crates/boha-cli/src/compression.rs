@@ -0,0 +1,119 @@
+//! gzip/zstd encoding for exports and transparent decoding for imports.
+
+use std::io::{self, Read, Write};
+
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+}
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+impl Compression {
+    /// File name suffix, without the leading dot.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gzip => "gz",
+            Self::Zstd => "zst",
+        }
+    }
+
+    /// Detect the format from its magic bytes.
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(&GZIP_MAGIC) {
+            Some(Self::Gzip)
+        } else if data.starts_with(&ZSTD_MAGIC) {
+            Some(Self::Zstd)
+        } else {
+            None
+        }
+    }
+}
+
+/// Writer that compresses into `W` as data arrives, or passes it through
+/// unchanged without a compression.
+pub enum Encoder<W: Write> {
+    Plain(W),
+    Gzip(flate2::write::GzEncoder<W>),
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> Encoder<W> {
+    pub fn new(inner: W, compression: Option<Compression>) -> io::Result<Self> {
+        Ok(match compression {
+            None => Self::Plain(inner),
+            Some(Compression::Gzip) => Self::Gzip(flate2::write::GzEncoder::new(
+                inner,
+                flate2::Compression::default(),
+            )),
+            Some(Compression::Zstd) => Self::Zstd(zstd::Encoder::new(inner, 0)?),
+        })
+    }
+
+    /// Write the trailer, if any, and hand back the inner writer.
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            Self::Plain(inner) => Ok(inner),
+            Self::Gzip(encoder) => encoder.finish(),
+            Self::Zstd(encoder) => encoder.finish(),
+        }
+    }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(inner) => inner.write(buf),
+            Self::Gzip(encoder) => encoder.write(buf),
+            Self::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(inner) => inner.flush(),
+            Self::Gzip(encoder) => encoder.flush(),
+            Self::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// Decompress `data` if it is gzip or zstd, otherwise return it unchanged.
+pub fn decompress_auto(data: Vec<u8>) -> io::Result<Vec<u8>> {
+    match Compression::detect(&data) {
+        Some(Compression::Gzip) => {
+            let mut out = Vec::new();
+            flate2::read::MultiGzDecoder::new(data.as_slice()).read_to_end(&mut out)?;
+            Ok(out)
+        }
+        Some(Compression::Zstd) => zstd::decode_all(data.as_slice()),
+        None => Ok(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_and_detect() {
+        let data = br#"{"collections":[]}"#;
+        for compression in [Compression::Gzip, Compression::Zstd] {
+            let mut encoder = Encoder::new(Vec::new(), Some(compression)).unwrap();
+            encoder.write_all(data).unwrap();
+            let packed = encoder.finish().unwrap();
+            assert_eq!(Compression::detect(&packed), Some(compression));
+            assert_eq!(decompress_auto(packed).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn plain_data_passes_through() {
+        assert_eq!(Compression::detect(b"{}"), None);
+        assert_eq!(decompress_auto(b"{}".to_vec()).unwrap(), b"{}");
+    }
+}
@@ -0,0 +1,351 @@
+//! `~/.config/boha/config.toml`: defaults for flags users repeat.
+//!
+//! Every setting stands in for an environment variable the CLI (or
+//! `boha-chain`) already reads, so precedence falls out of clap: a flag beats
+//! the environment, the environment beats this file, and the file beats the
+//! built-in default. A setting is exported only for the subcommands it is a
+//! default for, and never for `boha config`, which must run to repair the
+//! file.
+
+use std::fs;
+use std::path::PathBuf;
+
+use toml_edit::{Array, DocumentMut, Item, TableLike, Value};
+
+use crate::color::Colorize;
+use crate::output::error_exit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Text,
+    /// Text that `config list` redacts.
+    Secret,
+    Number,
+    /// A TOML array, joined with commas for the environment.
+    List,
+}
+
+/// Subcommands a setting is a default for.
+#[derive(Debug, Clone, Copy)]
+pub enum Scope {
+    All,
+    Only(&'static [&'static str]),
+    Except(&'static [&'static str]),
+}
+
+impl Scope {
+    pub fn covers(self, command: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(commands) => commands.contains(&command),
+            Self::Except(commands) => !commands.contains(&command),
+        }
+    }
+}
+
+/// Subcommands that fetch balances.
+const BALANCE_COMMANDS: &[&str] = &["balance", "audit-prizes", "watch", "metrics"];
+
+#[derive(Debug)]
+pub struct Setting {
+    /// Dotted TOML key.
+    pub key: &'static str,
+    /// Environment variable the value is exported as.
+    pub env: &'static str,
+    pub kind: Kind,
+    pub scope: Scope,
+    pub about: &'static str,
+}
+
+pub const SETTINGS: &[Setting] = &[
+    Setting {
+        key: "output",
+        env: "BOHA_OUTPUT",
+        kind: Kind::Text,
+        scope: Scope::Except(&["metrics"]),
+        about: "Default -o format",
+    },
+    Setting {
+        key: "color",
+        env: "BOHA_COLOR",
+        kind: Kind::Text,
+        scope: Scope::All,
+        about: "auto, always or never",
+    },
+    Setting {
+        key: "balance.backend",
+        env: "BOHA_BALANCE_BACKEND",
+        kind: Kind::Text,
+        scope: Scope::Only(BALANCE_COMMANDS),
+        about: "Default --backend for balance, audit-prizes and watch",
+    },
+    Setting {
+        key: "balance.backend_url",
+        env: "BOHA_BACKEND_URL",
+        kind: Kind::Text,
+        scope: Scope::Only(BALANCE_COMMANDS),
+        about: "Default --backend-url",
+    },
+    Setting {
+        key: "balance.cache_ttl",
+        env: "BOHA_CACHE_TTL",
+        kind: Kind::Number,
+        scope: Scope::Only(BALANCE_COMMANDS),
+        about: "Default --cache-ttl in seconds",
+    },
+    Setting {
+        key: "proxy",
+        env: "BOHA_PROXY",
+        kind: Kind::Secret,
+        scope: Scope::All,
+        about: "Default --proxy for network requests",
+    },
+    Setting {
+        key: "api_keys.etherscan",
+        env: "ETHERSCAN_API_KEY",
+        kind: Kind::Secret,
+        scope: Scope::Only(BALANCE_COMMANDS),
+        about: "Etherscan API key for ETH balances",
+    },
+    Setting {
+        key: "notify.urls",
+        env: "BOHA_NOTIFY_URL",
+        kind: Kind::List,
+        scope: Scope::Only(&["watch"]),
+        about: "Webhooks watch posts events to",
+    },
+    Setting {
+        key: "notify.telegram_token",
+        env: "BOHA_TELEGRAM_TOKEN",
+        kind: Kind::Secret,
+        scope: Scope::Only(&["watch"]),
+        about: "Telegram bot token for watch alerts",
+    },
+    Setting {
+        key: "notify.telegram_chat",
+        env: "BOHA_TELEGRAM_CHAT",
+        kind: Kind::Text,
+        scope: Scope::Only(&["watch"]),
+        about: "Telegram chat for watch alerts",
+    },
+    Setting {
+        key: "watchlist",
+        env: "BOHA_WATCHLIST",
+        kind: Kind::List,
+        scope: Scope::Only(&["watch"]),
+        about: "Puzzle ids watch polls by default",
+    },
+];
+
+pub fn setting(key: &str) -> Option<&'static Setting> {
+    SETTINGS.iter().find(|s| s.key == key)
+}
+
+/// `$BOHA_CONFIG`, else `config.toml` under the user's XDG config directory.
+pub fn path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("BOHA_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("boha").join("config.toml"))
+}
+
+/// The config file, empty when there is none.
+pub fn load() -> Result<DocumentMut, String> {
+    let Some(path) = path() else {
+        return Ok(DocumentMut::new());
+    };
+    match fs::read_to_string(&path) {
+        Ok(text) => text
+            .parse()
+            .map_err(|e| format!("Invalid config {}: {}", path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(DocumentMut::new()),
+        Err(e) => Err(format!("Cannot read {}: {}", path.display(), e)),
+    }
+}
+
+pub fn save(doc: &DocumentMut) -> Result<PathBuf, String> {
+    let path = path().ok_or("No config directory; set XDG_CONFIG_HOME or HOME")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Cannot create {}: {}", parent.display(), e))?;
+    }
+    fs::write(&path, doc.to_string())
+        .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Export the file's settings for `command` to the environment, except
+/// where the environment already has a value, and return the file. Runs
+/// before arguments are parsed; `command` is `None` when there is no
+/// subcommand to parse. A broken file ends every command but `config`, which
+/// gets the error back so it can still repair the file.
+pub fn apply(command: Option<&str>) -> Result<DocumentMut, String> {
+    let Some(command) = command else {
+        return Ok(DocumentMut::new());
+    };
+    let loaded = load();
+    if command == "config" {
+        return loaded;
+    }
+    let doc = loaded.unwrap_or_else(|e| error_exit(e));
+    for key in leaf_keys(doc.as_table(), "") {
+        if setting(&key).is_none() {
+            eprintln!(
+                "{} unknown config key {} ignored",
+                "Warning:".yellow().bold(),
+                key
+            );
+        }
+    }
+    for setting in SETTINGS {
+        if !setting.scope.covers(command) || std::env::var_os(setting.env).is_some() {
+            continue;
+        }
+        if let Some(value) = get(&doc, setting.key) {
+            std::env::set_var(setting.env, value);
+        }
+    }
+    Ok(doc)
+}
+
+/// Dotted keys of every value in `table`.
+fn leaf_keys(table: &dyn TableLike, prefix: &str) -> Vec<String> {
+    table
+        .iter()
+        .flat_map(|(key, item)| {
+            let key = format!("{}{}", prefix, key);
+            match item.as_table_like() {
+                Some(table) => leaf_keys(table, &format!("{}.", key)),
+                None => vec![key],
+            }
+        })
+        .collect()
+}
+
+fn item<'a>(doc: &'a DocumentMut, key: &str) -> Option<&'a Item> {
+    key.split('.')
+        .try_fold(doc.as_item(), |item, part| item.get(part))
+}
+
+/// The value of `key` as the environment sees it.
+pub fn get(doc: &DocumentMut, key: &str) -> Option<String> {
+    let value = item(doc, key)?.as_value()?;
+    Some(match value {
+        Value::Array(values) => values.iter().map(plain).collect::<Vec<_>>().join(","),
+        value => plain(value),
+    })
+}
+
+/// A scalar without TOML quoting.
+fn plain(value: &Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string().trim().to_string(), str::to_string)
+}
+
+pub fn set(doc: &mut DocumentMut, setting: &Setting, value: &str) -> Result<(), String> {
+    let value: Value = match setting.kind {
+        Kind::Text | Kind::Secret => value.into(),
+        Kind::Number => value
+            .parse::<i64>()
+            .map_err(|_| format!("{} takes a number, got {:?}", setting.key, value))?
+            .into(),
+        Kind::List => value
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .collect::<Array>()
+            .into(),
+    };
+    let (parents, name) = setting.key.rsplit_once('.').unwrap_or(("", setting.key));
+    let mut table = doc.as_table_mut();
+    for part in parents.split('.').filter(|p| !p.is_empty()) {
+        table = table
+            .entry(part)
+            .or_insert_with(toml_edit::table)
+            .as_table_mut()
+            .ok_or_else(|| format!("{} is not a table in the config file", part))?;
+    }
+    table.insert(name, Item::Value(value));
+    Ok(())
+}
+
+/// Remove `key`; whether it was set.
+pub fn unset(doc: &mut DocumentMut, key: &str) -> bool {
+    let (parents, name) = key.rsplit_once('.').unwrap_or(("", key));
+    let mut table = doc.as_table_mut();
+    for part in parents.split('.').filter(|p| !p.is_empty()) {
+        match table.get_mut(part).and_then(Item::as_table_mut) {
+            Some(inner) => table = inner,
+            None => return false,
+        }
+    }
+    table.remove(name).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_get_and_unset() {
+        let mut doc: DocumentMut = "# mine\noutput = \"json\"\n".parse().unwrap();
+        set(&mut doc, setting("balance.cache_ttl").unwrap(), "600").unwrap();
+        set(
+            &mut doc,
+            setting("watchlist").unwrap(),
+            "b1000/71, b1000/72",
+        )
+        .unwrap();
+        assert_eq!(get(&doc, "output").as_deref(), Some("json"));
+        assert_eq!(get(&doc, "balance.cache_ttl").as_deref(), Some("600"));
+        assert_eq!(get(&doc, "watchlist").as_deref(), Some("b1000/71,b1000/72"));
+        assert!(doc.to_string().starts_with("# mine\n"));
+        assert!(doc.to_string().contains("[balance]\ncache_ttl = 600\n"));
+        let reread: DocumentMut = doc.to_string().parse().unwrap();
+        assert_eq!(get(&reread, "watchlist"), get(&doc, "watchlist"));
+
+        assert!(unset(&mut doc, "balance.cache_ttl"));
+        assert!(!unset(&mut doc, "balance.cache_ttl"));
+        assert_eq!(get(&doc, "balance.cache_ttl"), None);
+        assert!(set(&mut doc, setting("balance.cache_ttl").unwrap(), "soon").is_err());
+    }
+
+    // The balance subcommands only exist with the feature.
+    #[cfg(feature = "balance")]
+    #[test]
+    fn scopes_name_subcommands() {
+        use clap::CommandFactory;
+
+        let cli = crate::Cli::command();
+        for setting in SETTINGS {
+            if let Scope::Only(commands) | Scope::Except(commands) = setting.scope {
+                for command in commands {
+                    assert!(
+                        cli.find_subcommand(command).is_some(),
+                        "{}: no subcommand {}",
+                        setting.key,
+                        command
+                    );
+                }
+            }
+        }
+        assert!(setting("output").unwrap().scope.covers("stats"));
+        assert!(!setting("output").unwrap().scope.covers("metrics"));
+        assert!(!setting("watchlist").unwrap().scope.covers("balance"));
+    }
+
+    #[test]
+    fn every_setting_is_a_leaf_key() {
+        let mut doc = DocumentMut::new();
+        for setting in SETTINGS {
+            set(&mut doc, setting, "1").unwrap();
+        }
+        let keys: Vec<&str> = SETTINGS.iter().map(|s| s.key).collect();
+        assert_eq!(leaf_keys(doc.as_table(), ""), keys);
+    }
+}
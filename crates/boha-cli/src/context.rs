@@ -0,0 +1,31 @@
+//! State shared by every command invocation.
+
+use toml_edit::DocumentMut;
+
+use crate::output::{Out, OutputFormat};
+
+/// Global options resolved once in `main` and handed to each command.
+#[derive(Debug, Clone)]
+pub struct Context {
+    pub output: OutputFormat,
+    /// Where the command prints; write through [`outln!`](crate::output::outln).
+    pub out: Out,
+    /// The config file as read at startup. Only `boha config` runs with an
+    /// unreadable one, so other commands always see `Ok`.
+    pub config: Result<DocumentMut, String>,
+    /// The one HTTP client, already routed through `--proxy`.
+    #[cfg(feature = "balance")]
+    pub client: reqwest::Client,
+}
+
+impl Context {
+    pub fn new(output: OutputFormat, out: Out, config: Result<DocumentMut, String>) -> Self {
+        Self {
+            output,
+            out,
+            config,
+            #[cfg(feature = "balance")]
+            client: boha_chain::http::client().clone(),
+        }
+    }
+}
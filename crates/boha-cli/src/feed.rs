@@ -0,0 +1,138 @@
+//! RSS 2.0 feed of recent solves, claims and sweeps.
+
+use std::fmt::Write;
+
+use boha::{Puzzle, Status};
+use chrono::{DateTime, Utc};
+
+use crate::report::html_escape as xml_escape;
+
+/// Items in a feed; readers only show the latest anyway.
+const FEED_ITEMS: usize = 50;
+
+const REPO_URL: &str = "https://github.com/oritwoen/boha";
+
+/// When `puzzle` stopped being open: its solve date, or for claims and
+/// sweeps recorded without one, the date of that transaction.
+fn closed_at(puzzle: &Puzzle) -> Option<DateTime<Utc>> {
+    let date = puzzle.solve_naive().or_else(|| {
+        puzzle
+            .transactions
+            .iter()
+            .rev()
+            .find(|tx| {
+                matches!(
+                    tx.tx_type,
+                    boha::TransactionType::Claim | boha::TransactionType::Sweep
+                )
+            })
+            .and_then(|tx| tx.date?.parse::<boha::DateTime>().ok())
+            .map(boha::DateTime::naive_datetime)
+    })?;
+    Some(date.and_utc())
+}
+
+/// Solved, claimed and swept puzzles among `puzzles`, newest first.
+pub fn rss(puzzles: &[&Puzzle], generated: DateTime<Utc>) -> String {
+    let mut closed: Vec<(&Puzzle, DateTime<Utc>)> = puzzles
+        .iter()
+        .filter(|p| matches!(p.status, Status::Solved | Status::Claimed | Status::Swept))
+        .filter_map(|p| Some((*p, closed_at(p)?)))
+        .collect();
+    closed.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.id.cmp(b.0.id)));
+    closed.truncate(FEED_ITEMS);
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>"
+    )
+    .unwrap();
+    writeln!(out, "<title>boha: solved puzzles</title>").unwrap();
+    writeln!(out, "<link>{}</link>", REPO_URL).unwrap();
+    writeln!(
+        out,
+        "<description>Recently solved, claimed and swept crypto puzzles and bounties</description>"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "<lastBuildDate>{}</lastBuildDate>",
+        generated.to_rfc2822()
+    )
+    .unwrap();
+
+    for (puzzle, date) in closed {
+        let link = puzzle
+            .source_url
+            .map_or_else(|| puzzle.explorer_url(), str::to_string);
+        writeln!(out, "<item>").unwrap();
+        writeln!(
+            out,
+            "<title>{} {}</title>",
+            xml_escape(puzzle.id),
+            puzzle.status
+        )
+        .unwrap();
+        writeln!(out, "<link>{}</link>", xml_escape(&link)).unwrap();
+        writeln!(
+            out,
+            "<guid isPermaLink=\"false\">{}/{}</guid>",
+            puzzle.uuid, puzzle.status
+        )
+        .unwrap();
+        writeln!(out, "<pubDate>{}</pubDate>", date.to_rfc2822()).unwrap();
+        writeln!(
+            out,
+            "<description>{}</description>",
+            xml_escape(&description(puzzle))
+        )
+        .unwrap();
+        writeln!(out, "</item>").unwrap();
+    }
+    writeln!(out, "</channel>\n</rss>").unwrap();
+    out
+}
+
+/// `"6.6 BTC prize, 9y 8mo 3d 4h 52m after funding."`, as much as is known.
+fn description(puzzle: &Puzzle) -> String {
+    let mut parts = Vec::new();
+    if let Some(prize) = puzzle.prize {
+        parts.push(format!("{} {} prize", prize, puzzle.currency()));
+    }
+    if let Some(duration) = puzzle.solve_time_formatted() {
+        parts.push(format!("{} after funding", duration));
+    }
+    if let Some(name) = puzzle.solver.as_ref().and_then(|s| s.name) {
+        parts.push(format!("by {}", name));
+    }
+    if parts.is_empty() {
+        format!("{} on {}", puzzle.address.value, puzzle.chain.name())
+    } else {
+        parts.join(", ") + "."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newest_solves_come_first() {
+        let puzzles: Vec<&Puzzle> = boha::b1000::all().collect();
+        let feed = rss(&puzzles, Utc::now());
+        let first = feed.find("<item>").unwrap();
+        let dates: Vec<DateTime<Utc>> = feed[first..]
+            .split("<pubDate>")
+            .skip(1)
+            .map(|s| {
+                DateTime::parse_from_rfc2822(&s[..s.find('<').unwrap()])
+                    .unwrap()
+                    .into()
+            })
+            .collect();
+        assert!(!dates.is_empty() && dates.len() <= FEED_ITEMS);
+        assert!(dates.windows(2).all(|w| w[0] >= w[1]));
+        assert!(!feed.contains("unsolved</title>"));
+    }
+}
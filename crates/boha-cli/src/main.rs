@@ -0,0 +1,444 @@
+mod calendar;
+#[cfg(feature = "publish")]
+mod car;
+mod color;
+#[cfg(feature = "parquet")]
+mod columnar;
+mod commands;
+mod compression;
+mod config;
+mod context;
+mod feed;
+mod markdown;
+#[cfg(feature = "balance")]
+mod notify;
+mod output;
+mod qr;
+mod query;
+mod report;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+mod template;
+#[cfg(feature = "publish")]
+mod torrent;
+mod util;
+
+use std::path::PathBuf;
+
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+
+use commands::{author, export, list, range, search, show, stats, verify};
+use context::Context;
+use output::{Out, OutputFormat};
+
+#[derive(Parser)]
+#[command(name = "boha")]
+#[command(about = "Crypto bounties, puzzles and challenges data")]
+#[command(version = boha::version::FULL_VERSION)]
+struct Cli {
+    /// Output format
+    #[arg(
+        short,
+        long,
+        value_enum,
+        env = "BOHA_OUTPUT",
+        default_value = "table",
+        global = true
+    )]
+    output: OutputFormat,
+
+    /// When to colour output
+    #[arg(
+        long,
+        value_enum,
+        env = "BOHA_COLOR",
+        default_value = "auto",
+        global = true
+    )]
+    color: color::ColorChoice,
+
+    /// Log to stderr: -v requests and polls, -vv cache hits and timings, -vvv everything (BOHA_LOG overrides)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Send balance, price and webhook requests through a proxy, e.g. socks5h://127.0.0.1:9050 for Tor
+    #[cfg(any(feature = "balance", feature = "price"))]
+    #[arg(long, value_name = "URL", env = "BOHA_PROXY", global = true)]
+    proxy: Option<String>,
+
+    /// Verify every embedded solved key derives its address before running the command
+    #[arg(long, global = true)]
+    self_check: bool,
+
+    /// Write output to PATH instead, replacing it only once the command succeeds (watch writes as it goes)
+    // Own id: `import` already has a `file` argument.
+    #[arg(long = "file", value_name = "PATH", global = true)]
+    output_file: Option<PathBuf>,
+
+    /// Filter JSON output with a JMESPath expression (subset), e.g. '[].address.value'; needs -o json
+    // Own id: `search` already has a `query` argument.
+    #[arg(long = "query", value_name = "EXPR", global = true)]
+    json_query: Option<String>,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// List puzzles from a collection
+    List(list::ListArgs),
+
+    /// Show puzzle details
+    Show(show::ShowArgs),
+
+    /// On-chain timeline of a puzzle: funding, top-ups, claims and sweeps, with explorer links
+    History(commands::history::HistoryArgs),
+
+    /// Recent funding, key reveals, claims and sweeps across the dataset, newest first
+    Timeline(commands::timeline::TimelineArgs),
+
+    /// Show one random puzzle matching the filters
+    Random(commands::random::RandomArgs),
+
+    /// Show statistics
+    Stats(stats::StatsArgs),
+
+    /// Show key range for puzzle
+    Range(range::RangeArgs),
+
+    /// Revealed public key of a puzzle, optionally as Kangaroo solver input
+    Pubkey(commands::pubkey::PubkeyArgs),
+
+    /// Ready-to-run BitCrack, KeyHunt or VanitySearch arguments for a puzzle
+    SolverConfig(commands::solver_config::SolverConfigArgs),
+
+    /// Expected time to search a puzzle's key range at a given rate
+    Estimate(commands::estimate::EstimateArgs),
+
+    /// Unsolved puzzles ranked by expected prize per day of search at a given rate
+    Rank(commands::rank::RankArgs),
+
+    /// Record searched key ranges and show how much of a puzzle is covered
+    Progress(commands::progress::ProgressArgs),
+
+    /// Show collection author
+    Author(author::AuthorArgs),
+
+    /// List every author with their collections, profiles and totals
+    Authors,
+
+    /// Check balance (requires balance feature)
+    #[cfg(feature = "balance")]
+    Balance(commands::balance::BalanceArgs),
+
+    /// Compare stored prizes of unsolved puzzles with live balances (requires balance feature)
+    #[cfg(feature = "balance")]
+    AuditPrizes(commands::audit_prizes::AuditPrizesArgs),
+
+    /// Poll unsolved puzzle addresses and report funding or spends (requires balance feature)
+    #[cfg(feature = "balance")]
+    Watch(commands::watch::WatchArgs),
+
+    /// Search puzzles by query
+    Search(search::SearchArgs),
+
+    /// Browse puzzles in an interactive terminal UI (requires tui feature)
+    #[cfg(feature = "tui")]
+    Tui(commands::tui::TuiArgs),
+
+    /// Search redeem scripts by opcode sequence or hex fragment
+    GrepScripts(commands::grep_scripts::GrepScriptsArgs),
+
+    /// Verify puzzle private key derives correct address
+    Verify(verify::VerifyArgs),
+
+    /// Check dataset integrity rules (addresses, hash160s, pubkeys, dates); exits 5 on failure
+    Validate(commands::validate::ValidateArgs),
+
+    /// Public keys, HASH160s and BTC/LTC/ETH/DCR addresses of a hex key, WIF or mnemonic
+    Derive(commands::derive::DeriveArgs),
+
+    /// Convert a hex private key to WIF, or decode a WIF
+    Wif(commands::wif::WifArgs),
+
+    /// Chain, type and payload of any address, and the puzzle it belongs to
+    Decode(commands::decode::DecodeArgs),
+
+    /// Export full puzzle database
+    Export(export::ExportArgs),
+
+    /// Read a file written by `export` (gzip/zstd input is detected automatically)
+    Import(commands::import::ImportArgs),
+
+    /// Puzzles added, removed or changed between two exports, or since a git ref
+    Diff(commands::diff::DiffArgs),
+
+    /// Generate a static HTML dashboard: stats, collection and puzzle pages
+    Site(commands::site::SiteArgs),
+
+    /// Compute an IPFS CID/CAR or a .torrent for an export snapshot (requires publish feature)
+    #[cfg(feature = "publish")]
+    Publish(commands::publish::PublishArgs),
+
+    /// Serve puzzle data to AI assistants over the Model Context Protocol on stdio (requires mcp feature)
+    #[cfg(feature = "mcp")]
+    Mcp,
+
+    /// Prometheus gauges for the dataset, printed once or served with --listen
+    Metrics(commands::metrics::MetricsArgs),
+
+    /// Check a solver's signed announcement and print the data patch and markdown post
+    Announce(commands::announce::AnnounceArgs),
+
+    /// Cross-collection analyses (e.g. solvers sharing payout addresses)
+    Analyze(commands::analyze::AnalyzeArgs),
+
+    /// Interactive walkthrough using the fake example collection
+    #[cfg(feature = "examples-data")]
+    Tutorial,
+
+    /// Runnable example invocations using real puzzle ids
+    Examples(commands::examples::ExamplesArgs),
+
+    /// Print a shell completion script (bash, zsh, fish, powershell)
+    Completions(commands::completions::CompletionsArgs),
+
+    /// Get or set defaults in the config file (~/.config/boha/config.toml)
+    Config(commands::config::ConfigArgs),
+
+    /// Throughput benchmarks for maintainers
+    #[command(hide = true)]
+    Bench(commands::bench::BenchArgs),
+}
+
+fn main() {
+    human_panic::setup_panic!();
+    complete();
+    // Before parsing, so config values reach clap as environment defaults,
+    // and before any runtime thread exists.
+    let config = config::apply(subcommand_name().as_deref());
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        if !e.use_stderr() {
+            e.exit();
+        }
+        let _ = e.print();
+        std::process::exit(output::ErrorCode::Usage.exit_code());
+    });
+    let out = open_output(&cli);
+    color::init(cli.color, out.is_terminal());
+    output::set_error_format(cli.output);
+    init_logging(cli.verbose);
+    #[cfg(any(feature = "balance", feature = "price"))]
+    if let Some(proxy) = &cli.proxy {
+        boha_chain::http::set_proxy(proxy)
+            .unwrap_or_else(|e| output::fail(output::ErrorCode::Usage, e.to_string()));
+    }
+    check_output(&cli);
+    check_query(&cli);
+    if cli.self_check {
+        self_check();
+    }
+    let ctx = Context::new(cli.output, out, config);
+    match &cli.command {
+        #[cfg(feature = "balance")]
+        Commands::Balance(_) | Commands::AuditPrizes(_) | Commands::Watch(_) => {
+            run_async(&ctx, &cli.command);
+        }
+        command => run(&ctx, command),
+    }
+    if let Err(e) = ctx.out.finish() {
+        output::error_exit(format!("Cannot write output: {}", e));
+    }
+}
+
+/// The subcommand on the command line, from a lenient parse that ignores
+/// missing and invalid arguments.
+fn subcommand_name() -> Option<String> {
+    Cli::command()
+        .ignore_errors(true)
+        .try_get_matches()
+        .ok()?
+        .subcommand_name()
+        .map(str::to_string)
+}
+
+#[cfg(feature = "balance")]
+#[tokio::main]
+async fn run_async(ctx: &Context, command: &Commands) {
+    match command {
+        Commands::Balance(args) => commands::balance::run(ctx, args).await,
+        Commands::AuditPrizes(args) => commands::audit_prizes::run(ctx, args).await,
+        Commands::Watch(args) => commands::watch::run(ctx, args).await,
+        _ => unreachable!("sync commands are dispatched in run"),
+    }
+}
+
+/// Answer a shell's completion callback (`COMPLETE=<shell> boha -- ...`)
+/// and exit; a no-op on normal runs.
+fn complete() {
+    clap_complete::CompleteEnv::with_factory(Cli::command)
+        .var(commands::completions::COMPLETE_VAR)
+        .complete();
+}
+
+/// Send `tracing` events to stderr. `BOHA_LOG` takes `RUST_LOG`-style
+/// directives; otherwise `-v` raises boha's own level from warnings up.
+fn init_logging(verbose: u8) {
+    let filter = std::env::var("BOHA_LOG")
+        .ok()
+        .filter(|directives| !directives.is_empty())
+        .unwrap_or_else(|| {
+            let level = match verbose {
+                0 => "warn",
+                1 => "info",
+                2 => "debug",
+                _ => "trace",
+            };
+            format!("warn,boha={},boha_chain={}", level, level)
+        });
+    let filter = tracing_subscriber::EnvFilter::try_new(&filter).unwrap_or_else(|e| {
+        output::fail(output::ErrorCode::Usage, format!("Invalid BOHA_LOG: {}", e))
+    });
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(color::enabled())
+        .init();
+}
+
+/// Reject dataset-only formats (Markdown, HTML, Parquet) on commands that
+/// cannot produce them, so those commands never see them.
+fn check_output(cli: &Cli) {
+    let Some(supported) = cli.output.dataset_commands() else {
+        return;
+    };
+    let allowed = match cli.command {
+        Commands::Export(_) => true,
+        #[cfg(feature = "parquet")]
+        Commands::List(_) => matches!(cli.output, OutputFormat::Parquet),
+        _ => false,
+    };
+    if !allowed {
+        output::fail(
+            output::ErrorCode::Usage,
+            format!(
+                "-o {} is only supported by {}",
+                cli.output
+                    .to_possible_value()
+                    .expect("no skipped variants")
+                    .get_name(),
+                supported
+            ),
+        );
+    }
+}
+
+/// Parse `--query` up front, so a typo fails before the command runs.
+fn check_query(cli: &Cli) {
+    let Some(expression) = &cli.json_query else {
+        return;
+    };
+    if !matches!(cli.output, OutputFormat::Json) {
+        output::fail(output::ErrorCode::Usage, "--query requires -o json");
+    }
+    let query = query::Query::parse(expression).unwrap_or_else(|e| {
+        output::fail(output::ErrorCode::Usage, format!("Invalid --query: {}", e))
+    });
+    output::set_query(query);
+}
+
+/// Stdout, or the `--file` target. Interactive commands keep the terminal.
+fn open_output(cli: &Cli) -> Out {
+    let Some(path) = cli.output_file.as_deref() else {
+        return Out::stdout();
+    };
+    let interactive = match cli.command {
+        #[cfg(feature = "tui")]
+        Commands::Tui(_) => true,
+        #[cfg(feature = "mcp")]
+        Commands::Mcp => true,
+        #[cfg(feature = "examples-data")]
+        Commands::Tutorial => true,
+        _ => false,
+    };
+    if interactive {
+        output::fail(
+            output::ErrorCode::Usage,
+            "--file is not supported by interactive commands",
+        );
+    }
+    if path.file_name().is_none() {
+        output::error_exit(format!("--file needs a file path, got {}", path.display()));
+    }
+    #[cfg(feature = "balance")]
+    let stream = matches!(cli.command, Commands::Watch(_));
+    #[cfg(not(feature = "balance"))]
+    let stream = false;
+    Out::file(path, stream)
+        .unwrap_or_else(|e| output::error_exit(format!("Cannot write {}: {}", path.display(), e)))
+}
+
+/// Refuse to run when the embedded data no longer verifies.
+fn self_check() {
+    match boha::verify::verify_embedded() {
+        Ok(checked) => eprintln!("Self-check: {} embedded keys verified", checked),
+        Err(failures) => {
+            let ids: Vec<&str> = failures.iter().map(|r| r.id.as_str()).collect();
+            output::error_exit(format!(
+                "Self-check failed for {} puzzle(s): {}. This build may be corrupted.",
+                ids.len(),
+                ids.join(", ")
+            ));
+        }
+    }
+}
+
+fn run(ctx: &Context, command: &Commands) {
+    match command {
+        Commands::List(args) => list::run(ctx, args),
+        Commands::Show(args) => show::run(ctx, args),
+        Commands::Random(args) => commands::random::run(ctx, args),
+        Commands::Stats(args) => stats::run(ctx, args),
+        Commands::Range(args) => range::run(ctx, args),
+        Commands::Author(args) => author::run(ctx, args),
+        Commands::Authors => commands::authors::run(ctx),
+        Commands::History(args) => commands::history::run(ctx, args),
+        Commands::Timeline(args) => commands::timeline::run(ctx, args),
+        Commands::Pubkey(args) => commands::pubkey::run(ctx, args),
+        Commands::SolverConfig(args) => commands::solver_config::run(ctx, args),
+        Commands::Estimate(args) => commands::estimate::run(ctx, args),
+        Commands::Rank(args) => commands::rank::run(ctx, args),
+        Commands::Progress(args) => commands::progress::run(ctx, args),
+        #[cfg(feature = "balance")]
+        Commands::Balance(_) | Commands::AuditPrizes(_) | Commands::Watch(_) => {
+            unreachable!("async commands are dispatched in run_async")
+        }
+        Commands::Search(args) => search::run(ctx, args),
+        #[cfg(feature = "tui")]
+        Commands::Tui(args) => commands::tui::run(args),
+        Commands::GrepScripts(args) => commands::grep_scripts::run(ctx, args),
+        Commands::Verify(args) => verify::run(ctx, args),
+        Commands::Validate(args) => commands::validate::run(ctx, args),
+        Commands::Derive(args) => commands::derive::run(ctx, args),
+        Commands::Wif(args) => commands::wif::run(ctx, args),
+        Commands::Decode(args) => commands::decode::run(ctx, args),
+        Commands::Export(args) => export::run(ctx, args),
+        Commands::Import(args) => commands::import::run(ctx, args),
+        Commands::Diff(args) => commands::diff::run(ctx, args),
+        Commands::Site(args) => commands::site::run(ctx, args),
+        #[cfg(feature = "publish")]
+        Commands::Publish(args) => commands::publish::run(ctx, args),
+        #[cfg(feature = "mcp")]
+        Commands::Mcp => commands::mcp::run(),
+        Commands::Metrics(args) => commands::metrics::run(ctx, args),
+        Commands::Announce(args) => commands::announce::run(ctx, args),
+        Commands::Analyze(args) => commands::analyze::run(ctx, args),
+        #[cfg(feature = "examples-data")]
+        Commands::Tutorial => commands::tutorial::run(ctx),
+        Commands::Examples(args) => commands::examples::run(ctx, args),
+        Commands::Completions(args) => commands::completions::run(ctx, args),
+        Commands::Config(args) => commands::config::run(ctx, args),
+        Commands::Bench(args) => commands::bench::run(ctx, args),
+    }
+}
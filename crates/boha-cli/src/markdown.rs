@@ -0,0 +1,103 @@
+//! Plain-terminal rendering of the short markdown in puzzle descriptions.
+//!
+//! Only the inline subset the dataset uses: `**bold**`, `*em*`/`_em_`,
+//! `` `code` `` and `[text](url)`. Blank lines separate paragraphs.
+
+/// `markdown` as plain text, each paragraph word-wrapped to `width`
+/// columns (a longer single word keeps its own line).
+pub fn render(markdown: &str, width: usize) -> String {
+    markdown
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| wrap(&inline(paragraph), width).join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Drops emphasis and code markers; links become `text (url)`.
+fn inline(markdown: &str) -> String {
+    let mut out = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+    while let Some(c) = rest.chars().next() {
+        if c == '[' {
+            if let Some((text, url, tail)) = link(rest) {
+                out.push_str(&inline(text));
+                out.push_str(" (");
+                out.push_str(url);
+                out.push(')');
+                rest = tail;
+                continue;
+            }
+        }
+        if !matches!(c, '*' | '_' | '`') || is_intraword(&out, rest) {
+            out.push(c);
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+/// `_` inside a word (`snake_case`, `peter_todd`) is not emphasis.
+fn is_intraword(before: &str, rest: &str) -> bool {
+    let word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    rest.starts_with('_') && word(before.chars().last()) && word(rest[1..].chars().next())
+}
+
+/// `[text](url)` at the start of `s`: text, url and what follows.
+fn link(s: &str) -> Option<(&str, &str, &str)> {
+    let close = s.find("](")?;
+    let end = close + s[close..].find(')')?;
+    Some((&s[1..close], &s[close + 2..end], &s[end + 1..]))
+}
+
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_markup_is_dropped() {
+        assert_eq!(
+            render("Made by **peter_todd** to show `rand()` is *weak*", 80),
+            "Made by peter_todd to show rand() is weak"
+        );
+    }
+
+    #[test]
+    fn links_keep_their_target() {
+        assert_eq!(
+            render("See [the thread](https://bitcointalk.org/t=1).", 80),
+            "See the thread (https://bitcointalk.org/t=1)."
+        );
+    }
+
+    #[test]
+    fn paragraphs_wrap_separately() {
+        assert_eq!(
+            render("one two three four\n\nfive six", 9),
+            "one two\nthree\nfour\n\nfive six"
+        );
+        assert_eq!(
+            render("a https://example.com/long b", 5),
+            "a\nhttps://example.com/long\nb"
+        );
+    }
+}
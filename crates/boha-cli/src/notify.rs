@@ -0,0 +1,70 @@
+//! Push `watch` events to webhooks and Telegram.
+
+use serde::Serialize;
+
+const TELEGRAM_URL: &str = "https://api.telegram.org";
+
+struct Telegram {
+    base_url: String,
+    token: String,
+    chat_id: String,
+}
+
+/// Delivery targets for alerts. Failures are returned to the caller rather
+/// than aborting, so a flaky endpoint never stops the watch loop.
+pub struct Notifier {
+    client: reqwest::Client,
+    webhooks: Vec<String>,
+    telegram: Option<Telegram>,
+}
+
+impl Notifier {
+    pub fn new(
+        client: reqwest::Client,
+        webhooks: Vec<String>,
+        telegram: Option<(String, String)>,
+    ) -> Self {
+        let base_url =
+            std::env::var("BOHA_TELEGRAM_URL").unwrap_or_else(|_| TELEGRAM_URL.to_string());
+        Self {
+            client,
+            webhooks,
+            telegram: telegram.map(|(token, chat_id)| Telegram {
+                base_url: base_url.trim_end_matches('/').to_string(),
+                token,
+                chat_id,
+            }),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.webhooks.is_empty() && self.telegram.is_none()
+    }
+
+    /// Number of configured targets, for the startup banner.
+    pub fn targets(&self) -> usize {
+        self.webhooks.len() + usize::from(self.telegram.is_some())
+    }
+
+    /// POST `payload` as JSON to every webhook and `text` to Telegram.
+    /// Returns one message per failed delivery.
+    pub async fn send(&self, payload: &(impl Serialize + Sync), text: &str) -> Vec<String> {
+        let mut errors = Vec::new();
+        for url in &self.webhooks {
+            let result = self.client.post(url).json(payload).send().await;
+            if let Err(e) = result.and_then(reqwest::Response::error_for_status) {
+                errors.push(format!("webhook {}: {}", url, e.without_url()));
+            }
+        }
+        if let Some(telegram) = &self.telegram {
+            let url = format!("{}/bot{}/sendMessage", telegram.base_url, telegram.token);
+            let body = serde_json::json!({ "chat_id": telegram.chat_id, "text": text });
+            let result = self.client.post(&url).json(&body).send().await;
+            // The URL carries the bot token, so never print it.
+            if let Err(e) = result.and_then(reqwest::Response::error_for_status) {
+                errors.push(format!("telegram: {}", e.without_url()));
+            }
+        }
+        errors
+    }
+}
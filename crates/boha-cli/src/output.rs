@@ -0,0 +1,411 @@
+//! Output formats and shared rendering helpers.
+
+use std::fs::File;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use crate::color::Colorize;
+use clap::ValueEnum;
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+
+use crate::context::Context;
+use crate::query::Query;
+
+/// The global `--query`, set once in `main` before any command runs.
+static QUERY: OnceLock<Query> = OnceLock::new();
+
+/// The global `-o`, so errors can match the output they replace.
+static ERROR_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// The temporary `--file` output, removed if the run exits early.
+static PENDING: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Where commands print: stdout, or the `--file` target.
+///
+/// A file is written beside its target and renamed into place by
+/// [`Out::finish`], so a failed run leaves the old file untouched. Streamed
+/// output (`watch`) goes to the target as it is printed instead.
+#[derive(Clone)]
+pub struct Out(Arc<Mutex<Sink>>);
+
+enum Sink {
+    Stdout(io::Stdout),
+    Temp {
+        writer: io::BufWriter<File>,
+        temp: PathBuf,
+        path: PathBuf,
+    },
+    Stream(io::LineWriter<File>),
+}
+
+impl Out {
+    pub fn stdout() -> Self {
+        Self::new(Sink::Stdout(io::stdout()))
+    }
+
+    /// Output to `path`; `stream` writes it in place, line by line.
+    pub fn file(path: &Path, stream: bool) -> io::Result<Self> {
+        if stream {
+            return Ok(Self::new(Sink::Stream(io::LineWriter::new(File::create(
+                path,
+            )?))));
+        }
+        let temp = crate::util::temp_path(path);
+        let file = File::create(&temp)?;
+        *PENDING.lock().unwrap_or_else(PoisonError::into_inner) = Some(temp.clone());
+        Ok(Self::new(Sink::Temp {
+            writer: io::BufWriter::new(file),
+            temp,
+            path: path.to_path_buf(),
+        }))
+    }
+
+    fn new(sink: Sink) -> Self {
+        Self(Arc::new(Mutex::new(sink)))
+    }
+
+    fn sink(&self) -> std::sync::MutexGuard<'_, Sink> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Whether output lands on a terminal (colours, pretty JSON).
+    pub fn is_terminal(&self) -> bool {
+        matches!(&*self.sink(), Sink::Stdout(stdout) if stdout.is_terminal())
+    }
+
+    /// Flush, and move a `--file` into place now that the run succeeded.
+    pub fn finish(&self) -> io::Result<()> {
+        let mut sink = self.sink();
+        sink.flush()?;
+        let Sink::Temp { temp, path, .. } = &*sink else {
+            return Ok(());
+        };
+        std::fs::rename(temp, path)?;
+        drop(sink);
+        PENDING
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Out {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match &*self.sink() {
+            Sink::Stdout(_) => "Out::Stdout",
+            Sink::Temp { .. } => "Out::Temp",
+            Sink::Stream(_) => "Out::Stream",
+        })
+    }
+}
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Stdout(stdout) => stdout.write(buf),
+            Self::Temp { writer, .. } => writer.write(buf),
+            Self::Stream(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Stdout(stdout) => stdout.flush(),
+            Self::Temp { writer, .. } => writer.flush(),
+            Self::Stream(writer) => writer.flush(),
+        }
+    }
+}
+
+impl Write for &Out {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.sink().write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.sink().write_all(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink().flush()
+    }
+}
+
+/// Write formatted output, exiting quietly once the reader has gone away
+/// (`boha list | head`).
+pub fn write_out(mut out: &Out, args: std::fmt::Arguments<'_>) {
+    if let Err(e) = out.write_fmt(args) {
+        if e.kind() == io::ErrorKind::BrokenPipe {
+            exit(0);
+        }
+        error_exit(format!("Cannot write output: {}", e));
+    }
+}
+
+/// `println!` to the command's output (stdout or `--file`).
+macro_rules! outln {
+    ($ctx:expr) => {
+        $crate::output::write_out(&$ctx.out, format_args!("\n"))
+    };
+    ($ctx:expr, $($arg:tt)*) => {
+        $crate::output::write_out(&$ctx.out, format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+/// `print!` to the command's output (stdout or `--file`).
+macro_rules! out {
+    ($ctx:expr, $($arg:tt)*) => {
+        $crate::output::write_out(&$ctx.out, format_args!($($arg)*))
+    };
+}
+
+pub(crate) use {out, outln};
+
+/// Leave with `code`, dropping an unfinished `--file`.
+pub fn exit(code: i32) -> ! {
+    let temp = PENDING
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    if let Some(temp) = temp {
+        std::fs::remove_file(temp).ok();
+    }
+    std::process::exit(code)
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Jsonl,
+    Yaml,
+    Csv,
+    /// Per-collection report; `export` only
+    Markdown,
+    /// Standalone HTML page of the Markdown report; `export` only
+    Html,
+    /// RSS feed of the latest solves, claims and sweeps; `export` only
+    Rss,
+    /// iCalendar of funding and solve dates; `export` only
+    Ics,
+    /// Binary, one typed row per puzzle; `list` and `export` only
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl OutputFormat {
+    /// Formats that only make sense for the dataset as a whole, so only
+    /// the listed commands produce them.
+    pub const fn dataset_commands(self) -> Option<&'static str> {
+        match self {
+            Self::Markdown | Self::Html | Self::Rss | Self::Ics => Some("export"),
+            #[cfg(feature = "parquet")]
+            Self::Parquet => Some("list and export"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Tabled)]
+pub struct KeyValueRow {
+    #[tabled(rename = "Field")]
+    pub field: String,
+    #[tabled(rename = "Value")]
+    pub value: String,
+}
+
+pub fn section(title: &str) -> KeyValueRow {
+    KeyValueRow {
+        field: format!("▸ {}", title).cyan().bold().to_string(),
+        value: String::new(),
+    }
+}
+
+/// Print a single record in one of the structured formats.
+///
+/// `Table` is rendered by the caller, so `table` is invoked for it instead.
+pub fn print_record<T: Serialize>(ctx: &Context, value: &T, table: impl FnOnce(&Context, &T)) {
+    match ctx.output {
+        OutputFormat::Table => table(ctx, value),
+        OutputFormat::Json => {
+            print_json(ctx, value);
+        }
+        OutputFormat::Jsonl => {
+            outln!(ctx, "{}", serde_json::to_string(value).unwrap());
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(value).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            wtr.serialize(value).unwrap();
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+/// Print a list of rows in one of the structured formats; `Table` is a
+/// rounded table of the rows.
+pub fn print_rows<T: Serialize + Tabled>(ctx: &Context, rows: &[T]) {
+    match ctx.output {
+        OutputFormat::Table => {
+            outln!(ctx, "{}", Table::new(rows).with(Style::rounded()));
+        }
+        OutputFormat::Json => {
+            print_json(ctx, rows);
+        }
+        OutputFormat::Jsonl => {
+            for row in rows {
+                outln!(ctx, "{}", serde_json::to_string(row).unwrap());
+            }
+        }
+        OutputFormat::Yaml => {
+            outln!(ctx, "{}", serde_yaml::to_string(rows).unwrap());
+        }
+        OutputFormat::Csv => {
+            let mut wtr = csv::Writer::from_writer(&ctx.out);
+            for row in rows {
+                wtr.serialize(row).unwrap();
+            }
+            wtr.flush().unwrap();
+        }
+        _ => unreachable!("dataset-only formats are rejected in main"),
+    }
+}
+
+pub fn set_query(query: Query) {
+    QUERY
+        .set(query)
+        .expect("--query is set once, before any output");
+}
+
+/// The global `--query`, if one was given.
+pub fn query() -> Option<&'static Query> {
+    QUERY.get()
+}
+
+/// Pretty JSON on the command's output, after `--query`. Every `-o json`
+/// goes through here.
+pub fn print_json<T: Serialize + ?Sized>(ctx: &Context, value: &T) {
+    let json = match QUERY.get() {
+        Some(query) => {
+            serde_json::to_string_pretty(&query.apply(&serde_json::to_value(value).unwrap()))
+        }
+        None => serde_json::to_string_pretty(value),
+    };
+    outln!(ctx, "{}", json.unwrap());
+}
+
+/// Why a run failed; each code is also the process exit status.
+///
+/// The numbers are stable: scripts may rely on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// Anything without a more specific code
+    Error,
+    /// The puzzle has no key, public key or address to work with
+    NoKey,
+    /// A balance, price or other remote lookup failed
+    #[cfg_attr(not(any(feature = "balance", feature = "price")), allow(dead_code))]
+    Network,
+    /// No puzzle or collection matches
+    NotFound,
+    /// A key, claim or dataset check did not pass
+    CheckFailed,
+    /// The arguments were rejected before the command ran
+    Usage,
+}
+
+impl ErrorCode {
+    pub const fn exit_code(self) -> i32 {
+        match self {
+            Self::Error => 1,
+            Self::NoKey => 2,
+            Self::Network => 3,
+            Self::NotFound => 4,
+            Self::CheckFailed => 5,
+            Self::Usage => 64,
+        }
+    }
+}
+
+/// A fatal error, printed as `{"error": {...}}` on stderr under
+/// `-o json`/`-o jsonl` and as a plain line otherwise.
+#[derive(Debug, Serialize)]
+pub struct CliError {
+    pub code: ErrorCode,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
+}
+
+impl CliError {
+    pub fn new(code: ErrorCode, message: impl std::fmt::Display) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+            id: None,
+            suggestions: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn exit(self) -> ! {
+        match ERROR_FORMAT.get() {
+            Some(OutputFormat::Json | OutputFormat::Jsonl) => {
+                #[derive(Serialize)]
+                struct Wrapper<'a> {
+                    error: &'a CliError,
+                }
+                eprintln!(
+                    "{}",
+                    serde_json::to_string(&Wrapper { error: &self }).unwrap()
+                );
+            }
+            _ => eprintln!("{} {}", "Error:".red().bold(), self.message),
+        }
+        exit(self.code.exit_code());
+    }
+}
+
+impl From<boha::Error> for CliError {
+    fn from(error: boha::Error) -> Self {
+        let message = error.to_string();
+        match error {
+            boha::Error::NotFound { id, suggestions } => Self {
+                suggestions,
+                ..Self::new(ErrorCode::NotFound, message).id(id)
+            },
+            boha::Error::InvalidNumber(_) | boha::Error::InvalidCollection(_) => {
+                Self::new(ErrorCode::NotFound, message)
+            }
+            _ => Self::new(ErrorCode::Error, message),
+        }
+    }
+}
+
+/// Choose how later errors are printed; called once in `main`.
+pub fn set_error_format(format: OutputFormat) {
+    let _ = ERROR_FORMAT.set(format);
+}
+
+pub fn fail(code: ErrorCode, message: impl std::fmt::Display) -> ! {
+    CliError::new(code, message).exit()
+}
+
+pub fn error_exit(message: impl std::fmt::Display) -> ! {
+    fail(ErrorCode::Error, message)
+}
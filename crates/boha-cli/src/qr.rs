@@ -0,0 +1,44 @@
+//! Terminal QR codes, so addresses and keys can be scanned instead of typed.
+
+use clap::ValueEnum;
+use qrcode::render::unicode::Dense1x2;
+use qrcode::QrCode;
+
+use crate::context::Context;
+use crate::output::{error_exit, outln};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum QrTarget {
+    Address,
+    Pubkey,
+}
+
+impl QrTarget {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Address => "address",
+            Self::Pubkey => "public key",
+        }
+    }
+}
+
+/// `value` as half-height block characters, light modules drawn, for the
+/// usual dark terminal background.
+pub fn render(value: &str) -> String {
+    let code = QrCode::new(value.as_bytes())
+        .unwrap_or_else(|e| error_exit(format!("Cannot encode {} as a QR code: {}", value, e)));
+    code.render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .build()
+}
+
+/// Print the code above its value; on stderr when stdout carries
+/// structured output that must stay parseable.
+pub fn print(ctx: &Context, value: &str, stderr: bool) {
+    if stderr {
+        eprintln!("{}\n{}", render(value), value);
+    } else {
+        outln!(ctx, "{}\n{}", render(value), value);
+    }
+}
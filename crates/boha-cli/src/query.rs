@@ -0,0 +1,512 @@
+//! The JMESPath subset behind `--query`: fields (`address.value`, quoted
+//! `"key"`), `@`, indexes (`[0]`, `[-1]`), projections (`[]`, `[*]`, `.*`),
+//! filters (``[?status == 'unsolved' && prize > `1`]``), multi-select
+//! lists and hashes (`[id, prize]`, `{id: id, bits: key.bits}`) and pipes.
+//!
+//! Ordering comparisons work on numbers and on strings, so dates can be
+//! filtered too. Functions are not supported.
+
+use std::cmp::Ordering;
+
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone)]
+pub struct Query {
+    /// Pipe-separated stages; a pipe ends any projection.
+    stages: Vec<Vec<Step>>,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Current,
+    Field(String),
+    Index(i64),
+    /// `[]`: flatten one level, then project.
+    Flatten,
+    /// `[*]`: project over an array.
+    Elements,
+    /// `.*`: project over an object's values.
+    Values,
+    Filter(Condition),
+    List(Vec<Query>),
+    Hash(Vec<(String, Query)>),
+}
+
+#[derive(Debug, Clone)]
+enum Condition {
+    Or(Box<Condition>, Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+    Compare(Operand, Comparator, Operand),
+    Truthy(Operand),
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Literal(Value),
+    Path(Query),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Query {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            chars: expression.chars().collect(),
+            pos: 0,
+        };
+        let query = parser.query()?;
+        parser.skip_space();
+        match parser.peek() {
+            None => Ok(query),
+            Some(c) => Err(parser.error(&format!("unexpected '{}'", c))),
+        }
+    }
+
+    pub fn apply(&self, value: &Value) -> Value {
+        self.stages
+            .iter()
+            .fold(value.clone(), |value, steps| eval(steps, &value))
+    }
+}
+
+fn eval(steps: &[Step], value: &Value) -> Value {
+    let Some((step, rest)) = steps.split_first() else {
+        return value.clone();
+    };
+    // A projection runs the steps up to the next `[]` on each item; that
+    // flatten then applies to the projected list as a whole.
+    let project = |items: Vec<&Value>| {
+        let end = rest
+            .iter()
+            .position(|step| matches!(step, Step::Flatten))
+            .unwrap_or(rest.len());
+        let projected = Value::Array(
+            items
+                .into_iter()
+                .map(|item| eval(&rest[..end], item))
+                .filter(|v| !v.is_null())
+                .collect(),
+        );
+        eval(&rest[end..], &projected)
+    };
+    match (step, value) {
+        (Step::Current, _) => eval(rest, value),
+        (Step::Field(name), Value::Object(map)) => {
+            map.get(name).map_or(Value::Null, |v| eval(rest, v))
+        }
+        (Step::Index(index), Value::Array(items)) => {
+            let len = i64::try_from(items.len()).unwrap_or(i64::MAX);
+            let at = if *index < 0 { len + index } else { *index };
+            usize::try_from(at)
+                .ok()
+                .and_then(|at| items.get(at))
+                .map_or(Value::Null, |v| eval(rest, v))
+        }
+        (Step::Flatten, Value::Array(items)) => project(
+            items
+                .iter()
+                .flat_map(|item| match item {
+                    Value::Array(inner) => inner.iter().collect(),
+                    other => vec![other],
+                })
+                .collect(),
+        ),
+        (Step::Elements, Value::Array(items)) => project(items.iter().collect()),
+        (Step::Values, Value::Object(map)) => project(map.values().collect()),
+        (Step::Filter(condition), Value::Array(items)) => {
+            project(items.iter().filter(|item| condition.holds(item)).collect())
+        }
+        (Step::List(queries), value) if !value.is_null() => eval(
+            rest,
+            &Value::Array(queries.iter().map(|q| q.apply(value)).collect()),
+        ),
+        (Step::Hash(pairs), value) if !value.is_null() => eval(
+            rest,
+            &Value::Object(
+                pairs
+                    .iter()
+                    .map(|(key, q)| (key.clone(), q.apply(value)))
+                    .collect::<Map<_, _>>(),
+            ),
+        ),
+        _ => Value::Null,
+    }
+}
+
+impl Condition {
+    fn holds(&self, value: &Value) -> bool {
+        match self {
+            Condition::Or(a, b) => a.holds(value) || b.holds(value),
+            Condition::And(a, b) => a.holds(value) && b.holds(value),
+            Condition::Not(c) => !c.holds(value),
+            Condition::Truthy(operand) => truthy(&operand.resolve(value)),
+            Condition::Compare(a, comparator, b) => {
+                let (a, b) = (a.resolve(value), b.resolve(value));
+                match comparator {
+                    Comparator::Eq => same(&a, &b),
+                    Comparator::Ne => !same(&a, &b),
+                    Comparator::Lt => order(&a, &b) == Some(Ordering::Less),
+                    Comparator::Le => order(&a, &b).is_some_and(Ordering::is_le),
+                    Comparator::Gt => order(&a, &b) == Some(Ordering::Greater),
+                    Comparator::Ge => order(&a, &b).is_some_and(Ordering::is_ge),
+                }
+            }
+        }
+    }
+}
+
+impl Operand {
+    fn resolve(&self, value: &Value) -> Value {
+        match self {
+            Operand::Literal(literal) => literal.clone(),
+            Operand::Path(query) => query.apply(value),
+        }
+    }
+}
+
+/// JMESPath truthiness: null, false and empty strings, arrays and objects
+/// are false.
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(false) => false,
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+        _ => true,
+    }
+}
+
+/// Equal values, with numbers compared by value so `1` matches `1.0`.
+#[allow(clippy::float_cmp)]
+fn same(a: &Value, b: &Value) -> bool {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn order(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> String {
+        format!("{} at position {}", message, self.pos + 1)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_space(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consume `token` (after any whitespace) if it comes next.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_space();
+        let end = self.pos + token.chars().count();
+        if end <= self.chars.len() && self.chars[self.pos..end].iter().copied().eq(token.chars()) {
+            self.pos = end;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", token)))
+        }
+    }
+
+    fn query(&mut self) -> Result<Query, String> {
+        let mut stages = vec![self.path()?];
+        while self.peek_pipe() {
+            self.expect("|")?;
+            stages.push(self.path()?);
+        }
+        Ok(Query { stages })
+    }
+
+    /// A `|` that is not the start of `||`.
+    fn peek_pipe(&mut self) -> bool {
+        self.skip_space();
+        self.peek() == Some('|') && self.chars.get(self.pos + 1) != Some(&'|')
+    }
+
+    fn path(&mut self) -> Result<Vec<Step>, String> {
+        self.skip_space();
+        let mut steps = vec![match self.peek() {
+            Some('@') => {
+                self.pos += 1;
+                Step::Current
+            }
+            Some('[') => self.bracket()?,
+            Some('{') => self.hash()?,
+            Some('*') => {
+                self.pos += 1;
+                Step::Values
+            }
+            _ => Step::Field(self.identifier()?),
+        }];
+        loop {
+            self.skip_space();
+            match self.peek() {
+                Some('.') => {
+                    self.pos += 1;
+                    self.skip_space();
+                    steps.push(match self.peek() {
+                        Some('*') => {
+                            self.pos += 1;
+                            Step::Values
+                        }
+                        Some('[') => {
+                            self.pos += 1;
+                            self.list()?
+                        }
+                        Some('{') => self.hash()?,
+                        _ => Step::Field(self.identifier()?),
+                    });
+                }
+                Some('[') => steps.push(self.bracket()?),
+                _ => return Ok(steps),
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, String> {
+        self.skip_space();
+        if self.peek() == Some('"') {
+            let start = self.pos;
+            self.pos += 1;
+            while self.peek().is_some_and(|c| c != '"') {
+                if self.peek() == Some('\\') {
+                    self.pos += 1;
+                }
+                self.pos += 1;
+            }
+            self.expect("\"")?;
+            let quoted: String = self.chars[start..self.pos].iter().collect();
+            return serde_json::from_str(&quoted).map_err(|e| self.error(&e.to_string()));
+        }
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.pos += 1;
+        }
+        if start == self.pos || self.chars[start].is_ascii_digit() {
+            self.pos = start;
+            return Err(self.error("expected a field name"));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn bracket(&mut self) -> Result<Step, String> {
+        self.expect("[")?;
+        if self.eat("]") {
+            return Ok(Step::Flatten);
+        }
+        if self.eat("*") {
+            self.expect("]")?;
+            return Ok(Step::Elements);
+        }
+        if self.eat("?") {
+            let condition = self.or()?;
+            self.expect("]")?;
+            return Ok(Step::Filter(condition));
+        }
+        self.skip_space();
+        if self.peek().is_some_and(|c| c == '-' || c.is_ascii_digit()) {
+            let start = self.pos;
+            self.pos += 1;
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            let digits: String = self.chars[start..self.pos].iter().collect();
+            let index = digits
+                .parse()
+                .map_err(|_| self.error(&format!("bad index {}", digits)))?;
+            self.expect("]")?;
+            return Ok(Step::Index(index));
+        }
+        self.list()
+    }
+
+    /// The rest of `[a, b]`, after the opening bracket.
+    fn list(&mut self) -> Result<Step, String> {
+        let mut queries = vec![self.query()?];
+        while self.eat(",") {
+            queries.push(self.query()?);
+        }
+        self.expect("]")?;
+        Ok(Step::List(queries))
+    }
+
+    fn hash(&mut self) -> Result<Step, String> {
+        self.expect("{")?;
+        let mut pairs = Vec::new();
+        loop {
+            let key = self.identifier()?;
+            self.expect(":")?;
+            pairs.push((key, self.query()?));
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect("}")?;
+        Ok(Step::Hash(pairs))
+    }
+
+    fn or(&mut self) -> Result<Condition, String> {
+        let mut condition = self.and()?;
+        while self.eat("||") {
+            condition = Condition::Or(Box::new(condition), Box::new(self.and()?));
+        }
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, String> {
+        let mut condition = self.not()?;
+        while self.eat("&&") {
+            condition = Condition::And(Box::new(condition), Box::new(self.not()?));
+        }
+        Ok(condition)
+    }
+
+    fn not(&mut self) -> Result<Condition, String> {
+        if self.eat("!") {
+            return Ok(Condition::Not(Box::new(self.not()?)));
+        }
+        if self.eat("(") {
+            let condition = self.or()?;
+            self.expect(")")?;
+            return Ok(condition);
+        }
+        let left = self.operand()?;
+        let comparator = [
+            ("==", Comparator::Eq),
+            ("!=", Comparator::Ne),
+            ("<=", Comparator::Le),
+            (">=", Comparator::Ge),
+            ("<", Comparator::Lt),
+            (">", Comparator::Gt),
+        ]
+        .into_iter()
+        .find(|(token, _)| self.eat(token));
+        Ok(match comparator {
+            Some((_, comparator)) => Condition::Compare(left, comparator, self.operand()?),
+            None => Condition::Truthy(left),
+        })
+    }
+
+    fn operand(&mut self) -> Result<Operand, String> {
+        self.skip_space();
+        match self.peek() {
+            Some('\'') => {
+                self.pos += 1;
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c != '\'') {
+                    self.pos += 1;
+                }
+                let raw: String = self.chars[start..self.pos].iter().collect();
+                self.expect("'")?;
+                Ok(Operand::Literal(Value::String(raw)))
+            }
+            Some('`') => {
+                self.pos += 1;
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c != '`') {
+                    self.pos += 1;
+                }
+                let json: String = self.chars[start..self.pos].iter().collect();
+                let literal = serde_json::from_str(&json)
+                    .map_err(|e| self.error(&format!("bad literal `{}`: {}", json, e)))?;
+                self.expect("`")?;
+                Ok(Operand::Literal(literal))
+            }
+            _ => Ok(Operand::Path(Query {
+                stages: vec![self.path()?],
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn query(expression: &str, value: &Value) -> Value {
+        Query::parse(expression).unwrap().apply(value)
+    }
+
+    #[test]
+    fn projections_and_filters() {
+        let puzzles = json!([
+            {"id": "b1000/1", "status": "solved", "prize": 0.001, "address": {"value": "1a"}},
+            {"id": "b1000/71", "status": "unsolved", "prize": 7.1, "address": {"value": "1b"}},
+            {"id": "gsmg", "status": "solved", "prize": null, "address": {"value": "1c"}},
+        ]);
+        assert_eq!(
+            query("[].address.value", &puzzles),
+            json!(["1a", "1b", "1c"])
+        );
+        assert_eq!(query("[*].prize", &puzzles), json!([0.001, 7.1]));
+        assert_eq!(
+            query("[?status == 'unsolved'].id", &puzzles),
+            json!(["b1000/71"])
+        );
+        assert_eq!(
+            query("[?prize > `1` || !prize].id | [0]", &puzzles),
+            json!("b1000/71")
+        );
+        assert_eq!(
+            query("[-1].{id: id, addr: address.value}", &puzzles),
+            json!({"id": "gsmg", "addr": "1c"})
+        );
+        assert_eq!(
+            query("[?id != 'gsmg'].[id, prize][]", &puzzles),
+            json!(["b1000/1", 0.001, "b1000/71", 7.1])
+        );
+        assert_eq!(
+            query("[*].[id][]", &puzzles),
+            json!(["b1000/1", "b1000/71", "gsmg"])
+        );
+        assert_eq!(query("stats.total", &puzzles), Value::Null);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_problem() {
+        assert_eq!(
+            Query::parse("[].address.").unwrap_err(),
+            "expected a field name at position 12"
+        );
+        assert!(Query::parse("[?status ==]").is_err());
+        assert!(Query::parse("a b").is_err());
+    }
+}
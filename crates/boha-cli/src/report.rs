@@ -0,0 +1,313 @@
+//! Human-readable export reports: Markdown for wikis, HTML for a static page.
+
+use std::fmt::Write;
+
+use boha::{Author, Puzzle, Stats};
+
+/// One collection of the report.
+pub struct Section {
+    pub name: &'static str,
+    pub author: Option<&'static Author>,
+    pub puzzles: Vec<&'static Puzzle>,
+}
+
+/// Header shared by both renderings.
+pub struct Report<'a> {
+    pub version: &'a str,
+    pub exported_at: &'a str,
+    pub stats: Option<&'a Stats>,
+    pub sections: Vec<Section>,
+}
+
+const TITLE: &str = "boha puzzle report";
+const COLUMNS: [&str; 6] = ["Puzzle", "Address", "Status", "Prize", "Solved", "Source"];
+const SUMMARY_COLUMNS: [&str; 7] = [
+    "Total",
+    "Solved",
+    "Unsolved",
+    "Claimed",
+    "Swept",
+    "Expired",
+    "With pubkey",
+];
+
+/// A puzzle as table cells, links kept separate from their text.
+struct Row {
+    id: &'static str,
+    address: &'static str,
+    explorer_url: String,
+    status: String,
+    prize: String,
+    solved: String,
+    source_url: Option<&'static str>,
+}
+
+impl Row {
+    fn new(puzzle: &'static Puzzle) -> Self {
+        Self {
+            id: puzzle.id,
+            address: puzzle.address.value,
+            explorer_url: puzzle.explorer_url(),
+            status: puzzle.status.to_string(),
+            prize: puzzle
+                .prize
+                .map_or_else(String::new, |p| format!("{} {}", p, puzzle.currency())),
+            solved: puzzle
+                .solve_date
+                .map_or_else(String::new, |d| d.naive_date().to_string()),
+            source_url: puzzle.source_url,
+        }
+    }
+}
+
+fn summary_cells(stats: &Stats) -> [usize; 7] {
+    [
+        stats.total,
+        stats.solved,
+        stats.unsolved,
+        stats.claimed,
+        stats.swept,
+        stats.expired,
+        stats.with_pubkey,
+    ]
+}
+
+/// `"12.5 BTC, 3 ETH"`, sorted by currency.
+pub fn prizes(prizes: &std::collections::HashMap<String, f64>) -> String {
+    let mut prizes: Vec<_> = prizes.iter().filter(|(_, v)| **v > 0.0).collect();
+    prizes.sort_by(|a, b| a.0.cmp(b.0));
+    prizes
+        .iter()
+        .map(|(currency, amount)| {
+            // Sums of f64 prizes pick up noise past the 8th decimal (satoshis).
+            let amount = format!("{:.8}", amount);
+            let amount = amount.trim_end_matches('0').trim_end_matches('.');
+            format!("{} {}", amount, currency)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn author_name(author: &Author) -> &'static str {
+    author.name.unwrap_or("Anonymous")
+}
+
+pub fn markdown(report: &Report<'_>) -> String {
+    let mut out = String::new();
+    writeln!(out, "# {}\n", TITLE).unwrap();
+    writeln!(
+        out,
+        "Exported {} by boha {}.\n",
+        report.exported_at, report.version
+    )
+    .unwrap();
+
+    if let Some(stats) = report.stats {
+        writeln!(out, "## Summary\n").unwrap();
+        md_row(&mut out, SUMMARY_COLUMNS);
+        md_row(&mut out, SUMMARY_COLUMNS.map(|_| "---"));
+        md_row(&mut out, summary_cells(stats).map(|n| n.to_string()));
+        writeln!(out).unwrap();
+        for (label, map) in [
+            ("Total prizes", &stats.total_prize),
+            ("Unsolved prizes", &stats.unsolved_prize),
+        ] {
+            let text = prizes(map);
+            if !text.is_empty() {
+                writeln!(out, "- {}: {}", label, text).unwrap();
+            }
+        }
+        writeln!(out).unwrap();
+    }
+
+    for section in &report.sections {
+        writeln!(out, "## {}\n", md_escape(section.name)).unwrap();
+        if let Some(author) = section.author {
+            let mut line = format!("Author: {}", md_escape(author_name(author)));
+            for profile in author.profiles {
+                write!(line, " · [{}]({})", md_escape(profile.name), profile.url).unwrap();
+            }
+            writeln!(out, "{}\n", line).unwrap();
+        }
+        md_row(&mut out, COLUMNS);
+        md_row(&mut out, COLUMNS.map(|_| "---"));
+        for row in section.puzzles.iter().map(|p| Row::new(p)) {
+            md_row(
+                &mut out,
+                [
+                    md_escape(row.id),
+                    format!("[{}]({})", md_escape(row.address), row.explorer_url),
+                    row.status,
+                    row.prize,
+                    row.solved.clone(),
+                    row.source_url
+                        .map_or_else(String::new, |url| format!("[link]({})", url)),
+                ],
+            );
+        }
+        writeln!(out).unwrap();
+    }
+    out
+}
+
+fn md_row<S: AsRef<str>>(out: &mut String, cells: impl IntoIterator<Item = S>) {
+    out.push('|');
+    for cell in cells {
+        write!(out, " {} |", cell.as_ref()).unwrap();
+    }
+    out.push('\n');
+}
+
+/// Keep cell text from breaking the table or turning into markup.
+fn md_escape(text: &str) -> String {
+    text.chars()
+        .flat_map(|c| {
+            let escape = matches!(c, '|' | '\\' | '*' | '_' | '[' | ']' | '`');
+            escape.then_some('\\').into_iter().chain([c])
+        })
+        .collect()
+}
+
+/// Stylesheet of the HTML report, also written out by `boha site`.
+pub const STYLE: &str = include_str!("../templates/style.css");
+
+pub fn html(report: &Report<'_>) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>",
+        TITLE, STYLE
+    )
+    .unwrap();
+    writeln!(out, "<h1>{}</h1>", TITLE).unwrap();
+    writeln!(
+        out,
+        "<p>Exported {} by boha {}.</p>",
+        html_escape(report.exported_at),
+        html_escape(report.version)
+    )
+    .unwrap();
+
+    if let Some(stats) = report.stats {
+        writeln!(out, "<h2>Summary</h2>").unwrap();
+        html_summary(&mut out, stats);
+    }
+
+    for section in &report.sections {
+        writeln!(out, "<h2 id=\"{0}\">{0}</h2>", html_escape(section.name)).unwrap();
+        if let Some(author) = section.author {
+            html_author(&mut out, author);
+        }
+        html_puzzle_table(&mut out, &section.puzzles, |_| None);
+    }
+    writeln!(out, "</body>\n</html>").unwrap();
+    out
+}
+
+/// Status counts table followed by the prize totals.
+pub fn html_summary(out: &mut String, stats: &Stats) {
+    writeln!(out, "<table>").unwrap();
+    html_row(out, "th", SUMMARY_COLUMNS.map(html_escape));
+    html_row(out, "td", summary_cells(stats).map(|n| n.to_string()));
+    writeln!(out, "</table>\n<ul>").unwrap();
+    for (label, map) in [
+        ("Total prizes", &stats.total_prize),
+        ("Unsolved prizes", &stats.unsolved_prize),
+    ] {
+        let text = prizes(map);
+        if !text.is_empty() {
+            writeln!(out, "<li>{}: {}</li>", label, html_escape(&text)).unwrap();
+        }
+    }
+    writeln!(out, "</ul>").unwrap();
+}
+
+pub fn html_author(out: &mut String, author: &Author) {
+    write!(out, "<p>Author: {}", html_escape(author_name(author))).unwrap();
+    for profile in author.profiles {
+        write!(
+            out,
+            " · <a href=\"{}\">{}</a>",
+            html_escape(profile.url),
+            html_escape(profile.name)
+        )
+        .unwrap();
+    }
+    writeln!(out, "</p>").unwrap();
+}
+
+/// One row per puzzle; `page` gives the link for the id cell, if any.
+pub fn html_puzzle_table(
+    out: &mut String,
+    puzzles: &[&'static Puzzle],
+    page: impl Fn(&Puzzle) -> Option<String>,
+) {
+    writeln!(out, "<table>").unwrap();
+    html_row(out, "th", COLUMNS.map(html_escape));
+    for puzzle in puzzles {
+        let row = Row::new(puzzle);
+        let id = page(puzzle).map_or_else(
+            || html_escape(row.id),
+            |href| {
+                format!(
+                    "<a href=\"{}\">{}</a>",
+                    html_escape(&href),
+                    html_escape(row.id)
+                )
+            },
+        );
+        writeln!(
+            out,
+            "<tr><td>{}</td><td class=\"address\"><a href=\"{}\">{}</a></td><td class=\"{}\">{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            id,
+            html_escape(&row.explorer_url),
+            html_escape(row.address),
+            row.status,
+            row.status,
+            html_escape(&row.prize),
+            row.solved,
+            row.source_url.map_or_else(String::new, |url| {
+                format!("<a href=\"{}\">link</a>", html_escape(url))
+            }),
+        )
+        .unwrap();
+    }
+    writeln!(out, "</table>").unwrap();
+}
+
+pub fn html_row(out: &mut String, cell: &str, cells: impl IntoIterator<Item = String>) {
+    out.push_str("<tr>");
+    for text in cells {
+        write!(out, "<{0}>{1}</{0}>", cell, text).unwrap();
+    }
+    out.push_str("</tr>\n");
+}
+
+pub fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_cell_text() {
+        assert_eq!(md_escape("a|b_c"), "a\\|b\\_c");
+        assert_eq!(
+            html_escape("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! The balance snapshot embedded with `--features snapshot`, for machines
+//! that cannot reach an explorer.
+
+use chrono::{NaiveDateTime, Utc};
+
+use crate::output::error_exit;
+
+/// When the snapshot was taken; exits when this build embeds none.
+pub fn taken_at_or_exit() -> &'static str {
+    boha::snapshot::taken_at().unwrap_or_else(|| {
+        error_exit(
+            "This build has no balance snapshot; run snapshot-balances in scripts/ and rebuild",
+        )
+    })
+}
+
+/// Whole days between `taken_at` and now.
+pub fn age_days(taken_at: &str) -> i64 {
+    NaiveDateTime::parse_from_str(taken_at, "%Y-%m-%d %H:%M:%S")
+        .map_or(0, |taken| (Utc::now().naive_utc() - taken).num_days())
+}
+
+/// `2026-01-02 10:00:00 (12 days old)`.
+pub fn label(taken_at: &str) -> String {
+    match age_days(taken_at) {
+        1 => format!("{} (1 day old)", taken_at),
+        days => format!("{} ({} days old)", taken_at, days),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_counts_days() {
+        let day = |days: i64| {
+            (Utc::now().naive_utc() - chrono::Duration::days(days) - chrono::Duration::hours(1))
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        };
+        assert_eq!(age_days(&day(12)), 12);
+        assert!(label(&day(1)).ends_with("(1 day old)"));
+        assert!(label(&day(0)).ends_with("(0 days old)"));
+    }
+}
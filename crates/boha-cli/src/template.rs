@@ -0,0 +1,198 @@
+//! User-shaped text output for `list`, `show` and `search`.
+//!
+//! Inline templates (`--template`) take `{field}` placeholders, where
+//! `field` is a dotted path into the record's JSON (`{key.bits}`). Fields
+//! holding an object with a `value` (`address`, `pubkey`) print that value,
+//! arrays print comma-separated, nulls print nothing, and `{{`/`}}` are
+//! literal braces. Template files
+//! (`--template-file`) are Handlebars, rendered with the same record.
+
+use std::path::PathBuf;
+
+use clap::Args;
+use handlebars::Handlebars;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::context::Context;
+use crate::output::{error_exit, out, outln};
+
+/// Template options shared by the commands that print puzzles.
+#[derive(Debug, Clone, Args)]
+pub struct TemplateArgs {
+    /// Print each result through a template instead of -o, e.g. '{id}\t{address}\t{prize}'
+    #[arg(long, value_name = "TEMPLATE", conflicts_with = "template_file")]
+    pub template: Option<String>,
+
+    /// Print each result through a Handlebars template file, e.g. '{{id}}: {{address.value}}'
+    #[arg(long, value_name = "PATH")]
+    pub template_file: Option<PathBuf>,
+}
+
+impl TemplateArgs {
+    /// The template to render with, exiting on a malformed one.
+    pub fn template(&self) -> Option<Template> {
+        if let Some(inline) = &self.template {
+            return Some(Template::Inline(parse(inline).unwrap_or_else(|e| {
+                error_exit(format!("Invalid --template: {}", e))
+            })));
+        }
+        let path = self.template_file.as_ref()?;
+        let source = std::fs::read_to_string(path)
+            .unwrap_or_else(|e| error_exit(format!("Cannot read {}: {}", path.display(), e)));
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+        handlebars
+            .register_template_string("file", source)
+            .unwrap_or_else(|e| error_exit(format!("Invalid {}: {}", path.display(), e)));
+        Some(Template::File(Box::new(handlebars)))
+    }
+}
+
+pub enum Template {
+    Inline(Vec<Piece>),
+    File(Box<Handlebars<'static>>),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Piece {
+    Text(String),
+    /// JSON pointer of a placeholder, with its name for errors.
+    Field {
+        name: String,
+        pointer: String,
+    },
+}
+
+impl Template {
+    /// Print `record` through the template; inline templates end the line.
+    pub fn print(&self, ctx: &Context, record: &impl Serialize) {
+        let value = serde_json::to_value(record).unwrap();
+        match self {
+            Template::Inline(pieces) => outln!(ctx, "{}", render(pieces, &value)),
+            Template::File(handlebars) => out!(
+                ctx,
+                "{}",
+                handlebars
+                    .render("file", &value)
+                    .unwrap_or_else(|e| error_exit(format!("Cannot render template: {}", e)))
+            ),
+        }
+    }
+}
+
+fn parse(template: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                text.push(c);
+            }
+            ('\\', Some(&escaped @ ('t' | 'n' | '\\'))) => {
+                chars.next();
+                text.push(match escaped {
+                    't' => '\t',
+                    'n' => '\n',
+                    _ => '\\',
+                });
+            }
+            ('{', _) => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(format!("unclosed placeholder {{{}", name)),
+                    }
+                }
+                let name = name.trim().to_string();
+                if name.is_empty()
+                    || !name
+                        .split('.')
+                        .all(|part| !part.is_empty() && part.chars().all(is_field_char))
+                {
+                    return Err(format!("bad placeholder {{{}}}", name));
+                }
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                }
+                let pointer = name
+                    .split('.')
+                    .fold(String::new(), |p, part| p + "/" + part);
+                pieces.push(Piece::Field { name, pointer });
+            }
+            ('}', _) => return Err("unmatched '}' (write '}}' for a literal brace)".to_string()),
+            _ => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+    Ok(pieces)
+}
+
+fn is_field_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn render(pieces: &[Piece], record: &Value) -> String {
+    let mut out = String::new();
+    for piece in pieces {
+        match piece {
+            Piece::Text(text) => out.push_str(text),
+            Piece::Field { name, pointer } => {
+                let value = record
+                    .pointer(pointer)
+                    .unwrap_or_else(|| error_exit(format!("Unknown template field {{{}}}", name)));
+                out.push_str(&text(value));
+            }
+        }
+    }
+    out
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Object(map) if map.contains_key("value") => text(&map["value"]),
+        Value::Array(items) => items.iter().map(text).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn placeholders_escapes_and_braces() {
+        let record = json!({
+            "id": "b1000/71",
+            "address": {"value": "1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU", "chain": "bitcoin"},
+            "prize": 7.1,
+            "solve_date": null,
+            "key": {"bits": 71},
+            "matched_fields": ["id", "address"],
+        });
+        let pieces =
+            parse(r"{id}\t{address}\t{prize}\t{solve_date}|{key.bits} {{x}} {matched_fields}")
+                .unwrap();
+        assert_eq!(
+            render(&pieces, &record),
+            "b1000/71\t1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU\t7.1\t|71 {x} id,address"
+        );
+    }
+
+    #[test]
+    fn malformed_templates_are_rejected() {
+        assert!(parse("{id").is_err());
+        assert!(parse("{}").is_err());
+        assert!(parse("{a..b}").is_err());
+        assert!(parse("id}").is_err());
+    }
+}
@@ -0,0 +1,279 @@
+//! BitTorrent v1 metainfo (`.torrent`) for dataset snapshots.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sha1::{Digest, Sha1};
+
+/// Default piece length, 256 KiB.
+pub const DEFAULT_PIECE_LENGTH: usize = 256 * 1024;
+
+enum Bencode {
+    Int(u64),
+    Bytes(Vec<u8>),
+    List(Vec<Bencode>),
+    Dict(BTreeMap<&'static str, Bencode>),
+}
+
+impl Bencode {
+    fn str(value: &str) -> Self {
+        Self::Bytes(value.as_bytes().to_vec())
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Int(value) => out.extend_from_slice(format!("i{}e", value).as_bytes()),
+            Self::Bytes(bytes) => {
+                out.extend_from_slice(format!("{}:", bytes.len()).as_bytes());
+                out.extend_from_slice(bytes);
+            }
+            Self::List(items) => {
+                out.push(b'l');
+                for item in items {
+                    item.encode(out);
+                }
+                out.push(b'e');
+            }
+            // BTreeMap keeps keys in the byte order bencode requires.
+            Self::Dict(entries) => {
+                out.push(b'd');
+                for (key, value) in entries {
+                    Self::str(key).encode(out);
+                    value.encode(out);
+                }
+                out.push(b'e');
+            }
+        }
+    }
+}
+
+pub struct Torrent {
+    /// Bencoded metainfo file contents.
+    pub metainfo: Vec<u8>,
+    /// SHA-1 of the bencoded `info` dictionary.
+    pub info_hash: [u8; 20],
+    pub name: String,
+}
+
+impl Torrent {
+    pub fn magnet(&self) -> String {
+        format!(
+            "magnet:?xt=urn:btih:{}&dn={}",
+            hex::encode(self.info_hash),
+            percent_encode(&self.name)
+        )
+    }
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Metainfo for the file or directory at `path`. No creation date is
+/// written, so the same snapshot always yields the same torrent.
+pub fn create(path: &Path, piece_length: usize, trackers: &[String]) -> io::Result<Torrent> {
+    let name = path
+        .canonicalize()?
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no UTF-8 name"))?
+        .to_string();
+
+    let files = if path.is_dir() {
+        let mut files = Vec::new();
+        collect_files(path, &mut Vec::new(), &mut files)?;
+        files
+    } else {
+        vec![(Vec::new(), path.to_path_buf())]
+    };
+
+    let mut hasher = Pieces::new(piece_length);
+    let mut entries = Vec::new();
+    let mut total = 0;
+    for (components, file) in &files {
+        let content = fs::read(file)?;
+        hasher.update(&content);
+        total += content.len() as u64;
+        entries.push(Bencode::Dict(BTreeMap::from([
+            ("length", Bencode::Int(content.len() as u64)),
+            (
+                "path",
+                Bencode::List(components.iter().map(|c| Bencode::str(c)).collect()),
+            ),
+        ])));
+    }
+
+    let mut info = BTreeMap::from([
+        ("name", Bencode::str(&name)),
+        ("piece length", Bencode::Int(piece_length as u64)),
+        ("pieces", Bencode::Bytes(hasher.finish())),
+    ]);
+    if path.is_dir() {
+        info.insert("files", Bencode::List(entries));
+    } else {
+        info.insert("length", Bencode::Int(total));
+    }
+    let info = Bencode::Dict(info);
+
+    let mut info_bytes = Vec::new();
+    info.encode(&mut info_bytes);
+    let info_hash: [u8; 20] = Sha1::digest(&info_bytes).into();
+
+    let mut root = BTreeMap::from([
+        (
+            "created by",
+            Bencode::str(&format!("boha {}", env!("CARGO_PKG_VERSION"))),
+        ),
+        ("info", info),
+    ]);
+    if let Some(first) = trackers.first() {
+        root.insert("announce", Bencode::str(first));
+    }
+    if trackers.len() > 1 {
+        root.insert(
+            "announce-list",
+            Bencode::List(
+                trackers
+                    .iter()
+                    .map(|t| Bencode::List(vec![Bencode::str(t)]))
+                    .collect(),
+            ),
+        );
+    }
+    let mut metainfo = Vec::new();
+    Bencode::Dict(root).encode(&mut metainfo);
+
+    Ok(Torrent {
+        metainfo,
+        info_hash,
+        name,
+    })
+}
+
+/// Files under `dir` with their path components, sorted by path.
+fn collect_files(
+    dir: &Path,
+    prefix: &mut Vec<String>,
+    out: &mut Vec<(Vec<String>, PathBuf)>,
+) -> io::Result<()> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?.collect::<Result<_, _>>()?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+    for entry in entries {
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("non UTF-8 file name: {}", name.to_string_lossy()),
+            )
+        })?;
+        prefix.push(name);
+        if entry.path().is_dir() {
+            collect_files(&entry.path(), prefix, out)?;
+        } else {
+            out.push((prefix.clone(), entry.path()));
+        }
+        prefix.pop();
+    }
+    Ok(())
+}
+
+/// SHA-1 of each `piece_length` slice of the concatenated files.
+struct Pieces {
+    piece_length: usize,
+    current: Sha1,
+    filled: usize,
+    hashes: Vec<u8>,
+}
+
+impl Pieces {
+    fn new(piece_length: usize) -> Self {
+        Self {
+            piece_length,
+            current: Sha1::new(),
+            filled: 0,
+            hashes: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = (self.piece_length - self.filled).min(data.len());
+            self.current.update(&data[..take]);
+            self.filled += take;
+            data = &data[take..];
+            if self.filled == self.piece_length {
+                let piece = std::mem::replace(&mut self.current, Sha1::new()).finalize();
+                self.hashes.extend_from_slice(&piece);
+                self.filled = 0;
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.filled > 0 {
+            self.hashes.extend_from_slice(&self.current.finalize());
+        }
+        self.hashes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bencode_sorts_dictionary_keys() {
+        let mut out = Vec::new();
+        Bencode::Dict(BTreeMap::from([
+            (
+                "spam",
+                Bencode::List(vec![Bencode::str("a"), Bencode::Int(42)]),
+            ),
+            ("cow", Bencode::str("moo")),
+        ]))
+        .encode(&mut out);
+        assert_eq!(out, b"d3:cow3:moo4:spaml1:ai42eee");
+    }
+
+    #[test]
+    fn pieces_span_file_boundaries() {
+        let mut pieces = Pieces::new(4);
+        pieces.update(b"abc");
+        pieces.update(b"defgh");
+        pieces.update(b"ij");
+        let hashes = pieces.finish();
+        assert_eq!(hashes.len(), 3 * 20);
+        assert_eq!(&hashes[..20], Sha1::digest(b"abcd").as_slice());
+        assert_eq!(&hashes[40..], Sha1::digest(b"ij").as_slice());
+    }
+
+    #[test]
+    fn directory_torrent_is_deterministic() {
+        let dir = std::env::temp_dir().join(format!("boha-torrent-{}", std::process::id()));
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("index.json"), "{}").unwrap();
+        fs::write(dir.join("sub/b1000.json"), "[]").unwrap();
+
+        let trackers = vec!["udp://tracker.example:1337".to_string()];
+        let first = create(&dir, 16, &trackers).unwrap();
+        let second = create(&dir, 16, &trackers).unwrap();
+        assert_eq!(first.metainfo, second.metainfo);
+        assert_eq!(first.info_hash, second.info_hash);
+
+        let text = String::from_utf8_lossy(&first.metainfo);
+        assert!(text.contains("8:announce26:udp://tracker.example:1337"));
+        assert!(text.contains("4:pathl3:sub10:b1000.jsonee"));
+        assert!(first.magnet().starts_with("magnet:?xt=urn:btih:"));
+        assert_eq!(percent_encode("boha export"), "boha%20export");
+        fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,219 @@
+//! Small helpers shared across commands.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::color::Colorize;
+use crate::output::{CliError, ErrorCode};
+use boha::{Collection, Puzzle, Status};
+
+pub fn collection_help(include_all: bool) -> String {
+    let mut names: Vec<_> = boha::collections()
+        .into_iter()
+        .map(|collection| match collection.aliases().as_slice() {
+            [] => collection.name().to_string(),
+            aliases => format!("{} ({})", collection.name(), aliases.join(", ")),
+        })
+        .collect();
+
+    if include_all {
+        names.push("all".to_string());
+    }
+
+    names.join(", ")
+}
+
+pub fn collection_or_exit(name: &str, include_all: bool) -> Collection {
+    Collection::parse(name).unwrap_or_else(|_| {
+        CliError::new(
+            ErrorCode::NotFound,
+            format!(
+                "Unknown collection: {}. Use: {}",
+                name,
+                collection_help(include_all)
+            ),
+        )
+        .id(name)
+        .exit()
+    })
+}
+
+/// A puzzle by id, with bare numbers taken as b1000 puzzles.
+pub fn puzzle_or_exit(id: &str) -> &'static Puzzle {
+    match id.parse::<u32>() {
+        Ok(number) => boha::b1000::get(number),
+        Err(_) => boha::get(id),
+    }
+    .unwrap_or_else(|e| CliError::from(e).exit())
+}
+
+/// The id `build.rs` gives an entry of `data/<collection>.jsonc`: by name,
+/// by key bits (b1000), or the bare collection for single-puzzle files.
+pub fn data_file_id(collection: &str, puzzle: &serde_json::Value) -> String {
+    if let Some(name) = puzzle["name"].as_str() {
+        format!("{}/{}", collection, name)
+    } else if let Some(bits) = puzzle["key"]["bits"].as_u64() {
+        format!("{}/{}", collection, bits)
+    } else {
+        collection.to_string()
+    }
+}
+
+/// Whether `key` is a 64-digit hex private key (optionally `0x`-prefixed)
+/// rather than a WIF.
+pub fn is_hex_key(key: &str) -> bool {
+    let digits = key.strip_prefix("0x").unwrap_or(key);
+    digits.len() == 64 && digits.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// `arg` quoted for a POSIX shell when it needs it.
+pub fn shell_quote(arg: &str) -> String {
+    let plain = arg
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./:=".contains(c));
+    if plain && !arg.is_empty() {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// Hidden sibling of `path` to write to before renaming over it.
+pub fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()))
+}
+
+/// Write `contents` to `path` through a temporary file, so readers see the
+/// old file or the new one, never a partial write.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let temp = temp_path(path);
+    std::fs::write(&temp, contents)
+        .and_then(|()| std::fs::rename(&temp, path))
+        .inspect_err(|_| {
+            std::fs::remove_file(&temp).ok();
+        })
+}
+
+/// Lowercase status label, colored for table output.
+pub fn status_colored(status: Status) -> String {
+    match status {
+        Status::Solved => "solved".green().to_string(),
+        Status::Unsolved => "unsolved".yellow().to_string(),
+        Status::Claimed => "claimed".cyan().to_string(),
+        Status::Swept => "swept".red().to_string(),
+        Status::Expired => "expired".dimmed().to_string(),
+    }
+}
+
+/// Parse a `--currency` value: a fiat code such as `usd` or `EUR`.
+#[cfg(feature = "price")]
+pub fn parse_currency(value: &str) -> Result<String, String> {
+    if (3..=5).contains(&value.len()) && value.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(value.to_lowercase())
+    } else {
+        Err(format!(
+            "expected a currency code like usd or eur, got {}",
+            value
+        ))
+    }
+}
+
+/// Run `future` to completion on a fresh thread and runtime.
+///
+/// `stats`, `show` and `export` are synchronous; the thread keeps this safe
+/// should one ever be called from inside a runtime, where blocking panics.
+#[cfg(feature = "price")]
+fn block_on<F>(future: F) -> F::Output
+where
+    F: std::future::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    std::thread::spawn(move || {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start async runtime")
+            .block_on(future)
+    })
+    .join()
+    .expect("price lookup thread panicked")
+}
+
+/// Spot prices of `symbols` in `currency`, exiting on failure.
+#[cfg(feature = "price")]
+pub fn prices_or_exit(symbols: &[&str], currency: &str) -> boha_chain::price::Prices {
+    let symbols: Vec<String> = symbols.iter().map(|s| (*s).to_string()).collect();
+    let currency = currency.to_string();
+    block_on(async move {
+        let symbols: Vec<&str> = symbols.iter().map(String::as_str).collect();
+        boha_chain::price::fetch(&symbols, &currency).await
+    })
+    .unwrap_or_else(|e| {
+        crate::output::fail(ErrorCode::Network, format!("Price lookup failed: {}", e))
+    })
+}
+
+/// Prize value at solve date for each of `puzzles`, exiting on failure.
+///
+/// Lookups run one at a time through the on-disk price cache, so a run cut
+/// short by rate limiting resumes where it stopped.
+#[cfg(feature = "price")]
+pub fn historical_values_or_exit(
+    puzzles: &[&'static boha::Puzzle],
+    currency: &str,
+) -> Vec<Option<boha_chain::price::HistoricalValue>> {
+    let puzzles = puzzles.to_vec();
+    let currency = currency.to_string();
+    block_on(async move {
+        let cache = boha_chain::price::PriceCache::open_default();
+        let mut values = Vec::with_capacity(puzzles.len());
+        for puzzle in puzzles {
+            let value = boha_chain::price::historical_value(puzzle, &currency, cache.as_ref())
+                .await
+                .map_err(|e| format!("{}: {}", puzzle.id, e))?;
+            values.push(value);
+        }
+        Ok::<_, String>(values)
+    })
+    .unwrap_or_else(|e| {
+        crate::output::fail(
+            ErrorCode::Network,
+            format!("Historical price lookup failed for {}", e),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn data_file_ids_follow_build_rules() {
+        assert_eq!(
+            data_file_id("zden", &json!({"name": "Level 1"})),
+            "zden/Level 1"
+        );
+        assert_eq!(
+            data_file_id("b1000", &json!({"key": {"bits": 66}})),
+            "b1000/66"
+        );
+        assert_eq!(data_file_id("gsmg", &json!({})), "gsmg");
+    }
+
+    #[test]
+    fn collection_help_lists_registry_names() {
+        assert_eq!(
+            collection_help(false),
+            "arweave, b1000, ballet, bitaps, bitimage, gsmg, hash_collision (peter_todd), warp (warpwallet), zden"
+        );
+        assert_eq!(
+            collection_help(true),
+            "arweave, b1000, ballet, bitaps, bitimage, gsmg, hash_collision (peter_todd), warp (warpwallet), zden, all"
+        );
+    }
+}
@@ -0,0 +1,2060 @@
+//! End-to-end CLI flows run against isolated dirs and a mock explorer API.
+
+use boha_test_support::TestEnv;
+use predicates::prelude::*;
+
+fn env() -> TestEnv {
+    TestEnv::new(env!("CARGO_BIN_EXE_boha"))
+}
+
+#[test]
+fn runs_with_isolated_dirs() {
+    let env = env();
+    env.command()
+        .args(["-o", "json", "stats"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"total\""));
+    assert!(!env.state_dir().exists());
+}
+
+mod export {
+    use super::*;
+    use sha2::{Digest, Sha256};
+    use std::fs;
+
+    #[test]
+    fn split_per_collection_writes_files_and_manifest() {
+        let env = env();
+        env.command()
+            .args(["export", "b1000", "gsmg", "--split-per-collection", "out"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("Wrote 2 collections"));
+
+        let out = env.path().join("out");
+        let b1000 = fs::read_to_string(out.join("b1000.json")).unwrap();
+        assert!(b1000.contains("\"name\": \"b1000\""));
+        assert!(b1000.contains("\"id\": \"b1000/66\""));
+        assert!(!b1000.contains("gsmg"));
+
+        let manifest = fs::read_to_string(out.join("index.json")).unwrap();
+        assert!(manifest.contains("\"format\": \"json\""));
+        assert!(manifest.contains("\"file\": \"gsmg.json\""));
+        assert!(manifest.contains("\"schema_version\""));
+        let digest = hex::encode(Sha256::digest(b1000.as_bytes()));
+        assert!(manifest.contains(&digest));
+    }
+
+    #[test]
+    fn split_per_collection_jsonl() {
+        let env = env();
+        env.command()
+            .args([
+                "-o",
+                "jsonl",
+                "export",
+                "zden",
+                "--no-stats",
+                "--split-per-collection",
+                "out",
+            ])
+            .assert()
+            .success();
+
+        let zden = fs::read_to_string(env.path().join("out/zden.jsonl")).unwrap();
+        assert_eq!(zden.lines().count(), boha::zden::all().count());
+        let manifest = fs::read_to_string(env.path().join("out/index.json")).unwrap();
+        assert!(manifest.contains("\"file\": \"zden.jsonl\""));
+        assert!(!manifest.contains("\"stats\""));
+    }
+
+    #[test]
+    fn split_dir_replaces_files_without_leftovers() {
+        let env = env();
+        for _ in 0..2 {
+            env.command()
+                .args(["export", "zden", "--split-dir", "out"])
+                .assert()
+                .success();
+        }
+        let mut files: Vec<String> = fs::read_dir(env.path().join("out"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        assert_eq!(files, ["index.json", "zden.json"]);
+    }
+
+    #[test]
+    fn since_exports_only_changed_puzzles() {
+        let env = env();
+        env.command()
+            .args(["export", "b1000", "zden", "--split-per-collection", "v1"])
+            .assert()
+            .success();
+
+        // Pretend b1000/1 changed, b1000/2 is new and b1000/999 was dropped.
+        let path = env.path().join("v1/index.json");
+        let mut manifest: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let hashes = manifest["puzzle_hashes"].as_object_mut().unwrap();
+        assert_eq!(
+            hashes.len(),
+            boha::b1000::all().count() + boha::zden::all().count()
+        );
+        hashes.insert("b1000/1".into(), "0".repeat(64).into());
+        hashes.remove("b1000/2");
+        hashes.insert("b1000/999".into(), "0".repeat(64).into());
+        hashes.insert("gsmg".into(), "0".repeat(64).into());
+        fs::write(&path, manifest.to_string()).unwrap();
+
+        let output = env
+            .command()
+            .args(["export", "b1000", "zden", "--since", "v1/index.json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("2 changed, 1 removed"));
+
+        let delta: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let collections = delta["collections"].as_array().unwrap();
+        assert_eq!(collections.len(), 1, "unchanged zden is left out");
+        let ids: Vec<&str> = collections[0]["puzzles"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, ["b1000/1", "b1000/2"]);
+        // gsmg wasn't exported, so it isn't reported as removed.
+        assert_eq!(delta["since"]["removed"], serde_json::json!(["b1000/999"]));
+        assert_eq!(delta["since"]["exported_at"], manifest["exported_at"]);
+    }
+
+    #[test]
+    fn since_split_keeps_full_hashes_for_the_next_run() {
+        let env = env();
+        env.command()
+            .args(["export", "zden", "--split-per-collection", "v1"])
+            .assert()
+            .success();
+        env.command()
+            .args([
+                "export",
+                "zden",
+                "--split-per-collection",
+                "v2",
+                "--since",
+                "v1/index.json",
+            ])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("0 changed, 0 removed"));
+
+        assert!(!env.path().join("v2/zden.json").exists());
+        let v1 = fs::read_to_string(env.path().join("v1/index.json")).unwrap();
+        let v2 = fs::read_to_string(env.path().join("v2/index.json")).unwrap();
+        let hashes = |text: &str| {
+            serde_json::from_str::<serde_json::Value>(text).unwrap()["puzzle_hashes"].clone()
+        };
+        assert_eq!(hashes(&v1), hashes(&v2));
+    }
+
+    #[test]
+    fn since_rejects_manifest_without_hashes() {
+        let env = env();
+        fs::write(env.path().join("old.json"), r#"{"exported_at":"x"}"#).unwrap();
+        env.command()
+            .args(["export", "--since", "old.json"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("has no puzzle_hashes"));
+    }
+
+    const REPO_ASSETS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets");
+
+    #[test]
+    fn with_assets_bundles_files_by_content_hash() {
+        let env = env();
+        let output = env
+            .command()
+            .args(["export", "bitimage", "gsmg", "--with-assets", "bundle"])
+            .args(["--assets-from", REPO_ASSETS])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Bundled 3 assets"));
+
+        let export: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let mut paths = Vec::new();
+        for collection in export["collections"].as_array().unwrap() {
+            for puzzle in collection["puzzles"].as_array().unwrap() {
+                let assets = &puzzle["assets"];
+                paths.push(assets["puzzle"].as_str().unwrap().to_string());
+                for hint in assets["hints"].as_array().unwrap() {
+                    paths.push(hint.as_str().unwrap().to_string());
+                }
+            }
+        }
+        // Both kitten puzzles share one image, stored once.
+        assert_eq!(paths.len(), 4);
+        assert_eq!(paths[0], paths[1]);
+        for path in &paths {
+            let bytes = fs::read(env.path().join(path)).unwrap();
+            let name = path.strip_prefix("bundle/").unwrap();
+            assert!(name.starts_with(&hex::encode(Sha256::digest(&bytes))));
+        }
+        assert_eq!(fs::read_dir(env.path().join("bundle")).unwrap().count(), 3);
+    }
+
+    #[test]
+    fn with_assets_paths_are_relative_to_split_dir() {
+        let env = env();
+        env.command()
+            .args(["export", "gsmg", "--split-per-collection", "out"])
+            .args(["--with-assets", "out/assets", "--assets-from", REPO_ASSETS])
+            .assert()
+            .success();
+
+        let gsmg = fs::read_to_string(env.path().join("out/gsmg.json")).unwrap();
+        assert!(gsmg.contains("\"puzzle\": \"assets/"));
+        assert!(!gsmg.contains("out/assets"));
+    }
+
+    #[test]
+    fn with_assets_reports_missing_source() {
+        env()
+            .command()
+            .args(["export", "gsmg", "--with-assets", "bundle"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Cannot read asset"))
+            .stderr(predicate::str::contains("--assets-from"));
+    }
+
+    #[test]
+    fn gzip_export_roundtrips_through_import() {
+        let env = env();
+        let output = env
+            .command()
+            .args(["export", "gsmg", "bitaps", "--gzip"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(&output.stdout[..2], &[0x1f, 0x8b]);
+        fs::write(env.path().join("export.json.gz"), &output.stdout).unwrap();
+
+        env.command()
+            .args(["import", "export.json.gz"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("gz"))
+            .stdout(predicate::str::contains("gsmg"))
+            .stdout(predicate::str::contains(boha::version::FULL_VERSION));
+        env.command()
+            .args(["-o", "jsonl", "import", "export.json.gz"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(r#""id":"gsmg""#))
+            .stdout(predicate::str::contains(r#""id":"bitaps""#));
+    }
+
+    #[test]
+    fn compress_flag_streams_zstd() {
+        let env = env();
+        let output = env
+            .command()
+            .args(["-o", "jsonl", "export", "b1000", "--compress", "zstd"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(&output.stdout[..4], &[0x28, 0xb5, 0x2f, 0xfd]);
+        fs::write(env.path().join("b1000.jsonl.zst"), &output.stdout).unwrap();
+
+        let lines = boha::b1000::all().count();
+        env.command()
+            .args(["-o", "jsonl", "import", "b1000.jsonl.zst"])
+            .assert()
+            .success()
+            .stdout(predicate::function(move |out: &str| {
+                out.lines().count() == lines
+            }));
+        env.command()
+            .args(["export", "--compress", "gzip", "--zstd"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("cannot be used with"));
+    }
+
+    #[test]
+    fn zstd_split_files_are_importable() {
+        let env = env();
+        env.command()
+            .args([
+                "-o",
+                "jsonl",
+                "export",
+                "zden",
+                "--zstd",
+                "--split-per-collection",
+                "out",
+            ])
+            .assert()
+            .success();
+
+        let manifest = fs::read_to_string(env.path().join("out/index.json")).unwrap();
+        assert!(manifest.contains("\"file\": \"zden.jsonl.zst\""));
+        assert!(manifest.contains("\"compression\": \"zst\""));
+
+        let lines = boha::zden::all().count();
+        env.command()
+            .args(["-o", "jsonl", "import", "out/zden.jsonl.zst"])
+            .assert()
+            .success()
+            .stdout(predicate::function(move |out: &str| {
+                out.lines().count() == lines
+            }));
+    }
+
+    #[test]
+    fn import_reads_stdin_and_rejects_garbage() {
+        env()
+            .command()
+            .args(["import", "-"])
+            .write_stdin("{\"id\":\"b1000/1\"}\n{\"id\":\"b1000/2\"}\n")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("b1000"))
+            .stdout(predicate::str::contains("2"));
+
+        env()
+            .command()
+            .args(["import", "-"])
+            .write_stdin("not json\n")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Invalid JSON on line 1"));
+    }
+
+    #[test]
+    fn split_per_collection_rejects_yaml() {
+        env()
+            .command()
+            .args(["-o", "yaml", "export", "--split-per-collection", "out"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("json and jsonl"));
+    }
+}
+
+#[cfg(feature = "balance")]
+mod balance {
+    use super::*;
+    use boha_test_support::MockServer;
+
+    const B1000_71: &str = "1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU";
+
+    fn mempool_body(funded: u64, spent: u64, pending: u64) -> String {
+        format!(
+            r#"{{"chain_stats":{{"funded_txo_sum":{},"spent_txo_sum":{}}},"mempool_stats":{{"funded_txo_sum":{},"spent_txo_sum":0}}}}"#,
+            funded, spent, pending
+        )
+    }
+
+    #[test]
+    fn bitcoin_balance_from_mock() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", B1000_71);
+        server.mock(&path, 200, mempool_body(710_000_000, 0, 1_000));
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "balance", "b1000/71"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 710000000"))
+            .stdout(predicate::str::contains("\"unconfirmed\": 1000"));
+
+        assert_eq!(server.hits(&path), 1);
+    }
+
+    #[test]
+    fn second_lookup_is_served_from_cache() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", B1000_71);
+        server.mock(&path, 200, mempool_body(710_000_000, 0, 0));
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["-o", "json", "balance", "b1000/71"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"cached\": false"));
+        env.command()
+            .args(["-o", "json", "balance", "b1000/71"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 710000000"))
+            .stdout(predicate::str::contains("\"cached\": true"));
+
+        assert_eq!(server.hits(&path), 1);
+        assert!(env
+            .cache_dir()
+            .join("balances")
+            .join(format!("{}.json", B1000_71))
+            .is_file());
+    }
+
+    #[test]
+    fn refresh_and_zero_ttl_bypass_cache() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", B1000_71);
+        server.mock(&path, 200, mempool_body(710_000_000, 0, 0));
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["balance", "b1000/71"])
+            .assert()
+            .success();
+        env.command()
+            .args(["balance", "b1000/71", "--refresh"])
+            .assert()
+            .success();
+        env.command()
+            .args(["balance", "b1000/71", "--cache-ttl", "0"])
+            .assert()
+            .success();
+
+        assert_eq!(server.hits(&path), 3);
+    }
+
+    #[test]
+    fn no_cache_leaves_cache_dir_untouched() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", B1000_71);
+        server.mock(&path, 200, mempool_body(710_000_000, 0, 0));
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["balance", "b1000/71", "--no-cache"])
+            .assert()
+            .success();
+
+        assert!(!env.cache_dir().join("balances").exists());
+    }
+
+    #[test]
+    fn verbose_logs_requests_and_cache_hits() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", B1000_71);
+        server.mock(&path, 200, mempool_body(710_000_000, 0, 0));
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["balance", "b1000/71"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("GET").not());
+        env.command()
+            .args(["-vv", "balance", "b1000/71", "--refresh"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains(format!(
+                "{}{}",
+                server.url(),
+                path
+            )))
+            .stderr(predicate::str::contains("balance fetched"))
+            .stderr(predicate::str::contains("elapsed_ms="));
+        env.command()
+            .args(["-vv", "balance", "b1000/71"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("balance cache hit"));
+        env.command()
+            .env("BOHA_LOG", "off")
+            .args(["-vv", "balance", "b1000/71"])
+            .assert()
+            .success()
+            .stderr("");
+    }
+
+    #[test]
+    fn verbose_keeps_the_etherscan_key_out_of_the_log() {
+        let server = MockServer::start();
+        server.mock(
+            "/v2/api",
+            200,
+            r#"{"status":"1","message":"OK","result":"1"}"#,
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-vv", "balance", "zden/xixoio"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("/v2/api?chainid=1"))
+            .stderr(predicate::str::contains("apikey").not());
+    }
+
+    #[test]
+    fn invalid_address_status_is_reported() {
+        let server = MockServer::start();
+        server.mock(
+            &format!("/api/address/{}", B1000_71),
+            400,
+            "Invalid Bitcoin address",
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["balance", "b1000/71"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Invalid address"));
+    }
+
+    #[test]
+    fn litecoin_balance_from_mock() {
+        let server = MockServer::start();
+        server.mock(
+            "/api/address/LartGjF6UjmvmF1JXBhFf5wtM9uZX7LzeS",
+            200,
+            mempool_body(5_000, 2_000, 0),
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "balance", "zden/litecoin_segwit"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 3000"));
+    }
+
+    #[test]
+    fn decred_balance_from_mock() {
+        let server = MockServer::start();
+        server.mock(
+            "/api/address/DsRaAja82UvgnqYaBHYFuyCKURFX2rCyEJ8/totals",
+            200,
+            r#"{"dcr_unspent":1.5}"#,
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "balance", "zden/decred_janus"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 150000000"));
+    }
+
+    #[test]
+    fn ethereum_balance_from_etherscan_mock() {
+        let server = MockServer::start();
+        server.mock(
+            "/v2/api",
+            200,
+            r#"{"status":"1","message":"OK","result":"2500000000000000000"}"#,
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "balance", "zden/xixoio"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"total_display\": 2.5"))
+            .stdout(predicate::str::contains("\"unit\": \"wei\""));
+
+        assert_eq!(server.hits("/v2/api"), 1);
+    }
+
+    #[test]
+    fn ethereum_balance_falls_back_to_blockscout() {
+        let server = MockServer::start();
+        server.mock(
+            "/api",
+            200,
+            r#"{"status":"1","message":"OK","result":"1000000000000000000"}"#,
+        );
+
+        env()
+            .with_mock(&server)
+            .env("ETHERSCAN_API_KEY", "")
+            .command()
+            .args(["-o", "json", "balance", "zden/xixoio"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "\"confirmed\": 1000000000000000000",
+            ));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0]
+            .query
+            .as_deref()
+            .is_some_and(|q| q.contains("module=account") && !q.contains("apikey")));
+    }
+
+    #[test]
+    fn esplora_backend_uses_backend_url() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", B1000_71);
+        server.mock(&path, 200, mempool_body(42, 0, 0));
+
+        env()
+            .command()
+            .args(["-o", "json", "balance", "b1000/71", "--backend", "esplora"])
+            .args(["--backend-url", &server.url()])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 42"));
+
+        assert_eq!(server.hits(&path), 1);
+    }
+
+    #[test]
+    fn bitcoind_backend_scans_utxo_set() {
+        let server = MockServer::start();
+        server.mock(
+            "/",
+            200,
+            r#"{"result":{"success":true,"total_amount":7.1},"error":null,"id":"boha"}"#,
+        );
+
+        env()
+            .env("BOHA_BALANCE_BACKEND", "bitcoind")
+            .env("BOHA_BACKEND_URL", server.url())
+            .command()
+            .args(["-o", "json", "balance", "b1000/71"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 710000000"));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert!(requests[0].body.contains("scantxoutset"));
+        assert!(requests[0].body.contains(&format!("addr({})", B1000_71)));
+    }
+
+    #[test]
+    fn electrum_backend_queries_scripthash() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut lines = BufReader::new(stream.try_clone().unwrap()).lines();
+            let mut writer = stream;
+            let version = lines.next().unwrap().unwrap();
+            assert!(version.contains("server.version"));
+            writeln!(
+                writer,
+                r#"{{"jsonrpc":"2.0","id":0,"result":["mock","1.4"]}}"#
+            )
+            .unwrap();
+            let request = lines.next().unwrap().unwrap();
+            writeln!(
+                writer,
+                r#"{{"jsonrpc":"2.0","id":1,"result":{{"confirmed":5000,"unconfirmed":-100}}}}"#
+            )
+            .unwrap();
+            request
+        });
+
+        env()
+            .command()
+            .args(["-o", "json", "balance", "b1000/71", "--backend", "electrum"])
+            .args(["--backend-url", &addr.to_string()])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 5000"))
+            .stdout(predicate::str::contains("\"unconfirmed\": -100"));
+
+        let request = server.join().unwrap();
+        assert!(request.contains("blockchain.scripthash.get_balance"));
+    }
+
+    #[test]
+    fn bitcoin_only_backend_rejects_other_chains() {
+        env()
+            .command()
+            .args(["balance", "zden/decred_janus", "--backend", "electrum"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Unsupported chain"));
+    }
+
+    #[test]
+    fn proxy_receives_explorer_requests() {
+        let proxy = MockServer::start();
+        let target = format!("http://explorer.invalid/api/address/{}", B1000_71);
+        proxy.mock(&target, 200, mempool_body(710_000_000, 0, 0));
+
+        env()
+            .env("BOHA_MEMPOOL_URL", "http://explorer.invalid")
+            .command()
+            .args(["-o", "json", "balance", "b1000/71", "--no-cache"])
+            .args(["--proxy", &proxy.url()])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 710000000"));
+
+        assert_eq!(proxy.hits(&target), 1);
+    }
+
+    #[test]
+    fn electrum_goes_through_socks5_proxy() {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        // Plays both the SOCKS5 proxy and the Electrum server behind it.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut greeting = [0; 3];
+            stream.read_exact(&mut greeting).unwrap();
+            stream.write_all(&[5, 0]).unwrap();
+            let mut head = [0; 5];
+            stream.read_exact(&mut head).unwrap();
+            let mut target = vec![0; usize::from(head[4]) + 2];
+            stream.read_exact(&mut target).unwrap();
+            stream.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).unwrap();
+
+            let mut lines = BufReader::new(stream.try_clone().unwrap()).lines();
+            lines.next().unwrap().unwrap();
+            writeln!(
+                stream,
+                r#"{{"jsonrpc":"2.0","id":0,"result":["mock","1.4"]}}"#
+            )
+            .unwrap();
+            lines.next().unwrap().unwrap();
+            writeln!(
+                stream,
+                r#"{{"jsonrpc":"2.0","id":1,"result":{{"confirmed":42,"unconfirmed":0}}}}"#
+            )
+            .unwrap();
+            String::from_utf8_lossy(&target[..target.len() - 2]).into_owned()
+        });
+
+        env()
+            .command()
+            .args(["-o", "json", "balance", "b1000/71", "--no-cache"])
+            .args([
+                "--backend",
+                "electrum",
+                "--backend-url",
+                "electrum.onion:50001",
+            ])
+            .args(["--proxy", &format!("socks5h://{}", addr)])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 42"));
+
+        assert_eq!(server.join().unwrap(), "electrum.onion");
+    }
+
+    #[test]
+    fn invalid_proxy_is_a_usage_error() {
+        env()
+            .command()
+            .args(["balance", "b1000/71", "--proxy", "ftp://127.0.0.1:21"])
+            .assert()
+            .code(64)
+            .stderr(predicate::str::contains("Unsupported proxy scheme ftp"));
+    }
+
+    #[test]
+    fn all_reports_balance_and_delta_per_address() {
+        let server = MockServer::start();
+        server
+            .mock(
+                "/api/address/1JxWyNrkgYvgsHu8hVQZqTXEB9RftRGP5m",
+                200,
+                mempool_body(100_003_481, 0, 0),
+            )
+            .mock(
+                "/api/address/1QGtbKxx6FKDD66LwnrzHCAHmyZ7mDHqC4",
+                200,
+                mempool_body(100_003_877, 100_003_877, 0),
+            );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args([
+                "-o",
+                "jsonl",
+                "balance",
+                "--all",
+                "--collection",
+                "ballet",
+                "--unsolved",
+                "--concurrency",
+                "2",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                r#""id":"ballet/AA009926","address":"1JxWyNrkgYvgsHu8hVQZqTXEB9RftRGP5m""#,
+            ))
+            .stdout(predicate::str::contains(r#""delta":0.0"#))
+            .stdout(predicate::str::contains(r#""delta":-1.00003877"#))
+            .stdout(predicate::str::contains("ballet/AA007448").not());
+    }
+
+    #[test]
+    fn all_exits_nonzero_when_a_lookup_fails() {
+        let server = MockServer::start();
+        server.mock(
+            "/api/address/1JxWyNrkgYvgsHu8hVQZqTXEB9RftRGP5m",
+            200,
+            mempool_body(1, 0, 0),
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args([
+                "-o",
+                "json",
+                "balance",
+                "--all",
+                "--collection",
+                "ballet",
+                "--unsolved",
+                "--rate",
+                "50",
+            ])
+            .assert()
+            .code(3)
+            .stdout(predicate::str::contains("\"error\": \"HTTP request failed"))
+            .stderr(predicate::str::contains("1 of 2 balance lookups failed"));
+    }
+
+    #[test]
+    fn all_filters_require_all() {
+        env()
+            .command()
+            .args(["balance", "b1000/71", "--unsolved"])
+            .assert()
+            .failure();
+    }
+
+    #[test]
+    fn metrics_include_polled_balances() {
+        let server = MockServer::start();
+        server.mock(
+            "/api/address/1GSMG1JC9wtdSwfwApgj2xcmJPAwx7prBe",
+            200,
+            mempool_body(150_000_000, 25_000_000, 0),
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["metrics", "gsmg", "--balances"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "boha_puzzle_balance{id=\"gsmg\",address=\"1GSMG1JC9wtdSwfwApgj2xcmJPAwx7prBe\",chain=\"bitcoin\",symbol=\"BTC\"} 1.25\n",
+            ))
+            .stdout(predicate::str::contains("boha_balance_errors 0\n"));
+    }
+
+    #[test]
+    fn audit_prizes_reports_drift_from_confirmed_balance() {
+        let server = MockServer::start();
+        server
+            .mock(
+                "/api/address/1JxWyNrkgYvgsHu8hVQZqTXEB9RftRGP5m",
+                200,
+                mempool_body(100_003_481, 0, 5_000),
+            )
+            .mock(
+                "/api/address/1QGtbKxx6FKDD66LwnrzHCAHmyZ7mDHqC4",
+                200,
+                mempool_body(50_000_000, 0, 0),
+            );
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["-o", "jsonl", "audit-prizes", "--collection", "ballet"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                r#""id":"ballet/AA009926","address":"1JxWyNrkgYvgsHu8hVQZqTXEB9RftRGP5m","symbol":"BTC","prize":1.00003481,"confirmed":1.00003481,"drift":0.0,"status":"ok""#,
+            ))
+            .stdout(predicate::str::contains(r#""drift":-0.50003877,"status":"decreased""#))
+            .stdout(predicate::str::contains("AA007448").not());
+
+        env.command()
+            .args([
+                "-o",
+                "jsonl",
+                "audit-prizes",
+                "--collection",
+                "ballet",
+                "--drift-only",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("AA012381"))
+            .stdout(predicate::str::contains("AA009926").not());
+    }
+
+    #[test]
+    fn audit_prizes_tolerance_absorbs_small_drift() {
+        let server = MockServer::start();
+        server
+            .mock(
+                "/api/address/1JxWyNrkgYvgsHu8hVQZqTXEB9RftRGP5m",
+                200,
+                mempool_body(100_004_481, 0, 0),
+            )
+            .mock(
+                "/api/address/1QGtbKxx6FKDD66LwnrzHCAHmyZ7mDHqC4",
+                200,
+                mempool_body(100_003_877, 0, 0),
+            );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args([
+                "-o",
+                "json",
+                "audit-prizes",
+                "--collection",
+                "ballet",
+                "--tolerance",
+                "0.0001",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("increased").not())
+            .stdout(predicate::str::contains("\"drift\": 0.00001"));
+    }
+
+    const GSMG: &str = "1GSMG1JC9wtdSwfwApgj2xcmJPAwx7prBe";
+
+    #[test]
+    fn watch_streams_spend_between_polls() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        let env = env().with_mock(&server);
+
+        let mut cmd = env.command();
+        cmd.args([
+            "-o",
+            "jsonl",
+            "watch",
+            "gsmg",
+            "--interval",
+            "1",
+            "--polls",
+            "2",
+        ]);
+        let watcher = std::thread::spawn(move || cmd.output().unwrap());
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while server.hits(&path) == 0 {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "first poll never came"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        server.mock(&path, 200, mempool_body(150_000_000, 150_000_000, 0));
+
+        let output = watcher.join().unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let events: Vec<serde_json::Value> = stdout
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["id"], "gsmg");
+        assert_eq!(events[0]["kind"], "spent");
+        assert_eq!(events[0]["pending"], false);
+        assert_eq!(events[0]["change"], -1.5);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("1 addresses every 1s"));
+        assert_eq!(server.hits(&path), 2);
+    }
+
+    #[test]
+    fn watch_reports_spend_reverted_by_reorg() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        server.mock("/api/blocks/tip/height", 200, "850000");
+        let env = env().with_mock(&server);
+
+        let mut cmd = env.command();
+        cmd.args([
+            "-o",
+            "jsonl",
+            "watch",
+            "gsmg",
+            "--interval",
+            "1",
+            "--polls",
+            "3",
+        ]);
+        let watcher = std::thread::spawn(move || cmd.output().unwrap());
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while server.hits(&path) < 1 {
+            assert!(std::time::Instant::now() < deadline, "poll never came");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        server.mock(&path, 200, mempool_body(150_000_000, 150_000_000, 0));
+        // The spend on the second poll asks for the tip once.
+        while server.hits("/api/blocks/tip/height") < 1 {
+            assert!(std::time::Instant::now() < deadline, "tip never asked");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        server.mock("/api/blocks/tip/height", 200, "850001");
+
+        let output = watcher.join().unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let events: Vec<serde_json::Value> = stdout
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["kind"], "spent");
+        assert_eq!(events[0]["height"], 850_000);
+        assert_eq!(events[1]["kind"], "event_reverted");
+        assert_eq!(events[1]["height"], 850_000);
+        assert_eq!(events[1]["change"], 1.5);
+    }
+
+    #[test]
+    fn watch_holds_spend_until_confirmations() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        server.mock("/api/blocks/tip/height", 200, "850000");
+        let env = env().with_mock(&server);
+
+        let mut cmd = env.command();
+        cmd.args([
+            "-o",
+            "jsonl",
+            "watch",
+            "gsmg",
+            "--interval",
+            "1",
+            "--polls",
+            "3",
+            "--confirmations",
+            "2",
+        ]);
+        let watcher = std::thread::spawn(move || cmd.output().unwrap());
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while server.hits(&path) < 1 {
+            assert!(std::time::Instant::now() < deadline, "poll never came");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        server.mock(&path, 200, mempool_body(150_000_000, 150_000_000, 0));
+        // One tip lookup at startup, one for the spend on the second poll.
+        while server.hits("/api/blocks/tip/height") < 2 {
+            assert!(std::time::Instant::now() < deadline, "tip never asked");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        server.mock("/api/blocks/tip/height", 200, "850001");
+
+        let output = watcher.join().unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let events: Vec<serde_json::Value> = stdout
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["kind"], "pending");
+        assert_eq!(events[0]["awaiting"], "spent");
+        assert_eq!(events[0]["confirmations"], 1);
+        assert_eq!(events[1]["kind"], "spent");
+        assert_eq!(events[1]["confirmations"], 2);
+        assert_eq!(events[1]["change"], -1.5);
+    }
+
+    #[test]
+    fn watch_confirmations_need_block_heights() {
+        env()
+            .command()
+            .args(["watch", "arweave", "--confirmations", "3"])
+            .assert()
+            .code(64)
+            .stderr(predicate::str::contains("needs block heights"));
+    }
+
+    #[test]
+    fn watch_streams_events_into_file() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(
+            &path,
+            200,
+            r#"{"chain_stats":{"funded_txo_sum":150000000,"spent_txo_sum":0},"mempool_stats":{"funded_txo_sum":0,"spent_txo_sum":150000000}}"#,
+        );
+        let env = env().with_mock(&server);
+        let events = env.path().join("events.jsonl");
+
+        let mut cmd = env.command();
+        cmd.args([
+            "-o",
+            "jsonl",
+            "--file",
+            "events.jsonl",
+            "watch",
+            "gsmg",
+            "--interval",
+            "2",
+            "--polls",
+            "2",
+        ]);
+        let watcher = std::thread::spawn(move || cmd.output().unwrap());
+
+        // The first event lands in the file while watch is still polling.
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while !std::fs::read_to_string(&events).is_ok_and(|s| s.contains("unconfirmed_spend")) {
+            assert!(std::time::Instant::now() < deadline, "no event written");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        assert!(!watcher.is_finished());
+        assert!(watcher.join().unwrap().status.success());
+    }
+
+    #[test]
+    fn watch_flags_spend_waiting_in_mempool_on_first_poll() {
+        let server = MockServer::start();
+        server.mock(
+            &format!("/api/address/{}", GSMG),
+            200,
+            r#"{"chain_stats":{"funded_txo_sum":150000000,"spent_txo_sum":0},"mempool_stats":{"funded_txo_sum":0,"spent_txo_sum":150000000}}"#,
+        );
+
+        let output = env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "jsonl", "watch", "gsmg", "--polls", "1"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let event: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(event["kind"], "unconfirmed_spend");
+        assert_eq!(event["pending"], true);
+        assert_eq!(event["previous"], 1.5);
+        assert_eq!(event["change"], -1.5);
+    }
+
+    #[test]
+    fn watch_pushes_alerts_to_webhook_and_telegram() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        server.mock("/hook", 200, "");
+        server.mock("/botTOKEN/sendMessage", 200, r#"{"ok":true}"#);
+        let env = env().with_mock(&server);
+
+        let mut cmd = env.command();
+        cmd.args(["watch", "gsmg", "--interval", "1", "--polls", "2"])
+            .args(["--notify-url", &format!("{}/hook", server.url())])
+            .args(["--telegram-token", "TOKEN", "--telegram-chat", "42"]);
+        let watcher = std::thread::spawn(move || cmd.output().unwrap());
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while server.hits(&path) == 0 {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "first poll never came"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        server.mock(&path, 200, mempool_body(200_000_000, 0, 0));
+
+        let output = watcher.join().unwrap();
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("2 notification target(s)"));
+
+        let requests = server.requests();
+        let hook = requests.iter().find(|r| r.path == "/hook").unwrap();
+        assert_eq!(hook.method, "POST");
+        let event: serde_json::Value = serde_json::from_str(&hook.body).unwrap();
+        assert_eq!(event["kind"], "funded");
+        assert_eq!(event["change"], 0.5);
+
+        let telegram = requests
+            .iter()
+            .find(|r| r.path == "/botTOKEN/sendMessage")
+            .unwrap();
+        let message: serde_json::Value = serde_json::from_str(&telegram.body).unwrap();
+        assert_eq!(message["chat_id"], "42");
+        assert!(message["text"]
+            .as_str()
+            .unwrap()
+            .starts_with("boha: gsmg funded +0.50000000 BTC"));
+    }
+
+    #[test]
+    fn watch_keeps_running_when_a_webhook_fails() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        let env = env().with_mock(&server);
+
+        let mut cmd = env.command();
+        cmd.args(["watch", "gsmg", "--interval", "1", "--polls", "2"])
+            .args(["--notify-url", &format!("{}/missing", server.url())]);
+        let watcher = std::thread::spawn(move || cmd.output().unwrap());
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while server.hits(&path) == 0 {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "first poll never came"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        server.mock(&path, 200, mempool_body(150_000_000, 150_000_000, 0));
+
+        let output = watcher.join().unwrap();
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stdout).contains("spent"));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("404"));
+    }
+
+    #[test]
+    fn telegram_token_requires_chat() {
+        env()
+            .command()
+            .args(["watch", "gsmg", "--telegram-token", "TOKEN"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--telegram-chat"));
+    }
+
+    #[test]
+    fn watch_is_quiet_without_changes_and_rejects_csv() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["watch", "gsmg", "--interval", "1", "--polls", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_empty());
+        env.command()
+            .args(["-o", "csv", "watch", "gsmg"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("watch streams events"));
+    }
+
+    #[test]
+    fn watch_defaults_to_the_config_watchlist() {
+        let server = MockServer::start();
+        let path = format!("/api/address/{}", GSMG);
+        server.mock(&path, 200, mempool_body(150_000_000, 0, 0));
+        let env = env().with_mock(&server);
+        env.write_config("config.toml", "watchlist = [\"gsmg\"]\n");
+
+        env.command()
+            .args(["watch", "--polls", "1"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("1 addresses every"));
+        assert_eq!(server.hits(&path), 1);
+    }
+
+    #[test]
+    fn arweave_balance_from_mock() {
+        let server = MockServer::start();
+        server.mock(
+            "/wallet/PbdTDYikdddWfNFlDt2aZokALXKe1mJVSC9TALUBNv8/balance",
+            200,
+            "1000000000000",
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "balance", "arweave/weave1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"confirmed\": 1000000000000"));
+    }
+}
+
+#[cfg(feature = "price")]
+mod price {
+    use super::*;
+    use boha_test_support::MockServer;
+
+    const PRICE_PATH: &str = "/api/v3/simple/price";
+
+    #[test]
+    fn stats_values_prizes_in_requested_currency() {
+        let server = MockServer::start();
+        server.mock(
+            PRICE_PATH,
+            200,
+            r#"{"bitcoin":{"eur":50000.0},"ethereum":{"eur":2000.0}}"#,
+        );
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "stats", "--currency", "EUR"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"schema_version\""))
+            .stdout(predicate::str::contains("\"currency\": \"eur\""))
+            .stdout(predicate::str::contains("\"BTC\": 50000.0"));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let query = requests[0].query.as_deref().unwrap();
+        assert!(query.contains("ids=") && query.contains("bitcoin"));
+        assert!(query.ends_with("vs_currencies=eur"));
+    }
+
+    #[test]
+    fn show_adds_prize_value() {
+        let server = MockServer::start();
+        server.mock(PRICE_PATH, 200, r#"{"bitcoin":{"usd":50000.0}}"#);
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["-o", "json", "show", "b1000/71", "--currency", "usd"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"id\": \"b1000/71\""))
+            .stdout(predicate::str::contains("\"prize_fiat\""))
+            .stdout(predicate::str::contains("\"value\": 355011.3"));
+        env.command()
+            .args(["show", "b1000/71", "--currency", "usd"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Prize value"))
+            .stdout(predicate::str::contains("355011.30 USD"));
+    }
+
+    #[test]
+    fn no_lookup_without_currency() {
+        let server = MockServer::start();
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "show", "b1000/71"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("prize_fiat").not());
+        assert!(server.requests().is_empty());
+    }
+
+    const BTC_HISTORY_PATH: &str = "/api/v3/coins/bitcoin/history";
+
+    #[test]
+    fn show_values_prize_at_solve_date_once() {
+        let server = MockServer::start();
+        server.mock(
+            BTC_HISTORY_PATH,
+            200,
+            r#"{"market_data":{"current_price":{"usd":13.5,"eur":10.25}}}"#,
+        );
+        server.mock(PRICE_PATH, 200, r#"{"bitcoin":{"eur":60000.0}}"#);
+        let env = env().with_mock(&server);
+
+        for _ in 0..2 {
+            env.command()
+                .args(["-o", "json", "show", "b1000/1", "--with-historical-value"])
+                .assert()
+                .success()
+                .stdout(predicate::str::contains("\"historical_value\""))
+                .stdout(predicate::str::contains("\"date\": \"2013-01-10\""))
+                .stdout(predicate::str::contains("\"rate\": 13.5"))
+                .stdout(predicate::str::contains("\"value\": 0.01"));
+        }
+        env.command()
+            .args([
+                "show",
+                "b1000/1",
+                "--with-historical-value",
+                "--currency",
+                "eur",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("60.00 EUR"))
+            .stdout(predicate::str::contains("Value at solve"))
+            .stdout(predicate::str::contains("0.01 EUR (2013-01-10)"));
+
+        // Second usd lookup came from the cache; eur is a separate entry.
+        assert_eq!(server.hits(BTC_HISTORY_PATH), 2);
+        assert!(server.requests()[0]
+            .query
+            .as_deref()
+            .unwrap()
+            .contains("date=10-01-2013"));
+        assert!(env.cache_dir().join("prices").is_dir());
+    }
+
+    #[test]
+    fn show_without_market_data_omits_value() {
+        let server = MockServer::start();
+        server.mock(BTC_HISTORY_PATH, 200, r#"{"id":"bitcoin"}"#);
+
+        env()
+            .with_mock(&server)
+            .command()
+            .args(["-o", "json", "show", "b1000/1", "--with-historical-value"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("historical_value").not())
+            .stderr(predicate::str::contains("no usd price of BTC"));
+    }
+
+    #[test]
+    fn export_adds_historical_value_to_solved_puzzles() {
+        let server = MockServer::start();
+        server.mock(
+            BTC_HISTORY_PATH,
+            200,
+            r#"{"market_data":{"current_price":{"usd":100.0}}}"#,
+        );
+
+        let output = env()
+            .with_mock(&server)
+            .command()
+            .args(["export", "b1000", "--with-historical-value", "--no-stats"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let export: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let puzzles = export["collections"][0]["puzzles"].as_array().unwrap();
+        let mut dates = std::collections::HashSet::new();
+        for puzzle in puzzles {
+            let value = &puzzle["historical_value"];
+            if puzzle["status"] == "solved" {
+                assert_eq!(value["currency"], "usd");
+                dates.insert(value["date"].as_str().unwrap().to_string());
+            } else {
+                assert!(value.is_null(), "{}", puzzle["id"]);
+            }
+        }
+        // One request per distinct day; repeats are answered from the cache.
+        assert_eq!(server.hits(BTC_HISTORY_PATH), dates.len());
+    }
+
+    #[test]
+    fn export_currency_requires_historical_value() {
+        env()
+            .command()
+            .args(["export", "--currency", "eur"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--with-historical-value"));
+    }
+
+    #[test]
+    fn rejects_unknown_currency() {
+        let server = MockServer::start();
+        server.mock(PRICE_PATH, 200, r#"{"bitcoin":{}}"#);
+        let env = env().with_mock(&server);
+
+        env.command()
+            .args(["show", "b1000/71", "--currency", "xyz"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Unsupported currency: xyz"));
+        env.command()
+            .args(["stats", "--currency", "u$d"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("currency code"));
+    }
+}
+
+#[cfg(feature = "publish")]
+mod publish {
+    use super::*;
+    use std::fs;
+
+    fn snapshot(env: &TestEnv) {
+        env.command()
+            .args(["export", "b1000", "zden", "--split-per-collection", "snap"])
+            .assert()
+            .success();
+    }
+
+    #[test]
+    fn writes_car_and_torrent_for_split_export() {
+        let env = env();
+        snapshot(&env);
+
+        let output = env
+            .command()
+            .args([
+                "-o",
+                "json",
+                "publish",
+                "snap",
+                "--car",
+                "snap.car",
+                "--torrent",
+                "snap.torrent",
+                "--tracker",
+                "udp://tracker.example:1337",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["files"], 3);
+        let cid = json["cid"].as_str().unwrap();
+        assert!(cid.starts_with("bafybei"));
+        let info_hash = json["info_hash"].as_str().unwrap();
+        assert_eq!(info_hash.len(), 40);
+        assert!(json["magnet"].as_str().unwrap().contains(info_hash));
+
+        let torrent = fs::read(env.path().join("snap.torrent")).unwrap();
+        let torrent = String::from_utf8_lossy(&torrent);
+        assert!(torrent.contains("8:announce26:udp://tracker.example:1337"));
+        assert!(torrent.contains("10:b1000.json"));
+        assert!(!torrent.contains("creation date"));
+        assert!(fs::metadata(env.path().join("snap.car")).unwrap().len() > 0);
+    }
+
+    #[test]
+    fn artifacts_are_reproducible() {
+        let env = env();
+        snapshot(&env);
+
+        let run = |suffix: &str| {
+            let car = format!("{}.car", suffix);
+            let torrent = format!("{}.torrent", suffix);
+            env.command()
+                .args(["-o", "json", "publish", "snap", "--car", &car])
+                .args(["--torrent", &torrent])
+                .assert()
+                .success();
+            (
+                fs::read(env.path().join(car)).unwrap(),
+                fs::read(env.path().join(torrent)).unwrap(),
+            )
+        };
+        assert_eq!(run("a"), run("b"));
+    }
+
+    #[test]
+    fn requires_an_artifact() {
+        let env = env();
+        snapshot(&env);
+        env.command()
+            .args(["publish", "snap"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--car"));
+    }
+}
+
+mod solver_config {
+    use super::*;
+
+    #[test]
+    fn writes_keyhunt_target_file() {
+        let env = env();
+        env.command()
+            .args([
+                "solver-config",
+                "b1000/71",
+                "--tool",
+                "keyhunt",
+                "--dir",
+                "pool",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "keyhunt -m address -f pool/b1000_71.txt",
+            ))
+            .stderr(predicate::str::contains("pool/b1000_71.txt"));
+        let target = std::fs::read_to_string(env.path().join("pool/b1000_71.txt")).unwrap();
+        assert_eq!(target, "1PWo3JeB9jrGwfHDNpdGK54CRas7fsVzXU\n");
+    }
+}
+
+mod file {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn writes_stdout_to_the_file() {
+        let env = env();
+        env.command()
+            .args(["-o", "json", "stats", "--file", "stats.json"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_empty());
+        let stats: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(env.path().join("stats.json")).unwrap())
+                .unwrap();
+        assert!(stats["total"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn failed_command_keeps_the_old_file() {
+        let env = env();
+        fs::write(env.path().join("out.txt"), "previous\n").unwrap();
+        env.command()
+            .args(["--file", "out.txt", "show", "nope/1"])
+            .assert()
+            .code(4)
+            .stderr(predicate::str::contains("Puzzle not found"));
+        assert_eq!(
+            fs::read_to_string(env.path().join("out.txt")).unwrap(),
+            "previous\n"
+        );
+        assert!(fs::read_dir(env.path()).unwrap().all(|entry| !entry
+            .unwrap()
+            .file_name()
+            .to_string_lossy()
+            .ends_with(".tmp")));
+    }
+
+    #[cfg(feature = "examples-data")]
+    #[test]
+    fn interactive_commands_keep_the_terminal() {
+        env()
+            .command()
+            .args(["--file", "out.txt", "tutorial"])
+            .assert()
+            .code(64)
+            .stderr(predicate::str::contains("interactive"));
+    }
+}
+
+mod progress {
+    use super::*;
+
+    #[test]
+    fn add_show_and_clear() {
+        let env = env();
+        env.command()
+            .args(["progress", "add", "8", "0x80", "0x9f"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Recorded 0x80:0x9f for b1000/8"))
+            .stdout(predicate::str::contains("25.00%"));
+        env.command()
+            .args(["progress", "add", "b1000/8", "90", "bf"])
+            .assert()
+            .success();
+
+        let saved = env.read_state("progress.json").unwrap();
+        assert!(saved.contains("\"b1000/8\""));
+        assert!(saved.contains("\"start\": \"90\""));
+
+        env.command()
+            .args(["-o", "json", "progress", "show", "8"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"scanned\": \"64\""))
+            .stdout(predicate::str::contains("\"percent\": 50.0"));
+        env.command()
+            .args(["progress", "show"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("b1000/8"));
+
+        env.command()
+            .args(["progress", "clear", "8"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("Removed 2 range(s)"));
+        env.command()
+            .args(["progress", "show"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No progress recorded"));
+    }
+
+    #[test]
+    fn rejects_ranges_outside_the_puzzle() {
+        let env = env();
+        env.command()
+            .args(["progress", "add", "8", "0x10", "0x20"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("outside the key range of b1000/8"));
+        env.command()
+            .args(["progress", "add", "8", "0x9f", "0x80"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("START is above END"));
+        assert!(env.read_state("progress.json").is_none());
+    }
+}
+
+mod verify {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn preimages_check_a_collision_claim() {
+        let env = env();
+        fs::write(env.path().join("a.bin"), [0x01]).unwrap();
+        fs::write(env.path().join("b.bin"), [0x81]).unwrap();
+        fs::write(env.path().join("c.bin"), [0x02]).unwrap();
+
+        env.command()
+            .args([
+                "verify",
+                "hash_collision/op_abs",
+                "--preimages",
+                "a.bin",
+                "b.bin",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Collision verified"))
+            .stdout(predicate::str::contains("Digest: 01"));
+
+        env.command()
+            .args([
+                "verify",
+                "hash_collision/op_abs",
+                "--preimages",
+                "a.bin",
+                "c.bin",
+            ])
+            .assert()
+            .code(5)
+            .stdout(predicate::str::contains("Verification failed"));
+
+        env.command()
+            .args(["verify", "b1000/66", "--preimages", "a.bin", "b.bin"])
+            .assert()
+            .code(2);
+    }
+}
+
+mod site {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn writes_index_collection_and_puzzle_pages() {
+        let env = env();
+        env.command()
+            .args(["site", "--out", "public"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("pages to public"));
+
+        let out = env.path().join("public");
+        let index = fs::read_to_string(out.join("index.html")).unwrap();
+        assert!(index.contains("<a href=\"b1000/index.html\">b1000</a>"));
+        assert!(out.join("style.css").exists());
+
+        let collection = fs::read_to_string(out.join("b1000/index.html")).unwrap();
+        assert!(collection.contains("<a href=\"66.html\">b1000/66</a>"));
+
+        let puzzle = fs::read_to_string(out.join("b1000/66.html")).unwrap();
+        assert!(puzzle.contains("<link rel=\"stylesheet\" href=\"../style.css\">"));
+        assert!(puzzle.contains("0x20000000000000000 – 0x3ffffffffffffffff"));
+        assert!(puzzle.contains("<h2>Transactions</h2>"));
+        assert!(out.join("gsmg/gsmg.html").exists());
+    }
+}
+
+mod diff {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+
+    #[test]
+    fn compares_export_snapshots() {
+        let env = env();
+        let output = env
+            .command()
+            .args(["export", "b1000", "--compact"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        fs::write(env.path().join("old.json"), &output.stdout).unwrap();
+
+        let mut export: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let puzzles = export["collections"][0]["puzzles"].as_array_mut().unwrap();
+        let index = |puzzles: &[serde_json::Value], id: &str| {
+            puzzles.iter().position(|p| p["id"] == id).unwrap()
+        };
+        let solved = index(puzzles, "b1000/71");
+        puzzles[solved]["status"] = "solved".into();
+        puzzles[solved]["solve_date"] = "2026-01-01 00:00:00".into();
+        let removed = index(puzzles, "b1000/72");
+        puzzles.remove(removed);
+        fs::write(env.path().join("new.json"), export.to_string()).unwrap();
+
+        let output = env
+            .command()
+            .args(["-o", "json", "diff", "old.json", "new.json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let changes: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let changes: Vec<(&str, &str, &str)> = changes
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| {
+                (
+                    c["id"].as_str().unwrap(),
+                    c["change"].as_str().unwrap(),
+                    c["field"].as_str().unwrap_or(""),
+                )
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("b1000/71", "changed", "status"),
+                ("b1000/71", "changed", "solve_date"),
+                ("b1000/72", "removed", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn against_git_ref_compares_data_files() {
+        let env = env();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(["-c", "user.name=boha", "-c", "user.email=boha@localhost"])
+                .args(args)
+                .current_dir(env.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?}", args);
+        };
+        let data = env.path().join("data");
+        fs::create_dir(&data).unwrap();
+        let gsmg = include_str!("../../../data/gsmg.jsonc");
+        fs::write(data.join("gsmg.jsonc"), gsmg).unwrap();
+        git(&["init", "--quiet"]);
+        git(&["add", "data"]);
+        git(&["commit", "--quiet", "-m", "data"]);
+
+        fs::write(
+            data.join("gsmg.jsonc"),
+            gsmg.replacen("\"status\": \"unsolved\"", "\"status\": \"solved\"", 1),
+        )
+        .unwrap();
+        env.command()
+            .args(["-o", "csv", "diff", "--against", "HEAD"])
+            .assert()
+            .success()
+            .stdout("id,change,field,old,new\ngsmg,changed,status,unsolved,solved\n");
+    }
+}
+
+mod template {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn template_file_renders_handlebars_per_result() {
+        let env = env();
+        fs::write(
+            env.path().join("post.hbs"),
+            "{{id}} <{{address.value}}>{{#if solve_date}} solved {{solve_date}}{{/if}}\n",
+        )
+        .unwrap();
+        env.command()
+            .args(["search", "kitten", "--template-file", "post.hbs"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("bitimage/kitten <"))
+            .stdout(predicate::str::contains("&lt;").not());
+    }
+
+    #[test]
+    fn missing_template_file_fails() {
+        env()
+            .command()
+            .args(["show", "gsmg", "--template-file", "missing.hbs"])
+            .assert()
+            .code(1)
+            .stderr(predicate::str::contains("Cannot read missing.hbs"));
+    }
+}
+
+mod config {
+    use super::*;
+
+    #[test]
+    fn set_get_list_and_unset() {
+        let env = env();
+        env.command()
+            .args(["config", "set", "balance.cache_ttl", "600"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("Set balance.cache_ttl"));
+        env.command()
+            .args(["config", "set", "api_keys.etherscan", "secret-key"])
+            .assert()
+            .success();
+        assert_eq!(
+            env.read_config("config.toml").unwrap(),
+            "[balance]\ncache_ttl = 600\n\n[api_keys]\netherscan = \"secret-key\"\n"
+        );
+
+        env.command()
+            .args(["config", "get", "balance.cache_ttl"])
+            .assert()
+            .success()
+            .stdout("600\n");
+        env.command()
+            .args(["config", "list"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("BOHA_CACHE_TTL"))
+            .stdout(predicate::str::contains("<redacted>"))
+            .stdout(predicate::str::contains("secret-key").not());
+
+        env.command()
+            .args(["config", "unset", "balance.cache_ttl"])
+            .assert()
+            .success();
+        env.command()
+            .args(["config", "get", "balance.cache_ttl"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("balance.cache_ttl is not set"));
+    }
+
+    #[test]
+    fn flags_and_environment_override_the_file() {
+        let env = env();
+        env.write_config("config.toml", "output = \"json\"\n");
+        env.command()
+            .arg("stats")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"total\""));
+        env.command()
+            .args(["-o", "yaml", "stats"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\ntotal: "));
+        env.command()
+            .env("BOHA_OUTPUT", "yaml")
+            .arg("stats")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\ntotal: "));
+    }
+
+    #[test]
+    fn defaults_apply_only_where_accepted() {
+        let env = env();
+        env.write_config("config.toml", "output = \"json\"\n");
+        env.command()
+            .args(["metrics", "gsmg"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("# TYPE"));
+    }
+
+    #[test]
+    fn config_runs_with_a_broken_file() {
+        let env = env();
+        env.write_config("config.toml", "output = \"bogus\"\n");
+        env.command().arg("stats").assert().code(64);
+        env.command()
+            .args(["config", "unset", "output"])
+            .assert()
+            .success();
+        env.command().arg("stats").assert().success();
+
+        env.write_config("config.toml", "output = [\n");
+        env.command()
+            .args(["config", "path"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("config.toml"));
+        env.command()
+            .args(["config", "list"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Invalid config"));
+    }
+
+    #[test]
+    fn color_preference() {
+        let env = env();
+        env.command()
+            .args(["range", "8"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\x1b[").not());
+        env.write_config("config.toml", "color = \"always\"\n");
+        env.command()
+            .args(["range", "8"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\x1b["));
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_bad_values() {
+        let env = env();
+        env.command()
+            .args(["config", "set", "outptu", "json"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Unknown config key outptu"));
+        env.command()
+            .args(["config", "set", "output", "markdown"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("only supported by export"));
+        env.command()
+            .args(["config", "set", "watchlist", "b1000/71,b1000/300"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Puzzle not found: b1000/300"));
+        assert!(env.read_config("config.toml").is_none());
+
+        env.write_config("config.toml", "output = [\n");
+        env.command()
+            .arg("stats")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Invalid config"));
+    }
+}
@@ -0,0 +1,175 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use tempfile::TempDir;
+
+use crate::MockServer;
+
+/// Environment variables naming the explorer and price APIs boha talks to.
+const API_URL_VARS: &[&str] = &[
+    "BOHA_MEMPOOL_URL",
+    "BOHA_ETHERSCAN_URL",
+    "BOHA_BLOCKSCOUT_URL",
+    "BOHA_LITECOINSPACE_URL",
+    "BOHA_DCRDATA_URL",
+    "BOHA_ARWEAVE_URL",
+    "BOHA_COINGECKO_URL",
+    "BOHA_TELEGRAM_URL",
+];
+
+/// Isolated filesystem and environment for one CLI test.
+///
+/// Every command runs with `HOME` and the XDG config/state/cache homes
+/// pointing into a fresh temporary directory, which is removed on drop.
+pub struct TestEnv {
+    bin: PathBuf,
+    root: TempDir,
+    vars: Vec<(String, String)>,
+}
+
+impl TestEnv {
+    /// `bin` is the binary under test, normally `env!("CARGO_BIN_EXE_boha")`.
+    pub fn new(bin: impl Into<PathBuf>) -> Self {
+        let root = tempfile::tempdir().expect("failed to create temp dir");
+        for dir in ["home", "config", "state", "cache"] {
+            fs::create_dir_all(root.path().join(dir)).expect("failed to create temp subdir");
+        }
+        Self {
+            bin: bin.into(),
+            root,
+            vars: Vec::new(),
+        }
+    }
+
+    /// Root of the temporary tree; also the working directory of commands.
+    pub fn path(&self) -> &Path {
+        self.root.path()
+    }
+
+    pub fn home_dir(&self) -> PathBuf {
+        self.path().join("home")
+    }
+
+    /// `$XDG_CONFIG_HOME/boha`
+    pub fn config_dir(&self) -> PathBuf {
+        self.path().join("config").join("boha")
+    }
+
+    /// `$XDG_STATE_HOME/boha`
+    pub fn state_dir(&self) -> PathBuf {
+        self.path().join("state").join("boha")
+    }
+
+    /// `$XDG_CACHE_HOME/boha`
+    pub fn cache_dir(&self) -> PathBuf {
+        self.path().join("cache").join("boha")
+    }
+
+    /// Set an extra environment variable for every command.
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.vars.push((key.into(), value.into()));
+        self
+    }
+
+    /// Route all explorer API requests to `server`.
+    #[must_use]
+    pub fn with_mock(self, server: &MockServer) -> Self {
+        let url = server.url();
+        API_URL_VARS
+            .iter()
+            .fold(self, |env, var| env.env(*var, url.clone()))
+            .env("ETHERSCAN_API_KEY", "test")
+    }
+
+    /// Write `contents` to `relative` under the boha config directory.
+    pub fn write_config(&self, relative: impl AsRef<Path>, contents: &str) -> PathBuf {
+        write_file(&self.config_dir().join(relative), contents)
+    }
+
+    /// Read a file from the boha config directory, if it exists.
+    pub fn read_config(&self, relative: impl AsRef<Path>) -> Option<String> {
+        fs::read_to_string(self.config_dir().join(relative)).ok()
+    }
+
+    /// Write `contents` to `relative` under the boha state directory.
+    pub fn write_state(&self, relative: impl AsRef<Path>, contents: &str) -> PathBuf {
+        write_file(&self.state_dir().join(relative), contents)
+    }
+
+    /// Read a file from the boha state directory, if it exists.
+    pub fn read_state(&self, relative: impl AsRef<Path>) -> Option<String> {
+        fs::read_to_string(self.state_dir().join(relative)).ok()
+    }
+
+    /// A `boha` command bound to this environment.
+    pub fn command(&self) -> Command {
+        let mut cmd = Command::new(&self.bin);
+        cmd.current_dir(self.path())
+            .env("HOME", self.home_dir())
+            .env("XDG_CONFIG_HOME", self.path().join("config"))
+            .env("XDG_STATE_HOME", self.path().join("state"))
+            .env("XDG_CACHE_HOME", self.path().join("cache"))
+            .env("NO_COLOR", "1")
+            .env("NO_PROXY", "127.0.0.1,localhost");
+        for var in API_URL_VARS {
+            cmd.env_remove(var);
+        }
+        cmd.env_remove("ETHERSCAN_API_KEY")
+            .env_remove("BOHA_BALANCE_BACKEND")
+            .env_remove("BOHA_BACKEND_URL")
+            .env_remove("BOHA_CACHE_TTL")
+            .env_remove("BOHA_NOTIFY_URL")
+            .env_remove("BOHA_TELEGRAM_TOKEN")
+            .env_remove("BOHA_TELEGRAM_CHAT")
+            .env_remove("BOHA_WATCHLIST")
+            .env_remove("BOHA_OUTPUT")
+            .env_remove("BOHA_COLOR")
+            .env_remove("BOHA_CONFIG")
+            .env_remove("BOHA_LOG")
+            .env_remove("BOHA_PROXY");
+        for (key, value) in &self.vars {
+            cmd.env(key, value);
+        }
+        cmd
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> PathBuf {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("failed to create parent dir");
+    }
+    fs::write(path, contents).expect("failed to write file");
+    path.to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dirs_live_under_root() {
+        let env = TestEnv::new("boha");
+        assert!(env.home_dir().is_dir());
+        assert!(env.config_dir().starts_with(env.path()));
+        assert!(env.state_dir().starts_with(env.path()));
+        assert!(env.cache_dir().starts_with(env.path()));
+    }
+
+    #[test]
+    fn write_and_read_state_roundtrip() {
+        let env = TestEnv::new("boha");
+        env.write_state("progress.json", "{}");
+        assert_eq!(env.read_state("progress.json").as_deref(), Some("{}"));
+        assert_eq!(env.read_state("missing.json"), None);
+    }
+
+    #[test]
+    fn temp_dir_removed_on_drop() {
+        let env = TestEnv::new("boha");
+        let root = env.path().to_path_buf();
+        drop(env);
+        assert!(!root.exists());
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A request received by [`MockServer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    /// Path without the query string
+    pub path: String,
+    pub query: Option<String>,
+    pub body: String,
+}
+
+#[derive(Clone)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<String, Response>,
+    requests: Vec<Request>,
+}
+
+/// Minimal blocking HTTP/1.1 server answering canned responses by path.
+///
+/// Routes match on the request path only; the query string is recorded but
+/// ignored. Unknown paths answer `404`. The server stops when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind mock server");
+        let addr = listener.local_addr().expect("mock server has no address");
+        let state = Arc::new(Mutex::new(State::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread_state = Arc::clone(&state);
+        let thread_shutdown = Arc::clone(&shutdown);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    handle(stream, &thread_state);
+                }
+            }
+        });
+
+        Self {
+            addr,
+            state,
+            shutdown,
+        }
+    }
+
+    /// Base URL, e.g. `http://127.0.0.1:41234`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Answer `path` with `status` and `body`, replacing any previous route.
+    pub fn mock(&self, path: &str, status: u16, body: impl Into<String>) -> &Self {
+        self.state.lock().unwrap().routes.insert(
+            path.to_string(),
+            Response {
+                status,
+                body: body.into(),
+            },
+        );
+        self
+    }
+
+    /// Requests received so far, in arrival order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Number of requests received for `path`.
+    pub fn hits(&self, path: &str) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .filter(|r| r.path == path)
+            .count()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Unblock the accept loop so the thread can observe the flag.
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+fn handle(mut stream: TcpStream, state: &Mutex<State>) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    let mut content_length = 0;
+    let mut line = String::new();
+    while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+        line.clear();
+    }
+    let mut body = vec![0; content_length];
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+    let body = String::from_utf8_lossy(&body).into_owned();
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        let response = state.routes.get(&path).cloned();
+        state.requests.push(Request {
+            method,
+            path,
+            query,
+            body,
+        });
+        response
+    }
+    .unwrap_or_else(|| Response {
+        status: 404,
+        body: "not found".to_string(),
+    });
+
+    let content_type = if response.body.trim_start().starts_with(['{', '[']) {
+        "application/json"
+    } else {
+        "text/plain"
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        content_type,
+        response.body.len(),
+        response.body
+    );
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get(server: &MockServer, target: &str) -> String {
+        let mut stream = TcpStream::connect(server.addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: test\r\n\r\n", target).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn serves_mocked_route() {
+        let server = MockServer::start();
+        server.mock("/balance", 200, "42");
+
+        let response = get(&server, "/balance?apikey=x");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("42"));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/balance");
+        assert_eq!(requests[0].query.as_deref(), Some("apikey=x"));
+    }
+
+    #[test]
+    fn records_post_body() {
+        let server = MockServer::start();
+        server.mock("/", 200, "{}");
+
+        let mut stream = TcpStream::connect(server.addr).unwrap();
+        write!(
+            stream,
+            "POST / HTTP/1.1\r\nHost: test\r\nContent-Length: 7\r\n\r\n{{\"a\":1}}"
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, "{\"a\":1}");
+    }
+
+    #[test]
+    fn unknown_route_is_404() {
+        let server = MockServer::start();
+        assert!(get(&server, "/missing").starts_with("HTTP/1.1 404"));
+        assert_eq!(server.hits("/missing"), 1);
+    }
+}
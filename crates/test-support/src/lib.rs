@@ -0,0 +1,22 @@
+//! End-to-end test helpers for the `boha` CLI.
+//!
+//! [`TestEnv`] runs the binary against throwaway config/state/cache
+//! directories, and [`MockServer`] stands in for the block explorer APIs
+//! so network-dependent commands can be exercised offline.
+//!
+//! ```no_run
+//! use boha_test_support::{MockServer, TestEnv};
+//!
+//! let server = MockServer::start();
+//! server.mock("/api/address/1abc", 200, r#"{"chain_stats":{}}"#);
+//!
+//! // In an integration test of the boha package, pass env!("CARGO_BIN_EXE_boha").
+//! let env = TestEnv::new("target/debug/boha").with_mock(&server);
+//! env.command().args(["balance", "b1000/71"]).assert().success();
+//! ```
+
+mod env;
+mod http;
+
+pub use env::TestEnv;
+pub use http::{MockServer, Request};
@@ -66,7 +66,7 @@ fn add_scalar_to_pubkey(
 
 fn hash160(data: &[u8]) -> [u8; 20] {
     let sha256 = Sha256::digest(data);
-    let ripemd = Ripemd160::digest(&sha256);
+    let ripemd = Ripemd160::digest(sha256);
     let mut result = [0u8; 20];
     result.copy_from_slice(&ripemd);
     result
@@ -117,12 +117,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Path: /0/0 (relative to zpub at m/84'/0'/0')");
 
     let pubkey = derive_pubkey_from_zpub(zpub, &path)?;
-    let pubkey_hex = hex::encode(&pubkey);
+    let pubkey_hex = hex::encode(pubkey);
 
     println!("\nDerived public key: {}", pubkey_hex);
 
     let computed_hash160 = hash160(&pubkey);
-    let computed_hash160_hex = hex::encode(&computed_hash160);
+    let computed_hash160_hex = hex::encode(computed_hash160);
 
     println!("Computed HASH160:   {}", computed_hash160_hex);
     println!("Expected HASH160:   {}", expected_hash160);
@@ -136,7 +136,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let content = std::fs::read_to_string(jsonc_path)?;
             let mut value: serde_json::Value =
                 jsonc_parser::parse_to_serde_value(&content, &Default::default())?
-                    .ok_or_else(|| "Failed to parse JSONC")?;
+                    .ok_or("Failed to parse JSONC")?;
 
             if let Some(puzzle) = value.get_mut("puzzle") {
                 puzzle["pubkey"] = serde_json::json!(&pubkey_hex);
@@ -8,12 +8,13 @@
 //!   cargo run -p scripts --bin extract-pubkey --apply      # Actually update JSONC files
 //!   cargo run -p scripts --bin extract-pubkey --collection zden  # Filter by collection
 
+use boha_chain::endpoints;
+use boha_scripts::types::{strip_jsonc_comments, Collection, Puzzle};
 use reqwest::Client;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
 use std::time::Duration;
-use boha_scripts::types::{Collection, Puzzle, strip_jsonc_comments};
 
 const RATE_LIMIT_DELAY: Duration = Duration::from_millis(500);
 
@@ -31,7 +32,10 @@ fn write_cache<T: Serialize>(cache_key: &str, data: &T) {
     let cache_dir = cache_dir();
     let _ = std::fs::create_dir_all(&cache_dir);
     let cache_path = cache_dir.join(format!("{}.json", cache_key));
-    let _ = std::fs::write(&cache_path, serde_json::to_string_pretty(data).unwrap_or_default());
+    let _ = std::fs::write(
+        &cache_path,
+        serde_json::to_string_pretty(data).unwrap_or_default(),
+    );
 }
 
 // ============================================================================
@@ -95,8 +99,6 @@ struct EtherscanTxResult {
     s: String,
 }
 
-
-
 // ============================================================================
 // Pubkey Extraction
 // ============================================================================
@@ -158,15 +160,15 @@ async fn fetch_btc_pubkey(
     chain: &str,
 ) -> Result<Option<(String, String)>, Box<dyn std::error::Error>> {
     let cache_key = format!("{}-{}", chain, txid);
-    
+
     let response: MempoolTxResponse = if let Some(cached) = read_cache(&cache_key) {
         cached
     } else {
-        let base_url = match chain {
-            "litecoin" => "https://litecoinspace.org/api/tx",
-            _ => "https://mempool.space/api/tx",
+        let endpoint = match chain {
+            "litecoin" => endpoints::LITECOINSPACE,
+            _ => endpoints::MEMPOOL,
         };
-        let url = format!("{}/{}", base_url, txid);
+        let url = format!("{}/api/tx/{}", endpoint.base_url(), txid);
         tokio::time::sleep(RATE_LIMIT_DELAY).await;
         let data: MempoolTxResponse = client.get(&url).send().await?.json().await?;
         write_cache(&cache_key, &data);
@@ -213,11 +215,11 @@ async fn fetch_dcr_pubkey(
     txid: &str,
 ) -> Result<Option<(String, String)>, Box<dyn std::error::Error>> {
     let cache_key = format!("decred-{}", txid);
-    
+
     let response: DcrdataTxResponse = if let Some(cached) = read_cache(&cache_key) {
         cached
     } else {
-        let url = format!("https://dcrdata.decred.org/api/tx/{}", txid);
+        let url = format!("{}/api/tx/{}", endpoints::DCRDATA.base_url(), txid);
         tokio::time::sleep(RATE_LIMIT_DELAY).await;
         let data: DcrdataTxResponse = client.get(&url).send().await?.json().await?;
         write_cache(&cache_key, &data);
@@ -241,7 +243,11 @@ fn rlp_encode_length(len: usize, offset: u8) -> Vec<u8> {
         vec![offset + len as u8]
     } else {
         let len_bytes = len.to_be_bytes();
-        let len_bytes = len_bytes.iter().skip_while(|&&b| b == 0).copied().collect::<Vec<_>>();
+        let len_bytes = len_bytes
+            .iter()
+            .skip_while(|&&b| b == 0)
+            .copied()
+            .collect::<Vec<_>>();
         let mut result = vec![offset + 55 + len_bytes.len() as u8];
         result.extend(len_bytes);
         result
@@ -342,7 +348,10 @@ async fn fetch_eth_pubkey(
     api_key: &str,
     expected_address: &str,
 ) -> Result<Option<(String, String)>, Box<dyn std::error::Error>> {
-    use secp256k1::{ecdsa::{RecoverableSignature, RecoveryId}, Message, Secp256k1};
+    use secp256k1::{
+        ecdsa::{RecoverableSignature, RecoveryId},
+        Message, Secp256k1,
+    };
 
     let secp = Secp256k1::new();
     let cache_key = format!("ethereum-{}", txid);
@@ -350,9 +359,10 @@ async fn fetch_eth_pubkey(
     let response: EtherscanTxResponse = if let Some(cached) = read_cache(&cache_key) {
         cached
     } else {
-        let url = format!(
-            "https://api.etherscan.io/v2/api?chainid=1&module=proxy&action=eth_getTransactionByHash&txhash={}&apikey={}",
-            txid, api_key
+        let url =
+            format!(
+            "{}/v2/api?chainid=1&module=proxy&action=eth_getTransactionByHash&txhash={}&apikey={}",
+            endpoints::ETHERSCAN.base_url(), txid, api_key
         );
         tokio::time::sleep(RATE_LIMIT_DELAY).await;
         let data: EtherscanTxResponse = client.get(&url).send().await?.json().await?;
@@ -416,11 +426,17 @@ async fn fetch_eth_pubkey(
     let expected_lower = expected_address.to_lowercase();
 
     if derived_address != expected_lower {
-        eprintln!("    Error: derived address {} != expected {}", derived_address, expected_lower);
+        eprintln!(
+            "    Error: derived address {} != expected {}",
+            derived_address, expected_lower
+        );
         return Ok(None);
     }
 
-    Ok(Some((hex::encode(pubkey_bytes), "uncompressed".to_string())))
+    Ok(Some((
+        hex::encode(pubkey_bytes),
+        "uncompressed".to_string(),
+    )))
 }
 
 // ============================================================================
@@ -549,7 +565,10 @@ fn find_puzzles_needing_pubkey(data_dir: &Path) -> Vec<PuzzleToProcess> {
                 continue;
             }
 
-            if puzzle.address.kind.as_deref()
+            if puzzle
+                .address
+                .kind
+                .as_deref()
                 .map(|k| k.eq_ignore_ascii_case("p2sh"))
                 .unwrap_or(false)
             {
@@ -690,7 +709,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let result = match fetch_result {
             Ok(res) => res,
             Err(err) => {
-                eprintln!("    Fetch failed for {}:{}: {}", puzzle.chain, puzzle.claim_txid, err);
+                eprintln!(
+                    "    Fetch failed for {}:{}: {}",
+                    puzzle.chain, puzzle.claim_txid, err
+                );
                 continue;
             }
         };
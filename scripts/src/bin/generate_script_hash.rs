@@ -8,7 +8,7 @@ fn redeem_script_to_script_hash(
 ) -> Result<String, Box<dyn std::error::Error>> {
     let script_bytes = hex::decode(redeem_script_hex)?;
     let sha256_hash = Sha256::digest(&script_bytes);
-    let hash160 = Ripemd160::digest(&sha256_hash);
+    let hash160 = Ripemd160::digest(sha256_hash);
     Ok(hex::encode(hash160))
 }
 
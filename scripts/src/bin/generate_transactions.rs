@@ -2,7 +2,7 @@ mod utils {
     include!("../utils/mod.rs");
 }
 
-use boha_scripts::types::{Collection, strip_jsonc_comments};
+use boha_scripts::types::{strip_jsonc_comments, Collection};
 use serde_json::Value;
 use std::collections::HashSet;
 use std::path::Path;
@@ -67,8 +67,6 @@ async fn fetch_and_cache_eth(
     }
 }
 
-
-
 fn process_cached_btc(
     puzzle: &mut Value,
     address: &str,
@@ -86,7 +84,8 @@ fn process_cached_btc(
         .unwrap_or("")
         .to_string();
     let existing = extract_existing_transactions(puzzle);
-    let new_transactions = mempool::categorize_transactions(address, txs, author_addresses, &status);
+    let new_transactions =
+        mempool::categorize_transactions(address, txs, author_addresses, &status);
     let merged = merge_transactions(existing, new_transactions);
 
     if !merged.is_empty() {
@@ -166,7 +165,11 @@ fn process_cached_b1000(
     if let Some(puzzles) = doc.get_mut("puzzles") {
         if let Some(array) = puzzles.as_array_mut() {
             for (idx, puzzle) in array.iter_mut().enumerate() {
-                let bits = puzzle.get("key").and_then(|k| k.get("bits")).and_then(|b| b.as_i64()).unwrap_or(0);
+                let bits = puzzle
+                    .get("key")
+                    .and_then(|k| k.get("bits"))
+                    .and_then(|b| b.as_i64())
+                    .unwrap_or(0);
 
                 if let Some(filter) = filter_puzzle {
                     if bits != filter {
@@ -181,7 +184,12 @@ fn process_cached_b1000(
                     .unwrap_or("")
                     .to_string();
 
-                println!("  [{}/256] Processing puzzle {} ({})", idx + 1, bits, address);
+                println!(
+                    "  [{}/256] Processing puzzle {} ({})",
+                    idx + 1,
+                    bits,
+                    address
+                );
 
                 if process_cached_btc(puzzle, &address, "b1000", author_addresses) {
                     count += 1;
@@ -275,7 +283,8 @@ fn process_cached_dcr(
         .unwrap_or("")
         .to_string();
     let existing = extract_existing_transactions(puzzle);
-    let new_transactions = dcrdata::categorize_transactions(address, txs, author_addresses, &status);
+    let new_transactions =
+        dcrdata::categorize_transactions(address, txs, author_addresses, &status);
     let merged = merge_transactions(existing, new_transactions);
 
     if !merged.is_empty() {
@@ -300,15 +309,9 @@ async fn fetch_and_cache_collection(
         for (idx, puzzle) in puzzles.iter().enumerate() {
             let address = &puzzle.address.value;
 
-            let name = puzzle
-                .name
-                .as_deref()
-                .unwrap_or("unknown");
+            let name = puzzle.name.as_deref().unwrap_or("unknown");
 
-            let chain = puzzle
-                .chain
-                .as_deref()
-                .unwrap_or("bitcoin");
+            let chain = puzzle.chain.as_deref().unwrap_or("bitcoin");
 
             print!("  [{}/{}]", idx + 1, total);
 
@@ -325,9 +328,7 @@ async fn fetch_and_cache_collection(
                         false
                     }
                 }
-                "decred" => {
-                    fetch_and_cache_dcr(client, address, collection, name, force).await?
-                }
+                "decred" => fetch_and_cache_dcr(client, address, collection, name, force).await?,
                 _ => {
                     println!("    Skipping {} - unsupported chain: {}", name, chain);
                     false
@@ -387,9 +388,7 @@ fn process_cached_collection(
                     "ethereum" => {
                         process_cached_eth(puzzle, &address, collection, author_addresses)
                     }
-                    "decred" => {
-                        process_cached_dcr(puzzle, &address, collection, author_addresses)
-                    }
+                    "decred" => process_cached_dcr(puzzle, &address, collection, author_addresses),
                     _ => {
                         println!("    Unsupported chain: {}", chain);
                         false
@@ -494,7 +493,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             Mode::Fetch | Mode::Both => {
                 println!("Fetching: {}", collection);
                 let fetched = match collection.as_str() {
-                    "b1000" => fetch_and_cache_b1000(&client, &collection_data, filter_puzzle, force).await?,
+                    "b1000" => {
+                        fetch_and_cache_b1000(&client, &collection_data, filter_puzzle, force)
+                            .await?
+                    }
                     "gsmg" => fetch_and_cache_gsmg(&client, &collection_data, force).await?,
                     _ => {
                         fetch_and_cache_collection(
@@ -1,32 +1,16 @@
+use boha::address::Network;
+use boha::keytools;
+use boha::PubkeyFormat;
 use serde_json::{json, Value};
-use sha2::{Digest, Sha256};
 use std::path::Path;
 
-fn sha256(data: &[u8]) -> [u8; 32] {
-    let mut hasher = Sha256::new();
-    hasher.update(data);
-    hasher.finalize().into()
-}
-
-fn hex_to_wif(hex_key: &str, compressed: bool) -> Option<String> {
-    const MAINNET_VERSION: u8 = 0x80;
-    const COMPRESSION_FLAG: u8 = 0x01;
-
-    let key_bytes = hex::decode(hex_key).ok()?;
-    if key_bytes.len() != 32 {
-        return None;
-    }
-
-    let mut data = vec![MAINNET_VERSION];
-    data.extend_from_slice(&key_bytes);
-    if compressed {
-        data.push(COMPRESSION_FLAG);
-    }
-
-    let checksum = &sha256(&sha256(&data))[..4];
-    data.extend_from_slice(checksum);
-
-    Some(bs58::encode(data).into_string())
+fn hex_to_wif(hex_key: &str) -> Option<String> {
+    let key = keytools::key_from_hex(hex_key).ok()?;
+    Some(keytools::to_wif(
+        &key,
+        PubkeyFormat::Compressed,
+        Network::Mainnet,
+    ))
 }
 
 fn needs_wif(key_item: &Value) -> Option<String> {
@@ -67,7 +51,7 @@ fn update_puzzles_array(doc: &mut Value) -> usize {
             for puzzle in array.iter_mut() {
                 if let Some(key_item) = puzzle.get_mut("key") {
                     if let Some(hex) = needs_wif(key_item) {
-                        if let Some(wif) = hex_to_wif(&hex, true) {
+                        if let Some(wif) = hex_to_wif(&hex) {
                             add_wif_to_key(key_item, &wif);
                             count += 1;
                         }
@@ -84,7 +68,7 @@ fn update_single_puzzle(doc: &mut Value) -> usize {
     if let Some(puzzle) = doc.get_mut("puzzle") {
         if let Some(key_item) = puzzle.get_mut("key") {
             if let Some(hex) = needs_wif(key_item) {
-                if let Some(wif) = hex_to_wif(&hex, true) {
+                if let Some(wif) = hex_to_wif(&hex) {
                     add_wif_to_key(key_item, &wif);
                     return 1;
                 }
@@ -99,7 +83,7 @@ fn process_jsonc_file(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
 
     let content = std::fs::read_to_string(path)?;
     let mut doc: Value = jsonc_parser::parse_to_serde_value(&content, &Default::default())?
-        .ok_or_else(|| "Failed to parse JSONC")?;
+        .ok_or("Failed to parse JSONC")?;
 
     let count = if doc.get("puzzles").is_some() {
         update_puzzles_array(&mut doc)
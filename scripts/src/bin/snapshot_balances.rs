@@ -0,0 +1,131 @@
+//! Compute the confirmed balance of every puzzle address from the
+//! transaction cache (`data/cache`, filled by `generate-transactions
+//! --fetch`) and write `data/balances.json`, which the library embeds with
+//! the `balance-snapshot` feature.
+//!
+//! Usage: cargo run --bin snapshot-balances
+
+#[allow(dead_code)]
+mod utils {
+    include!("../utils/mod.rs");
+}
+
+use boha::Chain;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::time::SystemTime;
+use utils::{cache_path, dcrdata, etherscan, mempool};
+
+#[derive(Serialize)]
+struct Snapshot {
+    taken_at: String,
+    balances: BTreeMap<String, u128>,
+}
+
+/// Sats received minus sats spent, counting confirmed transactions only.
+fn mempool_balance(address: &str, txs: &[mempool::MempoolTx]) -> u128 {
+    let mut received: u128 = 0;
+    let mut spent: u128 = 0;
+    for tx in txs.iter().filter(|tx| tx.status.block_time.is_some()) {
+        for vout in &tx.vout {
+            if vout.scriptpubkey_address.as_deref() == Some(address) {
+                received += u128::from(vout.value);
+            }
+        }
+        for prevout in tx.vin.iter().filter_map(|vin| vin.prevout.as_ref()) {
+            if prevout.scriptpubkey_address.as_deref() == Some(address) {
+                spent += u128::from(prevout.value);
+            }
+        }
+    }
+    received.saturating_sub(spent)
+}
+
+/// Wei received minus wei sent by successful transactions. Gas is not in the
+/// cached data, so an address that has sent keeps a little too much.
+fn etherscan_balance(address: &str, txs: &[etherscan::EtherscanTx]) -> u128 {
+    let address = address.to_lowercase();
+    let mut received: u128 = 0;
+    let mut sent: u128 = 0;
+    for tx in txs.iter().filter(|tx| tx.is_error != "1") {
+        let value: u128 = tx.value.parse().unwrap_or(0);
+        if tx.to.to_lowercase() == address {
+            received += value;
+        }
+        if tx.from.to_lowercase() == address {
+            sent += value;
+        }
+    }
+    received.saturating_sub(sent)
+}
+
+/// Atoms in outputs to `address` that no cached transaction spends.
+fn dcrdata_balance(address: &str, txs: &[dcrdata::DcrdataTx]) -> u128 {
+    let spent: HashSet<(&str, u32)> = txs
+        .iter()
+        .flat_map(|tx| tx.vin.iter().map(|vin| (vin.txid.as_str(), vin.vout)))
+        .collect();
+    txs.iter()
+        .flat_map(|tx| tx.vout.iter().map(move |vout| (tx.txid.as_str(), vout)))
+        .filter(|(_, vout)| {
+            vout.script_pub_key
+                .addresses
+                .as_ref()
+                .is_some_and(|addresses| addresses.iter().any(|a| a == address))
+        })
+        .filter(|(txid, vout)| !spent.contains(&(*txid, vout.n)))
+        .map(|(_, vout)| (vout.value * 100_000_000.0).round() as u128)
+        .sum()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut balances = BTreeMap::new();
+    let mut newest: Option<SystemTime> = None;
+
+    for puzzle in boha::all() {
+        let collection = puzzle.id.split('/').next().unwrap_or(puzzle.id);
+        for address in puzzle.addresses().map(|a| a.value) {
+            let balance = match puzzle.chain {
+                Chain::Bitcoin | Chain::Litecoin => mempool::load_from_cache(collection, address)
+                    .map(|txs| mempool_balance(address, &txs)),
+                Chain::Ethereum => etherscan::load_from_cache(collection, address)
+                    .map(|txs| etherscan_balance(address, &txs)),
+                Chain::Decred => dcrdata::load_from_cache(collection, address)
+                    .map(|txs| dcrdata_balance(address, &txs)),
+                // No transaction cache for the other chains.
+                _ => None,
+            };
+            let Some(balance) = balance else {
+                continue;
+            };
+            newest = newest.max(modified(&cache_path(collection, address)));
+            balances.insert(address.to_string(), balance);
+        }
+    }
+
+    let Some(newest) = newest else {
+        return Err("No cached transactions; run generate-transactions --fetch first".into());
+    };
+    let snapshot = Snapshot {
+        taken_at: DateTime::<Utc>::from(newest)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+        balances,
+    };
+
+    let path = Path::new("../data/balances.json");
+    std::fs::write(path, serde_json::to_string_pretty(&snapshot)? + "\n")?;
+    println!(
+        "Wrote {} balances as of {} to {}",
+        snapshot.balances.len(),
+        snapshot.taken_at,
+        path.display()
+    );
+    Ok(())
+}
@@ -5,7 +5,8 @@ use std::path::Path;
 use std::time::Duration;
 
 pub mod types;
-use types::{Collection, strip_jsonc_comments};
+use boha_chain::endpoints;
+use types::{strip_jsonc_comments, Collection};
 
 #[derive(Debug, Deserialize)]
 struct TxStatus {
@@ -21,7 +22,11 @@ async fn fetch_first_tx_date(
     client: &reqwest::Client,
     address: &str,
 ) -> Result<Option<String>, Box<dyn std::error::Error>> {
-    let url = format!("https://mempool.space/api/address/{}/txs", address);
+    let url = format!(
+        "{}/api/address/{}/txs",
+        endpoints::MEMPOOL.base_url(),
+        address
+    );
 
     let mut retries = 3;
     loop {
@@ -46,10 +51,7 @@ async fn fetch_first_tx_date(
             return Ok(None);
         }
 
-        let oldest_time = txs
-            .iter()
-            .filter_map(|tx| tx.status.block_time)
-            .min();
+        let oldest_time = txs.iter().filter_map(|tx| tx.status.block_time).min();
 
         return match oldest_time {
             Some(timestamp) => {
@@ -61,10 +63,7 @@ async fn fetch_first_tx_date(
     }
 }
 
-fn update_jsonc_with_dates(
-    doc: &mut Value,
-    dates: &[(usize, String)],
-) {
+fn update_jsonc_with_dates(doc: &mut Value, dates: &[(usize, String)]) {
     if let Some(puzzles) = doc.get_mut("puzzles") {
         if let Some(array) = puzzles.as_array_mut() {
             for (idx, date) in dates {
@@ -86,14 +85,13 @@ async fn process_jsonc_file(
 
     let content = std::fs::read_to_string(path)?;
     let mut doc: Value = jsonc_parser::parse_to_serde_value(&content, &Default::default())?
-        .ok_or_else(|| "Failed to parse JSONC")?;
+        .ok_or("Failed to parse JSONC")?;
 
     let addresses: Vec<(usize, String)> = {
         let stripped = strip_jsonc_comments(&content);
         let collection: Collection = serde_json::from_str(&stripped)?;
-        
-        let puzzles = collection.puzzles
-            .ok_or("No puzzles array found")?;
+
+        let puzzles = collection.puzzles.ok_or("No puzzles array found")?;
 
         puzzles
             .iter()
@@ -102,7 +100,7 @@ async fn process_jsonc_file(
                 if puzzle.start_date.is_some() {
                     return None;
                 }
-                
+
                 Some((idx, puzzle.address.value.clone()))
             })
             .collect()
@@ -1,6 +1,7 @@
 use crate::utils::{cache_path, timestamp_to_date, Transaction, RATE_LIMIT_DELAY, RETRY_DELAY};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use boha_chain::endpoints;
 
 const PAGE_SIZE: u32 = 50;
 
@@ -102,8 +103,11 @@ pub async fn fetch_transactions(
 
     loop {
         let url = format!(
-            "https://dcrdata.decred.org/api/address/{}/count/{}/skip/{}/raw",
-            address, PAGE_SIZE, skip
+            "{}/api/address/{}/count/{}/skip/{}/raw",
+            endpoints::DCRDATA.base_url(),
+            address,
+            PAGE_SIZE,
+            skip
         );
 
         tokio::time::sleep(RATE_LIMIT_DELAY).await;
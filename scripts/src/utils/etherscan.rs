@@ -2,6 +2,7 @@ use crate::utils::{cache_path, timestamp_to_date, Transaction, RETRY_DELAY};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::time::Duration;
+use boha_chain::endpoints;
 
 const ETH_RATE_LIMIT_DELAY: Duration = Duration::from_millis(250);
 
@@ -58,8 +59,8 @@ pub async fn fetch_transactions(
     api_key: &str,
 ) -> Result<Vec<EtherscanTx>, Box<dyn std::error::Error>> {
     let url = format!(
-        "https://api.etherscan.io/v2/api?chainid=1&module=account&action=txlist&address={}&startblock=0&endblock=99999999&sort=asc&apikey={}",
-        address, api_key
+        "{}/v2/api?chainid=1&module=account&action=txlist&address={}&startblock=0&endblock=99999999&sort=asc&apikey={}",
+        endpoints::ETHERSCAN.base_url(), address, api_key
     );
 
     for attempt in 0..5 {
@@ -1,6 +1,7 @@
 use crate::utils::{cache_path, timestamp_to_date, Transaction, RATE_LIMIT_DELAY, RETRY_DELAY};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use boha_chain::endpoints;
 
 const DUST_THRESHOLD: u64 = 10_000;
 
@@ -110,10 +111,16 @@ pub async fn fetch_transactions(
     loop {
         let url = match &last_txid {
             Some(txid) => format!(
-                "https://mempool.space/api/address/{}/txs/chain/{}",
-                address, txid
+                "{}/api/address/{}/txs/chain/{}",
+                endpoints::MEMPOOL.base_url(),
+                address,
+                txid
+            ),
+            None => format!(
+                "{}/api/address/{}/txs",
+                endpoints::MEMPOOL.base_url(),
+                address
             ),
-            None => format!("https://mempool.space/api/address/{}/txs", address),
         };
 
         tokio::time::sleep(RATE_LIMIT_DELAY).await;
@@ -0,0 +1,672 @@
+//! Address parsing shared by everything that needs to know what an address
+//! pays to.
+//!
+//! [`AddressKind::detect`] decodes an address for a given chain, checking
+//! its checksum, and returns the script type together with the hash or
+//! witness program it commits to. Callers match on the variant instead of
+//! guessing from prefixes, and a typo or an address from the wrong chain is
+//! reported as such rather than as a mismatch.
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::Chain;
+
+/// Bitcoin network a base58 address belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+/// What an address pays to, with the decoded payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressKind {
+    /// Pay to public key hash: HASH160 of the public key.
+    P2pkh { network: Network, hash: [u8; 20] },
+    /// Pay to script hash: HASH160 of the redeem script.
+    P2sh { network: Network, hash: [u8; 20] },
+    /// SegWit v0 key hash.
+    P2wpkh { program: [u8; 20] },
+    /// SegWit v0 script hash: SHA256 of the witness script.
+    P2wsh { program: [u8; 32] },
+    /// Taproot: x-only output key.
+    P2tr { output_key: [u8; 32] },
+    /// Last 20 bytes of the Keccak-256 of the public key.
+    Ethereum { address: [u8; 20] },
+    /// SHA256 of the wallet's RSA modulus.
+    Arweave { owner_hash: [u8; 32] },
+}
+
+/// Why an address could not be parsed.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    #[error("{0} addresses cannot be parsed")]
+    UnsupportedChain(&'static str),
+
+    #[error("{address} is not a recognized {chain} address")]
+    Unrecognized {
+        address: String,
+        chain: &'static str,
+    },
+
+    #[error("{address} is a {found} address, expected {expected}")]
+    WrongChain {
+        address: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+
+    #[error("Invalid address {address}: {reason}")]
+    Invalid { address: String, reason: String },
+}
+
+impl AddressKind {
+    /// Decode `address` as an address on `chain`.
+    ///
+    /// Base58 and bech32 checksums are verified, as is the EIP-55 checksum
+    /// of mixed-case Ethereum addresses. Monero addresses are not parsed.
+    pub fn detect(address: &str, chain: Chain) -> Result<Self, AddressError> {
+        if let Some(kind) = Self::detect_on(address, chain)? {
+            return Ok(kind);
+        }
+        let found = Chain::ALL
+            .into_iter()
+            .filter(|&other| other != chain)
+            .find(|&other| matches!(Self::detect_on(address, other), Ok(Some(_))));
+        Err(match found {
+            Some(other) => AddressError::WrongChain {
+                address: address.to_string(),
+                expected: chain.name(),
+                found: other.name(),
+            },
+            None => AddressError::Unrecognized {
+                address: address.to_string(),
+                chain: chain.name(),
+            },
+        })
+    }
+
+    /// Decode `address` on whichever supported chain it belongs to.
+    ///
+    /// When it looks like an address of some chain but fails to decode
+    /// there (a checksum typo), that error is returned.
+    pub fn identify(address: &str) -> Result<(Chain, Self), AddressError> {
+        let mut invalid = None;
+        for chain in Chain::ALL {
+            match Self::detect_on(address, chain) {
+                Ok(Some(kind)) => return Ok((chain, kind)),
+                Ok(None) | Err(AddressError::UnsupportedChain(_)) => {}
+                Err(e) => {
+                    invalid.get_or_insert(e);
+                }
+            }
+        }
+        Err(invalid.unwrap_or_else(|| AddressError::Unrecognized {
+            address: address.to_string(),
+            chain: "any supported chain",
+        }))
+    }
+
+    /// `Ok(None)` when `address` does not look like a `chain` address at all,
+    /// an error when it does but fails to decode.
+    fn detect_on(address: &str, chain: Chain) -> Result<Option<Self>, AddressError> {
+        let invalid = |reason: &str| AddressError::Invalid {
+            address: address.to_string(),
+            reason: reason.to_string(),
+        };
+        match chain {
+            Chain::Bitcoin => {
+                if let Some(kind) = decode_segwit(address, "bc", &invalid)? {
+                    return Ok(Some(kind));
+                }
+                Ok(
+                    decode_base58check(address, chain, &invalid)?.and_then(|(version, hash)| {
+                        match version {
+                            0x00 => Some(Self::P2pkh {
+                                network: Network::Mainnet,
+                                hash,
+                            }),
+                            0x6f => Some(Self::P2pkh {
+                                network: Network::Testnet,
+                                hash,
+                            }),
+                            0x05 => Some(Self::P2sh {
+                                network: Network::Mainnet,
+                                hash,
+                            }),
+                            0xc4 => Some(Self::P2sh {
+                                network: Network::Testnet,
+                                hash,
+                            }),
+                            _ => None,
+                        }
+                    }),
+                )
+            }
+            Chain::Litecoin => {
+                if let Some(kind) = decode_segwit(address, "ltc", &invalid)? {
+                    return Ok(Some(kind));
+                }
+                Ok(
+                    decode_base58check(address, chain, &invalid)?.and_then(|(version, hash)| {
+                        match version {
+                            0x30 => Some(Self::P2pkh {
+                                network: Network::Mainnet,
+                                hash,
+                            }),
+                            0x32 => Some(Self::P2sh {
+                                network: Network::Mainnet,
+                                hash,
+                            }),
+                            _ => None,
+                        }
+                    }),
+                )
+            }
+            Chain::Decred => Ok(decode_base58check(address, chain, &invalid)?.and_then(
+                |(version, hash)| match version {
+                    0x3f => Some(Self::P2pkh {
+                        network: Network::Mainnet,
+                        hash,
+                    }),
+                    0x1a => Some(Self::P2sh {
+                        network: Network::Mainnet,
+                        hash,
+                    }),
+                    _ => None,
+                },
+            )),
+            Chain::Ethereum => decode_ethereum(address, &invalid),
+            Chain::Arweave => {
+                Ok(decode_arweave(address).map(|owner_hash| Self::Arweave { owner_hash }))
+            }
+            Chain::Monero => Err(AddressError::UnsupportedChain(chain.name())),
+            Chain::BitcoinCash => {
+                if let Some(kind) = decode_cashaddr(address, &invalid)? {
+                    return Ok(Some(kind));
+                }
+                Ok(decode_base58check(address, chain, &invalid)?
+                    .and_then(|(version, hash)| Self::mainnet(version, hash, 0x00, 0x05)))
+            }
+            Chain::Dogecoin => Ok(decode_base58check(address, chain, &invalid)?
+                .and_then(|(version, hash)| Self::mainnet(version, hash, 0x1e, 0x16))),
+            // Transparent addresses only; shielded ones carry no key hash.
+            Chain::Zcash => Ok(decode_base58check(address, chain, &invalid)?
+                .and_then(|(version, hash)| Self::mainnet(version, hash, 0xb8, 0xbd))),
+            Chain::Dash => Ok(decode_base58check(address, chain, &invalid)?
+                .and_then(|(version, hash)| Self::mainnet(version, hash, 0x4c, 0x10))),
+        }
+    }
+
+    /// Mainnet P2PKH or P2SH by base58 version byte.
+    fn mainnet(version: u8, hash: [u8; 20], p2pkh: u8, p2sh: u8) -> Option<Self> {
+        let network = Network::Mainnet;
+        if version == p2pkh {
+            Some(Self::P2pkh { network, hash })
+        } else if version == p2sh {
+            Some(Self::P2sh { network, hash })
+        } else {
+            None
+        }
+    }
+
+    /// The `address.kind` spelling used in the puzzle data.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::P2pkh { .. } => "p2pkh",
+            Self::P2sh { .. } => "p2sh",
+            Self::P2wpkh { .. } => "p2wpkh",
+            Self::P2wsh { .. } => "p2wsh",
+            Self::P2tr { .. } => "p2tr",
+            Self::Ethereum { .. } | Self::Arweave { .. } => "standard",
+        }
+    }
+
+    /// The hash, witness program or key the address encodes.
+    pub fn payload(&self) -> &[u8] {
+        match self {
+            Self::P2pkh { hash, .. } | Self::P2sh { hash, .. } => hash,
+            Self::P2wpkh { program } => program,
+            Self::P2wsh { program } => program,
+            Self::P2tr { output_key } => output_key,
+            Self::Ethereum { address } => address,
+            Self::Arweave { owner_hash } => owner_hash,
+        }
+    }
+
+    /// The HASH160 of a public key this address pays to, if it pays to one.
+    pub fn key_hash(&self) -> Option<&[u8; 20]> {
+        match self {
+            Self::P2pkh { hash, .. } => Some(hash),
+            Self::P2wpkh { program } => Some(program),
+            _ => None,
+        }
+    }
+}
+
+/// Decode a SegWit address with human-readable part `hrp`, or `Ok(None)` if
+/// `address` does not start with it.
+fn decode_segwit(
+    address: &str,
+    hrp: &str,
+    invalid: &dyn Fn(&str) -> AddressError,
+) -> Result<Option<AddressKind>, AddressError> {
+    let prefix = format!("{}1", hrp);
+    let looks_segwit = address
+        .get(..prefix.len())
+        .is_some_and(|p| p.eq_ignore_ascii_case(&prefix));
+    if !looks_segwit {
+        return Ok(None);
+    }
+
+    let (decoded_hrp, version, program) =
+        bech32::segwit::decode(address).map_err(|e| invalid(&e.to_string()))?;
+    if decoded_hrp.to_lowercase() != hrp {
+        return Err(invalid("unexpected human-readable part"));
+    }
+    let kind = match (version.to_u8(), program.len()) {
+        (0, 20) => AddressKind::P2wpkh {
+            program: program.try_into().unwrap(),
+        },
+        (0, 32) => AddressKind::P2wsh {
+            program: program.try_into().unwrap(),
+        },
+        (1, 32) => AddressKind::P2tr {
+            output_key: program.try_into().unwrap(),
+        },
+        (version, len) => {
+            return Err(invalid(&format!(
+                "unsupported witness v{} program of {} bytes",
+                version, len
+            )))
+        }
+    };
+    Ok(Some(kind))
+}
+
+/// Decode a base58check address into its version and 20-byte hash.
+///
+/// Decred and Zcash carry a two-byte version (`0x07` or `0x1c`, then the
+/// type); Decred also checksums with BLAKE-256. The returned version is the
+/// type byte.
+fn decode_base58check(
+    address: &str,
+    chain: Chain,
+    invalid: &dyn Fn(&str) -> AddressError,
+) -> Result<Option<(u8, [u8; 20])>, AddressError> {
+    let Ok(decoded) = bs58::decode(address).into_vec() else {
+        return Ok(None);
+    };
+    let lead = match chain {
+        Chain::Decred => Some(0x07),
+        Chain::Zcash => Some(0x1c),
+        _ => None,
+    };
+    let version_len = if lead.is_some() { 2 } else { 1 };
+    if decoded.len() != version_len + 24 {
+        return Ok(None);
+    }
+    if lead.is_some_and(|lead| decoded[0] != lead) {
+        return Ok(None);
+    }
+
+    let (payload, checksum) = decoded.split_at(decoded.len() - 4);
+    let expected = if chain == Chain::Decred {
+        use blake_hash::{Blake256, Digest};
+        Blake256::digest(&Blake256::digest(payload)).to_vec()
+    } else {
+        Sha256::digest(Sha256::digest(payload)).to_vec()
+    };
+    let version = payload[version_len - 1];
+    let hash: [u8; 20] = payload[version_len..].try_into().unwrap();
+
+    if expected[..4] != *checksum {
+        return Err(invalid("base58 checksum mismatch"));
+    }
+    Ok(Some((version, hash)))
+}
+
+fn decode_ethereum(
+    address: &str,
+    invalid: &dyn Fn(&str) -> AddressError,
+) -> Result<Option<AddressKind>, AddressError> {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let Some(digits) = address.strip_prefix("0x") else {
+        return Ok(None);
+    };
+    if digits.len() != 40 {
+        return Err(invalid("expected 40 hex digits after 0x"));
+    }
+    let address_bytes: [u8; 20] = hex::decode(digits)
+        .map_err(|_| invalid("not hexadecimal"))?
+        .try_into()
+        .unwrap();
+
+    // All-lowercase and all-uppercase spellings carry no EIP-55 checksum.
+    let has_lower = digits.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = digits.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        let lower = digits.to_ascii_lowercase();
+        let mut hash = [0u8; 32];
+        let mut keccak = Keccak::v256();
+        keccak.update(lower.as_bytes());
+        keccak.finalize(&mut hash);
+        let checksummed = digits.bytes().enumerate().all(|(i, b)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            !b.is_ascii_alphabetic() || b.is_ascii_uppercase() == (nibble >= 8)
+        });
+        if !checksummed {
+            return Err(invalid("EIP-55 checksum mismatch"));
+        }
+    }
+    Ok(Some(AddressKind::Ethereum {
+        address: address_bytes,
+    }))
+}
+
+pub(crate) const CASHADDR_PREFIX: &str = "bitcoincash";
+pub(crate) const CASHADDR_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// BCH-code checksum over CashAddr 5-bit values; zero for a valid address
+/// when run over the prefix, a separator and the payload with checksum.
+pub(crate) fn cashaddr_polymod(values: impl IntoIterator<Item = u8>) -> u64 {
+    const GENERATORS: [u64; 5] = [
+        0x98_f2bc_8e61,
+        0x79_b76d_99e2,
+        0xf3_3e5f_b3c4,
+        0xae_2eab_e2a8,
+        0x1e_4f43_e470,
+    ];
+    let mut c: u64 = 1;
+    for value in values {
+        let top = c >> 35;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ u64::from(value);
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                c ^= generator;
+            }
+        }
+    }
+    c ^ 1
+}
+
+/// The `bitcoincash` prefix as CashAddr checksum input.
+pub(crate) fn cashaddr_prefix_values() -> impl Iterator<Item = u8> {
+    CASHADDR_PREFIX.bytes().map(|b| b & 0x1f).chain([0])
+}
+
+/// Decode a Bitcoin Cash CashAddr (`bitcoincash:` prefix optional), or
+/// `Ok(None)` if `address` is not one.
+fn decode_cashaddr(
+    address: &str,
+    invalid: &dyn Fn(&str) -> AddressError,
+) -> Result<Option<AddressKind>, AddressError> {
+    let lower = address.to_ascii_lowercase();
+    let payload = lower
+        .strip_prefix(CASHADDR_PREFIX)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .unwrap_or(&lower);
+    // Version byte and 20-byte hash in 34 characters, then 8 of checksum.
+    if payload.len() != 42 || !payload.starts_with(['q', 'p']) {
+        return Ok(None);
+    }
+    let Some(values) = payload
+        .bytes()
+        .map(|c| {
+            CASHADDR_CHARSET
+                .iter()
+                .position(|&x| x == c)
+                .map(|i| i as u8)
+        })
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return Ok(None);
+    };
+    if address != lower && address != address.to_ascii_uppercase() {
+        return Err(invalid("mixed-case CashAddr"));
+    }
+    if cashaddr_polymod(cashaddr_prefix_values().chain(values.iter().copied())) != 0 {
+        return Err(invalid("CashAddr checksum mismatch"));
+    }
+
+    let mut bytes = Vec::with_capacity(21);
+    let (mut acc, mut bits) = (0u32, 0);
+    for &value in &values[..34] {
+        acc = (acc << 5) | u32::from(value);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    let network = Network::Mainnet;
+    let hash: [u8; 20] = bytes[1..].try_into().unwrap();
+    match bytes[0] {
+        0x00 => Ok(Some(AddressKind::P2pkh { network, hash })),
+        0x08 => Ok(Some(AddressKind::P2sh { network, hash })),
+        _ => Err(invalid("unsupported CashAddr version")),
+    }
+}
+
+/// Arweave addresses are the unpadded base64url encoding of 32 bytes.
+fn decode_arweave(address: &str) -> Option<[u8; 32]> {
+    if address.len() != 43 {
+        return None;
+    }
+    let mut bits: u32 = 0;
+    let mut nbits = 0;
+    let mut out = Vec::with_capacity(33);
+    for b in address.bytes() {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return None,
+        };
+        bits = (bits << 6) | u32::from(value);
+        nbits += 6;
+        if nbits >= 8 {
+            nbits -= 8;
+            out.push((bits >> nbits) as u8);
+            bits &= (1 << nbits) - 1;
+        }
+    }
+    // 43 characters hold 258 bits; the two spare bits must be zero.
+    if bits != 0 {
+        return None;
+    }
+    out.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitcoin_base58_kinds() {
+        let kind =
+            AddressKind::detect("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH", Chain::Bitcoin).unwrap();
+        assert_eq!(kind.name(), "p2pkh");
+        assert_eq!(
+            hex::encode(kind.key_hash().unwrap()),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert!(matches!(
+            AddressKind::detect("3P14159f73E4gFr7JterCCQh9QjiTjiZrG", Chain::Bitcoin),
+            Ok(AddressKind::P2sh {
+                network: Network::Mainnet,
+                ..
+            })
+        ));
+        assert!(matches!(
+            AddressKind::detect("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", Chain::Bitcoin),
+            Ok(AddressKind::P2pkh {
+                network: Network::Testnet,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn bitcoin_segwit_kinds() {
+        let detect = |a| AddressKind::detect(a, Chain::Bitcoin).unwrap().name();
+        assert_eq!(
+            detect("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            "p2wpkh"
+        );
+        assert_eq!(
+            detect("bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3"),
+            "p2wsh"
+        );
+        assert_eq!(
+            detect("bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"),
+            "p2tr"
+        );
+    }
+
+    #[test]
+    fn typos_fail_the_checksum() {
+        assert!(matches!(
+            AddressKind::detect("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb", Chain::Bitcoin),
+            Err(AddressError::Invalid { reason, .. }) if reason.contains("checksum")
+        ));
+        assert!(matches!(
+            AddressKind::detect("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5", Chain::Bitcoin),
+            Err(AddressError::Invalid { .. })
+        ));
+    }
+
+    #[test]
+    fn other_chain_addresses_are_named() {
+        let err =
+            AddressKind::detect("LVuDpNCSSj6pQ7t9Pv6d6sUkLKoqDEVUnJ", Chain::Bitcoin).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "LVuDpNCSSj6pQ7t9Pv6d6sUkLKoqDEVUnJ is a Litecoin address, expected Bitcoin"
+        );
+        assert!(matches!(
+            AddressKind::detect("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH", Chain::Decred),
+            Err(AddressError::WrongChain {
+                found: "Bitcoin",
+                ..
+            })
+        ));
+        assert!(matches!(
+            AddressKind::detect("hello", Chain::Bitcoin),
+            Err(AddressError::Unrecognized { .. })
+        ));
+    }
+
+    #[test]
+    fn ethereum_checks_eip55() {
+        let lower = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+        assert!(AddressKind::detect(lower, Chain::Ethereum).is_ok());
+        assert!(AddressKind::detect(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            Chain::Ethereum
+        )
+        .is_ok());
+        assert!(matches!(
+            AddressKind::detect("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", Chain::Ethereum),
+            Err(AddressError::Invalid { reason, .. }) if reason.contains("EIP-55")
+        ));
+        assert!(AddressKind::detect("0x5aaeb6", Chain::Ethereum).is_err());
+    }
+
+    #[test]
+    fn identify_finds_the_chain() {
+        let (chain, kind) = AddressKind::identify("LVuDpNCSSj6pQ7t9Pv6d6sUkLKoqDEVUnJ").unwrap();
+        assert_eq!(chain, Chain::Litecoin);
+        assert_eq!(
+            hex::encode(kind.payload()),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert_eq!(
+            AddressKind::identify("0x7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+                .unwrap()
+                .0,
+            Chain::Ethereum
+        );
+        assert!(matches!(
+            AddressKind::identify("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            Err(AddressError::Invalid { .. })
+        ));
+        assert!(matches!(
+            AddressKind::identify("hello"),
+            Err(AddressError::Unrecognized { .. })
+        ));
+    }
+
+    #[test]
+    fn bitcoin_forks_use_their_own_versions() {
+        let key_one = "751e76e8199196d454941c45d1b3a323f1433bd6";
+        for (address, chain) in [
+            ("DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE", Chain::Dogecoin),
+            ("XmN7PQYWKn5MJFna5fRYgP6mxT2F7xpekE", Chain::Dash),
+            ("t1UYsZVJkLPeMjxEtACvSxfWuNmddpWfxzs", Chain::Zcash),
+            (
+                "bitcoincash:qp63uahgrxged4z5jswyt5dn5v3lzsem6cy4spdc2h",
+                Chain::BitcoinCash,
+            ),
+            (
+                "qp63uahgrxged4z5jswyt5dn5v3lzsem6cy4spdc2h",
+                Chain::BitcoinCash,
+            ),
+            ("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH", Chain::BitcoinCash),
+        ] {
+            let kind = AddressKind::detect(address, chain).unwrap();
+            assert_eq!(kind.name(), "p2pkh", "{}", address);
+            assert_eq!(hex::encode(kind.payload()), key_one, "{}", address);
+        }
+        assert_eq!(
+            AddressKind::detect("t3VEtV2oBtHxjq7wKHJb3PHsqXHvMRgUmVw", Chain::Zcash)
+                .unwrap()
+                .name(),
+            "p2sh"
+        );
+        assert_eq!(
+            AddressKind::identify("DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE")
+                .unwrap()
+                .0,
+            Chain::Dogecoin
+        );
+    }
+
+    #[test]
+    fn cashaddr_checksum_and_case() {
+        // Spec test vector for 1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu.
+        let kind = AddressKind::detect(
+            "BITCOINCASH:QPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVY22GDX6A",
+            Chain::BitcoinCash,
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(kind.payload()),
+            "76a04053bda0a88bda5177b86a15c3b29f559873"
+        );
+        assert!(matches!(
+            AddressKind::detect("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6q", Chain::BitcoinCash),
+            Err(AddressError::Invalid { reason, .. }) if reason.contains("checksum")
+        ));
+        assert!(matches!(
+            AddressKind::detect(
+                "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6A",
+                Chain::BitcoinCash
+            ),
+            Err(AddressError::Invalid { .. })
+        ));
+    }
+
+    #[test]
+    fn monero_is_unsupported() {
+        assert_eq!(
+            AddressKind::detect("4", Chain::Monero),
+            Err(AddressError::UnsupportedChain("Monero"))
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Exact amounts in a currency's smallest unit (sats, wei, piconero, ...).
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+/// An exact amount, stored as an integer count of base units plus the number
+/// of decimals that make up one whole coin.
+///
+/// Unlike the `f64` amounts it sits alongside, it compares exactly and
+/// formats without rounding artifacts. Comparisons are by value, so `1.5`
+/// with 1 decimal equals `1.50` with 2; the currency is not part of it. `Display` prints the shortest exact
+/// decimal; a precision (`{:.8}`) rounds half away from zero or pads.
+///
+/// ```
+/// use boha::Amount;
+///
+/// let amount: Amount = Amount::parse("0.0260414", 8).unwrap();
+/// assert_eq!(amount.units(), 2_604_140);
+/// assert_eq!(amount.to_string(), "0.0260414");
+/// assert_eq!(format!("{:.8}", amount), "0.02604140");
+/// assert_eq!(format!("{:.2}", amount), "0.03");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Amount {
+    units: u128,
+    decimals: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("expected a non-negative decimal with at most {decimals} decimals, got {value:?}")]
+pub struct ParseAmountError {
+    value: String,
+    decimals: u8,
+}
+
+impl Amount {
+    pub const fn new(units: u128, decimals: u8) -> Self {
+        Self { units, decimals }
+    }
+
+    /// Parses a plain decimal (`"1.5"`, `"0.00000001"`, `"7"`) into base
+    /// units. More fractional digits than `decimals` is an error rather than
+    /// a silent rounding.
+    pub fn parse(value: &str, decimals: u8) -> Result<Self, ParseAmountError> {
+        let err = || ParseAmountError {
+            value: value.to_string(),
+            decimals,
+        };
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !digits(whole) || !digits(fraction) {
+            return Err(err());
+        }
+        if fraction.len() > usize::from(decimals) {
+            return Err(err());
+        }
+        let scale = 10u128.checked_pow(u32::from(decimals)).ok_or_else(err)?;
+        let whole: u128 = whole.parse().map_err(|_| err())?;
+        let fraction: u128 = if fraction.is_empty() {
+            0
+        } else {
+            let padded = format!("{:0<width$}", fraction, width = usize::from(decimals));
+            padded.parse().map_err(|_| err())?
+        };
+        let units = whole
+            .checked_mul(scale)
+            .and_then(|w| w.checked_add(fraction))
+            .ok_or_else(err)?;
+        Ok(Self { units, decimals })
+    }
+
+    /// Count of base units, e.g. satoshis for a BTC amount.
+    pub const fn units(&self) -> u128 {
+        self.units
+    }
+
+    /// Base units per whole coin, as a power of ten.
+    pub const fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// The same value with trailing zero decimals dropped, so equal amounts
+    /// have equal fields.
+    const fn normalized(self) -> Self {
+        let mut amount = self;
+        while amount.decimals > 0 && amount.units % 10 == 0 {
+            amount.units /= 10;
+            amount.decimals -= 1;
+        }
+        amount
+    }
+
+    /// Whole coins, for arithmetic where exactness does not matter.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_f64(&self) -> f64 {
+        self.units as f64 / 10f64.powi(i32::from(self.decimals))
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = usize::from(self.decimals);
+        let digits = format!("{:0>width$}", self.units, width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+        match f.precision() {
+            None => {
+                let fraction = fraction.trim_end_matches('0');
+                if fraction.is_empty() {
+                    f.pad_integral(true, "", whole)
+                } else {
+                    f.pad_integral(true, "", &format!("{whole}.{fraction}"))
+                }
+            }
+            Some(places) if places >= decimals => f.pad_integral(
+                true,
+                "",
+                format!("{whole}.{fraction:0<places$}").trim_end_matches('.'),
+            ),
+            Some(places) => {
+                let scale = 10u128.pow(u32::try_from(decimals - places).unwrap_or(u32::MAX));
+                let rounded = Self {
+                    units: self.units.saturating_add(scale / 2) / scale,
+                    decimals: u8::try_from(places).unwrap_or(u8::MAX),
+                };
+                f.pad_integral(true, "", &format!("{rounded:.places$}"))
+            }
+        }
+    }
+}
+
+impl PartialEq for Amount {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (self.normalized(), other.normalized());
+        a.units == b.units && a.decimals == b.decimals
+    }
+}
+
+impl Eq for Amount {}
+
+impl Ord for Amount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Scale the side with fewer decimals up; past `u128` it is the larger.
+        let scaled = |amount: &Self, decimals: u8| {
+            10u128
+                .checked_pow(u32::from(decimals - amount.decimals))
+                .and_then(|scale| amount.units.checked_mul(scale))
+        };
+        if self.units == 0 || other.units == 0 {
+            return self.units.cmp(&other.units);
+        }
+        let decimals = self.decimals.max(other.decimals);
+        match (scaled(self, decimals), scaled(other, decimals)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (None, _) => Ordering::Greater,
+            (_, None) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for Amount {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let amount = self.normalized();
+        amount.units.hash(state);
+        amount.decimals.hash(state);
+    }
+}
+
+/// Base units as a decimal string, since wei overflow the integers most JSON
+/// readers handle exactly.
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_scales_to_base_units() {
+        assert_eq!(Amount::parse("1", 8).unwrap().units(), 100_000_000);
+        assert_eq!(Amount::parse("0.00000001", 8).unwrap().units(), 1);
+        assert_eq!(
+            Amount::parse("2.994808896749136", 18).unwrap().units(),
+            2_994_808_896_749_136_000
+        );
+    }
+
+    #[test]
+    fn parse_rejects_excess_precision_and_junk() {
+        assert!(Amount::parse("0.000000001", 8).is_err());
+        assert!(Amount::parse("-1", 8).is_err());
+        assert!(Amount::parse(".5", 8).is_err());
+        assert!(Amount::parse("1e-8", 8).is_err());
+        assert!(Amount::parse("", 8).is_err());
+    }
+
+    #[test]
+    fn display_is_exact() {
+        assert_eq!(Amount::new(100_000_000, 8).to_string(), "1");
+        assert_eq!(Amount::new(1, 8).to_string(), "0.00000001");
+        assert_eq!(Amount::new(0, 12).to_string(), "0");
+        assert_eq!(
+            Amount::new(2_994_808_896_749_136_000, 18).to_string(),
+            "2.994808896749136"
+        );
+    }
+
+    #[test]
+    fn display_precision_rounds_and_pads() {
+        let amount = Amount::new(2_994_808_896_749_136_000, 18);
+        assert_eq!(format!("{amount:.8}"), "2.99480890");
+        assert_eq!(format!("{amount:.0}"), "3");
+        assert_eq!(format!("{:.8}", Amount::new(5, 2)), "0.05000000");
+        assert_eq!(format!("{:>6}", Amount::new(15, 1)), "   1.5");
+        assert_eq!(format!("{amount:>12.2}"), "        2.99");
+        assert_eq!(format!("{amount:*<8.2}"), "2.99****");
+    }
+
+    #[test]
+    fn equality_is_exact_where_floats_are_not() {
+        let sum = Amount::new(
+            Amount::parse("0.1", 8).unwrap().units() + Amount::parse("0.2", 8).unwrap().units(),
+            8,
+        );
+        assert_eq!(sum, Amount::parse("0.3", 8).unwrap());
+    }
+
+    #[test]
+    fn comparisons_ignore_trailing_decimals() {
+        use std::collections::HashSet;
+
+        assert_eq!(Amount::new(15, 1), Amount::new(150, 2));
+        assert_eq!(Amount::new(0, 8), Amount::new(0, 18));
+        assert!(Amount::new(2, 8) > Amount::new(10, 9));
+        assert!(Amount::new(1, 18) < Amount::new(1, 8));
+        assert!(Amount::new(u128::MAX, 0) > Amount::new(u128::MAX, 38));
+        let set: HashSet<_> = [Amount::new(15, 1), Amount::new(1500, 3)].into();
+        assert_eq!(set.len(), 1);
+    }
+}
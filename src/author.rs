@@ -0,0 +1,217 @@
+//! Collection authors: who they are, where to find them, and the identity
+//! that ties one author's collections together.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::{collections, serialize_prize_map, Collection, Profile, Puzzle, Status};
+
+/// Author/creator of a puzzle collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Author {
+    /// Author's name or pseudonym (None for anonymous).
+    pub name: Option<&'static str>,
+    /// Handles the author goes by (e.g. `peter_todd`). One held by a single
+    /// author also names their collection in [`Collection::parse`].
+    pub aliases: &'static [&'static str],
+    /// Addresses that initially funded the puzzle(s).
+    pub addresses: &'static [&'static str],
+    /// Profile links (forum, twitter, website, ...).
+    pub profiles: &'static [Profile],
+}
+
+impl Author {
+    /// The first profile on `platform` (e.g. "bitcointalk", "twitter").
+    pub fn profile(&self, platform: &str) -> Option<&'static Profile> {
+        self.profiles
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(platform))
+    }
+
+    /// Whether `address` is one of the author's known funding addresses.
+    pub fn owns_address(&self, address: &str) -> bool {
+        self.addresses.contains(&address)
+    }
+
+    /// Whether `other` is the same person, as seen from another collection:
+    /// same name, a shared alias or a shared funding address.
+    pub fn same_as(&self, other: &Author) -> bool {
+        std::ptr::eq(self, other)
+            || (self.name.is_some() && self.name == other.name)
+            || self.aliases.iter().any(|a| other.aliases.contains(a))
+            || self.addresses.iter().any(|a| other.owns_address(a))
+    }
+}
+
+/// The built-in collection `alias` stands for, if exactly one collection's
+/// author goes by it.
+pub fn collection_for_alias(alias: &str) -> Option<Collection> {
+    let mut matches = Collection::ALL
+        .into_iter()
+        .filter(|c| c.author().aliases.contains(&alias));
+    let collection = matches.next()?;
+    matches
+        .all(|c| c.author().same_as(collection.author()))
+        .then_some(collection)
+}
+
+/// Puzzle counts and prize totals for one author, across all their collections.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorStats {
+    /// The author as first seen, in collection order.
+    #[serde(skip)]
+    pub author: &'static Author,
+    pub name: Option<&'static str>,
+    pub collections: Vec<&'static str>,
+    pub total: usize,
+    pub solved: usize,
+    pub unsolved: usize,
+    pub claimed: usize,
+    pub swept: usize,
+    pub expired: usize,
+    /// Sum of all prizes the author put up.
+    #[serde(serialize_with = "serialize_prize_map")]
+    pub funded: HashMap<String, f64>,
+    /// Prizes the author took back unsolved.
+    #[serde(serialize_with = "serialize_prize_map")]
+    pub claimed_back: HashMap<String, f64>,
+    /// Prizes still waiting for a solver.
+    #[serde(serialize_with = "serialize_prize_map")]
+    pub outstanding: HashMap<String, f64>,
+}
+
+impl AuthorStats {
+    fn new(author: &'static Author) -> Self {
+        Self {
+            author,
+            name: author.name,
+            collections: Vec::new(),
+            total: 0,
+            solved: 0,
+            unsolved: 0,
+            claimed: 0,
+            swept: 0,
+            expired: 0,
+            funded: HashMap::new(),
+            claimed_back: HashMap::new(),
+            outstanding: HashMap::new(),
+        }
+    }
+
+    /// Count `puzzle` and its prize.
+    fn add(&mut self, puzzle: &Puzzle) {
+        self.total += 1;
+        match puzzle.status {
+            Status::Solved => self.solved += 1,
+            Status::Unsolved => self.unsolved += 1,
+            Status::Claimed => self.claimed += 1,
+            Status::Swept => self.swept += 1,
+            Status::Expired => self.expired += 1,
+        }
+        if let Some(prize) = puzzle.prize {
+            let currency = puzzle.currency().to_string();
+            *self.funded.entry(currency.clone()).or_insert(0.0) += prize;
+            match puzzle.status {
+                Status::Claimed => *self.claimed_back.entry(currency).or_insert(0.0) += prize,
+                Status::Unsolved => *self.outstanding.entry(currency).or_insert(0.0) += prize,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Statistics grouped by [`Puzzle::author`](crate::Puzzle::author), one
+/// entry per [same](Author::same_as) author, in collection order.
+pub fn author_stats() -> Vec<AuthorStats> {
+    let mut authors: Vec<AuthorStats> = Vec::new();
+
+    for collection in collections() {
+        let author = collection.author();
+        let index = authors
+            .iter()
+            .position(|a| a.author.same_as(author))
+            .unwrap_or_else(|| {
+                authors.push(AuthorStats::new(author));
+                authors.len() - 1
+            });
+        let stats = &mut authors[index];
+        if !stats.collections.contains(&collection.name()) {
+            stats.collections.push(collection.name());
+        }
+        collection.all().for_each(|puzzle| stats.add(puzzle));
+    }
+
+    authors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get, stats};
+
+    #[test]
+    fn author_stats_cover_every_puzzle() {
+        let by_author = author_stats();
+        let overall = stats();
+
+        assert_eq!(by_author.len(), Collection::ALL.len());
+        assert_eq!(
+            by_author.iter().map(|a| a.total).sum::<usize>(),
+            overall.total
+        );
+
+        let b1000 = by_author
+            .iter()
+            .find(|a| a.collections == ["b1000"])
+            .unwrap();
+        assert_eq!(b1000.name, Some("saatoshi_rising"));
+        assert!(std::ptr::eq(b1000.author, Collection::B1000.author()));
+        assert_eq!(b1000.total, Collection::B1000.slice().len());
+
+        let outstanding_btc: f64 = by_author
+            .iter()
+            .filter_map(|a| a.outstanding.get("BTC"))
+            .sum();
+        assert!((outstanding_btc - overall.unsolved_prize["BTC"]).abs() < 1e-6);
+    }
+
+    #[test]
+    fn puzzle_author_backlinks_to_collection() {
+        let puzzle = get("b1000/66").unwrap();
+        assert!(std::ptr::eq(
+            puzzle.author().unwrap(),
+            Collection::B1000.author()
+        ));
+    }
+
+    #[test]
+    fn aliases_resolve_to_their_collection() {
+        assert_eq!(
+            collection_for_alias("peter_todd"),
+            Some(Collection::HashCollision)
+        );
+        assert_eq!(collection_for_alias("satoshi"), None);
+        assert_eq!(Collection::HashCollision.aliases(), ["peter_todd"]);
+    }
+
+    #[test]
+    fn same_author_across_collections() {
+        static FORUM: Author = Author {
+            name: None,
+            aliases: &["peter_todd"],
+            addresses: &[],
+            profiles: &[],
+        };
+        let todd = Collection::HashCollision.author();
+        assert!(todd.same_as(&FORUM));
+        assert!(FORUM.same_as(todd));
+        assert!(!todd.same_as(Collection::B1000.author()));
+        assert!(todd.owns_address("1FCYd7j4CThTMzts78rh6iQJLBRGPW9fWv"));
+        assert_eq!(
+            todd.profile("BitcoinTalk").map(|p| p.url),
+            Some("https://bitcointalk.org/index.php?topic=293382.0")
+        );
+        assert!(todd.profile("twitter").is_none());
+    }
+}
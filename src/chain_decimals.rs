@@ -0,0 +1,11 @@
+// Included by both `Chain::decimals` and build.rs, so generated base-unit
+// amounts and the runtime agree on every chain's unit.
+
+/// Decimal places of a chain's native coin, by its dataset name.
+const fn native_decimals(chain: &str) -> u8 {
+    match chain.as_bytes() {
+        b"ethereum" => 18,
+        b"monero" | b"arweave" => 12,
+        _ => 8,
+    }
+}
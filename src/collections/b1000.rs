@@ -4,11 +4,13 @@
 
 #[allow(unused_imports)]
 use crate::{
-    Address, Author, Chain, Entropy, EntropySource, Error, IntoPuzzleNum, Key, Passphrase, Profile,
-    Pubkey, PubkeyFormat, Puzzle, RedeemScript, Result, Seed, Solver, Status, Transaction,
-    TransactionType, Wif,
+    Address, Amount, Author, BrainwalletScheme, Chain, DateTime, Entropy, EntropySource, Error,
+    IntoPuzzleNum, Key, Passphrase, Profile, Pubkey, PubkeyFormat, Puzzle, RedeemScript, Result,
+    Seed, Solver, Status, Transaction, TransactionType, Wif,
 };
 
+use crate::keymath::KeyRange;
+
 include!(concat!(env!("OUT_DIR"), "/b1000_data.rs"));
 
 pub fn author() -> &'static Author {
@@ -23,7 +25,7 @@ pub fn get(key: impl IntoPuzzleNum) -> Result<&'static Puzzle> {
     PUZZLES
         .iter()
         .find(|p| p.key.and_then(|k| k.bits) == Some(number as u16))
-        .ok_or_else(|| Error::NotFound(format!("b1000/{}", number)))
+        .ok_or_else(|| Error::not_found(format!("b1000/{}", number)))
 }
 
 pub fn slice() -> &'static [Puzzle] {
@@ -46,6 +48,14 @@ pub fn with_pubkey() -> impl Iterator<Item = &'static Puzzle> {
     PUZZLES.iter().filter(|p| p.pubkey.is_some())
 }
 
+/// Split the key range of puzzle `bits` into `parts` sub-ranges for
+/// distributing a search; see [`KeyRange::split`].
+///
+/// Returns none when `bits` is outside 1..=256 or `parts` is 0.
+pub fn split_range(bits: u32, parts: u64) -> Vec<KeyRange> {
+    KeyRange::from_bits(bits).map_or_else(Vec::new, |range| range.split(parts))
+}
+
 pub const fn count() -> usize {
     256
 }
@@ -0,0 +1,56 @@
+//! Fake testnet puzzles for docs, tutorials and tests.
+//!
+//! Three deterministic puzzles whose keys are `0x1`, `0x3` and `0x7` (the same
+//! keys as b1000/1-3) paying to Bitcoin testnet addresses. They are safe to
+//! reference anywhere a real bounty would be inappropriate and are excluded
+//! from [`crate::all`] and [`crate::stats`]. Without the `examples-data`
+//! feature the collection is empty.
+//!
+//! ```
+//! # #[cfg(feature = "examples-data")] {
+//! let puzzle = boha::example::get(3).unwrap();
+//! assert_eq!(puzzle.address.value, "mp5cELDJZ2pUNYrF1i5dCyT34j48UzaKRU");
+//! assert_eq!(puzzle.key.unwrap().bits, Some(3));
+//! # }
+//! ```
+
+#[allow(unused_imports)]
+use crate::{
+    Address, Author, BrainwalletScheme, Chain, DateTime, Error, IntoPuzzleNum, Key, Profile,
+    Pubkey, PubkeyFormat, Puzzle, Result, Status,
+};
+
+include!(concat!(env!("OUT_DIR"), "/example_data.rs"));
+
+pub fn author() -> &'static Author {
+    &AUTHOR
+}
+
+pub fn get(num: impl IntoPuzzleNum) -> Result<&'static Puzzle> {
+    let num = num.into_puzzle_num().ok_or(Error::InvalidNumber(0))?;
+    let id = format!("example/{}", num);
+    PUZZLES
+        .iter()
+        .find(|p| p.id == id)
+        .ok_or_else(|| Error::not_found(id))
+}
+
+pub fn slice() -> &'static [Puzzle] {
+    PUZZLES
+}
+
+pub fn all() -> impl Iterator<Item = &'static Puzzle> {
+    slice().iter()
+}
+
+pub fn solved() -> impl Iterator<Item = &'static Puzzle> {
+    PUZZLES.iter().filter(|p| p.status == Status::Solved)
+}
+
+pub fn unsolved() -> impl Iterator<Item = &'static Puzzle> {
+    PUZZLES.iter().filter(|p| p.status == Status::Unsolved)
+}
+
+pub const fn count() -> usize {
+    PUZZLES.len()
+}
@@ -3,6 +3,7 @@ pub mod b1000;
 pub mod ballet;
 pub mod bitaps;
 pub mod bitimage;
+pub mod example;
 pub mod gsmg;
 pub mod hash_collision;
 pub mod warp;
@@ -2,9 +2,9 @@
 
 #[allow(unused_imports)]
 use crate::{
-    Address, Author, Chain, Entropy, EntropySource, Error, Key, Passphrase, Profile, Pubkey,
-    PubkeyFormat, Puzzle, RedeemScript, Result, Seed, Solver, Status, Transaction, TransactionType,
-    Wif,
+    Address, Amount, Author, BrainwalletScheme, Chain, DateTime, Entropy, EntropySource, Error,
+    Key, Passphrase, Profile, Pubkey, PubkeyFormat, Puzzle, RedeemScript, Result, Seed, Solver,
+    Status, Transaction, TransactionType, Wif,
 };
 
 include!(concat!(env!("OUT_DIR"), "/warp_data.rs"));
@@ -23,7 +23,7 @@ pub fn get(name: &str) -> Result<&'static Puzzle> {
     PUZZLES
         .iter()
         .find(|p| p.id == search_id)
-        .ok_or(Error::NotFound(search_id))
+        .ok_or_else(|| Error::not_found(search_id))
 }
 
 pub fn slice() -> &'static [Puzzle] {
@@ -1,8 +1,8 @@
 #[allow(unused_imports)]
 use crate::{
-    Address, Assets, Author, Chain, Entropy, EntropySource, Error, Key, Passphrase, Profile,
-    Pubkey, PubkeyFormat, Puzzle, RedeemScript, Result, Seed, Solver, Status, Transaction,
-    TransactionType, Wif,
+    Address, Amount, Assets, Author, BrainwalletScheme, Chain, DateTime, Entropy, EntropySource,
+    Error, Key, Passphrase, Profile, Pubkey, PubkeyFormat, Puzzle, RedeemScript, Result, Seed,
+    Solver, Status, Transaction, TransactionType, Wif,
 };
 
 include!(concat!(env!("OUT_DIR"), "/zden_data.rs"));
@@ -21,7 +21,7 @@ pub fn get(name: &str) -> Result<&'static Puzzle> {
     PUZZLES
         .iter()
         .find(|p| p.id == search_id)
-        .ok_or(Error::NotFound(search_id))
+        .ok_or_else(|| Error::not_found(search_id))
 }
 
 pub fn slice() -> &'static [Puzzle] {
@@ -0,0 +1,326 @@
+//! Calendar dates as stored in the dataset (`YYYY-MM-DD HH:MM:SS`, UTC).
+
+use core::fmt;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+/// A UTC timestamp with second precision, ordered chronologically.
+///
+/// Parses the dataset's `YYYY-MM-DD HH:MM:SS` form, checking the calendar
+/// (month lengths, leap years) as well as the shape.
+///
+/// ```
+/// use boha::DateTime;
+///
+/// let date: DateTime = "2024-02-29 12:00:00".parse().unwrap();
+/// assert_eq!(date.year(), 2024);
+/// assert!("2023-02-29 12:00:00".parse::<DateTime>().is_err());
+/// assert!(DateTime::new(2023, 2, 29, 12, 0, 0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("expected \"YYYY-MM-DD HH:MM:SS\" or a prefix of it, got {0:?}")]
+pub struct ParseDateError(String);
+
+impl DateTime {
+    /// A date from its fields, checked like a parsed one.
+    pub fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, ParseDateError> {
+        let date = Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        };
+        if date.is_valid() {
+            Ok(date)
+        } else {
+            Err(ParseDateError(format!(
+                "{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}"
+            )))
+        }
+    }
+
+    /// A dataset date emitted by build.rs; an invalid one fails the build.
+    pub(crate) const fn dataset(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Self {
+        let date = Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        };
+        assert!(date.is_valid(), "dataset date is not on the calendar");
+        date
+    }
+
+    pub const fn year(self) -> u16 {
+        self.year
+    }
+
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    pub const fn hour(self) -> u8 {
+        self.hour
+    }
+
+    pub const fn minute(self) -> u8 {
+        self.minute
+    }
+
+    pub const fn second(self) -> u8 {
+        self.second
+    }
+
+    /// The first second of a date or date prefix: `2024`, `2024-03`,
+    /// `2024-03-05` or a full `2024-03-05 10:00:00`.
+    pub fn floor(value: &str) -> Result<Self, ParseDateError> {
+        Self::prefix(value, false)
+    }
+
+    /// The last second of a date or date prefix, so `ceil("2024")` is
+    /// `2024-12-31 23:59:59`.
+    pub fn ceil(value: &str) -> Result<Self, ParseDateError> {
+        Self::prefix(value, true)
+    }
+
+    fn prefix(value: &str, end: bool) -> Result<Self, ParseDateError> {
+        let err = || ParseDateError(value.to_string());
+        let (date, time) = match value.split_once(' ') {
+            Some((date, time)) => (date, Some(time)),
+            None => (value, None),
+        };
+        let mut parts = date.split('-');
+        let year = number(parts.next(), 4).ok_or_else(err)?;
+        let month = match parts.next() {
+            Some(month) => Some(number(Some(month), 2).ok_or_else(err)?),
+            None => None,
+        };
+        let day = match parts.next() {
+            Some(day) => Some(number(Some(day), 2).ok_or_else(err)?),
+            None => None,
+        };
+        if parts.next().is_some() || (time.is_some() && day.is_none()) {
+            return Err(err());
+        }
+        let month = month.unwrap_or(if end { 12 } else { 1 });
+        let day = match day {
+            Some(day) => day,
+            None if end => u16::from(days_in_month(year, month)),
+            None => 1,
+        };
+        let [hour, minute, second] = match time {
+            Some(time) => {
+                let mut fields = time.split(':').map(|f| number(Some(f), 2));
+                let hms = [fields.next(), fields.next(), fields.next()];
+                if fields.next().is_some() {
+                    return Err(err());
+                }
+                hms.map(Option::flatten)
+            }
+            None if end => [Some(23), Some(59), Some(59)],
+            None => [Some(0); 3],
+        };
+        let date = Self {
+            year,
+            month: narrow(month).ok_or_else(err)?,
+            day: narrow(day).ok_or_else(err)?,
+            hour: hour.and_then(narrow).ok_or_else(err)?,
+            minute: minute.and_then(narrow).ok_or_else(err)?,
+            second: second.and_then(narrow).ok_or_else(err)?,
+        };
+        if date.is_valid() {
+            Ok(date)
+        } else {
+            Err(err())
+        }
+    }
+
+    const fn is_valid(self) -> bool {
+        self.month >= 1
+            && self.month <= 12
+            && self.day >= 1
+            && self.day <= days_in_month(self.year, self.month as u16)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 60
+    }
+}
+
+/// A fixed-width run of ASCII digits.
+fn number(field: Option<&str>, width: usize) -> Option<u16> {
+    let field = field?;
+    if field.len() == width && field.bytes().all(|b| b.is_ascii_digit()) {
+        field.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn narrow(value: u16) -> Option<u8> {
+    u8::try_from(value).ok()
+}
+
+const fn days_in_month(year: u16, month: u16) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = ParseDateError;
+
+    /// Only the full `YYYY-MM-DD HH:MM:SS` form; see [`DateTime::floor`]
+    /// for prefixes.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.len() == 19 {
+            Self::floor(value)
+        } else {
+            Err(ParseDateError(value.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DateTime {
+    /// The calendar day, dropping the time.
+    pub fn naive_date(self) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(
+            i32::from(self.year),
+            u32::from(self.month),
+            u32::from(self.day),
+        )
+        .expect("DateTime is calendar-checked")
+    }
+
+    pub fn naive_datetime(self) -> chrono::NaiveDateTime {
+        self.naive_date()
+            .and_hms_opt(
+                u32::from(self.hour),
+                u32::from(self.minute),
+                u32::from(self.second),
+            )
+            .expect("DateTime is calendar-checked")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<DateTime> for chrono::NaiveDateTime {
+    fn from(date: DateTime) -> Self {
+        date.naive_datetime()
+    }
+}
+
+/// As the dataset's `YYYY-MM-DD HH:MM:SS` string.
+impl serde::Serialize for DateTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefixes_cover_their_period() {
+        assert_eq!(
+            DateTime::floor("2024").unwrap().to_string(),
+            "2024-01-01 00:00:00"
+        );
+        assert_eq!(
+            DateTime::ceil("2024").unwrap().to_string(),
+            "2024-12-31 23:59:59"
+        );
+        assert_eq!(
+            DateTime::ceil("2023-02").unwrap().to_string(),
+            "2023-02-28 23:59:59"
+        );
+        assert_eq!(
+            DateTime::ceil("2024-03-05 10:00:00").unwrap().to_string(),
+            "2024-03-05 10:00:00"
+        );
+    }
+
+    #[test]
+    fn malformed_dates_are_rejected() {
+        for bad in [
+            "24",
+            "2024-3",
+            "2024-13",
+            "2024-04-31",
+            "2024-01-01 24:00:00",
+            "2024-01-01 10:00",
+            "2024 10:00:00",
+            "2024-01-01-01",
+        ] {
+            assert!(DateTime::floor(bad).is_err(), "{}", bad);
+        }
+        assert!("2024-01-01".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn new_checks_the_calendar() {
+        let date = DateTime::new(2024, 2, 29, 23, 59, 58).unwrap();
+        assert_eq!(date, "2024-02-29 23:59:58".parse().unwrap());
+        assert_eq!(
+            DateTime::new(2023, 2, 29, 0, 0, 0).unwrap_err().to_string(),
+            r#"expected "YYYY-MM-DD HH:MM:SS" or a prefix of it, got "2023-02-29 00:00:00""#
+        );
+        assert!(DateTime::new(2024, 1, 1, 24, 0, 0).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_to_chrono() {
+        let date: DateTime = "2024-02-29 23:59:58".parse().unwrap();
+        let naive = chrono::NaiveDateTime::from(date);
+        assert_eq!(naive.to_string(), "2024-02-29 23:59:58");
+        assert_eq!(date.naive_date().to_string(), "2024-02-29");
+    }
+}
@@ -0,0 +1,77 @@
+//! Output script descriptors (BIP 380) for watching puzzle addresses.
+//!
+//! Bitcoin Core's `importdescriptors` only accepts descriptors carrying the
+//! eight-character checksum, so [`with_checksum`] is what callers want.
+
+const INPUT_CHARSET: &str =
+    "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The BIP 380 checksum of `descriptor`, or `None` if it contains a
+/// character descriptors cannot.
+pub fn checksum(descriptor: &str) -> Option<String> {
+    let mut c = 1u64;
+    let mut class = 0u64;
+    let mut class_count = 0;
+    for ch in descriptor.chars() {
+        let pos = INPUT_CHARSET.find(ch)? as u64;
+        c = polymod(c, pos & 31);
+        class = class * 3 + (pos >> 5);
+        class_count += 1;
+        if class_count == 3 {
+            c = polymod(c, class);
+            class = 0;
+            class_count = 0;
+        }
+    }
+    if class_count > 0 {
+        c = polymod(c, class);
+    }
+    for _ in 0..8 {
+        c = polymod(c, 0);
+    }
+    c ^= 1;
+
+    Some(
+        (0..8)
+            .map(|i| char::from(CHECKSUM_CHARSET[((c >> (5 * (7 - i))) & 31) as usize]))
+            .collect(),
+    )
+}
+
+/// `descriptor#checksum`.
+pub fn with_checksum(descriptor: &str) -> Option<String> {
+    checksum(descriptor).map(|sum| format!("{}#{}", descriptor, sum))
+}
+
+fn polymod(c: u64, value: u64) -> u64 {
+    const GENERATOR: [u64; 5] = [
+        0xf5_dee5_1989,
+        0xa9_fdca_3312,
+        0x1b_ab10_e32d,
+        0x37_06b1_677a,
+        0x64_4d62_6ffd,
+    ];
+    let top = c >> 35;
+    let mut c = ((c & 0x7_ffff_ffff) << 5) ^ value;
+    for (i, generator) in GENERATOR.iter().enumerate() {
+        if (top >> i) & 1 == 1 {
+            c ^= generator;
+        }
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_bitcoin_core() {
+        assert_eq!(
+            with_checksum("raw(deadbeef)").as_deref(),
+            Some("raw(deadbeef)#89f8spxm")
+        );
+        assert_eq!(checksum("addr(1é)"), None);
+    }
+}
@@ -0,0 +1,229 @@
+//! Selecting puzzles by status, chain, prize, key size and dates.
+
+#[cfg(feature = "random")]
+use rand_core::RngCore;
+
+use crate::{Chain, DateTime, Puzzle, Status};
+
+/// Criteria a puzzle must all meet; unset fields match everything.
+///
+/// Bounds are inclusive. A prize bound skips puzzles without a prize and
+/// compares amounts in each puzzle's own currency; a bits bound skips
+/// puzzles without a known key size, and a date bound skips puzzles
+/// without that date (funded is `start_date`, solved is `solve_date`).
+///
+/// ```
+/// use boha::{Filter, Status};
+///
+/// let filter = Filter::new()
+///     .status(Status::Unsolved)
+///     .max_bits(80)
+///     .min_prize(5.0);
+/// let ids: Vec<_> = boha::filter(&filter).map(|p| p.id).collect();
+/// assert!(ids.contains(&"b1000/71"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Filter {
+    pub status: Option<Status>,
+    pub chain: Option<Chain>,
+    pub min_prize: Option<f64>,
+    pub max_prize: Option<f64>,
+    pub min_bits: Option<u16>,
+    pub max_bits: Option<u16>,
+    pub funded_after: Option<DateTime>,
+    pub funded_before: Option<DateTime>,
+    pub solved_after: Option<DateTime>,
+    pub solved_before: Option<DateTime>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    #[must_use]
+    pub const fn chain(mut self, chain: Chain) -> Self {
+        self.chain = Some(chain);
+        self
+    }
+
+    #[must_use]
+    pub const fn min_prize(mut self, prize: f64) -> Self {
+        self.min_prize = Some(prize);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_prize(mut self, prize: f64) -> Self {
+        self.max_prize = Some(prize);
+        self
+    }
+
+    #[must_use]
+    pub const fn min_bits(mut self, bits: u16) -> Self {
+        self.min_bits = Some(bits);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_bits(mut self, bits: u16) -> Self {
+        self.max_bits = Some(bits);
+        self
+    }
+
+    #[must_use]
+    pub const fn funded_after(mut self, date: DateTime) -> Self {
+        self.funded_after = Some(date);
+        self
+    }
+
+    #[must_use]
+    pub const fn funded_before(mut self, date: DateTime) -> Self {
+        self.funded_before = Some(date);
+        self
+    }
+
+    #[must_use]
+    pub const fn solved_after(mut self, date: DateTime) -> Self {
+        self.solved_after = Some(date);
+        self
+    }
+
+    #[must_use]
+    pub const fn solved_before(mut self, date: DateTime) -> Self {
+        self.solved_before = Some(date);
+        self
+    }
+
+    pub fn matches(&self, puzzle: &Puzzle) -> bool {
+        let bits = puzzle.key.as_ref().and_then(|k| k.bits);
+        self.status.is_none_or(|s| puzzle.status == s)
+            && self.chain.is_none_or(|c| puzzle.chain == c)
+            && within(puzzle.prize, self.min_prize, self.max_prize)
+            && within(bits, self.min_bits, self.max_bits)
+            && within(puzzle.start_date, self.funded_after, self.funded_before)
+            && within(puzzle.solve_date, self.solved_after, self.solved_before)
+    }
+
+    /// One of the matching `puzzles`, each equally likely.
+    #[cfg(feature = "random")]
+    pub fn pick<'a, R: RngCore + ?Sized>(
+        &self,
+        puzzles: impl IntoIterator<Item = &'a Puzzle>,
+        rng: &mut R,
+    ) -> Option<&'a Puzzle> {
+        let matching: Vec<_> = puzzles.into_iter().filter(|p| self.matches(p)).collect();
+        let len = u64::try_from(matching.len()).ok().filter(|&n| n > 0)?;
+        // Rejection sampling keeps the draw unbiased for any length.
+        let zone = u64::MAX - u64::MAX % len;
+        loop {
+            let draw = rng.next_u64();
+            if draw < zone {
+                return usize::try_from(draw % len).ok().map(|i| matching[i]);
+            }
+        }
+    }
+
+    /// [`Filter::pick`] with the operating system's RNG.
+    #[cfg(feature = "random")]
+    pub fn random<'a>(&self, puzzles: impl IntoIterator<Item = &'a Puzzle>) -> Option<&'a Puzzle> {
+        self.pick(puzzles, &mut rand_core::OsRng)
+    }
+}
+
+/// `value` lies in `[min, max]`; a missing value only passes without bounds.
+fn within<T: PartialOrd>(value: Option<T>, min: Option<T>, max: Option<T>) -> bool {
+    if min.is_none() && max.is_none() {
+        return true;
+    }
+    value.is_some_and(|v| min.is_none_or(|m| v >= m) && max.is_none_or(|m| v <= m))
+}
+
+/// Puzzles of every collection that match `filter`.
+pub fn filter(filter: &Filter) -> impl Iterator<Item = &'static Puzzle> + '_ {
+    crate::all().filter(move |p| filter.matches(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_are_inclusive_and_skip_missing_values() {
+        let p71 = crate::b1000::get(71).unwrap();
+        assert!(Filter::new().min_bits(71).max_bits(71).matches(p71));
+        assert!(!Filter::new().max_bits(70).matches(p71));
+        assert!(Filter::new().min_prize(7.0).max_prize(8.0).matches(p71));
+
+        let gsmg = crate::gsmg::get();
+        assert!(Filter::new().matches(gsmg));
+        assert!(!Filter::new().max_bits(256).matches(gsmg));
+    }
+
+    /// Yields the given draws in order.
+    #[cfg(feature = "random")]
+    struct Draws(Vec<u64>);
+
+    #[cfg(feature = "random")]
+    impl RngCore for Draws {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.remove(0)
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest);
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn pick_draws_among_matches_only() {
+        let low = Filter::new().max_bits(3);
+        let b1000 = crate::b1000::all();
+        assert_eq!(low.pick(b1000, &mut Draws(vec![4])).unwrap().id, "b1000/2");
+        // u64::MAX lies in the biased tail for three matches and is redrawn.
+        let third = low.pick(crate::b1000::all(), &mut Draws(vec![u64::MAX, 2]));
+        assert_eq!(third.unwrap().id, "b1000/3");
+        assert!(Filter::new()
+            .min_bits(300)
+            .pick(crate::all(), &mut Draws(vec![]))
+            .is_none());
+    }
+
+    #[test]
+    fn solved_in_a_year() {
+        let in_2013 = Filter::new()
+            .solved_after(DateTime::floor("2013").unwrap())
+            .solved_before(DateTime::ceil("2013").unwrap());
+        assert!(in_2013.matches(crate::b1000::get(1).unwrap()));
+        assert!(!in_2013.matches(crate::b1000::get(71).unwrap()));
+        assert!(filter(&in_2013).all(|p| p.solve_date.is_some_and(|d| d.year() == 2013)));
+    }
+
+    #[test]
+    fn unsolved_under_80_bits_with_more_than_5_btc() {
+        let query = Filter::new()
+            .status(Status::Unsolved)
+            .chain(Chain::Bitcoin)
+            .max_bits(79)
+            .min_prize(5.0);
+        let puzzles: Vec<_> = filter(&query).collect();
+        assert!(!puzzles.is_empty());
+        assert!(puzzles.iter().all(|p| p.status == Status::Unsolved
+            && p.key.as_ref().and_then(|k| k.bits).is_some_and(|b| b < 80)
+            && p.prize.is_some_and(|v| v >= 5.0)));
+    }
+}
@@ -0,0 +1,139 @@
+//! Lookup tables over the embedded puzzles, built once per process.
+//!
+//! Lookups build the tables on first use unless [`init`] asked for them
+//! earlier. Servers that want a fast first request build eagerly or on a
+//! background thread; short-lived tools and WASM embedders keep the default.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::Puzzle;
+
+/// When [`init`] builds the lookup indexes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IndexBuild {
+    /// On the first lookup that needs them.
+    #[default]
+    Lazy,
+    /// Before [`init`] returns, on the calling thread.
+    Eager,
+    /// On a spawned thread; lookups that arrive first wait for it to finish.
+    /// Falls back to [`IndexBuild::Lazy`] on targets without threads.
+    Background,
+}
+
+/// Options for [`init`].
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct InitOptions {
+    pub indexes: IndexBuild,
+}
+
+impl InitOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn indexes(mut self, build: IndexBuild) -> Self {
+        self.indexes = build;
+        self
+    }
+}
+
+pub struct Index {
+    pub ids: HashMap<&'static str, &'static Puzzle>,
+    /// Keyed by [`address_key`].
+    pub addresses: HashMap<String, &'static Puzzle>,
+    pub uuids: HashMap<&'static str, &'static Puzzle>,
+}
+
+static INDEX: OnceLock<Index> = OnceLock::new();
+
+/// Prepare the library for use. Safe to call from any thread and more than
+/// once; after the indexes exist further calls do nothing.
+pub fn init(options: InitOptions) {
+    match options.indexes {
+        IndexBuild::Lazy => {}
+        IndexBuild::Eager => {
+            index();
+        }
+        IndexBuild::Background => {
+            #[cfg(not(target_family = "wasm"))]
+            std::thread::spawn(|| {
+                index();
+            });
+        }
+    }
+}
+
+/// Whether the indexes have been built.
+pub fn is_initialized() -> bool {
+    INDEX.get().is_some()
+}
+
+pub fn index() -> &'static Index {
+    INDEX.get_or_init(build)
+}
+
+fn build() -> Index {
+    crate::plugin::close();
+    let mut ids = HashMap::new();
+    let mut addresses = HashMap::new();
+    let mut uuids = HashMap::new();
+    for puzzle in crate::all() {
+        ids.insert(puzzle.id, puzzle);
+        uuids.insert(puzzle.uuid, puzzle);
+        // First puzzle wins if two ever share an address.
+        for address in puzzle.addresses() {
+            addresses
+                .entry(address_key(address.value).into_owned())
+                .or_insert(puzzle);
+        }
+    }
+    Index {
+        ids,
+        addresses,
+        uuids,
+    }
+}
+
+/// Lowercase `0x` addresses so EIP-55 checksummed and plain hex spellings
+/// resolve alike; other encodings are case-sensitive.
+pub fn address_key(address: &str) -> std::borrow::Cow<'_, str> {
+    if address.starts_with("0x") {
+        address.to_lowercase().into()
+    } else {
+        address.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_covers_every_puzzle() {
+        init(InitOptions::new().indexes(IndexBuild::Eager));
+        assert!(is_initialized());
+        let index = index();
+        assert_eq!(index.ids.len(), crate::all().count());
+        for puzzle in crate::all() {
+            assert!(std::ptr::eq(index.ids[puzzle.id], puzzle));
+        }
+    }
+
+    #[test]
+    fn background_build_is_shared() {
+        init(InitOptions::new().indexes(IndexBuild::Background));
+        let first: *const Index = index();
+        init(InitOptions::new().indexes(IndexBuild::Eager));
+        assert!(std::ptr::eq(first, index()));
+    }
+
+    #[test]
+    fn hex_addresses_are_case_insensitive() {
+        assert_eq!(address_key("0xAbC"), "0xabc");
+        assert_eq!(address_key("1AbC"), "1AbC");
+    }
+}
@@ -0,0 +1,267 @@
+//! Private key ranges and secp256k1 helpers for working with puzzle keys.
+//!
+//! Keys are [`BigUint`] so ranges up to 256 bits need no special casing.
+
+use std::fmt;
+
+use num_bigint::BigUint;
+use num_traits::One;
+#[cfg(feature = "random")]
+use rand_core::RngCore;
+
+#[cfg(feature = "verify")]
+mod point;
+
+#[cfg(feature = "verify")]
+pub use point::{
+    add, compressed_hex, mul_generator, parse_pubkey, scalar, shift, sub, uncompressed_hex,
+};
+
+/// A hex key, optionally `0x`-prefixed; `None` for anything else.
+pub fn parse_hex_key(key_hex: &str) -> Option<BigUint> {
+    let digits = key_hex
+        .strip_prefix("0x")
+        .or_else(|| key_hex.strip_prefix("0X"))
+        .unwrap_or(key_hex);
+    BigUint::parse_bytes(digits.as_bytes(), 16)
+}
+
+/// An inclusive range of private keys, `start..=end`.
+///
+/// Formats as `start:end`, the form key search tools take: decimal with
+/// `{}`, hex with `{:x}` or `{:X}`, and `0x`-prefixed hex with `{:#x}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyRange {
+    start: BigUint,
+    end: BigUint,
+}
+
+impl KeyRange {
+    /// `None` when `start` is above `end`.
+    pub fn new(start: BigUint, end: BigUint) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// Keys with exactly `bits` significant bits, `2^(bits-1) ..= 2^bits - 1`.
+    /// `None` outside 1..=256.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if !(1..=256).contains(&bits) {
+            return None;
+        }
+        let start = BigUint::one() << (bits - 1);
+        let end = (BigUint::one() << bits) - 1u32;
+        Some(Self { start, end })
+    }
+
+    pub fn start(&self) -> &BigUint {
+        &self.start
+    }
+
+    pub fn end(&self) -> &BigUint {
+        &self.end
+    }
+
+    /// Number of keys in the range.
+    pub fn size(&self) -> BigUint {
+        &self.end - &self.start + 1u32
+    }
+
+    /// Whether the hex key (optionally `0x`-prefixed) lies in the range.
+    /// Text that is not hex is never contained.
+    pub fn contains(&self, key_hex: &str) -> bool {
+        parse_hex_key(key_hex).is_some_and(|key| self.contains_key(&key))
+    }
+
+    pub fn contains_key(&self, key: &BigUint) -> bool {
+        &self.start <= key && key <= &self.end
+    }
+
+    /// The middle key, rounding down.
+    pub fn midpoint(&self) -> BigUint {
+        &self.start + ((&self.end - &self.start) >> 1)
+    }
+
+    /// Split into `parts` contiguous sub-ranges for distributing a search.
+    ///
+    /// Sizes differ by at most one key, larger parts first, and together the
+    /// parts cover the range exactly. Returns fewer parts when the range
+    /// holds fewer keys than `parts`, and none when `parts` is 0.
+    pub fn split(&self, parts: u64) -> Vec<KeyRange> {
+        if parts == 0 {
+            return Vec::new();
+        }
+        let size = self.size();
+        let parts = BigUint::from(parts).min(size.clone());
+        let (base, extra) = (&size / &parts, &size % &parts);
+
+        let mut ranges = Vec::new();
+        let mut start = self.start.clone();
+        let mut index = BigUint::ZERO;
+        while index < parts {
+            let len = if index < extra {
+                &base + 1u32
+            } else {
+                base.clone()
+            };
+            let next = &start + len;
+            let end = &next - 1u32;
+            ranges.push(KeyRange { start, end });
+            start = next;
+            index += 1u32;
+        }
+        ranges
+    }
+
+    /// A key drawn uniformly from the range.
+    #[cfg(feature = "random")]
+    pub fn random_sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> BigUint {
+        let size = self.size();
+        let bits = size.bits();
+        let mut buf = vec![0u8; bits.div_ceil(8) as usize];
+        let mask = 0xffu8 >> (buf.len() as u64 * 8 - bits);
+        // Rejection sampling; at least half the draws land in range.
+        loop {
+            rng.fill_bytes(&mut buf);
+            buf[0] &= mask;
+            let offset = BigUint::from_bytes_be(&buf);
+            if offset < size {
+                return &self.start + offset;
+            }
+        }
+    }
+
+    /// `n` keys drawn uniformly from the range with the operating system's
+    /// RNG. Samples are independent, so repeats are possible in small ranges.
+    #[cfg(feature = "random")]
+    pub fn random(&self, n: usize) -> Vec<BigUint> {
+        let mut rng = rand_core::OsRng;
+        (0..n).map(|_| self.random_sample(&mut rng)).collect()
+    }
+}
+
+impl fmt::Display for KeyRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.start, self.end)
+    }
+}
+
+impl fmt::LowerHex for KeyRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#x}:{:#x}", self.start, self.end)
+        } else {
+            write!(f, "{:x}:{:x}", self.start, self.end)
+        }
+    }
+}
+
+impl fmt::UpperHex for KeyRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#X}:{:#X}", self.start, self.end)
+        } else {
+            write!(f, "{:X}:{:X}", self.start, self.end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic xorshift so sampling tests do not depend on an OS RNG.
+    #[cfg(feature = "random")]
+    struct XorShift(u64);
+
+    #[cfg(feature = "random")]
+    impl RngCore for XorShift {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                *b = self.0 as u8;
+            }
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn bits_ranges() {
+        let range = KeyRange::from_bits(8).unwrap();
+        assert_eq!(range.start(), &BigUint::from(128u32));
+        assert_eq!(range.end(), &BigUint::from(255u32));
+        assert_eq!(range.size(), BigUint::from(128u32));
+        assert_eq!(range.midpoint(), BigUint::from(191u32));
+        assert!(KeyRange::from_bits(0).is_none());
+        assert!(KeyRange::from_bits(257).is_none());
+        assert_eq!(KeyRange::from_bits(256).unwrap().end().bits(), 256);
+    }
+
+    #[test]
+    fn contains_hex_keys() {
+        let range = KeyRange::from_bits(8).unwrap();
+        assert!(range.contains("80"));
+        assert!(range.contains("0xff"));
+        assert!(range.contains("00000000ff"));
+        assert!(!range.contains("7f"));
+        assert!(!range.contains("100"));
+        assert!(!range.contains("zz"));
+        assert!(!range.contains(""));
+    }
+
+    #[test]
+    fn formatting() {
+        let range = KeyRange::from_bits(8).unwrap();
+        assert_eq!(range.to_string(), "128:255");
+        assert_eq!(format!("{:x}", range), "80:ff");
+        assert_eq!(format!("{:#x}", range), "0x80:0xff");
+        assert_eq!(format!("{:X}", range), "80:FF");
+    }
+
+    #[test]
+    fn split_covers_range() {
+        let range = KeyRange::new(BigUint::from(10u32), BigUint::from(19u32)).unwrap();
+        let parts = range.split(3);
+        assert_eq!(
+            parts.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["10:13", "14:16", "17:19"]
+        );
+        assert_eq!(range.split(20).len(), 10);
+        assert!(range.split(0).is_empty());
+        assert!(KeyRange::new(BigUint::from(2u32), BigUint::from(1u32)).is_none());
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn samples_stay_in_range() {
+        let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+        for bits in [1, 2, 9, 71, 256] {
+            let range = KeyRange::from_bits(bits).unwrap();
+            for _ in 0..50 {
+                assert!(range.contains_key(&range.random_sample(&mut rng)));
+            }
+        }
+        let single = KeyRange::new(BigUint::from(7u32), BigUint::from(7u32)).unwrap();
+        assert_eq!(single.random_sample(&mut rng), BigUint::from(7u32));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn random_keys_stay_in_range() {
+        let range = KeyRange::from_bits(71).unwrap();
+        let keys = range.random(20);
+        assert_eq!(keys.len(), 20);
+        assert!(keys.iter().all(|k| range.contains_key(k)));
+        assert!(range.random(0).is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+//! secp256k1 point helpers for working with revealed public keys.
+//!
+//! Points are [`PublicKey`], which cannot hold the point at infinity, so
+//! operations that can land there return `None`.
+
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+use k256::{ProjectivePoint, PublicKey, Scalar};
+use num_bigint::BigUint;
+
+/// Parse a SEC1 public key in hex, compressed or uncompressed.
+pub fn parse_pubkey(hex: &str) -> Option<PublicKey> {
+    let bytes = hex::decode(hex).ok()?;
+    PublicKey::from_sec1_bytes(&bytes).ok()
+}
+
+/// `k` as a curve scalar, or `None` when it is not below the group order.
+pub fn scalar(k: &BigUint) -> Option<Scalar> {
+    let bytes = k.to_bytes_be();
+    if bytes.len() > 32 {
+        return None;
+    }
+    let mut repr = [0u8; 32];
+    repr[32 - bytes.len()..].copy_from_slice(&bytes);
+    Option::from(Scalar::from_repr(repr.into()))
+}
+
+/// `k·G`, the public key of private key `k`. `None` for zero and for `k`
+/// at or above the group order.
+pub fn mul_generator(k: &BigUint) -> Option<PublicKey> {
+    from_projective(ProjectivePoint::GENERATOR * scalar(k)?)
+}
+
+/// `a + b`, or `None` when they cancel out.
+pub fn add(a: &PublicKey, b: &PublicKey) -> Option<PublicKey> {
+    from_projective(a.to_projective() + b.to_projective())
+}
+
+/// `a − b`, or `None` when the points are equal.
+pub fn sub(a: &PublicKey, b: &PublicKey) -> Option<PublicKey> {
+    from_projective(a.to_projective() - b.to_projective())
+}
+
+/// `P − start·G`: the point whose private key is `key − start` when `P` has
+/// private key `key`. Moves a search over `[start, end]` to `[0, end − start]`.
+///
+/// `None` when `P` is exactly `start·G` or `start` is out of range.
+pub fn shift(point: &PublicKey, start: &BigUint) -> Option<PublicKey> {
+    let offset = ProjectivePoint::GENERATOR * scalar(start)?;
+    from_projective(point.to_projective() - offset)
+}
+
+/// 33-byte SEC1 encoding in hex.
+pub fn compressed_hex(point: &PublicKey) -> String {
+    hex::encode(point.to_encoded_point(true).as_bytes())
+}
+
+/// 65-byte SEC1 encoding in hex.
+pub fn uncompressed_hex(point: &PublicKey) -> String {
+    hex::encode(point.to_encoded_point(false).as_bytes())
+}
+
+fn from_projective(point: ProjectivePoint) -> Option<PublicKey> {
+    PublicKey::from_affine(point.to_affine()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const G: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn generator_multiples() {
+        let one = mul_generator(&BigUint::from(1u32)).unwrap();
+        assert_eq!(compressed_hex(&one), G);
+        assert!(mul_generator(&BigUint::from(0u32)).is_none());
+
+        let two = add(&one, &one).unwrap();
+        assert_eq!(two, mul_generator(&BigUint::from(2u32)).unwrap());
+        assert_eq!(sub(&two, &one).unwrap(), one);
+        assert!(sub(&one, &one).is_none());
+    }
+
+    #[test]
+    fn parses_both_encodings() {
+        let g = parse_pubkey(G).unwrap();
+        assert_eq!(parse_pubkey(&uncompressed_hex(&g)).unwrap(), g);
+        assert!(parse_pubkey("02").is_none());
+        assert!(parse_pubkey("zz").is_none());
+    }
+
+    #[test]
+    fn scalar_rejects_group_order() {
+        let n = BigUint::parse_bytes(
+            b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            16,
+        )
+        .unwrap();
+        assert!(scalar(&n).is_none());
+        assert!(scalar(&(n - 1u32)).is_some());
+        assert!(scalar(&(BigUint::from(1u32) << 256)).is_none());
+    }
+
+    #[test]
+    fn shift_subtracts_range_start() {
+        let key = BigUint::from(0x2832ed74f2b5e35ee_u128);
+        let start = BigUint::from(1u128 << 65);
+        let point = mul_generator(&key).unwrap();
+        assert_eq!(
+            shift(&point, &start).unwrap(),
+            mul_generator(&(key - &start)).unwrap()
+        );
+        assert!(shift(&mul_generator(&start).unwrap(), &start).is_none());
+    }
+}
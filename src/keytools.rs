@@ -0,0 +1,579 @@
+//! Key parsing and address derivation.
+//!
+//! The encoders behind [`crate::verify`], usable on their own: read a
+//! private key from hex, WIF or a BIP39 mnemonic, then [`derive`] every
+//! public key, HASH160 and address it controls.
+
+use k256::ecdsa::SigningKey;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::PublicKey;
+use ripemd::Ripemd160;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::address::Network;
+use crate::verify::VerifyError;
+use crate::{Chain, PubkeyFormat};
+
+/// Everything derivable from one private key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Derived {
+    /// Private key, 64-char hex.
+    pub private_key: String,
+    /// SEC1 compressed public key hex (33 bytes).
+    pub compressed_pubkey: String,
+    /// SEC1 uncompressed public key hex (65 bytes).
+    pub uncompressed_pubkey: String,
+    /// HASH160 of the compressed public key.
+    pub hash160_compressed: String,
+    /// HASH160 of the uncompressed public key.
+    pub hash160_uncompressed: String,
+    pub addresses: Vec<DerivedAddress>,
+}
+
+/// One address controlled by a [`Derived`] key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DerivedAddress {
+    pub chain: Chain,
+    /// Address type, as named by [`crate::address::AddressKind::name`].
+    pub kind: &'static str,
+    /// Public key encoding hashed into the address; `None` when the
+    /// address does not depend on it (taproot, Ethereum).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pubkey_format: Option<PubkeyFormat>,
+    pub address: String,
+}
+
+/// Derive public keys, HASH160s and Bitcoin, Litecoin, Ethereum and Decred
+/// mainnet addresses from a private key.
+///
+/// SegWit addresses exist for the compressed key only, as BIP143 requires.
+pub fn derive(key: &SigningKey) -> Result<Derived, VerifyError> {
+    let public_key = PublicKey::from(key.verifying_key());
+    let compressed = sec1_bytes(&public_key, PubkeyFormat::Compressed);
+    let uncompressed = sec1_bytes(&public_key, PubkeyFormat::Uncompressed);
+    let hash_compressed = hash160(&compressed);
+    let hash_uncompressed = hash160(&uncompressed);
+
+    let mut nested = vec![0x00, 0x14];
+    nested.extend_from_slice(&hash_compressed);
+
+    let address = |chain, kind, pubkey_format, address| DerivedAddress {
+        chain,
+        kind,
+        pubkey_format,
+        address,
+    };
+    let compressed_format = Some(PubkeyFormat::Compressed);
+    let uncompressed_format = Some(PubkeyFormat::Uncompressed);
+
+    let addresses = vec![
+        address(
+            Chain::Bitcoin,
+            "p2pkh",
+            compressed_format,
+            base58check(0x00, &hash_compressed),
+        ),
+        address(
+            Chain::Bitcoin,
+            "p2pkh",
+            uncompressed_format,
+            base58check(0x00, &hash_uncompressed),
+        ),
+        address(
+            Chain::Bitcoin,
+            "p2sh",
+            compressed_format,
+            base58check(0x05, &hash160(&nested)),
+        ),
+        address(
+            Chain::Bitcoin,
+            "p2wpkh",
+            compressed_format,
+            segwit("bc", bech32::Fe32::Q, &hash_compressed)?,
+        ),
+        address(Chain::Bitcoin, "p2tr", None, taproot(&public_key, "bc")?),
+        address(
+            Chain::Litecoin,
+            "p2pkh",
+            compressed_format,
+            base58check(0x30, &hash_compressed),
+        ),
+        address(
+            Chain::Litecoin,
+            "p2pkh",
+            uncompressed_format,
+            base58check(0x30, &hash_uncompressed),
+        ),
+        address(
+            Chain::Litecoin,
+            "p2wpkh",
+            compressed_format,
+            segwit("ltc", bech32::Fe32::Q, &hash_compressed)?,
+        ),
+        address(
+            Chain::Ethereum,
+            "standard",
+            None,
+            ethereum_address(&public_key),
+        ),
+        address(
+            Chain::Decred,
+            "p2pkh",
+            compressed_format,
+            decred_p2pkh(&hash160_blake256(&compressed)),
+        ),
+        address(
+            Chain::Decred,
+            "p2pkh",
+            uncompressed_format,
+            decred_p2pkh(&hash160_blake256(&uncompressed)),
+        ),
+    ];
+
+    Ok(Derived {
+        private_key: hex::encode(key.to_bytes()),
+        compressed_pubkey: hex::encode(&compressed),
+        uncompressed_pubkey: hex::encode(&uncompressed),
+        hash160_compressed: hex::encode(hash_compressed),
+        hash160_uncompressed: hex::encode(hash_uncompressed),
+        addresses,
+    })
+}
+
+/// Parse a 64-char hex private key (an optional `0x` prefix is accepted).
+pub fn key_from_hex(hex_key: &str) -> Result<SigningKey, VerifyError> {
+    let hex_key = hex_key.strip_prefix("0x").unwrap_or(hex_key);
+    let key_bytes =
+        hex::decode(hex_key).map_err(|e| VerifyError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+    if key_bytes.len() != 32 {
+        return Err(VerifyError::InvalidKey(format!(
+            "Key must be 32 bytes, got {}",
+            key_bytes.len()
+        )));
+    }
+
+    SigningKey::from_bytes((&key_bytes[..]).into())
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid secp256k1 key: {}", e)))
+}
+
+/// A private key decoded from WIF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WifKey {
+    pub key: SigningKey,
+    /// Public key encoding selected by the compression flag.
+    pub pubkey_format: PubkeyFormat,
+    pub network: Network,
+}
+
+const WIF_MAINNET: u8 = 0x80;
+const WIF_TESTNET: u8 = 0xef;
+const WIF_COMPRESSED: u8 = 0x01;
+
+/// Encode a private key as Bitcoin WIF.
+///
+/// WIF format:
+/// - Version byte (0x80 mainnet, 0xef testnet)
+/// - 32-byte private key
+/// - Compression flag (0x01), only for compressed public keys
+/// - 4-byte checksum (double SHA256)
+pub fn to_wif(key: &SigningKey, pubkey_format: PubkeyFormat, network: Network) -> String {
+    let version = wif_version(network);
+    let mut payload = key.to_bytes().to_vec();
+    if pubkey_format == PubkeyFormat::Compressed {
+        payload.push(WIF_COMPRESSED);
+    }
+    base58check(version, &payload)
+}
+
+/// The WIF version byte for `network`.
+pub(crate) const fn wif_version(network: Network) -> u8 {
+    match network {
+        Network::Mainnet => WIF_MAINNET,
+        Network::Testnet => WIF_TESTNET,
+    }
+}
+
+/// Decode a Bitcoin mainnet or testnet WIF key; see [`to_wif`] for the
+/// format.
+pub fn from_wif(wif: &str) -> Result<WifKey, VerifyError> {
+    let decoded = bs58::decode(wif)
+        .into_vec()
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid base58: {}", e)))?;
+
+    if decoded.len() != 37 && decoded.len() != 38 {
+        return Err(VerifyError::InvalidKey(format!(
+            "Invalid WIF length: {} bytes (expected 37 or 38)",
+            decoded.len()
+        )));
+    }
+
+    let network = match decoded[0] {
+        WIF_MAINNET => Network::Mainnet,
+        WIF_TESTNET => Network::Testnet,
+        byte => {
+            return Err(VerifyError::InvalidKey(format!(
+                "Invalid network byte: 0x{:02x} (expected 0x80 or 0xef)",
+                byte
+            )))
+        }
+    };
+
+    let checksum_start = decoded.len() - 4;
+    let payload = &decoded[..checksum_start];
+    let checksum = &decoded[checksum_start..];
+
+    let hash = sha256(&sha256(payload));
+    if &hash[..4] != checksum {
+        return Err(VerifyError::InvalidKey(
+            "WIF checksum verification failed".to_string(),
+        ));
+    }
+
+    let compressed = decoded.len() == 38;
+    if compressed && decoded[33] != WIF_COMPRESSED {
+        return Err(VerifyError::InvalidKey(format!(
+            "Invalid compression flag: 0x{:02x} (expected 0x01)",
+            decoded[33]
+        )));
+    }
+
+    let key = SigningKey::from_bytes((&decoded[1..33]).into())
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid secp256k1 key: {}", e)))?;
+    let pubkey_format = if compressed {
+        PubkeyFormat::Compressed
+    } else {
+        PubkeyFormat::Uncompressed
+    };
+    Ok(WifKey {
+        key,
+        pubkey_format,
+        network,
+    })
+}
+
+/// Derive the private key at BIP32 `path` from a BIP39 mnemonic and
+/// optional passphrase (empty string for none).
+pub fn key_from_mnemonic(
+    phrase: &str,
+    path: &str,
+    passphrase: &str,
+) -> Result<SigningKey, VerifyError> {
+    use bip32::{DerivationPath, XPrv};
+    use bip39::Mnemonic;
+    use std::str::FromStr;
+
+    let mnemonic = Mnemonic::parse_normalized(phrase)
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid mnemonic: {}", e)))?;
+
+    let seed = mnemonic.to_seed(passphrase);
+
+    let derivation_path = DerivationPath::from_str(path)
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid derivation path: {}", e)))?;
+
+    let xprv = XPrv::derive_from_path(seed, &derivation_path)
+        .map_err(|e| VerifyError::DerivationFailed(format!("Key derivation failed: {}", e)))?;
+
+    key_from_hex(&hex::encode(xprv.private_key().to_bytes()))
+}
+
+/// SEC1 encoding of `public_key` in the given format.
+pub fn sec1_bytes(public_key: &PublicKey, pubkey_format: PubkeyFormat) -> Vec<u8> {
+    match pubkey_format {
+        PubkeyFormat::Compressed => public_key.to_sec1_bytes().to_vec(),
+        PubkeyFormat::Uncompressed => public_key.to_encoded_point(false).as_bytes().to_vec(),
+    }
+}
+
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// HASH160: RIPEMD160(SHA256(data)).
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    let sha = sha256(data);
+    Ripemd160::digest(sha).into()
+}
+
+/// HASH160 using BLAKE-256 instead of SHA-256 (for Decred).
+/// Formula: RIPEMD160(BLAKE256(data))
+pub fn hash160_blake256(data: &[u8]) -> [u8; 20] {
+    use blake_hash::{Blake256, Digest};
+    let blake = Blake256::digest(data);
+    Ripemd160::digest(blake).into()
+}
+
+/// Base58Check with a one-byte version: `0x00` Bitcoin P2PKH, `0x05` P2SH,
+/// `0x6f` testnet P2PKH, `0x30` Litecoin P2PKH.
+pub fn base58check(version: u8, payload: &[u8]) -> String {
+    base58check_prefixed(&[version], payload)
+}
+
+/// Base58Check with a multi-byte version, e.g. `[0x1c, 0xb8]` for Zcash
+/// transparent P2PKH.
+pub fn base58check_prefixed(version: &[u8], payload: &[u8]) -> String {
+    let mut data = version.to_vec();
+    data.extend_from_slice(payload);
+    let checksum = &sha256(&sha256(&data))[..4];
+    data.extend_from_slice(checksum);
+
+    bs58::encode(data).into_string()
+}
+
+/// SegWit address for a witness `program`: bech32 for version 0, bech32m
+/// from version 1 on.
+pub fn segwit(hrp: &str, version: bech32::Fe32, program: &[u8]) -> Result<String, VerifyError> {
+    use bech32::{segwit, Hrp};
+
+    let hrp = Hrp::parse(hrp)
+        .map_err(|e| VerifyError::DerivationFailed(format!("Invalid HRP: {}", e)))?;
+
+    segwit::encode(hrp, version, program)
+        .map_err(|e| VerifyError::DerivationFailed(format!("Bech32 encoding failed: {}", e)))
+}
+
+/// Key-path-only taproot (BIP86): output key `Q = P + H_TapTweak(P)·G`,
+/// where `P` is the internal key with even Y, encoded as bech32m v1.
+pub fn taproot(public_key: &PublicKey, hrp: &str) -> Result<String, VerifyError> {
+    use k256::elliptic_curve::point::AffineCoordinates;
+    use k256::elliptic_curve::PrimeField;
+    use k256::{ProjectivePoint, Scalar};
+
+    let point = public_key.as_affine();
+    let internal = if bool::from(point.y_is_odd()) {
+        -ProjectivePoint::from(*point)
+    } else {
+        ProjectivePoint::from(*point)
+    };
+    let internal_x: [u8; 32] = point.x().into();
+
+    let tweak = tagged_hash("TapTweak", &internal_x);
+    let tweak = Option::<Scalar>::from(Scalar::from_repr(tweak.into()))
+        .ok_or_else(|| VerifyError::DerivationFailed("TapTweak exceeds curve order".to_string()))?;
+
+    let output = (internal + ProjectivePoint::GENERATOR * tweak).to_affine();
+    let output_x: [u8; 32] = output.x().into();
+
+    segwit(hrp, bech32::Fe32::P, &output_x)
+}
+
+/// BIP340 tagged hash: `SHA256(SHA256(tag) || SHA256(tag) || data)`.
+fn tagged_hash(tag: &str, data: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Ethereum address, lowercase: the last 20 bytes of Keccak256 over the
+/// uncompressed public key without its `0x04` prefix.
+pub fn ethereum_address(public_key: &PublicKey) -> String {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let pubkey_bytes = public_key.to_encoded_point(false);
+
+    let mut keccak = Keccak::v256();
+    let mut hash = [0u8; 32];
+    keccak.update(&pubkey_bytes.as_bytes()[1..]);
+    keccak.finalize(&mut hash);
+
+    format!("0x{}", hex::encode(&hash[12..]))
+}
+
+/// Bitcoin Cash CashAddr with the `bitcoincash:` prefix, for a key hash or,
+/// with `script`, a script hash.
+pub fn cashaddr(hash: &[u8; 20], script: bool) -> String {
+    use crate::address::{
+        cashaddr_polymod, cashaddr_prefix_values, CASHADDR_CHARSET, CASHADDR_PREFIX,
+    };
+
+    let mut values = Vec::with_capacity(42);
+    let (mut acc, mut bits) = (0u32, 0);
+    let version = if script { 0x08 } else { 0x00 };
+    for &byte in std::iter::once(&version).chain(hash) {
+        acc = (acc << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            values.push(((acc >> bits) & 0x1f) as u8);
+        }
+    }
+    if bits > 0 {
+        values.push(((acc << (5 - bits)) & 0x1f) as u8);
+    }
+
+    let checksum = cashaddr_polymod(
+        cashaddr_prefix_values()
+            .chain(values.iter().copied())
+            .chain([0; 8]),
+    );
+    values.extend((0..8).map(|i| ((checksum >> (5 * (7 - i))) & 0x1f) as u8));
+
+    let encoded: String = values
+        .iter()
+        .map(|&v| char::from(CASHADDR_CHARSET[usize::from(v)]))
+        .collect();
+    format!("{}:{}", CASHADDR_PREFIX, encoded)
+}
+
+/// Decred mainnet P2PKH address for a [`hash160_blake256`] key hash:
+/// network bytes `[0x07, 0x3f]`, double BLAKE-256 checksum.
+pub fn decred_p2pkh(hash: &[u8; 20]) -> String {
+    use blake_hash::{Blake256, Digest};
+
+    let mut data = vec![0x07, 0x3f];
+    data.extend_from_slice(hash);
+
+    let first_hash = Blake256::digest(&data);
+    let checksum_hash = Blake256::digest(&first_hash);
+    data.extend_from_slice(&checksum_hash[..4]);
+
+    bs58::encode(data).into_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_one() -> SigningKey {
+        key_from_hex(&format!("{:064x}", 1)).unwrap()
+    }
+
+    fn address(
+        derived: &Derived,
+        chain: Chain,
+        kind: &str,
+        format: Option<PubkeyFormat>,
+    ) -> String {
+        derived
+            .addresses
+            .iter()
+            .find(|a| a.chain == chain && a.kind == kind && a.pubkey_format == format)
+            .unwrap()
+            .address
+            .clone()
+    }
+
+    #[test]
+    fn derives_every_chain_from_key_one() {
+        let derived = derive(&key_one()).unwrap();
+
+        assert_eq!(
+            derived.compressed_pubkey,
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+        assert_eq!(
+            derived.hash160_compressed,
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert_eq!(
+            address(
+                &derived,
+                Chain::Bitcoin,
+                "p2pkh",
+                Some(PubkeyFormat::Compressed)
+            ),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+        );
+        assert_eq!(
+            address(
+                &derived,
+                Chain::Bitcoin,
+                "p2pkh",
+                Some(PubkeyFormat::Uncompressed)
+            ),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"
+        );
+        assert_eq!(
+            address(
+                &derived,
+                Chain::Bitcoin,
+                "p2wpkh",
+                Some(PubkeyFormat::Compressed)
+            ),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+        assert_eq!(
+            address(&derived, Chain::Ethereum, "standard", None),
+            "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+    }
+
+    #[test]
+    fn wif_round_trips_on_both_networks() {
+        let cases = [
+            (
+                "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn",
+                PubkeyFormat::Compressed,
+                Network::Mainnet,
+            ),
+            (
+                "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf",
+                PubkeyFormat::Uncompressed,
+                Network::Mainnet,
+            ),
+            (
+                "cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN87JcbXMTcA",
+                PubkeyFormat::Compressed,
+                Network::Testnet,
+            ),
+        ];
+        for (wif, pubkey_format, network) in cases {
+            let decoded = from_wif(wif).unwrap();
+            assert_eq!(decoded.key, key_one());
+            assert_eq!(decoded.pubkey_format, pubkey_format);
+            assert_eq!(decoded.network, network);
+            assert_eq!(to_wif(&key_one(), pubkey_format, network), wif);
+        }
+    }
+
+    #[test]
+    fn mnemonic_follows_the_path() {
+        let phrase = "abandon abandon abandon abandon abandon abandon \
+                      abandon abandon abandon abandon abandon about";
+        let key = key_from_mnemonic(phrase, "m/44'/0'/0'/0/0", "").unwrap();
+        let derived = derive(&key).unwrap();
+        assert_eq!(
+            address(
+                &derived,
+                Chain::Bitcoin,
+                "p2pkh",
+                Some(PubkeyFormat::Compressed)
+            ),
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+        );
+    }
+
+    #[test]
+    fn fork_encodings_match_known_addresses() {
+        let hash: [u8; 20] = hex::decode("76a04053bda0a88bda5177b86a15c3b29f559873")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            cashaddr(&hash, false),
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"
+        );
+        assert_eq!(
+            base58check_prefixed(
+                &[0x1c, 0xb8],
+                &hash160(&sec1_bytes(
+                    &PublicKey::from(key_one().verifying_key()),
+                    PubkeyFormat::Compressed
+                ))
+            ),
+            "t1UYsZVJkLPeMjxEtACvSxfWuNmddpWfxzs"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_keys() {
+        assert!(key_from_hex("abcd").is_err());
+        assert!(key_from_hex(&"0".repeat(64)).is_err());
+        assert!(from_wif("KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWo").is_err());
+        assert!(key_from_mnemonic("abandon", "m/0", "").is_err());
+    }
+}
@@ -1,21 +1,62 @@
+mod amount;
+mod author;
 mod collections;
+mod date;
+mod filter;
+mod index;
 mod puzzle;
+mod suggest;
+mod timeline;
 
-#[cfg(feature = "balance")]
-pub mod balance;
+pub mod descriptor;
 
-#[cfg(feature = "cli")]
+pub mod script;
+
+pub mod txid;
+
+#[cfg(feature = "verify")]
+pub mod address;
+
+pub mod keymath;
+
+#[cfg(feature = "verify")]
+pub mod keytools;
+
+pub mod plugin;
+
+#[cfg(feature = "progress")]
+pub mod progress;
+
+#[cfg(feature = "balance-snapshot")]
+pub mod snapshot;
+
+pub mod validate;
+
+#[cfg(feature = "verify")]
 pub mod verify;
 
 pub mod version {
     include!(concat!(env!("OUT_DIR"), "/data_version.rs"));
 }
 
+pub use keymath::KeyRange;
+
+pub use plugin::DynCollection;
+
+pub use amount::{Amount, ParseAmountError};
+pub use author::{author_stats, Author, AuthorStats};
+pub use date::{DateTime, ParseDateError};
+pub use filter::{filter, Filter};
+pub use index::{init, is_initialized, IndexBuild, InitOptions};
+pub use timeline::{Event, EventKind};
+
 pub use collections::{arweave, b1000, ballet, bitaps, bitimage, gsmg, hash_collision, warp, zden};
+
+pub use collections::example;
 pub use puzzle::{
-    Address, Assets, Author, Chain, Entropy, EntropySource, IntoPuzzleNum, Key, Passphrase,
-    Profile, Pubkey, PubkeyFormat, Puzzle, RedeemScript, Seed, Share, Shares, Solver, Status,
-    Transaction, TransactionType, Wif,
+    Address, Assets, BrainwalletScheme, Chain, Effort, Entropy, EntropySource, IntoPuzzleNum, Key,
+    KeySource, Passphrase, Profile, Pubkey, PubkeyFormat, Puzzle, RedeemScript, Seed, Share,
+    Shares, Solver, Status, Transaction, TransactionType, Wif,
 };
 
 use std::collections::HashMap;
@@ -23,17 +64,29 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("Puzzle not found: {0}")]
-    NotFound(String),
+    /// `suggestions` holds existing ids close to `id`, best first; only
+    /// [`get`] fills it.
+    #[error("Puzzle not found: {id}{}", did_you_mean(.suggestions))]
+    NotFound {
+        id: String,
+        suggestions: Vec<String>,
+    },
     #[error("Invalid puzzle number: {0}")]
     InvalidNumber(u32),
     #[error("Invalid collection: {0}")]
     InvalidCollection(String),
+    #[error("Invalid transaction id: {0}")]
+    InvalidTxid(String),
+    #[error("Invalid script: {0}")]
+    InvalidScript(String),
+    #[error("Cannot register collection {0}")]
+    Registration(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Collection {
     Arweave,
     B1000,
@@ -44,9 +97,15 @@ pub enum Collection {
     HashCollision,
     Warp,
     Zden,
+    /// Fake testnet puzzles; not part of [`Collection::ALL`], and reachable by
+    /// name and non-empty only with the `examples-data` feature.
+    Example,
+    /// Provided by another crate through [`plugin::register`].
+    Plugin(plugin::Registered),
 }
 
 impl Collection {
+    /// Every built-in bounty collection; [`collections`] adds registered ones.
     pub const ALL: [Self; 9] = [
         Self::Arweave,
         Self::B1000,
@@ -70,21 +129,49 @@ impl Collection {
             Self::HashCollision => "hash_collision",
             Self::Warp => "warp",
             Self::Zden => "zden",
+            Self::Example => "example",
+            Self::Plugin(registered) => registered.name(),
         }
     }
 
     pub fn parse(name: &str) -> Result<Self> {
+        Self::parse_builtin(name)
+            .or_else(|| author::collection_for_alias(name))
+            .or_else(|| plugin::find(name).map(|c| Self::Plugin(plugin::Registered::new(c))))
+            .ok_or_else(|| Error::InvalidCollection(name.to_string()))
+    }
+
+    /// Other names [`Collection::parse`] accepts: `warpwallet` for `warp`,
+    /// and author aliases such as `peter_todd`.
+    pub fn aliases(self) -> Vec<&'static str> {
+        let mut aliases = match self {
+            Self::Warp => vec!["warpwallet"],
+            _ => Vec::new(),
+        };
+        aliases.extend(
+            self.author()
+                .aliases
+                .iter()
+                .copied()
+                .filter(|alias| author::collection_for_alias(alias) == Some(self)),
+        );
+        aliases
+    }
+
+    fn parse_builtin(name: &str) -> Option<Self> {
         match name {
-            "arweave" => Ok(Self::Arweave),
-            "b1000" => Ok(Self::B1000),
-            "ballet" => Ok(Self::Ballet),
-            "bitaps" => Ok(Self::Bitaps),
-            "bitimage" => Ok(Self::Bitimage),
-            "gsmg" => Ok(Self::Gsmg),
-            "hash_collision" | "peter_todd" => Ok(Self::HashCollision),
-            "warp" | "warpwallet" => Ok(Self::Warp),
-            "zden" => Ok(Self::Zden),
-            _ => Err(Error::InvalidCollection(name.to_string())),
+            "arweave" => Some(Self::Arweave),
+            "b1000" => Some(Self::B1000),
+            "ballet" => Some(Self::Ballet),
+            "bitaps" => Some(Self::Bitaps),
+            "bitimage" => Some(Self::Bitimage),
+            "gsmg" => Some(Self::Gsmg),
+            "hash_collision" => Some(Self::HashCollision),
+            "warp" | "warpwallet" => Some(Self::Warp),
+            "zden" => Some(Self::Zden),
+            #[cfg(feature = "examples-data")]
+            "example" => Some(Self::Example),
+            _ => None,
         }
     }
 
@@ -99,6 +186,8 @@ impl Collection {
             Self::HashCollision => hash_collision::slice(),
             Self::Warp => warp::slice(),
             Self::Zden => zden::slice(),
+            Self::Example => example::slice(),
+            Self::Plugin(registered) => registered.collection().puzzles(),
         }
     }
 
@@ -117,6 +206,8 @@ impl Collection {
             Self::HashCollision => hash_collision::author(),
             Self::Warp => warp::author(),
             Self::Zden => zden::author(),
+            Self::Example => example::author(),
+            Self::Plugin(registered) => registered.collection().author(),
         }
     }
 
@@ -126,7 +217,7 @@ impl Collection {
             Self::B1000 => {
                 let num = name
                     .parse::<u32>()
-                    .map_err(|_| Error::NotFound(format!("{}/{}", self.name(), name)))?;
+                    .map_err(|_| Error::not_found(format!("{}/{}", self.name(), name)))?;
                 b1000::get(num)
             }
             Self::Ballet => ballet::get(name),
@@ -134,7 +225,7 @@ impl Collection {
                 if name.is_empty() {
                     Ok(bitaps::get())
                 } else {
-                    Err(Error::NotFound(format!("{}/{}", self.name(), name)))
+                    Err(Error::not_found(format!("{}/{}", self.name(), name)))
                 }
             }
             Self::Bitimage => bitimage::get(name),
@@ -142,17 +233,56 @@ impl Collection {
                 if name.is_empty() {
                     Ok(gsmg::get())
                 } else {
-                    Err(Error::NotFound(format!("{}/{}", self.name(), name)))
+                    Err(Error::not_found(format!("{}/{}", self.name(), name)))
                 }
             }
             Self::HashCollision => hash_collision::get(name),
             Self::Warp => warp::get(name),
             Self::Zden => zden::get(name),
+            Self::Example => example::get(name),
+            Self::Plugin(registered) => registered
+                .collection()
+                .get(name)
+                .ok_or_else(|| Error::not_found(format!("{}/{}", self.name(), name))),
+        }
+    }
+}
+
+impl Error {
+    pub(crate) fn not_found(id: impl Into<String>) -> Self {
+        Self::NotFound {
+            id: id.into(),
+            suggestions: Vec::new(),
         }
     }
 }
 
+fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [only] => format!(" (did you mean {}?)", only),
+        [rest @ .., last] => format!(" (did you mean {} or {}?)", rest.join(", "), last),
+    }
+}
+
+/// The puzzle with id `id`; a miss, out-of-range numbers included, is
+/// [`Error::NotFound`] with "did you mean" suggestions.
 pub fn get(id: &str) -> Result<&'static Puzzle> {
+    if let Some(puzzle) = index::index().ids.get(id) {
+        return Ok(puzzle);
+    }
+    lookup(id).map_err(|e| match e {
+        // Out-of-range numbers are misses too, with the nearest suggested.
+        Error::NotFound { .. } | Error::InvalidNumber(_) => Error::NotFound {
+            id: id.to_string(),
+            suggestions: suggest::suggest(id),
+        },
+        other => other,
+    })
+}
+
+fn lookup(id: &str) -> Result<&'static Puzzle> {
+    // Aliases and alternate spellings (`peter_todd/...`, `b1000/066`).
     if id == "gsmg" {
         return Collection::Gsmg.get("");
     }
@@ -162,24 +292,61 @@ pub fn get(id: &str) -> Result<&'static Puzzle> {
 
     let parts: Vec<&str> = id.split('/').collect();
     if parts.len() != 2 {
-        return Err(Error::NotFound(id.to_string()));
+        return Err(Error::not_found(id.to_string()));
     }
 
-    let collection = Collection::parse(parts[0]).map_err(|_| Error::NotFound(id.to_string()))?;
+    let collection = Collection::parse(parts[0]).map_err(|_| Error::not_found(id.to_string()))?;
 
     if matches!(collection, Collection::Gsmg | Collection::Bitaps) {
-        return Err(Error::NotFound(id.to_string()));
+        return Err(Error::not_found(id.to_string()));
     }
 
     collection.get(parts[1])
 }
 
+/// The puzzle whose address is `address`. `0x` addresses match in any case.
+pub fn get_by_address(address: &str) -> Result<&'static Puzzle> {
+    index::index()
+        .addresses
+        .get(index::address_key(address).as_ref())
+        .copied()
+        .ok_or_else(|| Error::not_found(address.to_string()))
+}
+
+/// The puzzle with [`Puzzle::uuid`] `uuid`, in any case.
+pub fn get_by_uuid(uuid: &str) -> Result<&'static Puzzle> {
+    index::index()
+        .uuids
+        .get(uuid.to_lowercase().as_str())
+        .copied()
+        .ok_or_else(|| Error::not_found(uuid.to_string()))
+}
+
+/// Built-in collections followed by [registered](plugin::register) ones.
+pub fn collections() -> Vec<Collection> {
+    let mut collections = Collection::ALL.to_vec();
+    collections.extend(
+        plugin::registered()
+            .into_iter()
+            .map(|c| Collection::Plugin(plugin::Registered::new(c))),
+    );
+    collections
+}
+
 pub fn all() -> impl Iterator<Item = &'static Puzzle> {
-    Collection::ALL.into_iter().flat_map(Collection::all)
+    collections().into_iter().flat_map(Collection::all)
 }
 
-#[derive(Debug, Default, Clone, serde::Serialize)]
+/// Version of the serialized [`Stats`] layout.
+///
+/// Changes within a version are additive only: fields are never renamed,
+/// removed or reshaped. Version 1 is the unversioned layout used before
+/// `schema_version` existed.
+pub const STATS_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct Stats {
+    pub schema_version: u32,
     pub total: usize,
     pub solved: usize,
     pub unsolved: usize,
@@ -187,10 +354,46 @@ pub struct Stats {
     pub swept: usize,
     pub expired: usize,
     pub with_pubkey: usize,
+    /// Serialized with sorted keys and every [`Chain`] present, so the map
+    /// keeps its shape when a currency has no prizes.
+    #[serde(serialize_with = "serialize_prize_map")]
     pub total_prize: HashMap<String, f64>,
+    #[serde(serialize_with = "serialize_prize_map")]
     pub unsolved_prize: HashMap<String, f64>,
 }
 
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            schema_version: STATS_SCHEMA_VERSION,
+            total: 0,
+            solved: 0,
+            unsolved: 0,
+            claimed: 0,
+            swept: 0,
+            expired: 0,
+            with_pubkey: 0,
+            total_prize: HashMap::new(),
+            unsolved_prize: HashMap::new(),
+        }
+    }
+}
+
+pub(crate) fn serialize_prize_map<S: serde::Serializer>(
+    map: &HashMap<String, f64>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let mut sorted: std::collections::BTreeMap<&str, f64> = Chain::ALL
+        .iter()
+        .map(|chain| (chain.symbol(), 0.0))
+        .collect();
+    sorted.extend(
+        map.iter()
+            .map(|(symbol, amount)| (symbol.as_str(), *amount)),
+    );
+    serializer.collect_map(sorted)
+}
+
 pub fn stats() -> Stats {
     let mut stats = Stats::default();
 
@@ -222,6 +425,42 @@ pub fn stats() -> Stats {
 mod tests {
     use super::*;
 
+    #[test]
+    fn get_by_address_finds_puzzle() {
+        let gsmg = get("gsmg").unwrap();
+        assert!(std::ptr::eq(
+            get_by_address(gsmg.address.value).unwrap(),
+            gsmg
+        ));
+        assert!(get_by_address("1NotAPuzzleAddress").is_err());
+
+        let eth = all().find(|p| p.address.value.starts_with("0x")).unwrap();
+        let upper = format!("0x{}", eth.address.value[2..].to_uppercase());
+        assert_eq!(get_by_address(&upper).unwrap().id, eth.id);
+    }
+
+    #[test]
+    fn get_by_uuid_finds_puzzle() {
+        let puzzle = get_by_uuid("d7aaa59b-c326-5d73-81e4-55d1e7c68f0a").unwrap();
+        assert_eq!(puzzle.id, "b1000/1");
+        assert_eq!(
+            get_by_uuid("D7AAA59B-C326-5D73-81E4-55D1E7C68F0A")
+                .unwrap()
+                .id,
+            "b1000/1"
+        );
+        assert!(get_by_uuid("00000000-0000-0000-0000-000000000000").is_err());
+    }
+
+    #[test]
+    fn get_keeps_aliases_after_index() {
+        assert_eq!(get("b1000/066").unwrap().id, "b1000/66");
+        assert_eq!(
+            get("peter_todd/sha256").unwrap().id,
+            "hash_collision/sha256"
+        );
+    }
+
     #[test]
     fn collection_parse_supports_aliases() {
         assert_eq!(Collection::parse("arweave").unwrap(), Collection::Arweave);
@@ -267,11 +506,11 @@ mod tests {
     fn collection_get_rejects_singleton_suffixes() {
         assert!(matches!(
             Collection::parse("gsmg").unwrap().get("extra"),
-            Err(Error::NotFound(id)) if id == "gsmg/extra"
+            Err(Error::NotFound { id, .. }) if id == "gsmg/extra"
         ));
         assert!(matches!(
             Collection::parse("bitaps").unwrap().get("extra"),
-            Err(Error::NotFound(id)) if id == "bitaps/extra"
+            Err(Error::NotFound { id, .. }) if id == "bitaps/extra"
         ));
     }
 
@@ -279,31 +518,55 @@ mod tests {
     fn global_get_rejects_singleton_slash_ids() {
         assert!(matches!(
             get("gsmg/extra"),
-            Err(Error::NotFound(id)) if id == "gsmg/extra"
+            Err(Error::NotFound { id, .. }) if id == "gsmg/extra"
         ));
         assert!(matches!(
             get("bitaps/extra"),
-            Err(Error::NotFound(id)) if id == "bitaps/extra"
+            Err(Error::NotFound { id, .. }) if id == "bitaps/extra"
         ));
         assert!(matches!(
             get("gsmg/"),
-            Err(Error::NotFound(id)) if id == "gsmg/"
+            Err(Error::NotFound { id, .. }) if id == "gsmg/"
         ));
         assert!(matches!(
             get("bitaps/"),
-            Err(Error::NotFound(id)) if id == "bitaps/"
+            Err(Error::NotFound { id, .. }) if id == "bitaps/"
         ));
     }
 
+    #[cfg(feature = "examples-data")]
+    #[test]
+    fn example_collection_is_reachable_but_not_global() {
+        assert_eq!(get("example/2").unwrap().id, "example/2");
+        assert_eq!(Collection::parse("example").unwrap().all().count(), 3);
+        assert!(!Collection::ALL.contains(&Collection::Example));
+        assert!(all().all(|p| !p.id.starts_with("example/")));
+    }
+
+    #[test]
+    fn global_get_misses_carry_suggestions() {
+        match get("b1000/0") {
+            Err(Error::NotFound { id, suggestions }) => {
+                assert_eq!(id, "b1000/0");
+                assert_eq!(suggestions, ["b1000/1"]);
+            }
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+        assert_eq!(
+            get("hash_colision/sha256").unwrap_err().to_string(),
+            "Puzzle not found: hash_colision/sha256 (did you mean hash_collision/sha256?)"
+        );
+    }
+
     #[test]
     fn global_get_rejects_extra_path_segments() {
         assert!(matches!(
             get("b1000/66/extra"),
-            Err(Error::NotFound(id)) if id == "b1000/66/extra"
+            Err(Error::NotFound { id, .. }) if id == "b1000/66/extra"
         ));
         assert!(matches!(
             get("hash_collision/sha256/extra"),
-            Err(Error::NotFound(id)) if id == "hash_collision/sha256/extra"
+            Err(Error::NotFound { id, .. }) if id == "hash_collision/sha256/extra"
         ));
     }
 }
@@ -0,0 +1,146 @@
+//! Puzzle collections provided by other crates.
+//!
+//! A provider implements [`DynCollection`] and calls [`register`] once at
+//! startup. Registered collections are returned by [`crate::collections`],
+//! so they show up in [`crate::all`], [`crate::get`], [`crate::stats`] and
+//! everywhere a [`Collection`] is parsed from a name.
+//!
+//! Registration closes when the lookup indexes are built (the first
+//! [`crate::get`] or an eager [`crate::init`]), so register before either.
+
+use std::sync::{PoisonError, RwLock};
+
+use crate::{author, Author, Collection, Error, Puzzle, Result};
+
+/// A puzzle collection that is not compiled into boha.
+///
+/// Every puzzle id must be `<name>/<puzzle>`.
+pub trait DynCollection: Send + Sync {
+    /// Collection name, the part of every puzzle id before `/`.
+    fn name(&self) -> &'static str;
+
+    fn author(&self) -> &'static Author;
+
+    fn puzzles(&self) -> &'static [Puzzle];
+
+    /// The puzzle whose id ends in `/<name>`.
+    fn get(&self, name: &str) -> Option<&'static Puzzle> {
+        self.puzzles().iter().find(|p| p.name() == name)
+    }
+}
+
+struct Registry {
+    collections: Vec<&'static dyn DynCollection>,
+    closed: bool,
+}
+
+static REGISTRY: RwLock<Registry> = RwLock::new(Registry {
+    collections: Vec::new(),
+    closed: false,
+});
+
+/// Add `collection` to every lookup in this process.
+///
+/// Fails once the lookup indexes exist, when the name is taken by a built-in
+/// or registered collection or is a built-in author alias such as
+/// `peter_todd`, or when a puzzle id lacks the `<name>/` prefix.
+pub fn register(collection: &'static dyn DynCollection) -> Result<()> {
+    let name = collection.name();
+    let fail = |reason: String| Err(Error::Registration(format!("{}: {}", name, reason)));
+
+    if name.is_empty() || name.contains('/') {
+        return fail("name must be non-empty and contain no '/'".to_string());
+    }
+    if let Some(puzzle) = collection
+        .puzzles()
+        .iter()
+        .find(|p| p.collection() != name || p.name().is_empty())
+    {
+        return fail(format!("puzzle id {} is not {}/<name>", puzzle.id, name));
+    }
+
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    if registry.closed {
+        return fail("lookups have already started".to_string());
+    }
+    // Built-in names and author aliases win in `Collection::parse`, so a
+    // collection using one could never be looked up.
+    if Collection::parse_builtin(name).is_some()
+        || author::collection_for_alias(name).is_some()
+        || registry.collections.iter().any(|c| c.name() == name)
+    {
+        return fail("name is already taken".to_string());
+    }
+    registry.collections.push(collection);
+    drop(registry);
+    Ok(())
+}
+
+/// Registered collections, in registration order.
+pub fn registered() -> Vec<&'static dyn DynCollection> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .collections
+        .clone()
+}
+
+pub(crate) fn find(name: &str) -> Option<&'static dyn DynCollection> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .collections
+        .iter()
+        .copied()
+        .find(|c| c.name() == name)
+}
+
+/// A collection that passed [`register`], as held by [`Collection::Plugin`].
+///
+/// Only [`Collection::parse`] and [`crate::collections`] hand these out, so a
+/// [`Collection`] always names a collection that exists.
+#[derive(Clone, Copy)]
+pub struct Registered {
+    name: &'static str,
+    collection: &'static dyn DynCollection,
+}
+
+impl Registered {
+    pub(crate) fn new(collection: &'static dyn DynCollection) -> Self {
+        Self {
+            name: collection.name(),
+            collection,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    pub(crate) fn collection(self) -> &'static dyn DynCollection {
+        self.collection
+    }
+}
+
+impl std::fmt::Debug for Registered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Registered").field(&self.name).finish()
+    }
+}
+
+impl PartialEq for Registered {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Registered {}
+
+/// Refuse further registrations; called before the indexes are built so no
+/// collection can be missing from them.
+pub(crate) fn close() {
+    REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .closed = true;
+}
@@ -0,0 +1,251 @@
+//! Local record of the key ranges a user has already searched.
+//!
+//! Lives in `$XDG_STATE_HOME/boha/progress.json` (or
+//! `~/.local/state/boha/progress.json`). Ranges are stored as added; overlaps
+//! are merged only when coverage is computed.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use serde::{Deserialize, Serialize};
+
+use crate::keymath::{self, KeyRange};
+
+/// Scanned ranges per puzzle id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default)]
+    puzzles: BTreeMap<String, Vec<ScannedRange>>,
+}
+
+/// One range reported as searched, hex without `0x`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScannedRange {
+    pub start: String,
+    pub end: String,
+    /// Unix seconds when the range was recorded.
+    pub added_at: u64,
+}
+
+/// How much of a puzzle's key range has been searched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    /// Merged, sorted scanned ranges, clipped to the puzzle range.
+    pub ranges: Vec<KeyRange>,
+    pub scanned: BigUint,
+    pub total: BigUint,
+    /// `scanned / total` as a percentage.
+    pub percent: f64,
+}
+
+impl Progress {
+    /// Record `range` as searched for `puzzle_id`.
+    pub fn add(&mut self, puzzle_id: &str, range: &KeyRange) {
+        self.puzzles
+            .entry(puzzle_id.to_string())
+            .or_default()
+            .push(ScannedRange {
+                start: format!("{:x}", range.start()),
+                end: format!("{:x}", range.end()),
+                added_at: now_secs(),
+            });
+    }
+
+    /// Forget every range recorded for `puzzle_id`; returns how many there were.
+    pub fn clear(&mut self, puzzle_id: &str) -> usize {
+        self.puzzles
+            .remove(puzzle_id)
+            .map_or(0, |ranges| ranges.len())
+    }
+
+    /// Ranges recorded for `puzzle_id`, in the order they were added.
+    pub fn entries(&self, puzzle_id: &str) -> &[ScannedRange] {
+        self.puzzles.get(puzzle_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Puzzle ids with at least one recorded range.
+    pub fn puzzle_ids(&self) -> impl Iterator<Item = &str> {
+        self.puzzles.keys().map(String::as_str)
+    }
+
+    /// Coverage of `key_range` by the ranges recorded for `puzzle_id`.
+    /// Entries that no longer parse are ignored.
+    pub fn coverage(&self, puzzle_id: &str, key_range: &KeyRange) -> Coverage {
+        let mut ranges: Vec<KeyRange> = self
+            .entries(puzzle_id)
+            .iter()
+            .filter_map(|entry| {
+                let start = keymath::parse_hex_key(&entry.start)?;
+                let end = keymath::parse_hex_key(&entry.end)?;
+                let start = start.max(key_range.start().clone());
+                let end = end.min(key_range.end().clone());
+                KeyRange::new(start, end)
+            })
+            .collect();
+        ranges.sort_by(|a, b| a.start().cmp(b.start()));
+
+        let mut merged: Vec<KeyRange> = Vec::new();
+        for range in ranges {
+            if let Some(last) = merged.last_mut() {
+                if range.start() <= &(last.end() + 1u32) {
+                    if range.end() > last.end() {
+                        *last = KeyRange::new(last.start().clone(), range.end().clone())
+                            .expect("merged range is ordered");
+                    }
+                    continue;
+                }
+            }
+            merged.push(range);
+        }
+
+        let scanned = merged.iter().map(KeyRange::size).sum::<BigUint>();
+        let total = key_range.size();
+        let percent = if scanned.is_zero() {
+            0.0
+        } else {
+            ratio(&scanned, &total) * 100.0
+        };
+        Coverage {
+            ranges: merged,
+            scanned,
+            total,
+            percent,
+        }
+    }
+}
+
+/// `a / b` as a float, keeping precision for values beyond `f64` range.
+fn ratio(a: &BigUint, b: &BigUint) -> f64 {
+    let shift = b.bits().saturating_sub(64);
+    let a = (a >> shift).to_f64().unwrap_or(f64::INFINITY);
+    let b = (b >> shift).to_f64().unwrap_or(f64::INFINITY);
+    a / b
+}
+
+/// Progress file on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressStore {
+    path: PathBuf,
+}
+
+impl ProgressStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Store under the user's XDG state directory, if one can be found.
+    pub fn open_default() -> Option<Self> {
+        default_path().map(Self::new)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Saved progress; empty when the file does not exist yet.
+    pub fn load(&self) -> io::Result<Progress> {
+        match fs::read_to_string(&self.path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, progress: &Progress) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(progress).map_err(io::Error::other)?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write then rename, so an interrupted save never truncates the file.
+        let tmp = self
+            .path
+            .with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &self.path)
+    }
+}
+
+/// `$XDG_STATE_HOME/boha/progress.json`, falling back to `$HOME/.local/state`.
+pub fn default_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("state"))
+        })?;
+    Some(base.join("boha").join("progress.json"))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u32, end: u32) -> KeyRange {
+        KeyRange::new(BigUint::from(start), BigUint::from(end)).unwrap()
+    }
+
+    #[test]
+    fn overlapping_ranges_count_once() {
+        let puzzle = KeyRange::from_bits(8).unwrap(); // 128..=255
+        let mut progress = Progress::default();
+        progress.add("b1000/8", &range(128, 159));
+        progress.add("b1000/8", &range(150, 191));
+        progress.add("b1000/8", &range(192, 200));
+        progress.add("b1000/8", &range(250, 300));
+
+        let coverage = progress.coverage("b1000/8", &puzzle);
+        assert_eq!(
+            coverage
+                .ranges
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["128:200", "250:255"]
+        );
+        assert_eq!(coverage.scanned, BigUint::from(79u32));
+        assert!((coverage.percent - 7900.0 / 128.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tiny_fractions_of_wide_ranges() {
+        let puzzle = KeyRange::from_bits(256).unwrap();
+        let mut progress = Progress::default();
+        progress.add(
+            "x",
+            &KeyRange::new(puzzle.start().clone(), puzzle.midpoint()).unwrap(),
+        );
+        assert!((progress.coverage("x", &puzzle).percent - 50.0).abs() < 1e-9);
+        assert!(progress.coverage("y", &puzzle).scanned.is_zero());
+    }
+
+    #[test]
+    fn store_round_trips() {
+        let path = std::env::temp_dir().join(format!(
+            "boha-progress-test-{}/progress.json",
+            std::process::id()
+        ));
+        let store = ProgressStore::new(&path);
+        assert_eq!(store.load().unwrap(), Progress::default());
+
+        let mut progress = Progress::default();
+        progress.add("b1000/71", &range(1, 2));
+        store.save(&progress).unwrap();
+        assert_eq!(store.load().unwrap(), progress);
+        assert_eq!(progress.clear("b1000/71"), 1);
+        assert_eq!(progress.puzzle_ids().count(), 0);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
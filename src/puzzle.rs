@@ -1,15 +1,21 @@
 //! Core puzzle types and structures.
 
+use crate::keymath::KeyRange;
+use crate::{Amount, Author, DateTime};
 use num_bigint::BigUint;
-use num_traits::One;
+use num_traits::ToPrimitive;
 use serde::Serialize;
 use std::fmt;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 /// Blockchain network for a puzzle.
+///
+/// New chains are added as bounties on them turn up, so match with a
+/// wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum Chain {
     Bitcoin,
     Ethereum,
@@ -17,16 +23,24 @@ pub enum Chain {
     Monero,
     Decred,
     Arweave,
+    Dogecoin,
+    BitcoinCash,
+    Zcash,
+    Dash,
 }
 
 impl Chain {
-    pub const ALL: [Chain; 6] = [
+    pub const ALL: [Chain; 10] = [
         Chain::Bitcoin,
         Chain::Ethereum,
         Chain::Litecoin,
         Chain::Monero,
         Chain::Decred,
         Chain::Arweave,
+        Chain::Dogecoin,
+        Chain::BitcoinCash,
+        Chain::Zcash,
+        Chain::Dash,
     ];
 
     /// Currency symbol (e.g., "BTC", "ETH").
@@ -38,6 +52,10 @@ impl Chain {
             Chain::Monero => "XMR",
             Chain::Decred => "DCR",
             Chain::Arweave => "AR",
+            Chain::Dogecoin => "DOGE",
+            Chain::BitcoinCash => "BCH",
+            Chain::Zcash => "ZEC",
+            Chain::Dash => "DASH",
         }
     }
 
@@ -50,6 +68,30 @@ impl Chain {
             Chain::Monero => "Monero",
             Chain::Decred => "Decred",
             Chain::Arweave => "Arweave",
+            Chain::Dogecoin => "Dogecoin",
+            Chain::BitcoinCash => "Bitcoin Cash",
+            Chain::Zcash => "Zcash",
+            Chain::Dash => "Dash",
+        }
+    }
+
+    /// Decimal places between the base unit and one coin (8 for BTC sats).
+    pub fn decimals(&self) -> u8 {
+        native_decimals(self.dataset_name())
+    }
+
+    /// Name of the smallest on-chain unit.
+    pub fn base_unit(&self) -> &'static str {
+        match self {
+            Chain::Bitcoin | Chain::BitcoinCash => "sats",
+            Chain::Ethereum => "wei",
+            Chain::Litecoin => "litoshis",
+            Chain::Monero => "piconero",
+            Chain::Decred => "atoms",
+            Chain::Arweave => "winston",
+            Chain::Dogecoin => "koinu",
+            Chain::Zcash => "zatoshis",
+            Chain::Dash => "duffs",
         }
     }
 
@@ -61,6 +103,13 @@ impl Chain {
             Chain::Monero => format!("https://xmrchain.net/tx/{}", txid),
             Chain::Decred => format!("https://dcrdata.decred.org/tx/{}", txid),
             Chain::Arweave => format!("https://viewblock.io/arweave/tx/{}", txid),
+            Chain::Dogecoin | Chain::BitcoinCash | Chain::Zcash | Chain::Dash => {
+                if let Some(slug) = self.blockchair_slug() {
+                    format!("https://blockchair.com/{}/transaction/{}", slug, txid)
+                } else {
+                    format!("https://blockchair.com/search?q={}", txid)
+                }
+            }
         }
     }
 
@@ -72,6 +121,29 @@ impl Chain {
             Chain::Monero => format!("https://xmrchain.net/search?value={}", address),
             Chain::Decred => format!("https://dcrdata.decred.org/address/{}", address),
             Chain::Arweave => format!("https://viewblock.io/arweave/address/{}", address),
+            Chain::Dogecoin | Chain::BitcoinCash | Chain::Zcash | Chain::Dash => {
+                if let Some(slug) = self.blockchair_slug() {
+                    format!("https://blockchair.com/{}/address/{}", slug, address)
+                } else {
+                    format!("https://blockchair.com/search?q={}", address)
+                }
+            }
+        }
+    }
+
+    /// Path segment Blockchair uses for the chain, e.g. `bitcoin-cash`, or
+    /// `None` where Blockchair has no address dashboard (Monero, Decred,
+    /// Arweave).
+    pub fn blockchair_slug(&self) -> Option<&'static str> {
+        match self {
+            Chain::Bitcoin => Some("bitcoin"),
+            Chain::Ethereum => Some("ethereum"),
+            Chain::Litecoin => Some("litecoin"),
+            Chain::Dogecoin => Some("dogecoin"),
+            Chain::BitcoinCash => Some("bitcoin-cash"),
+            Chain::Zcash => Some("zcash"),
+            Chain::Dash => Some("dash"),
+            _ => None,
         }
     }
 
@@ -90,22 +162,42 @@ impl Chain {
         match self {
             Chain::Ethereum => txid.starts_with("0x") && txid.len() == 66 && is_hex64(&txid[2..]),
             // Current chains use hex-encoded 256-bit hashes.
-            Chain::Bitcoin | Chain::Litecoin | Chain::Monero | Chain::Decred => is_hex64(txid),
+            Chain::Bitcoin
+            | Chain::Litecoin
+            | Chain::Monero
+            | Chain::Decred
+            | Chain::Dogecoin
+            | Chain::BitcoinCash
+            | Chain::Zcash
+            | Chain::Dash => is_hex64(txid),
             Chain::Arweave => is_base64url_43(txid),
         }
     }
 }
 
-impl fmt::Display for Chain {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+include!("chain_decimals.rs");
+
+impl Chain {
+    /// The lowercase name used in the dataset and by `Display`.
+    const fn dataset_name(self) -> &'static str {
+        match self {
             Chain::Bitcoin => "bitcoin",
             Chain::Ethereum => "ethereum",
             Chain::Litecoin => "litecoin",
             Chain::Monero => "monero",
             Chain::Decred => "decred",
             Chain::Arweave => "arweave",
-        })
+            Chain::Dogecoin => "dogecoin",
+            Chain::BitcoinCash => "bitcoincash",
+            Chain::Zcash => "zcash",
+            Chain::Dash => "dash",
+        }
+    }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.dataset_name())
     }
 }
 
@@ -120,8 +212,12 @@ impl FromStr for Chain {
             "monero" | "xmr" => Ok(Chain::Monero),
             "decred" | "dcr" => Ok(Chain::Decred),
             "arweave" | "ar" => Ok(Chain::Arweave),
+            "dogecoin" | "doge" => Ok(Chain::Dogecoin),
+            "bitcoincash" | "bitcoin-cash" | "bch" => Ok(Chain::BitcoinCash),
+            "zcash" | "zec" => Ok(Chain::Zcash),
+            "dash" => Ok(Chain::Dash),
             _ => Err(format!(
-                "unknown chain: '{}'. expected: bitcoin, ethereum, litecoin, monero, decred, arweave (or symbol: btc, eth, ltc, xmr, dcr, ar)",
+                "unknown chain: '{}'. expected: bitcoin, ethereum, litecoin, monero, decred, arweave, dogecoin, bitcoincash, zcash, dash (or symbol: btc, eth, ltc, xmr, dcr, ar, doge, bch, zec)",
                 s
             )),
         }
@@ -154,7 +250,34 @@ pub struct Transaction {
     pub tx_type: TransactionType,
     pub txid: Option<&'static str>,
     pub date: Option<&'static str>,
+    /// In whole units of [`Puzzle::currency`]; may carry float noise, so
+    /// prefer [`native_amount`](Self::native_amount) for display and
+    /// comparisons.
     pub amount: Option<f64>,
+    native_amount: Option<Amount>,
+}
+
+impl Transaction {
+    pub const fn new(
+        tx_type: TransactionType,
+        txid: Option<&'static str>,
+        date: Option<&'static str>,
+        amount: Option<f64>,
+        native_amount: Option<Amount>,
+    ) -> Self {
+        Self {
+            tx_type,
+            txid,
+            date,
+            amount,
+            native_amount,
+        }
+    }
+
+    /// [`amount`](Self::amount), exact, in the currency's base units.
+    pub const fn native_amount(&self) -> Option<Amount> {
+        self.native_amount
+    }
 }
 
 impl Status {
@@ -290,6 +413,43 @@ pub struct Wif {
     pub passphrase: Option<&'static str>,
     /// KDF salt for brainwallet-style derivations (e.g. WarpWallet email salt)
     pub salt: Option<&'static str>,
+    /// Brainwallet scheme that turns `passphrase` (and `salt`) into the key
+    pub brainwallet: Option<BrainwalletScheme>,
+}
+
+/// How a brainwallet derives its private key from a passphrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BrainwalletScheme {
+    /// Classic brainwallet: `SHA256(passphrase)`
+    Sha256,
+    /// Keybase WarpWallet: scrypt XOR pbkdf2 over passphrase and salt
+    WarpWallet,
+}
+
+/// Where a private key comes from when it is not stored in the clear.
+///
+/// A view over [`Key`] fields, returned by [`Key::source`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KeySource {
+    /// BIP38-encrypted key (starts with 6P); the passphrase is known once solved
+    Bip38 {
+        encrypted: &'static str,
+        passphrase: Option<&'static str>,
+    },
+    /// Key derived from a human-chosen passphrase
+    Brainwallet {
+        scheme: BrainwalletScheme,
+        passphrase: Option<&'static str>,
+        salt: Option<&'static str>,
+    },
+    /// Key split with Shamir secret sharing; `threshold` shares reconstruct it
+    Shares {
+        threshold: u8,
+        total: u8,
+        shares: &'static [Share],
+    },
 }
 
 /// Private key in various representations.
@@ -340,17 +500,6 @@ pub struct Profile {
     pub url: &'static str,
 }
 
-/// Author/creator of a puzzle collection.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct Author {
-    /// Author's name or pseudonym (None for anonymous).
-    pub name: Option<&'static str>,
-    /// Addresses that initially funded the puzzle(s).
-    pub addresses: &'static [&'static str],
-    /// Profile links.
-    pub profiles: &'static [Profile],
-}
-
 /// Information about who solved a puzzle.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Solver {
@@ -360,6 +509,8 @@ pub struct Solver {
     pub addresses: &'static [&'static str],
     /// Profile links.
     pub profiles: &'static [Profile],
+    /// Where this puzzle's solve was announced (forum post, tweet, gist).
+    pub announcement_url: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -371,18 +522,33 @@ pub struct Pubkey {
 #[derive(Debug, Clone, Serialize)]
 pub struct Puzzle {
     pub id: &'static str,
+    /// Stable machine identifier: UUIDv5 of the collection and address the
+    /// puzzle was added under. Unlike `id` it is never renamed, so use it
+    /// for joins in downstream databases.
+    pub uuid: &'static str,
     pub chain: Chain,
     pub address: Address,
+    /// Further addresses that hold part of the bounty, e.g. a split prize or
+    /// funds the author moved without retiring the puzzle. Same chain as
+    /// `address`; usually empty.
+    pub extra_addresses: &'static [Address],
     pub status: Status,
     pub pubkey: Option<Pubkey>,
     pub key: Option<Key>,
     pub prize: Option<f64>,
     pub currency: Option<&'static str>,
-    pub start_date: Option<&'static str>,
-    pub solve_date: Option<&'static str>,
+    pub start_date: Option<DateTime>,
+    pub solve_date: Option<DateTime>,
     pub solve_time: Option<u64>,
     pub pre_genesis: bool,
+    /// Id of the record that replaces this one, e.g. after the author moved
+    /// the funds to a new address. Kept so exports stay historically complete.
+    pub superseded_by: Option<&'static str>,
+    /// Retired without a replacement (the author pulled the puzzle).
+    pub withdrawn: bool,
     pub source_url: Option<&'static str>,
+    /// Short provenance note (markdown), e.g. why the puzzle was created.
+    pub description: Option<&'static str>,
     pub transactions: &'static [Transaction],
     pub solver: Option<Solver>,
     pub assets: Option<Assets>,
@@ -430,6 +596,42 @@ fn format_duration_human_readable(seconds: u64) -> String {
     }
 }
 
+/// Expected brute-force time for a key range at a fixed search rate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Effort {
+    /// Keys in the range.
+    pub keys: BigUint,
+    pub keys_per_second: f64,
+    /// Seconds to try every key in the range.
+    pub exhaust_secs: f64,
+    /// Seconds until the key is found with 50% probability (half the range).
+    pub half_secs: f64,
+}
+
+impl Effort {
+    pub fn exhaust_formatted(&self) -> String {
+        format_estimate(self.exhaust_secs)
+    }
+
+    pub fn half_formatted(&self) -> String {
+        format_estimate(self.half_secs)
+    }
+}
+
+/// Like [`format_duration_human_readable`], but for estimates that can run
+/// far past `u64` seconds: a million years and up switch to scientific years.
+fn format_estimate(seconds: f64) -> String {
+    const YEAR: f64 = 365.0 * 86400.0;
+
+    if seconds < 1.0 {
+        "<1s".to_string()
+    } else if seconds < 1e6 * YEAR {
+        format_duration_human_readable(seconds as u64)
+    } else {
+        format!("{:.2e}y", seconds / YEAR)
+    }
+}
+
 impl Key {
     pub fn has_hex(&self) -> bool {
         self.hex.is_some()
@@ -443,6 +645,30 @@ impl Key {
         self.shares.is_some()
     }
 
+    /// How the key is protected or derived, if not only stored in the clear.
+    pub fn source(&self) -> Option<KeySource> {
+        if let Some(wif) = self.wif.as_ref() {
+            if let Some(encrypted) = wif.encrypted {
+                return Some(KeySource::Bip38 {
+                    encrypted,
+                    passphrase: wif.passphrase,
+                });
+            }
+            if let Some(scheme) = wif.brainwallet {
+                return Some(KeySource::Brainwallet {
+                    scheme,
+                    passphrase: wif.passphrase,
+                    salt: wif.salt,
+                });
+            }
+        }
+        self.shares.map(|s| KeySource::Shares {
+            threshold: s.threshold,
+            total: s.total,
+            shares: s.shares,
+        })
+    }
+
     pub fn is_known(&self) -> bool {
         self.hex.is_some() || self.wif.is_some() || self.seed.is_some() || self.mini.is_some()
     }
@@ -461,14 +687,8 @@ impl Key {
         Some(start..=end)
     }
 
-    pub fn range_big(&self) -> Option<(BigUint, BigUint)> {
-        let bits = self.bits?;
-        if !(1..=256).contains(&bits) {
-            return None;
-        }
-        let start = BigUint::one() << (bits - 1) as usize;
-        let end = (BigUint::one() << bits as usize) - 1u32;
-        Some((start, end))
+    pub fn range_big(&self) -> Option<KeyRange> {
+        KeyRange::from_bits(u32::from(self.bits?))
     }
 }
 
@@ -479,6 +699,11 @@ impl Puzzle {
         self.currency.unwrap_or_else(|| self.chain.symbol())
     }
 
+    /// The primary address followed by [`Puzzle::extra_addresses`].
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> {
+        std::iter::once(&self.address).chain(self.extra_addresses)
+    }
+
     pub fn has_pubkey(&self) -> bool {
         self.pubkey.is_some()
     }
@@ -491,6 +716,23 @@ impl Puzzle {
         self.key.is_some_and(|k| k.is_known())
     }
 
+    /// Superseded or withdrawn: kept for history, hidden from listings.
+    pub fn is_superseded(&self) -> bool {
+        self.superseded_by.is_some() || self.withdrawn
+    }
+
+    /// `start_date` as a chrono timestamp (UTC).
+    #[cfg(feature = "chrono")]
+    pub fn start_naive(&self) -> Option<chrono::NaiveDateTime> {
+        self.start_date.map(DateTime::naive_datetime)
+    }
+
+    /// `solve_date` as a chrono timestamp (UTC).
+    #[cfg(feature = "chrono")]
+    pub fn solve_naive(&self) -> Option<chrono::NaiveDateTime> {
+        self.solve_date.map(DateTime::naive_datetime)
+    }
+
     pub fn solve_time_formatted(&self) -> Option<String> {
         self.solve_time.map(format_duration_human_readable)
     }
@@ -503,6 +745,14 @@ impl Puzzle {
         self.id.split('/').nth(1).unwrap_or("")
     }
 
+    /// Author of the collection this puzzle belongs to, or `None` when the
+    /// id names no built-in or registered collection.
+    pub fn author(&self) -> Option<&'static Author> {
+        crate::Collection::parse(self.collection())
+            .ok()
+            .map(crate::Collection::author)
+    }
+
     pub fn funding_tx(&self) -> Option<&Transaction> {
         self.transactions
             .iter()
@@ -523,6 +773,12 @@ impl Puzzle {
         self.funding_tx().and_then(|tx| tx.txid)
     }
 
+    /// Funding, top-ups, key reveals, claims and sweeps with parsed dates,
+    /// oldest first.
+    pub fn timeline(&self) -> Vec<crate::Event> {
+        crate::timeline::events(self)
+    }
+
     pub fn has_transactions(&self) -> bool {
         !self.transactions.is_empty()
     }
@@ -551,13 +807,51 @@ impl Puzzle {
         self.address.chain.address_explorer_url(self.address.value)
     }
 
+    /// Output descriptor for watching this puzzle in Bitcoin Core, checksum
+    /// included. `None` off the Bitcoin chain.
+    ///
+    /// A known public key gives `pkh(...)`/`wpkh(...)`; anything else, P2SH
+    /// bounties included, is `addr(...)`, since Core refuses `raw()` inside
+    /// `sh()` and the address alone is enough to watch.
+    pub fn descriptor(&self) -> Option<String> {
+        if self.chain != Chain::Bitcoin {
+            return None;
+        }
+        let descriptor = match (self.address.kind, self.pubkey) {
+            ("p2pkh", Some(pubkey)) => format!("pkh({})", pubkey.value),
+            ("p2wpkh", Some(pubkey)) if pubkey.format == PubkeyFormat::Compressed => {
+                format!("wpkh({})", pubkey.value)
+            }
+            _ => format!("addr({})", self.address.value),
+        };
+        crate::descriptor::with_checksum(&descriptor)
+    }
+
     pub fn key_range(&self) -> Option<RangeInclusive<u128>> {
         self.key.and_then(|k| k.range())
     }
 
-    pub fn key_range_big(&self) -> Option<(BigUint, BigUint)> {
+    pub fn key_range_big(&self) -> Option<KeyRange> {
         self.key.and_then(|k| k.range_big())
     }
+
+    /// Time to search this puzzle's key range at `keys_per_second`.
+    ///
+    /// `None` without a known key range or for a rate that is not a positive
+    /// finite number.
+    pub fn estimated_effort(&self, keys_per_second: f64) -> Option<Effort> {
+        if !keys_per_second.is_finite() || keys_per_second <= 0.0 {
+            return None;
+        }
+        let keys = self.key_range_big()?.size();
+        let exhaust_secs = keys.to_f64().unwrap_or(f64::INFINITY) / keys_per_second;
+        Some(Effort {
+            keys,
+            keys_per_second,
+            exhaust_secs,
+            half_secs: exhaust_secs / 2.0,
+        })
+    }
 }
 
 pub trait IntoPuzzleNum {
@@ -602,6 +896,127 @@ impl IntoPuzzleNum for String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn key_source_bip38_from_encrypted_wif() {
+        let key = Key {
+            hex: None,
+            wif: Some(Wif {
+                encrypted: Some("6PRVWUbkzzsbcVac2qwfssoUJAN1Xhrg6bNk8J7Nzm5H7kxEbn2Nh2ZoGg"),
+                decrypted: None,
+                passphrase: None,
+                salt: None,
+                brainwallet: None,
+            }),
+            seed: None,
+            mini: None,
+            bits: None,
+            shares: None,
+        };
+        assert_eq!(
+            key.source(),
+            Some(KeySource::Bip38 {
+                encrypted: "6PRVWUbkzzsbcVac2qwfssoUJAN1Xhrg6bNk8J7Nzm5H7kxEbn2Nh2ZoGg",
+                passphrase: None,
+            })
+        );
+
+        let plain = Key { wif: None, ..key };
+        assert_eq!(plain.source(), None);
+    }
+
+    #[test]
+    fn superseded_and_withdrawn_are_hidden() {
+        let live = crate::get("b1000/66").unwrap();
+        assert!(!live.is_superseded());
+
+        let moved = Puzzle {
+            superseded_by: Some("b1000/67"),
+            ..live.clone()
+        };
+        let pulled = Puzzle {
+            withdrawn: true,
+            ..live.clone()
+        };
+        assert!(moved.is_superseded());
+        assert!(pulled.is_superseded());
+    }
+
+    #[test]
+    fn addresses_start_with_primary() {
+        static EXTRA: [Address; 1] = [Address {
+            value: "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            chain: Chain::Bitcoin,
+            kind: "p2pkh",
+            hash160: None,
+            witness_program: None,
+            redeem_script: None,
+        }];
+        let single = crate::get("b1000/66").unwrap();
+        let values = |p: &Puzzle| p.addresses().map(|a| a.value).collect::<Vec<_>>();
+        assert_eq!(values(single), [single.address.value]);
+
+        let split = Puzzle {
+            extra_addresses: &EXTRA,
+            ..single.clone()
+        };
+        assert_eq!(values(&split), [single.address.value, EXTRA[0].value]);
+    }
+
+    #[test]
+    fn key_source_brainwallet_from_scheme() {
+        let wif = Wif {
+            encrypted: None,
+            decrypted: None,
+            passphrase: Some("correct horse battery staple"),
+            salt: None,
+            brainwallet: Some(BrainwalletScheme::Sha256),
+        };
+        let key = Key {
+            hex: None,
+            wif: Some(wif),
+            seed: None,
+            mini: None,
+            bits: None,
+            shares: None,
+        };
+        assert_eq!(
+            key.source(),
+            Some(KeySource::Brainwallet {
+                scheme: BrainwalletScheme::Sha256,
+                passphrase: Some("correct horse battery staple"),
+                salt: None,
+            })
+        );
+    }
+
+    #[test]
+    fn key_source_shares_when_no_wif_source() {
+        const SHARES: &[Share] = &[Share {
+            index: 1,
+            data: "session cigar grape merry useful churn fatal thought very any arm unaware",
+        }];
+        let key = Key {
+            hex: None,
+            wif: None,
+            seed: None,
+            mini: None,
+            bits: None,
+            shares: Some(Shares {
+                threshold: 3,
+                total: 5,
+                shares: SHARES,
+            }),
+        };
+        assert_eq!(
+            key.source(),
+            Some(KeySource::Shares {
+                threshold: 3,
+                total: 5,
+                shares: SHARES,
+            })
+        );
+    }
+
     #[test]
     fn test_status_is_active() {
         assert!(Status::Unsolved.is_active());
@@ -610,6 +1025,17 @@ mod tests {
         assert!(!Status::Swept.is_active());
     }
 
+    #[test]
+    fn chain_decimals_and_units() {
+        assert_eq!(Chain::Bitcoin.decimals(), 8);
+        assert_eq!(Chain::Ethereum.decimals(), 18);
+        assert_eq!(Chain::Arweave.decimals(), 12);
+        assert_eq!(Chain::Decred.base_unit(), "atoms");
+        assert_eq!(Chain::Ethereum.base_unit(), "wei");
+        assert_eq!(Chain::Dogecoin.decimals(), 8);
+        assert_eq!(Chain::Dash.base_unit(), "duffs");
+    }
+
     #[test]
     fn chain_display_matches_serde() {
         assert_eq!(Chain::Bitcoin.to_string(), "bitcoin");
@@ -618,6 +1044,7 @@ mod tests {
         assert_eq!(Chain::Monero.to_string(), "monero");
         assert_eq!(Chain::Decred.to_string(), "decred");
         assert_eq!(Chain::Arweave.to_string(), "arweave");
+        assert_eq!(Chain::BitcoinCash.to_string(), "bitcoincash");
     }
 
     #[test]
@@ -635,11 +1062,15 @@ mod tests {
         assert_eq!("xmr".parse::<Chain>().unwrap(), Chain::Monero);
         assert_eq!("dcr".parse::<Chain>().unwrap(), Chain::Decred);
         assert_eq!("ar".parse::<Chain>().unwrap(), Chain::Arweave);
+        assert_eq!("doge".parse::<Chain>().unwrap(), Chain::Dogecoin);
+        assert_eq!("BCH".parse::<Chain>().unwrap(), Chain::BitcoinCash);
+        assert_eq!("zec".parse::<Chain>().unwrap(), Chain::Zcash);
+        assert_eq!("dash".parse::<Chain>().unwrap(), Chain::Dash);
     }
 
     #[test]
     fn chain_fromstr_invalid() {
-        assert!("dogecoin".parse::<Chain>().is_err());
+        assert!("solana".parse::<Chain>().is_err());
         assert!("".parse::<Chain>().is_err());
     }
 
@@ -808,6 +1239,26 @@ mod tests {
         assert_eq!(format_duration_human_readable(duration), "1y 1mo 1d 1h 1m");
     }
 
+    #[test]
+    fn estimated_effort_scales_with_rate() {
+        let puzzle = crate::b1000::get(20).unwrap();
+        let effort = puzzle.estimated_effort(1000.0).unwrap();
+        assert_eq!(effort.keys, BigUint::from(1u32 << 19));
+        assert!((effort.exhaust_secs - 524.288).abs() < 1e-9);
+        assert!((effort.half_secs - 262.144).abs() < 1e-9);
+        assert_eq!(effort.exhaust_formatted(), "8m");
+        assert!(puzzle.estimated_effort(0.0).is_none());
+        assert!(puzzle.estimated_effort(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn estimates_beyond_a_million_years_are_scientific() {
+        let puzzle = crate::b1000::get(160).unwrap();
+        let effort = puzzle.estimated_effort(1e9).unwrap();
+        assert_eq!(effort.half_formatted(), "1.16e31y");
+        assert_eq!(format_estimate(0.5), "<1s");
+    }
+
     #[test]
     fn tx_explorer_url_bitcoin() {
         let url = Chain::Bitcoin.tx_explorer_url("abc123");
@@ -844,6 +1295,18 @@ mod tests {
         assert_eq!(url, "https://viewblock.io/arweave/tx/abc");
     }
 
+    #[test]
+    fn tx_explorer_url_blockchair() {
+        assert_eq!(
+            Chain::BitcoinCash.tx_explorer_url("abc"),
+            "https://blockchair.com/bitcoin-cash/transaction/abc"
+        );
+        assert_eq!(
+            Chain::Dash.tx_explorer_url("abc"),
+            "https://blockchair.com/dash/transaction/abc"
+        );
+    }
+
     #[test]
     fn chain_symbol() {
         assert_eq!(Chain::Bitcoin.symbol(), "BTC");
@@ -852,6 +1315,10 @@ mod tests {
         assert_eq!(Chain::Monero.symbol(), "XMR");
         assert_eq!(Chain::Decred.symbol(), "DCR");
         assert_eq!(Chain::Arweave.symbol(), "AR");
+        assert_eq!(Chain::Dogecoin.symbol(), "DOGE");
+        assert_eq!(Chain::BitcoinCash.symbol(), "BCH");
+        assert_eq!(Chain::Zcash.symbol(), "ZEC");
+        assert_eq!(Chain::Dash.symbol(), "DASH");
     }
 
     #[test]
@@ -862,17 +1329,31 @@ mod tests {
         assert_eq!(Chain::Monero.name(), "Monero");
         assert_eq!(Chain::Decred.name(), "Decred");
         assert_eq!(Chain::Arweave.name(), "Arweave");
+        assert_eq!(Chain::BitcoinCash.name(), "Bitcoin Cash");
+    }
+
+    #[test]
+    fn blockchair_slug_only_for_supported_chains() {
+        assert_eq!(Chain::BitcoinCash.blockchair_slug(), Some("bitcoin-cash"));
+        assert_eq!(Chain::Dogecoin.blockchair_slug(), Some("dogecoin"));
+        assert_eq!(Chain::Monero.blockchair_slug(), None);
+        assert_eq!(Chain::Decred.blockchair_slug(), None);
+        assert_eq!(Chain::Arweave.blockchair_slug(), None);
     }
 
     #[test]
     fn chain_all_contains_every_variant() {
-        assert_eq!(Chain::ALL.len(), 6);
+        assert_eq!(Chain::ALL.len(), 10);
         assert!(Chain::ALL.contains(&Chain::Bitcoin));
         assert!(Chain::ALL.contains(&Chain::Ethereum));
         assert!(Chain::ALL.contains(&Chain::Litecoin));
         assert!(Chain::ALL.contains(&Chain::Monero));
         assert!(Chain::ALL.contains(&Chain::Decred));
         assert!(Chain::ALL.contains(&Chain::Arweave));
+        assert!(Chain::ALL.contains(&Chain::Dogecoin));
+        assert!(Chain::ALL.contains(&Chain::BitcoinCash));
+        assert!(Chain::ALL.contains(&Chain::Zcash));
+        assert!(Chain::ALL.contains(&Chain::Dash));
     }
 
     #[test]
@@ -884,6 +1365,8 @@ mod tests {
             (Chain::Monero, "44AFFq5kSiGBoZ4NMDwYtN18obc8AemS33DBLWs3H7otXft3XjrpDtQGv7SqSsaBYBb98uNbr2VBBEt7f2wfn3RVGQBEP3A", "https://xmrchain.net/search?value=44AFFq5kSiGBoZ4NMDwYtN18obc8AemS33DBLWs3H7otXft3XjrpDtQGv7SqSsaBYBb98uNbr2VBBEt7f2wfn3RVGQBEP3A"),
             (Chain::Decred, "DsUZxxoHJSty8DCfwfartwTYbuhmVct7tJu", "https://dcrdata.decred.org/address/DsUZxxoHJSty8DCfwfartwTYbuhmVct7tJu"),
             (Chain::Arweave, "vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI", "https://viewblock.io/arweave/address/vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI"),
+            (Chain::BitcoinCash, "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a", "https://blockchair.com/bitcoin-cash/address/qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"),
+            (Chain::Dogecoin, "DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE", "https://blockchair.com/dogecoin/address/DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE"),
         ];
         for (chain, addr, expected) in cases {
             assert_eq!(
@@ -910,6 +1393,10 @@ mod tests {
     }
 
     #[test]
+    #[allow(
+        clippy::unnecessary_lazy_evaluations,
+        clippy::unnecessary_literal_unwrap
+    )]
     fn currency_returns_explicit_value() {
         // All current puzzles use native tokens, so test the method directly
         let puzzle = crate::b1000::get(1).expect("puzzle b1000/1 should exist");
@@ -919,4 +1406,22 @@ mod tests {
         // Verify the method signature: Some("DAI") would return "DAI"
         assert_eq!(Some("DAI").unwrap_or_else(|| "BTC"), "DAI");
     }
+
+    #[test]
+    fn descriptor_prefers_the_known_pubkey() {
+        let b1 = crate::b1000::get(1).unwrap();
+        assert_eq!(
+            b1.descriptor().unwrap(),
+            "pkh(0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798)#e48zzw02"
+        );
+
+        let sha1 = crate::get("hash_collision/sha1").unwrap();
+        assert!(sha1
+            .descriptor()
+            .unwrap()
+            .starts_with("addr(37k7toV1Nv4DfmQbmZ8KuZDQCYK9x5KpzP)#"));
+
+        let arweave = crate::all().find(|p| p.chain == Chain::Arweave).unwrap();
+        assert_eq!(arweave.descriptor(), None);
+    }
 }
@@ -0,0 +1,244 @@
+//! Bitcoin script disassembly for stored redeem scripts.
+//!
+//! Output follows Bitcoin Core's `asm` style: opcodes by name, pushed data
+//! as hex. Only parsing is done here; nothing is executed.
+
+use std::fmt;
+
+use crate::{Error, Result};
+
+/// One parsed script element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction<'a> {
+    pub opcode: u8,
+    /// Bytes pushed by a data push (`0x01`–`0x4e`); `None` for other opcodes.
+    pub data: Option<&'a [u8]>,
+}
+
+impl fmt::Display for Instruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.data, opcode_name(self.opcode)) {
+            (Some(data), _) => f.write_str(&hex::encode(data)),
+            (None, Some(name)) => f.write_str(name),
+            (None, None) => write!(f, "OP_UNKNOWN_0x{:02x}", self.opcode),
+        }
+    }
+}
+
+/// Split `script` into instructions, or [`Error::InvalidScript`] when a push
+/// runs past the end.
+pub fn parse(script: &[u8]) -> Result<Vec<Instruction<'_>>> {
+    let mut instructions = Vec::new();
+    let mut rest = script;
+    while let Some((&opcode, tail)) = rest.split_first() {
+        rest = tail;
+        let len = match opcode {
+            0x01..=0x4b => Some(usize::from(opcode)),
+            0x4c..=0x4e => {
+                let width = 1 << (opcode - 0x4c);
+                if rest.len() < width {
+                    return Err(truncated(script));
+                }
+                let (len, tail) = rest.split_at(width);
+                rest = tail;
+                Some(
+                    len.iter()
+                        .rev()
+                        .fold(0usize, |acc, &b| (acc << 8) | usize::from(b)),
+                )
+            }
+            _ => None,
+        };
+        let data = match len {
+            Some(len) if len > rest.len() => return Err(truncated(script)),
+            Some(len) => {
+                let (data, tail) = rest.split_at(len);
+                rest = tail;
+                Some(data)
+            }
+            None => None,
+        };
+        instructions.push(Instruction { opcode, data });
+    }
+    Ok(instructions)
+}
+
+fn truncated(script: &[u8]) -> Error {
+    Error::InvalidScript(format!("{}: push past end of script", hex::encode(script)))
+}
+
+/// Space-separated disassembly of a hex-encoded script.
+pub fn to_asm(script_hex: &str) -> Result<String> {
+    let script = hex::decode(script_hex)
+        .map_err(|e| Error::InvalidScript(format!("{}: {}", script_hex, e)))?;
+    Ok(parse(&script)?
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" "))
+}
+
+/// Opcode byte for a name such as `OP_HASH160`; the `OP_` prefix and case
+/// are optional.
+pub fn opcode_by_name(name: &str) -> Option<u8> {
+    let upper = name.to_ascii_uppercase();
+    let name = upper.strip_prefix("OP_").unwrap_or(&upper);
+    (0..=u8::MAX).find(|&op| opcode_name(op).is_some_and(|n| &n[3..] == name))
+}
+
+/// Bitcoin Core's name for `opcode`, or `None` for data pushes and
+/// unassigned bytes.
+pub fn opcode_name(opcode: u8) -> Option<&'static str> {
+    const SMALL_INTS: [&str; 16] = [
+        "OP_1", "OP_2", "OP_3", "OP_4", "OP_5", "OP_6", "OP_7", "OP_8", "OP_9", "OP_10", "OP_11",
+        "OP_12", "OP_13", "OP_14", "OP_15", "OP_16",
+    ];
+    let name = match opcode {
+        0x00 => "OP_0",
+        0x4c => "OP_PUSHDATA1",
+        0x4d => "OP_PUSHDATA2",
+        0x4e => "OP_PUSHDATA4",
+        0x4f => "OP_1NEGATE",
+        0x50 => "OP_RESERVED",
+        0x51..=0x60 => SMALL_INTS[usize::from(opcode - 0x51)],
+        0x61 => "OP_NOP",
+        0x62 => "OP_VER",
+        0x63 => "OP_IF",
+        0x64 => "OP_NOTIF",
+        0x65 => "OP_VERIF",
+        0x66 => "OP_VERNOTIF",
+        0x67 => "OP_ELSE",
+        0x68 => "OP_ENDIF",
+        0x69 => "OP_VERIFY",
+        0x6a => "OP_RETURN",
+        0x6b => "OP_TOALTSTACK",
+        0x6c => "OP_FROMALTSTACK",
+        0x6d => "OP_2DROP",
+        0x6e => "OP_2DUP",
+        0x6f => "OP_3DUP",
+        0x70 => "OP_2OVER",
+        0x71 => "OP_2ROT",
+        0x72 => "OP_2SWAP",
+        0x73 => "OP_IFDUP",
+        0x74 => "OP_DEPTH",
+        0x75 => "OP_DROP",
+        0x76 => "OP_DUP",
+        0x77 => "OP_NIP",
+        0x78 => "OP_OVER",
+        0x79 => "OP_PICK",
+        0x7a => "OP_ROLL",
+        0x7b => "OP_ROT",
+        0x7c => "OP_SWAP",
+        0x7d => "OP_TUCK",
+        0x7e => "OP_CAT",
+        0x7f => "OP_SUBSTR",
+        0x80 => "OP_LEFT",
+        0x81 => "OP_RIGHT",
+        0x82 => "OP_SIZE",
+        0x83 => "OP_INVERT",
+        0x84 => "OP_AND",
+        0x85 => "OP_OR",
+        0x86 => "OP_XOR",
+        0x87 => "OP_EQUAL",
+        0x88 => "OP_EQUALVERIFY",
+        0x89 => "OP_RESERVED1",
+        0x8a => "OP_RESERVED2",
+        0x8b => "OP_1ADD",
+        0x8c => "OP_1SUB",
+        0x8d => "OP_2MUL",
+        0x8e => "OP_2DIV",
+        0x8f => "OP_NEGATE",
+        0x90 => "OP_ABS",
+        0x91 => "OP_NOT",
+        0x92 => "OP_0NOTEQUAL",
+        0x93 => "OP_ADD",
+        0x94 => "OP_SUB",
+        0x95 => "OP_MUL",
+        0x96 => "OP_DIV",
+        0x97 => "OP_MOD",
+        0x98 => "OP_LSHIFT",
+        0x99 => "OP_RSHIFT",
+        0x9a => "OP_BOOLAND",
+        0x9b => "OP_BOOLOR",
+        0x9c => "OP_NUMEQUAL",
+        0x9d => "OP_NUMEQUALVERIFY",
+        0x9e => "OP_NUMNOTEQUAL",
+        0x9f => "OP_LESSTHAN",
+        0xa0 => "OP_GREATERTHAN",
+        0xa1 => "OP_LESSTHANOREQUAL",
+        0xa2 => "OP_GREATERTHANOREQUAL",
+        0xa3 => "OP_MIN",
+        0xa4 => "OP_MAX",
+        0xa5 => "OP_WITHIN",
+        0xa6 => "OP_RIPEMD160",
+        0xa7 => "OP_SHA1",
+        0xa8 => "OP_SHA256",
+        0xa9 => "OP_HASH160",
+        0xaa => "OP_HASH256",
+        0xab => "OP_CODESEPARATOR",
+        0xac => "OP_CHECKSIG",
+        0xad => "OP_CHECKSIGVERIFY",
+        0xae => "OP_CHECKMULTISIG",
+        0xaf => "OP_CHECKMULTISIGVERIFY",
+        0xb0 => "OP_NOP1",
+        0xb1 => "OP_CHECKLOCKTIMEVERIFY",
+        0xb2 => "OP_CHECKSEQUENCEVERIFY",
+        0xb3 => "OP_NOP4",
+        0xb4 => "OP_NOP5",
+        0xb5 => "OP_NOP6",
+        0xb6 => "OP_NOP7",
+        0xb7 => "OP_NOP8",
+        0xb8 => "OP_NOP9",
+        0xb9 => "OP_NOP10",
+        0xba => "OP_CHECKSIGADD",
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha1_collision_script() {
+        assert_eq!(
+            to_asm("6e879169a77ca787").unwrap(),
+            "OP_2DUP OP_EQUAL OP_NOT OP_VERIFY OP_SHA1 OP_SWAP OP_SHA1 OP_EQUAL"
+        );
+    }
+
+    #[test]
+    fn pushes_show_as_hex() {
+        let p2pkh = "76a914751e76e8199196d454941c45d1b3a323f1433bd688ac";
+        assert_eq!(
+            to_asm(p2pkh).unwrap(),
+            "OP_DUP OP_HASH160 751e76e8199196d454941c45d1b3a323f1433bd6 OP_EQUALVERIFY OP_CHECKSIG"
+        );
+        assert_eq!(to_asm("4c02abcd00").unwrap(), "abcd OP_0");
+        assert_eq!(to_asm("4d0100ff").unwrap(), "ff");
+    }
+
+    #[test]
+    fn truncated_push_is_an_error() {
+        assert!(matches!(to_asm("14abcd"), Err(Error::InvalidScript(_))));
+        assert!(matches!(to_asm("4d01"), Err(Error::InvalidScript(_))));
+        assert!(to_asm("zz").is_err());
+    }
+
+    #[test]
+    fn names_round_trip() {
+        assert_eq!(opcode_by_name("OP_HASH160"), Some(0xa9));
+        assert_eq!(opcode_by_name("sha1"), Some(0xa7));
+        assert_eq!(opcode_by_name("OP_16"), Some(0x60));
+        assert_eq!(opcode_by_name("OP_FOO"), None);
+        assert_eq!(
+            Instruction {
+                opcode: 0xff,
+                data: None
+            }
+            .to_string(),
+            "OP_UNKNOWN_0xff"
+        );
+    }
+}
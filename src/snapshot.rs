@@ -0,0 +1,49 @@
+//! Last-known address balances, embedded at build time.
+//!
+//! The `snapshot-balances` script computes them from the transaction cache
+//! under `data/cache` into `data/balances.json`. A build without that file
+//! gets an empty snapshot, with [`taken_at`] returning `None`.
+
+include!(concat!(env!("OUT_DIR"), "/balance_snapshot.rs"));
+
+/// When the newest cached transaction data behind the snapshot was
+/// fetched (`YYYY-MM-DD HH:MM:SS`, UTC); `None` when the snapshot is empty.
+pub const fn taken_at() -> Option<&'static str> {
+    TAKEN_AT
+}
+
+/// Confirmed balance of `address` in its chain's base unit (sats, wei, ...)
+/// as of [`taken_at`].
+///
+/// ```
+/// assert_eq!(boha::snapshot::balance("not an address"), None);
+/// ```
+pub fn balance(address: &str) -> Option<u128> {
+    BALANCES
+        .binary_search_by_key(&address, |(address, _)| address)
+        .ok()
+        .map(|i| BALANCES[i].1)
+}
+
+/// Number of addresses in the snapshot.
+pub const fn len() -> usize {
+    BALANCES.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses_are_sorted_and_known() {
+        assert!(BALANCES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (address, _) in BALANCES {
+            assert!(
+                crate::all().any(|p| p.addresses().any(|a| a.value == *address)),
+                "{} is not a puzzle address",
+                address
+            );
+        }
+        assert!(len() == 0 || taken_at().is_some());
+    }
+}
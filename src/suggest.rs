@@ -0,0 +1,136 @@
+//! "Did you mean" candidates for ids that do not resolve.
+
+use crate::{collections, Collection, Puzzle};
+
+/// Most suggestions attached to one [`crate::Error::NotFound`].
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Existing ids close to `id`: the same name under the nearest collection
+/// spelling, the nearest numbers in numbered collections, or the nearest
+/// names by edit distance. A bare name (`66`) suggests the puzzles of any
+/// collection carrying it.
+pub fn suggest(id: &str) -> Vec<String> {
+    let Some((collection, name)) = id.split_once('/') else {
+        let named: Vec<String> = crate::all()
+            .filter(|p| p.name() == id)
+            .map(|p| p.id.to_string())
+            .take(MAX_SUGGESTIONS)
+            .collect();
+        if !named.is_empty() {
+            return named;
+        }
+        return nearest(id, collections().into_iter().map(Collection::name))
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+    };
+
+    let candidates: Vec<Collection> = Collection::parse(collection).map_or_else(
+        |_| {
+            nearest(collection, collections().into_iter().map(Collection::name))
+                .into_iter()
+                .filter_map(|n| Collection::parse(n).ok())
+                .collect()
+        },
+        |known| vec![known],
+    );
+
+    let mut suggestions = Vec::new();
+    for collection in candidates {
+        let puzzles: Vec<&'static Puzzle> = collection.all().collect();
+        if let Some(exact) = puzzles.iter().find(|p| p.name() == name) {
+            suggestions.push(exact.id.to_string());
+            continue;
+        }
+        suggestions.extend(
+            closest_names(name, &puzzles)
+                .into_iter()
+                .map(str::to_string),
+        );
+    }
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+/// Nearest numbers when both sides are numeric, nearest spellings otherwise.
+fn closest_names(name: &str, puzzles: &[&'static Puzzle]) -> Vec<&'static str> {
+    if let Ok(number) = name.parse::<u64>() {
+        let mut numbered: Vec<(u64, &'static str)> = puzzles
+            .iter()
+            .filter_map(|p| Some((p.name().parse::<u64>().ok()?.abs_diff(number), p.id)))
+            .collect();
+        if !numbered.is_empty() {
+            numbered.sort_unstable();
+            let best = numbered[0].0;
+            return numbered
+                .into_iter()
+                .take_while(|&(distance, _)| distance == best)
+                .map(|(_, id)| id)
+                .collect();
+        }
+    }
+    let names = nearest(name, puzzles.iter().map(|p| p.name()));
+    puzzles
+        .iter()
+        .filter(|p| names.contains(&p.name()))
+        .map(|p| p.id)
+        .collect()
+}
+
+/// The `candidates` at the smallest edit distance from `target`, if that
+/// distance is small enough to be a typo.
+fn nearest<'a>(target: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let limit = (target.chars().count() / 3).max(1);
+    let mut best = Vec::new();
+    let mut best_distance = limit + 1;
+    for candidate in candidates {
+        let distance = levenshtein(target, candidate);
+        if distance < best_distance {
+            best_distance = distance;
+            best.clear();
+        }
+        if distance == best_distance && distance <= limit {
+            best.push(candidate);
+        }
+    }
+    best
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (above + 1)
+                .min(row[j] + 1)
+                .min(diagonal + usize::from(ca != cb));
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance() {
+        assert_eq!(levenshtein("hash_colision", "hash_collision"), 1);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn misspelt_collection_and_numbers() {
+        assert_eq!(suggest("hash_colision/sha256"), ["hash_collision/sha256"]);
+        assert_eq!(suggest("b1000/300"), ["b1000/256"]);
+        assert_eq!(suggest("b100/66"), ["b1000/66"]);
+        assert_eq!(suggest("zden/level4"), ["zden/level_4"]);
+        assert_eq!(suggest("66"), ["b1000/66"]);
+        assert!(suggest("nothing/like/this").is_empty());
+    }
+}
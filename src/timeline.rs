@@ -0,0 +1,115 @@
+//! A puzzle's history as typed events, derived from its transactions.
+
+use core::fmt;
+
+use serde::Serialize;
+
+use crate::{Amount, DateTime, Puzzle, TransactionType};
+
+/// What happened to a puzzle's funds or key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    Funded,
+    Increased,
+    Decreased,
+    PubkeyRevealed,
+    Claimed,
+    Swept,
+}
+
+impl From<TransactionType> for EventKind {
+    fn from(tx_type: TransactionType) -> Self {
+        match tx_type {
+            TransactionType::Funding => Self::Funded,
+            TransactionType::Increase => Self::Increased,
+            TransactionType::Decrease => Self::Decreased,
+            TransactionType::PubkeyReveal => Self::PubkeyRevealed,
+            TransactionType::Claim => Self::Claimed,
+            TransactionType::Sweep => Self::Swept,
+        }
+    }
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Funded => "funded",
+            Self::Increased => "increased",
+            Self::Decreased => "decreased",
+            Self::PubkeyRevealed => "pubkey revealed",
+            Self::Claimed => "claimed",
+            Self::Swept => "swept",
+        })
+    }
+}
+
+/// One entry of [`Puzzle::timeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Event {
+    pub kind: EventKind,
+    /// `None` when the transaction has no date, or a malformed one.
+    pub date: Option<DateTime>,
+    /// In whole units of [`Puzzle::currency`].
+    pub amount: Option<f64>,
+    /// Exact counterpart of `amount`, see [`crate::Transaction::native_amount`].
+    pub native_amount: Option<Amount>,
+    pub txid: Option<&'static str>,
+}
+
+/// `puzzle`'s transactions as events, oldest first, undated ones last.
+pub fn events(puzzle: &Puzzle) -> Vec<Event> {
+    let mut events: Vec<Event> = puzzle
+        .transactions
+        .iter()
+        .map(|tx| Event {
+            kind: tx.tx_type.into(),
+            date: tx.date.and_then(|d| d.parse().ok()),
+            amount: tx.amount,
+            native_amount: tx.native_amount(),
+            txid: tx.txid,
+        })
+        .collect();
+    // Stable, so same-second events keep the dataset's order.
+    events.sort_by_key(|e| (e.date.is_none(), e.date));
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn b1000_66_runs_from_funding_to_claim() {
+        let puzzle = crate::get("b1000/66").unwrap();
+        let timeline = puzzle.timeline();
+        assert_eq!(timeline.len(), puzzle.transactions.len());
+        assert_eq!(timeline.first().unwrap().kind, EventKind::Funded);
+        let last = timeline.last().unwrap();
+        assert_eq!(last.kind, EventKind::Claimed);
+        assert_eq!(last.date, Some("2024-09-12 22:59:39".parse().unwrap()));
+        assert!(timeline.windows(2).all(|w| w[0].date <= w[1].date));
+    }
+
+    #[test]
+    fn every_dataset_date_parses() {
+        for puzzle in crate::all() {
+            let dated = puzzle.transactions.iter().filter(|tx| tx.date.is_some());
+            let parsed = puzzle
+                .timeline()
+                .iter()
+                .filter(|e| e.date.is_some())
+                .count();
+            assert_eq!(dated.count(), parsed, "{}", puzzle.id);
+        }
+    }
+
+    #[test]
+    fn kinds_follow_transaction_types() {
+        assert_eq!(
+            EventKind::from(TransactionType::PubkeyReveal),
+            EventKind::PubkeyRevealed
+        );
+        assert_eq!(EventKind::PubkeyRevealed.to_string(), "pubkey revealed");
+    }
+}
@@ -0,0 +1,90 @@
+//! Canonical transaction id spelling per chain.
+//!
+//! Explorers and APIs disagree on case and on the `0x` prefix, so ids copied
+//! from them need cleaning before they are stored or compared. Hex ids are
+//! lowercased; Ethereum keeps a `0x` prefix, other hex chains drop it;
+//! Arweave ids are base64url and case-sensitive, so only whitespace goes.
+
+use crate::{Chain, Error, Result};
+
+/// The canonical form of `txid` on `chain`, or [`Error::InvalidTxid`] when it
+/// is not a well-formed id for that chain even after cleaning.
+pub fn normalize(chain: Chain, txid: &str) -> Result<String> {
+    let trimmed = txid.trim();
+    let normalized = match chain {
+        Chain::Arweave => trimmed.to_string(),
+        Chain::Ethereum => format!("0x{}", strip_hex_prefix(trimmed).to_ascii_lowercase()),
+        Chain::Bitcoin
+        | Chain::Litecoin
+        | Chain::Monero
+        | Chain::Decred
+        | Chain::Dogecoin
+        | Chain::BitcoinCash
+        | Chain::Zcash
+        | Chain::Dash => strip_hex_prefix(trimmed).to_ascii_lowercase(),
+    };
+    if chain.is_valid_txid(&normalized) {
+        Ok(normalized)
+    } else {
+        Err(Error::InvalidTxid(txid.to_string()))
+    }
+}
+
+/// Whether `txid` is already in the form [`normalize`] returns.
+pub fn is_normalized(chain: Chain, txid: &str) -> bool {
+    normalize(chain, txid).is_ok_and(|n| n == txid)
+}
+
+fn strip_hex_prefix(s: &str) -> &str {
+    s.strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "a1075db55d416d3ca199f55b6084e2115b9345e16c5cf302fc80e9d5fbf5d48d";
+
+    #[test]
+    fn bitcoin_drops_prefix_and_case() {
+        let messy = format!(" 0X{} ", HEX.to_uppercase());
+        assert_eq!(normalize(Chain::Bitcoin, &messy).unwrap(), HEX);
+        assert!(is_normalized(Chain::Bitcoin, HEX));
+        assert!(!is_normalized(Chain::Bitcoin, &HEX.to_uppercase()));
+    }
+
+    #[test]
+    fn ethereum_keeps_lowercase_prefix() {
+        let expected = format!("0x{}", HEX);
+        assert_eq!(normalize(Chain::Ethereum, HEX).unwrap(), expected);
+        assert_eq!(
+            normalize(Chain::Ethereum, &format!("0x{}", HEX.to_uppercase())).unwrap(),
+            expected
+        );
+        assert!(is_normalized(Chain::Ethereum, &expected));
+    }
+
+    #[test]
+    fn arweave_is_case_sensitive() {
+        let txid = "hKMMPNh_emBf8v_at1tFzNYACisyMQNcKzeeE1QE9p8";
+        assert_eq!(
+            normalize(Chain::Arweave, &format!(" {}\n", txid)).unwrap(),
+            txid
+        );
+        let lower = txid.to_lowercase();
+        assert_eq!(normalize(Chain::Arweave, &lower).unwrap(), lower);
+    }
+
+    #[test]
+    fn malformed_ids_are_rejected() {
+        assert!(matches!(
+            normalize(Chain::Bitcoin, "abcd"),
+            Err(Error::InvalidTxid(id)) if id == "abcd"
+        ));
+        assert!(normalize(Chain::Decred, &HEX[1..]).is_err());
+        assert!(normalize(Chain::Ethereum, "0x").is_err());
+        assert!(normalize(Chain::Arweave, HEX).is_err());
+    }
+}
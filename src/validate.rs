@@ -0,0 +1,369 @@
+//! Field checks shared by the dataset tests, `boha validate` and
+//! `boha import --check`.
+//!
+//! Each check looks at one field (or a pair that must agree) and reports
+//! what is wrong with it. They take plain values so the same code can
+//! check raw data files as well as embedded puzzles; [`Rule`] applies them
+//! to [`Puzzle`]s.
+//!
+//! The date, pubkey and base58 checks need no cryptography; the hash160
+//! rule and full address decoding (bech32, EIP-55, Blake-256 checksums)
+//! come with the `verify` feature.
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+#[cfg(feature = "verify")]
+use crate::address::{AddressError, AddressKind};
+use crate::{Chain, DateTime, PubkeyFormat, Puzzle};
+
+/// Why a field failed a check.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    #[error("{format} pubkey must be {expected} hex characters, got {found}")]
+    PubkeyLength {
+        format: &'static str,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("{format} pubkey must start with {expected}")]
+    PubkeyPrefix {
+        format: &'static str,
+        expected: &'static str,
+    },
+
+    #[error("pubkey is not lowercase hex")]
+    PubkeyNotHex,
+
+    #[error("hash160 {0} is not 40 lowercase hex characters")]
+    Hash160Format(String),
+
+    #[error("hash160 {stored} does not match the address ({computed})")]
+    Hash160Mismatch { stored: String, computed: String },
+
+    #[error("{0} addresses do not encode a hash160")]
+    NoHash160(&'static str),
+
+    #[cfg(feature = "verify")]
+    #[error(transparent)]
+    Address(#[from] AddressError),
+
+    #[error("{0} is not base58check")]
+    Base58(String),
+
+    #[error("{field} {value} is not a UTC \"YYYY-MM-DD HH:MM:SS\" date")]
+    DateFormat { field: &'static str, value: String },
+
+    #[error("start_date {start} is after solve_date {solve}")]
+    DateOrder { start: String, solve: String },
+}
+
+/// Length, prefix and digits of a SEC1 public key in `format`.
+pub fn pubkey(value: &str, format: PubkeyFormat) -> Result<(), ValidationError> {
+    let (name, expected, prefixes): (_, _, &[&str]) = match format {
+        PubkeyFormat::Compressed => ("compressed", 66, &["02", "03"]),
+        PubkeyFormat::Uncompressed => ("uncompressed", 130, &["04"]),
+    };
+    if value.len() != expected {
+        return Err(ValidationError::PubkeyLength {
+            format: name,
+            expected,
+            found: value.len(),
+        });
+    }
+    if !is_lower_hex(value) {
+        return Err(ValidationError::PubkeyNotHex);
+    }
+    if !prefixes.iter().any(|p| value.starts_with(p)) {
+        return Err(ValidationError::PubkeyPrefix {
+            format: name,
+            expected: if prefixes.len() == 2 {
+                "02 or 03"
+            } else {
+                "04"
+            },
+        });
+    }
+    Ok(())
+}
+
+/// `address` is base58 with a valid checksum: double SHA-256, or for
+/// Decred (Blake-256) just the alphabet.
+pub fn base58(address: &str, chain: Chain) -> Result<(), ValidationError> {
+    let invalid = || ValidationError::Base58(address.to_string());
+    let bytes = bs58::decode(address).into_vec().map_err(|_| invalid())?;
+    if chain == Chain::Decred {
+        return Ok(());
+    }
+    let (payload, checksum) = bytes
+        .split_last_chunk::<4>()
+        .filter(|(payload, _)| !payload.is_empty())
+        .ok_or_else(invalid)?;
+    let hash = Sha256::digest(Sha256::digest(payload));
+    if hash[..4] != checksum[..] {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// The base58 addresses of `chain`: legacy Bitcoin and Litecoin
+/// addresses, and every address on the other Bitcoin-derived chains that
+/// do not use cashaddr.
+#[cfg(not(feature = "verify"))]
+fn is_base58(address: &str, chain: Chain) -> bool {
+    match chain {
+        Chain::Bitcoin => !address.starts_with("bc1"),
+        Chain::Litecoin => !address.starts_with("ltc1"),
+        Chain::Decred | Chain::Dogecoin | Chain::Zcash | Chain::Dash => true,
+        _ => false,
+    }
+}
+
+/// `stored` is the hash160 `address` encodes: the key hash of P2PKH and
+/// P2WPKH, the script hash of P2SH.
+///
+/// The address itself must decode on `chain`, checksum included.
+#[cfg(feature = "verify")]
+pub fn hash160(address: &str, chain: Chain, stored: &str) -> Result<(), ValidationError> {
+    if stored.len() != 40 || !is_lower_hex(stored) {
+        return Err(ValidationError::Hash160Format(stored.to_string()));
+    }
+    let kind = AddressKind::detect(address, chain)?;
+    let hash = match &kind {
+        AddressKind::P2pkh { hash, .. } | AddressKind::P2sh { hash, .. } => hash,
+        AddressKind::P2wpkh { program } => program,
+        other => return Err(ValidationError::NoHash160(other.name())),
+    };
+    let computed = hex::encode(hash);
+    if computed != stored {
+        return Err(ValidationError::Hash160Mismatch {
+            stored: stored.to_string(),
+            computed,
+        });
+    }
+    Ok(())
+}
+
+/// Both dates are well-formed and the puzzle was not solved before it
+/// was funded.
+pub fn dates(start: Option<&str>, solve: Option<&str>) -> Result<(), ValidationError> {
+    let start_at = start.map(|v| date("start_date", v)).transpose()?;
+    let solve_at = solve.map(|v| date("solve_date", v)).transpose()?;
+    date_order(start_at, solve_at)
+}
+
+/// The puzzle was not solved before it was funded.
+pub fn date_order(start: Option<DateTime>, solve: Option<DateTime>) -> Result<(), ValidationError> {
+    match (start, solve) {
+        (Some(start), Some(solve)) if start > solve => Err(ValidationError::DateOrder {
+            start: start.to_string(),
+            solve: solve.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// `value` is a real `YYYY-MM-DD HH:MM:SS` date, the only date form in
+/// the dataset.
+pub fn date(field: &'static str, value: &str) -> Result<DateTime, ValidationError> {
+    value.parse().map_err(|_| ValidationError::DateFormat {
+        field,
+        value: value.to_string(),
+    })
+}
+
+/// A dataset integrity rule, checked per puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Rule {
+    /// The address decodes on the puzzle's chain, checksum included;
+    /// without `verify` only base58 addresses are checked.
+    Address,
+    /// The stored hash160 is the one the address encodes.
+    #[cfg(feature = "verify")]
+    Hash160,
+    /// The public key has the length and prefix of its format.
+    Pubkey,
+    /// Dates are well-formed and the solve is not before the funding.
+    Dates,
+}
+
+impl Rule {
+    #[cfg(feature = "verify")]
+    pub const ALL: &'static [Rule] = &[Rule::Address, Rule::Hash160, Rule::Pubkey, Rule::Dates];
+    #[cfg(not(feature = "verify"))]
+    pub const ALL: &'static [Rule] = &[Rule::Address, Rule::Pubkey, Rule::Dates];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Rule::Address => "address",
+            #[cfg(feature = "verify")]
+            Rule::Hash160 => "hash160",
+            Rule::Pubkey => "pubkey",
+            Rule::Dates => "dates",
+        }
+    }
+
+    pub const fn description(self) -> &'static str {
+        match self {
+            Rule::Address => "address decodes on its chain (base58/bech32 checksum)",
+            #[cfg(feature = "verify")]
+            Rule::Hash160 => "hash160 matches the address",
+            Rule::Pubkey => "pubkey length and prefix match its format",
+            Rule::Dates => "dates are well-formed and start_date <= solve_date",
+        }
+    }
+
+    /// `None` when the rule does not apply: no such field, or (for
+    /// addresses) a chain without a decoder.
+    pub fn check(self, puzzle: &Puzzle) -> Option<Result<(), ValidationError>> {
+        match self {
+            #[cfg(feature = "verify")]
+            Rule::Address => match AddressKind::detect(puzzle.address.value, puzzle.chain) {
+                Err(AddressError::UnsupportedChain(_)) => None,
+                result => Some(result.map(|_| ()).map_err(ValidationError::from)),
+            },
+            #[cfg(not(feature = "verify"))]
+            Rule::Address => is_base58(puzzle.address.value, puzzle.chain)
+                .then(|| base58(puzzle.address.value, puzzle.chain)),
+            #[cfg(feature = "verify")]
+            Rule::Hash160 => puzzle
+                .address
+                .hash160
+                .map(|stored| hash160(puzzle.address.value, puzzle.chain, stored)),
+            Rule::Pubkey => puzzle
+                .pubkey
+                .as_ref()
+                .map(|pubkey| self::pubkey(pubkey.value, pubkey.format)),
+            Rule::Dates => (puzzle.start_date.is_some() || puzzle.solve_date.is_some())
+                .then(|| date_order(puzzle.start_date, puzzle.solve_date)),
+        }
+    }
+}
+
+/// Outcome of one rule over a set of puzzles.
+#[derive(Debug, Clone)]
+pub struct RuleReport {
+    pub rule: Rule,
+    /// Puzzles the rule applied to.
+    pub checked: usize,
+    /// Ids of the puzzles that failed, with why.
+    pub failures: Vec<(&'static str, ValidationError)>,
+}
+
+impl RuleReport {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Every [`Rule`] over `puzzles`, in [`Rule::ALL`] order.
+pub fn validate<'a>(puzzles: impl IntoIterator<Item = &'a Puzzle>) -> Vec<RuleReport> {
+    let mut reports: Vec<RuleReport> = Rule::ALL
+        .iter()
+        .map(|&rule| RuleReport {
+            rule,
+            checked: 0,
+            failures: Vec::new(),
+        })
+        .collect();
+    for puzzle in puzzles {
+        for report in &mut reports {
+            if let Some(result) = report.rule.check(puzzle) {
+                report.checked += 1;
+                if let Err(e) = result {
+                    report.failures.push((puzzle.id, e));
+                }
+            }
+        }
+    }
+    reports
+}
+
+fn is_lower_hex(value: &str) -> bool {
+    value
+        .bytes()
+        .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBKEY_1: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn pubkey_length_and_prefix() {
+        assert_eq!(pubkey(PUBKEY_1, PubkeyFormat::Compressed), Ok(()));
+        assert!(matches!(
+            pubkey(PUBKEY_1, PubkeyFormat::Uncompressed),
+            Err(ValidationError::PubkeyLength { expected: 130, .. })
+        ));
+        let wrong_prefix = format!("04{}", &PUBKEY_1[2..]);
+        assert!(matches!(
+            pubkey(&wrong_prefix, PubkeyFormat::Compressed),
+            Err(ValidationError::PubkeyPrefix { .. })
+        ));
+    }
+
+    #[test]
+    fn base58_checksum() {
+        let address = "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH";
+        assert_eq!(base58(address, Chain::Bitcoin), Ok(()));
+        assert!(matches!(
+            base58("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMh", Chain::Bitcoin),
+            Err(ValidationError::Base58(_))
+        ));
+        assert!(base58("0OIl", Chain::Bitcoin).is_err());
+    }
+
+    #[cfg(feature = "verify")]
+    #[test]
+    fn hash160_must_match_address() {
+        let address = "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH";
+        let hash = "751e76e8199196d454941c45d1b3a323f1433bd6";
+        assert_eq!(hash160(address, Chain::Bitcoin, hash), Ok(()));
+        assert!(matches!(
+            hash160(address, Chain::Bitcoin, &hash.replace('7', "8")),
+            Err(ValidationError::Hash160Mismatch { .. })
+        ));
+        assert!(matches!(
+            hash160("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMh", Chain::Bitcoin, hash),
+            Err(ValidationError::Address(_))
+        ));
+    }
+
+    #[test]
+    fn dates_are_ordered() {
+        let start = "2015-01-15 18:07:14";
+        let solve = "2019-05-31 12:00:00";
+        assert_eq!(dates(Some(start), Some(solve)), Ok(()));
+        assert!(matches!(
+            dates(Some(solve), Some(start)),
+            Err(ValidationError::DateOrder { .. })
+        ));
+        assert!(matches!(
+            dates(Some("2015-01-15"), None),
+            Err(ValidationError::DateFormat {
+                field: "start_date",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rules_skip_missing_fields() {
+        let puzzle = crate::gsmg::get();
+        assert!(Rule::Pubkey.check(puzzle).is_some());
+        let unsolved = crate::all()
+            .find(|p| p.pubkey.is_none() && p.solve_date.is_none())
+            .unwrap();
+        assert!(Rule::Pubkey.check(unsolved).is_none());
+        let reports = validate([puzzle, unsolved]);
+        assert_eq!(reports.len(), Rule::ALL.len());
+        let pubkeys = reports.iter().find(|r| r.rule == Rule::Pubkey).unwrap();
+        assert_eq!(pubkeys.checked, 1);
+    }
+}
@@ -3,12 +3,11 @@
 //! This module provides functions to verify that a puzzle's private key
 //! correctly derives its stored address across multiple blockchains.
 
-use crate::{Chain, Passphrase, PubkeyFormat, Puzzle};
-use k256::ecdsa::SigningKey;
-use k256::elliptic_curve::sec1::ToEncodedPoint;
+use crate::address::{AddressError, AddressKind, Network};
+use crate::keytools::{self, hash160, hash160_blake256, sec1_bytes, sha256};
+use crate::{BrainwalletScheme, Chain, KeySource, Passphrase, PubkeyFormat, Puzzle, Share};
 use k256::PublicKey;
-use ripemd::Ripemd160;
-use sha2::{Digest, Sha256};
+use rayon::prelude::*;
 use thiserror::Error;
 
 /// Result of a verification operation.
@@ -34,6 +33,9 @@ pub enum VerifyError {
     #[error("Puzzle has no private key")]
     NoPrivateKey,
 
+    #[error("Puzzle has no public key")]
+    NoPublicKey,
+
     #[error("Invalid private key format: {0}")]
     InvalidKey(String),
 
@@ -48,6 +50,41 @@ pub enum VerifyError {
 
     #[error("Unsupported chain: {0}")]
     UnsupportedChain(String),
+
+    #[error("{0}")]
+    InvalidAddress(String),
+
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+
+    #[error("Invalid collision: {0}")]
+    InvalidCollision(String),
+}
+
+impl From<AddressError> for VerifyError {
+    fn from(e: AddressError) -> Self {
+        match e {
+            AddressError::UnsupportedChain(_) | AddressError::Unrecognized { .. } => {
+                Self::UnsupportedChain(e.to_string())
+            }
+            AddressError::WrongChain { .. } | AddressError::Invalid { .. } => {
+                Self::InvalidAddress(e.to_string())
+            }
+        }
+    }
+}
+
+impl VerifyError {
+    /// Whether the puzzle lacks the data to verify, rather than failing verification.
+    pub fn is_skipped(&self) -> bool {
+        matches!(
+            self,
+            Self::NoPrivateKey
+                | Self::NoPublicKey
+                | Self::UnverifiableKey(_)
+                | Self::UnsupportedChain(_)
+        )
+    }
 }
 
 impl VerifyResult {
@@ -80,11 +117,28 @@ impl VerifyResult {
 /// Verify a puzzle's private key derives its address.
 ///
 /// Dispatches to the appropriate chain-specific verification based on the
-/// puzzle's key type (hex, WIF, or seed phrase) and blockchain.
+/// puzzle's key type (hex, WIF, or seed phrase) and blockchain. P2SH puzzles
+/// without a key (e.g. hash_collision) have their redeem script verified instead.
 pub fn verify_puzzle(puzzle: &Puzzle) -> Result<VerifyResult, VerifyError> {
-    let key = puzzle.key.as_ref().ok_or(VerifyError::NoPrivateKey)?;
     let expected_address = puzzle.address.value;
 
+    let Some(key) = puzzle.key.as_ref() else {
+        let script = puzzle
+            .address
+            .redeem_script
+            .as_ref()
+            .ok_or(VerifyError::NoPrivateKey)?;
+        let derived = verify_redeem_script(script.script, expected_address)?;
+        return Ok(VerifyResult {
+            id: puzzle.id.to_string(),
+            verified: true,
+            private_key: None,
+            expected_address: expected_address.to_string(),
+            derived_address: Some(derived),
+            error: None,
+        });
+    };
+
     let pubkey_format = puzzle
         .pubkey
         .as_ref()
@@ -94,14 +148,45 @@ pub fn verify_puzzle(puzzle: &Puzzle) -> Result<VerifyResult, VerifyError> {
         let addr = verify_hex_by_chain(hex, expected_address, puzzle.chain, pubkey_format)?;
         (addr, hex.to_string())
     } else if let Some(ref wif_data) = key.wif {
-        let wif = wif_data
-            .decrypted
-            .ok_or_else(|| VerifyError::UnverifiableKey("WIF is encrypted".to_string()))?;
-        verify_wif(wif, expected_address)?
+        if let Some(wif) = wif_data.decrypted {
+            verify_wif(wif, expected_address)?
+        } else if let Some(KeySource::Bip38 {
+            encrypted,
+            passphrase,
+        }) = key.source()
+        {
+            let passphrase = passphrase.ok_or_else(|| {
+                VerifyError::UnverifiableKey("BIP38 passphrase unknown".to_string())
+            })?;
+            verify_bip38(encrypted, passphrase, expected_address)?
+        } else if let Some(KeySource::Brainwallet {
+            scheme: BrainwalletScheme::Sha256,
+            passphrase,
+            ..
+        }) = key.source()
+        {
+            let passphrase = passphrase.ok_or_else(|| {
+                VerifyError::UnverifiableKey("Brainwallet passphrase unknown".to_string())
+            })?;
+            verify_brainwallet(passphrase, expected_address)?
+        } else {
+            return Err(VerifyError::UnverifiableKey("WIF is encrypted".to_string()));
+        }
     } else if let Some(ref seed) = key.seed {
-        let phrase = seed.phrase.ok_or_else(|| {
-            VerifyError::UnverifiableKey("Seed has no mnemonic phrase".to_string())
-        })?;
+        let phrase = match (seed.phrase, key.source()) {
+            (Some(phrase), _) => phrase.to_string(),
+            (
+                None,
+                Some(KeySource::Shares {
+                    threshold, shares, ..
+                }),
+            ) => combine_shares(shares, threshold)?,
+            (None, _) => {
+                return Err(VerifyError::UnverifiableKey(
+                    "Seed has no mnemonic phrase".to_string(),
+                ))
+            }
+        };
         let path = seed.path.ok_or_else(|| {
             VerifyError::UnverifiableKey("Seed has no derivation path".to_string())
         })?;
@@ -114,7 +199,7 @@ pub fn verify_puzzle(puzzle: &Puzzle) -> Result<VerifyResult, VerifyError> {
             Some(Passphrase::Known(p)) => p,
             None => "",
         };
-        verify_seed(phrase, path, expected_address, pubkey_format, passphrase)?
+        verify_seed(&phrase, path, expected_address, pubkey_format, passphrase)?
     } else {
         return Err(VerifyError::NoPrivateKey);
     };
@@ -129,6 +214,50 @@ pub fn verify_puzzle(puzzle: &Puzzle) -> Result<VerifyResult, VerifyError> {
     })
 }
 
+/// Run `verifier` over `puzzles` in parallel, returning outcomes in input order.
+pub fn verify_puzzles<F>(puzzles: &[&Puzzle], verifier: F) -> Vec<Result<VerifyResult, VerifyError>>
+where
+    F: Fn(&Puzzle) -> Result<VerifyResult, VerifyError> + Sync,
+{
+    puzzles.par_iter().map(|p| verifier(p)).collect()
+}
+
+/// Verify every puzzle in the database that carries a key or redeem script.
+///
+/// Runs in parallel. Puzzles without anything verifiable are left out;
+/// failures are returned with `verified: false` and the error message.
+pub fn verify_all() -> Vec<VerifyResult> {
+    let puzzles: Vec<&Puzzle> = crate::all().collect();
+    puzzles
+        .iter()
+        .zip(verify_puzzles(&puzzles, verify_puzzle))
+        .filter_map(|(puzzle, outcome)| match outcome {
+            Ok(result) => Some(result),
+            Err(e) if e.is_skipped() => None,
+            Err(e) => Some(VerifyResult::failure(
+                puzzle.id.to_string(),
+                puzzle.address.value.to_string(),
+                e,
+            )),
+        })
+        .collect()
+}
+
+/// Re-derive every embedded key and redeem script and compare the result
+/// with the stored address, guarding against a corrupted or tampered build.
+///
+/// Returns how many puzzles were checked, or the ones that failed.
+pub fn verify_embedded() -> Result<usize, Vec<VerifyResult>> {
+    let results = verify_all();
+    let checked = results.len();
+    let failures: Vec<VerifyResult> = results.into_iter().filter(|r| !r.verified).collect();
+    if failures.is_empty() {
+        Ok(checked)
+    } else {
+        Err(failures)
+    }
+}
+
 /// Dispatch hex key verification to the appropriate chain.
 fn verify_hex_by_chain(
     hex_key: &str,
@@ -141,6 +270,20 @@ fn verify_hex_by_chain(
         Chain::Ethereum => verify_ethereum_address(hex_key, expected_address),
         Chain::Litecoin => verify_litecoin_address(hex_key, expected_address, pubkey_format),
         Chain::Decred => verify_decred_address(hex_key, expected_address, pubkey_format),
+        Chain::Dogecoin | Chain::BitcoinCash | Chain::Zcash | Chain::Dash => {
+            let public_key = PublicKey::from(keytools::key_from_hex(hex_key)?.verifying_key());
+            verify_fork_hash160(
+                chain,
+                &hash160(&sec1_bytes(&public_key, pubkey_format)),
+                expected_address,
+            )
+        }
+        #[cfg(feature = "monero")]
+        Chain::Monero => verify_monero_address(
+            hex_key,
+            &monero_view_key_from_spend(hex_key)?,
+            expected_address,
+        ),
         chain => Err(VerifyError::UnsupportedChain(format!(
             "{} verification not supported",
             chain.name()
@@ -148,71 +291,179 @@ fn verify_hex_by_chain(
     }
 }
 
-fn sha256(data: &[u8]) -> [u8; 32] {
-    Sha256::digest(data).into()
-}
+/// Verify a redeem (or witness) script hashes to the expected address.
+///
+/// For P2SH, HASH160 of the script is encoded as a version `0x05` address;
+/// for P2WSH (62-char `bc1q`), SHA256 of the script is the v0 witness program.
+pub fn verify_redeem_script(
+    script_hex: &str,
+    expected_address: &str,
+) -> Result<String, VerifyError> {
+    let script = hex::decode(script_hex)
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid redeem script hex: {}", e)))?;
+
+    if script.is_empty() {
+        return Err(VerifyError::InvalidKey(
+            "Redeem script is empty".to_string(),
+        ));
+    }
 
-fn hash160(data: &[u8]) -> [u8; 20] {
-    let sha = sha256(data);
-    Ripemd160::digest(sha).into()
+    match AddressKind::detect(expected_address, Chain::Bitcoin)? {
+        AddressKind::P2wsh { .. } => verify_p2wsh(&sha256(&script), expected_address),
+        AddressKind::P2sh { .. } => verify_p2sh(&hash160(&script), expected_address),
+        kind => Err(VerifyError::UnverifiableKey(format!(
+            "{} address commits to a key, not a script",
+            kind.name()
+        ))),
+    }
 }
 
-/// HASH160 using BLAKE-256 instead of SHA-256 (for Decred).
-/// Formula: RIPEMD160(BLAKE256(data))
-#[cfg(feature = "cli")]
-fn hash160_blake256(data: &[u8]) -> [u8; 20] {
-    use blake_hash::{Blake256, Digest};
-    let blake = Blake256::digest(data);
-    Ripemd160::digest(blake).into()
+/// Verify a puzzle's stored public key hashes to its address.
+///
+/// Needs no private key, so it also covers unsolved puzzles with a revealed
+/// pubkey. When the puzzle stores a HASH160, that is checked as well.
+pub fn verify_puzzle_pubkey(puzzle: &Puzzle) -> Result<VerifyResult, VerifyError> {
+    let pubkey = puzzle.pubkey.as_ref().ok_or(VerifyError::NoPublicKey)?;
+    let expected_address = puzzle.address.value;
+
+    let derived = verify_pubkey(pubkey.value, pubkey.format, expected_address, puzzle.chain)?;
+
+    if let (
+        Some(expected_hash),
+        Chain::Bitcoin
+        | Chain::Litecoin
+        | Chain::Dogecoin
+        | Chain::BitcoinCash
+        | Chain::Zcash
+        | Chain::Dash,
+    ) = (puzzle.address.hash160, puzzle.chain)
+    {
+        let bytes = decode_pubkey(pubkey.value, pubkey.format)?;
+        let derived_hash = hex::encode(hash160(&bytes));
+        if derived_hash != expected_hash {
+            return Err(VerifyError::Mismatch {
+                expected: expected_hash.to_string(),
+                derived: derived_hash,
+            });
+        }
+    }
+
+    Ok(VerifyResult {
+        id: puzzle.id.to_string(),
+        verified: true,
+        private_key: None,
+        expected_address: expected_address.to_string(),
+        derived_address: Some(derived),
+        error: None,
+    })
 }
 
-pub fn verify_bitcoin_address(
-    hex_key: &str,
+/// Verify a public key derives the expected address on the given chain.
+///
+/// The key must be SEC1-encoded hex whose length matches `format`
+/// (33 bytes compressed, 65 bytes uncompressed) and lie on secp256k1.
+pub fn verify_pubkey(
+    pubkey_hex: &str,
+    format: PubkeyFormat,
     expected_address: &str,
-    pubkey_format: PubkeyFormat,
+    chain: Chain,
 ) -> Result<String, VerifyError> {
-    let key_bytes =
-        hex::decode(hex_key).map_err(|e| VerifyError::InvalidKey(format!("Invalid hex: {}", e)))?;
+    let bytes = decode_pubkey(pubkey_hex, format)?;
+
+    match chain {
+        Chain::Bitcoin => {
+            let public_key = PublicKey::from_sec1_bytes(&bytes)
+                .map_err(|e| VerifyError::InvalidKey(format!("Invalid public key: {}", e)))?;
+            verify_bitcoin_key(&public_key, format, expected_address)
+        }
+        Chain::Litecoin => verify_litecoin_hash160(&hash160(&bytes), expected_address),
+        Chain::Decred => verify_decred_hash160(&hash160_blake256(&bytes), expected_address),
+        Chain::Dogecoin | Chain::BitcoinCash | Chain::Zcash | Chain::Dash => {
+            verify_fork_hash160(chain, &hash160(&bytes), expected_address)
+        }
+        Chain::Ethereum => {
+            let public_key = PublicKey::from_sec1_bytes(&bytes)
+                .map_err(|e| VerifyError::InvalidKey(format!("Invalid public key: {}", e)))?;
+            verify_ethereum_pubkey(&public_key, expected_address)
+        }
+        chain => Err(VerifyError::UnsupportedChain(format!(
+            "{} verification not supported",
+            chain.name()
+        ))),
+    }
+}
 
-    if key_bytes.len() != 32 {
+fn decode_pubkey(pubkey_hex: &str, format: PubkeyFormat) -> Result<Vec<u8>, VerifyError> {
+    let bytes = hex::decode(pubkey_hex)
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+    let expected_len = match format {
+        PubkeyFormat::Compressed => 33,
+        PubkeyFormat::Uncompressed => 65,
+    };
+    if bytes.len() != expected_len {
         return Err(VerifyError::InvalidKey(format!(
-            "Key must be 32 bytes, got {}",
-            key_bytes.len()
+            "Public key must be {} bytes for {:?} format, got {}",
+            expected_len,
+            format,
+            bytes.len()
         )));
     }
 
-    let signing_key = SigningKey::from_bytes((&key_bytes[..]).into())
-        .map_err(|e| VerifyError::InvalidKey(format!("Invalid secp256k1 key: {}", e)))?;
+    PublicKey::from_sec1_bytes(&bytes)
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid public key: {}", e)))?;
 
-    let public_key = PublicKey::from(signing_key.verifying_key());
-
-    let pubkey_bytes = match pubkey_format {
-        PubkeyFormat::Compressed => public_key.to_sec1_bytes().to_vec(),
-        PubkeyFormat::Uncompressed => public_key.to_encoded_point(false).as_bytes().to_vec(),
-    };
+    Ok(bytes)
+}
 
-    let hash = hash160(&pubkey_bytes);
+pub fn verify_bitcoin_address(
+    hex_key: &str,
+    expected_address: &str,
+    pubkey_format: PubkeyFormat,
+) -> Result<String, VerifyError> {
+    let public_key = PublicKey::from(keytools::key_from_hex(hex_key)?.verifying_key());
+    verify_bitcoin_key(&public_key, pubkey_format, expected_address)
+}
 
-    if expected_address.starts_with("bc1q") {
-        verify_p2wpkh(&hash, expected_address)
-    } else if expected_address.starts_with('1') || expected_address.starts_with('3') {
-        verify_p2pkh(&hash, expected_address)
-    } else {
-        Err(VerifyError::UnsupportedChain(format!(
-            "Unsupported address format: {}",
-            expected_address
-        )))
+fn verify_bitcoin_key(
+    public_key: &PublicKey,
+    pubkey_format: PubkeyFormat,
+    expected_address: &str,
+) -> Result<String, VerifyError> {
+    let kind = AddressKind::detect(expected_address, Chain::Bitcoin)?;
+    let hash = || hash160(&sec1_bytes(public_key, pubkey_format));
+    match kind {
+        AddressKind::P2pkh {
+            network: Network::Mainnet,
+            ..
+        } => verify_p2pkh(&hash(), expected_address),
+        AddressKind::P2pkh {
+            network: Network::Testnet,
+            ..
+        } => verify_testnet_p2pkh(&hash(), expected_address),
+        AddressKind::P2wpkh { .. } => verify_p2wpkh(&hash(), expected_address),
+        AddressKind::P2tr { .. } => verify_p2tr(public_key, expected_address),
+        kind => Err(commits_to_script(kind)),
     }
 }
 
-fn verify_p2pkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
-    let mut data = vec![0x00];
-    data.extend_from_slice(hash160);
-    let checksum = &sha256(&sha256(&data))[..4];
-    data.extend_from_slice(checksum);
-
-    let derived = bs58::encode(data).into_string();
+/// Script-hash addresses pay whoever reveals a script, so a key alone
+/// cannot be checked against them.
+fn commits_to_script(kind: AddressKind) -> VerifyError {
+    let script = if matches!(kind, AddressKind::P2wsh { .. }) {
+        "witness script"
+    } else {
+        "redeem script"
+    };
+    VerifyError::UnverifiableKey(format!(
+        "{} address commits to a {}, not a key",
+        kind.name().to_uppercase(),
+        script
+    ))
+}
 
+/// `Ok(derived)` when it is the expected address, a mismatch otherwise.
+fn compare(derived: String, expected_address: &str) -> Result<String, VerifyError> {
     if derived == expected_address {
         Ok(derived)
     } else {
@@ -223,25 +474,34 @@ fn verify_p2pkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, Ve
     }
 }
 
-fn verify_p2wpkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
-    use bech32::{segwit, Hrp};
+fn verify_p2pkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
+    compare(keytools::base58check(0x00, hash160), expected_address)
+}
 
-    let hrp = Hrp::parse("bc")
-        .map_err(|e| VerifyError::DerivationFailed(format!("Invalid HRP: {}", e)))?;
+fn verify_testnet_p2pkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
+    compare(keytools::base58check(0x6f, hash160), expected_address)
+}
 
-    let witness_version = bech32::Fe32::Q;
+fn verify_p2sh(script_hash: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
+    compare(keytools::base58check(0x05, script_hash), expected_address)
+}
 
-    let derived = segwit::encode(hrp, witness_version, hash160)
-        .map_err(|e| VerifyError::DerivationFailed(format!("Bech32 encoding failed: {}", e)))?;
+fn verify_p2wsh(script_hash: &[u8; 32], expected_address: &str) -> Result<String, VerifyError> {
+    compare(
+        keytools::segwit("bc", bech32::Fe32::Q, script_hash)?,
+        expected_address,
+    )
+}
 
-    if derived == expected_address {
-        Ok(derived)
-    } else {
-        Err(VerifyError::Mismatch {
-            expected: expected_address.to_string(),
-            derived,
-        })
-    }
+fn verify_p2tr(public_key: &PublicKey, expected_address: &str) -> Result<String, VerifyError> {
+    compare(keytools::taproot(public_key, "bc")?, expected_address)
+}
+
+fn verify_p2wpkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
+    compare(
+        keytools::segwit("bc", bech32::Fe32::Q, hash160)?,
+        expected_address,
+    )
 }
 
 /// Verify Ethereum address derivation from private key.
@@ -255,41 +515,18 @@ pub fn verify_ethereum_address(
     hex_key: &str,
     expected_address: &str,
 ) -> Result<String, VerifyError> {
-    use tiny_keccak::{Hasher, Keccak};
-
-    let key_bytes =
-        hex::decode(hex_key).map_err(|e| VerifyError::InvalidKey(format!("Invalid hex: {}", e)))?;
-
-    if key_bytes.len() != 32 {
-        return Err(VerifyError::InvalidKey(format!(
-            "Key must be 32 bytes, got {}",
-            key_bytes.len()
-        )));
-    }
-
-    let signing_key = SigningKey::from_bytes((&key_bytes[..]).into())
-        .map_err(|e| VerifyError::InvalidKey(format!("Invalid secp256k1 key: {}", e)))?;
-
-    let public_key = PublicKey::from(signing_key.verifying_key());
-    let pubkey_bytes = public_key.to_encoded_point(false);
-    let pubkey_slice = pubkey_bytes.as_bytes();
-
-    if pubkey_slice.len() != 65 || pubkey_slice[0] != 0x04 {
-        return Err(VerifyError::DerivationFailed(
-            "Invalid uncompressed public key".to_string(),
-        ));
-    }
-
-    let mut keccak = Keccak::v256();
-    let mut hash = [0u8; 32];
-    keccak.update(&pubkey_slice[1..]);
-    keccak.finalize(&mut hash);
+    let public_key = PublicKey::from(keytools::key_from_hex(hex_key)?.verifying_key());
+    verify_ethereum_pubkey(&public_key, expected_address)
+}
 
-    let address_bytes = &hash[12..];
-    let derived = format!("0x{}", hex::encode(address_bytes));
+fn verify_ethereum_pubkey(
+    public_key: &PublicKey,
+    expected_address: &str,
+) -> Result<String, VerifyError> {
+    AddressKind::detect(expected_address, Chain::Ethereum)?;
 
-    let expected_lower = expected_address.to_lowercase();
-    if derived == expected_lower {
+    let derived = keytools::ethereum_address(public_key);
+    if derived == expected_address.to_lowercase() {
         Ok(derived)
     } else {
         Err(VerifyError::Mismatch {
@@ -309,77 +546,30 @@ pub fn verify_litecoin_address(
     expected_address: &str,
     pubkey_format: PubkeyFormat,
 ) -> Result<String, VerifyError> {
-    let key_bytes =
-        hex::decode(hex_key).map_err(|e| VerifyError::InvalidKey(format!("Invalid hex: {}", e)))?;
-
-    if key_bytes.len() != 32 {
-        return Err(VerifyError::InvalidKey(format!(
-            "Key must be 32 bytes, got {}",
-            key_bytes.len()
-        )));
-    }
-
-    let signing_key = SigningKey::from_bytes((&key_bytes[..]).into())
-        .map_err(|e| VerifyError::InvalidKey(format!("Invalid secp256k1 key: {}", e)))?;
-
-    let public_key = PublicKey::from(signing_key.verifying_key());
-
-    let pubkey_bytes = match pubkey_format {
-        PubkeyFormat::Compressed => public_key.to_sec1_bytes().to_vec(),
-        PubkeyFormat::Uncompressed => public_key.to_encoded_point(false).as_bytes().to_vec(),
-    };
-
-    let hash = hash160(&pubkey_bytes);
+    let public_key = PublicKey::from(keytools::key_from_hex(hex_key)?.verifying_key());
+    verify_litecoin_hash160(
+        &hash160(&sec1_bytes(&public_key, pubkey_format)),
+        expected_address,
+    )
+}
 
-    if expected_address.starts_with("ltc1") {
-        verify_ltc_p2wpkh(&hash, expected_address)
-    } else if expected_address.starts_with('L') || expected_address.starts_with('M') {
-        verify_ltc_p2pkh(&hash, expected_address)
-    } else {
-        Err(VerifyError::UnsupportedChain(format!(
-            "Unsupported Litecoin address format: {}",
-            expected_address
-        )))
+fn verify_litecoin_hash160(hash: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
+    match AddressKind::detect(expected_address, Chain::Litecoin)? {
+        AddressKind::P2pkh { .. } => verify_ltc_p2pkh(hash, expected_address),
+        AddressKind::P2wpkh { .. } => verify_ltc_p2wpkh(hash, expected_address),
+        kind => Err(commits_to_script(kind)),
     }
 }
 
 fn verify_ltc_p2pkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
-    let mut data = vec![0x30];
-    data.extend_from_slice(hash160);
-    let checksum = &sha256(&sha256(&data))[..4];
-    data.extend_from_slice(checksum);
-
-    let derived = bs58::encode(data).into_string();
-
-    if derived == expected_address {
-        Ok(derived)
-    } else {
-        Err(VerifyError::Mismatch {
-            expected: expected_address.to_string(),
-            derived,
-        })
-    }
+    compare(keytools::base58check(0x30, hash160), expected_address)
 }
 
 fn verify_ltc_p2wpkh(hash160: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
-    use bech32::{segwit, Hrp};
-
-    let hrp = Hrp::parse("ltc")
-        .map_err(|e| VerifyError::DerivationFailed(format!("Invalid HRP: {}", e)))?;
-
-    let witness_version = bech32::Fe32::Q;
-
-    let derived = segwit::encode(hrp, witness_version, hash160)
-        .map_err(|e| VerifyError::DerivationFailed(format!("Bech32 encoding failed: {}", e)))?;
-
-    if derived == expected_address {
-        Ok(derived)
-    } else {
-        Err(VerifyError::Mismatch {
-            expected: expected_address.to_string(),
-            derived,
-        })
-    }
+    compare(
+        keytools::segwit("ltc", bech32::Fe32::Q, hash160)?,
+        expected_address,
+    )
 }
 
 /// Verify Decred address derivation from private key.
@@ -393,39 +583,92 @@ pub fn verify_decred_address(
     expected_address: &str,
     pubkey_format: PubkeyFormat,
 ) -> Result<String, VerifyError> {
-    use blake_hash::{Blake256, Digest};
-
-    let key_bytes =
-        hex::decode(hex_key).map_err(|e| VerifyError::InvalidKey(format!("Invalid hex: {}", e)))?;
+    let public_key = PublicKey::from(keytools::key_from_hex(hex_key)?.verifying_key());
+    verify_decred_hash160(
+        &hash160_blake256(&sec1_bytes(&public_key, pubkey_format)),
+        expected_address,
+    )
+}
 
-    if key_bytes.len() != 32 {
-        return Err(VerifyError::InvalidKey(format!(
-            "Key must be 32 bytes, got {}",
-            key_bytes.len()
-        )));
+fn verify_decred_hash160(hash: &[u8; 20], expected_address: &str) -> Result<String, VerifyError> {
+    let kind = AddressKind::detect(expected_address, Chain::Decred)?;
+    if !matches!(kind, AddressKind::P2pkh { .. }) {
+        return Err(commits_to_script(kind));
     }
 
-    let signing_key = SigningKey::from_bytes((&key_bytes[..]).into())
-        .map_err(|e| VerifyError::InvalidKey(format!("Invalid secp256k1 key: {}", e)))?;
+    compare(keytools::decred_p2pkh(hash), expected_address)
+}
 
-    let public_key = PublicKey::from(signing_key.verifying_key());
+/// Verify a key hash against a P2PKH address on a chain that kept Bitcoin's
+/// scheme under its own version bytes:
+/// - Dogecoin: 0x1e (`D...`)
+/// - Dash: 0x4c (`X...`)
+/// - Zcash transparent: 0x1cb8 (`t1...`)
+/// - Bitcoin Cash: CashAddr, or Bitcoin's 0x00 in the legacy format
+fn verify_fork_hash160(
+    chain: Chain,
+    hash: &[u8; 20],
+    expected_address: &str,
+) -> Result<String, VerifyError> {
+    let kind = AddressKind::detect(expected_address, chain)?;
+    if !matches!(kind, AddressKind::P2pkh { .. }) {
+        return Err(commits_to_script(kind));
+    }
 
-    let pubkey_bytes = match pubkey_format {
-        PubkeyFormat::Compressed => public_key.to_sec1_bytes().to_vec(),
-        PubkeyFormat::Uncompressed => public_key.to_encoded_point(false).as_bytes().to_vec(),
+    let derived = match chain {
+        Chain::Dogecoin => keytools::base58check(0x1e, hash),
+        Chain::Dash => keytools::base58check(0x4c, hash),
+        Chain::Zcash => keytools::base58check_prefixed(&[0x1c, 0xb8], hash),
+        Chain::BitcoinCash if expected_address.starts_with('1') => {
+            keytools::base58check(0x00, hash)
+        }
+        Chain::BitcoinCash => {
+            // Match the spelling of the expected address, prefix or not.
+            let cashaddr = keytools::cashaddr(hash, false);
+            if expected_address.contains(':') {
+                cashaddr
+            } else {
+                cashaddr[cashaddr.find(':').map_or(0, |i| i + 1)..].to_string()
+            }
+        }
+        chain => {
+            return Err(VerifyError::UnsupportedChain(format!(
+                "{} verification not supported",
+                chain.name()
+            )))
+        }
     };
+    compare(derived, expected_address)
+}
 
-    let hash = hash160_blake256(&pubkey_bytes);
+/// Verify Monero private spend and view keys derive a standard address.
+///
+/// Monero address format:
+/// - Network byte (0x12 for mainnet)
+/// - Public spend key and public view key (ed25519, 32 bytes each)
+/// - First 4 bytes of Keccak256 over the above as checksum
+/// - Encoded with Monero's block-wise base58 (8-byte blocks)
+///
+/// Keys are 64-char hex little-endian scalars, as shown by Monero wallets.
+#[cfg(feature = "monero")]
+pub fn verify_monero_address(
+    spend_key: &str,
+    view_key: &str,
+    expected_address: &str,
+) -> Result<String, VerifyError> {
+    use tiny_keccak::{Hasher, Keccak};
 
-    let mut data = vec![0x07, 0x3f];
-    data.extend_from_slice(&hash);
+    let mut data = vec![0x12];
+    data.extend_from_slice(&monero_public_key(spend_key)?);
+    data.extend_from_slice(&monero_public_key(view_key)?);
 
-    let first_hash = Blake256::digest(&data);
-    let checksum_hash = Blake256::digest(&first_hash);
-    let checksum = &checksum_hash[..4];
-    data.extend_from_slice(checksum);
+    let mut keccak = Keccak::v256();
+    let mut hash = [0u8; 32];
+    keccak.update(&data);
+    keccak.finalize(&mut hash);
+    data.extend_from_slice(&hash[..4]);
 
-    let derived = bs58::encode(data).into_string();
+    let derived = monero_base58(&data);
 
     if derived == expected_address {
         Ok(derived)
@@ -437,56 +680,444 @@ pub fn verify_decred_address(
     }
 }
 
-/// Verify WIF (Wallet Import Format) private key.
+/// Derive the private view key wallets generate from a private spend key.
 ///
-/// WIF format:
-/// - Version byte (0x80 for Bitcoin mainnet)
-/// - 32-byte private key
-/// - Optional compression flag (0x01)
-/// - 4-byte checksum (double SHA256)
+/// Standard (mnemonic) wallets use `Keccak256(spend_key) mod l`.
+#[cfg(feature = "monero")]
+pub fn monero_view_key_from_spend(spend_key: &str) -> Result<String, VerifyError> {
+    use curve25519_dalek::Scalar;
+    use tiny_keccak::{Hasher, Keccak};
+
+    let spend = monero_scalar(spend_key)?;
+    let mut keccak = Keccak::v256();
+    let mut hash = [0u8; 32];
+    keccak.update(spend.as_bytes());
+    keccak.finalize(&mut hash);
+
+    Ok(hex::encode(Scalar::from_bytes_mod_order(hash).as_bytes()))
+}
+
+#[cfg(feature = "monero")]
+fn monero_scalar(hex_key: &str) -> Result<curve25519_dalek::Scalar, VerifyError> {
+    use curve25519_dalek::Scalar;
+
+    let key_bytes: [u8; 32] = hex::decode(hex_key)
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid hex: {}", e)))?
+        .try_into()
+        .map_err(|b: Vec<u8>| {
+            VerifyError::InvalidKey(format!("Key must be 32 bytes, got {}", b.len()))
+        })?;
+
+    Option::from(Scalar::from_canonical_bytes(key_bytes))
+        .ok_or_else(|| VerifyError::InvalidKey("Key is not a reduced ed25519 scalar".to_string()))
+}
+
+#[cfg(feature = "monero")]
+fn monero_public_key(hex_key: &str) -> Result<[u8; 32], VerifyError> {
+    use curve25519_dalek::EdwardsPoint;
+
+    let scalar = monero_scalar(hex_key)?;
+    Ok(EdwardsPoint::mul_base(&scalar).compress().to_bytes())
+}
+
+/// Monero base58: each full 8-byte block becomes 11 characters, a shorter
+/// final block uses the minimal fixed width for its length.
+#[cfg(feature = "monero")]
+fn monero_base58(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    const ENCODED_BLOCK_SIZES: [usize; 9] = [0, 2, 3, 5, 6, 7, 9, 10, 11];
+
+    let mut out = String::new();
+    for block in data.chunks(8) {
+        let mut num = block.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+        let width = ENCODED_BLOCK_SIZES[block.len()];
+        let mut chars = vec![ALPHABET[0]; width];
+        for c in chars.iter_mut().rev() {
+            *c = ALPHABET[(num % 58) as usize];
+            num /= 58;
+        }
+        out.extend(chars.into_iter().map(char::from));
+    }
+    out
+}
+
+/// Verify a Bitcoin mainnet WIF (Wallet Import Format) private key, decoded
+/// by [`keytools::from_wif`].
 ///
 /// Supports:
 /// - Compressed WIF (K/L prefix, 52 chars)
 /// - Uncompressed WIF (5 prefix, 51 chars)
 pub fn verify_wif(wif: &str, expected_address: &str) -> Result<(String, String), VerifyError> {
-    let decoded = bs58::decode(wif)
-        .into_vec()
-        .map_err(|e| VerifyError::InvalidKey(format!("Invalid base58: {}", e)))?;
-
-    if decoded.len() != 37 && decoded.len() != 38 {
+    let decoded = keytools::from_wif(wif)?;
+    if decoded.network != Network::Mainnet {
         return Err(VerifyError::InvalidKey(format!(
-            "Invalid WIF length: {} bytes (expected 37 or 38)",
-            decoded.len()
+            "Invalid network byte: 0x{:02x} (expected 0x{:02x} for Bitcoin)",
+            keytools::wif_version(decoded.network),
+            keytools::wif_version(Network::Mainnet)
         )));
     }
+    let hex_key = hex::encode(decoded.key.to_bytes());
+    let pubkey_format = decoded.pubkey_format;
 
-    if decoded[0] != 0x80 {
-        return Err(VerifyError::InvalidKey(format!(
-            "Invalid network byte: 0x{:02x} (expected 0x80 for Bitcoin)",
-            decoded[0]
-        )));
+    let derived = verify_bitcoin_address(&hex_key, expected_address, pubkey_format)?;
+    Ok((derived, hex_key))
+}
+
+/// Verify `signature` over `message` was made by the key behind `address`.
+///
+/// Bitcoin and Litecoin take a base64 BIP137 signature, as produced by
+/// `signmessage` and Electrum; the header byte selects the address type.
+/// Ethereum takes a hex `personal_sign` (EIP-191) signature.
+/// Returns the address the signature recovers to.
+pub fn verify_message(
+    chain: Chain,
+    address: &str,
+    message: &str,
+    signature: &str,
+) -> Result<String, VerifyError> {
+    match chain {
+        Chain::Bitcoin | Chain::Litecoin => {
+            use base64::Engine;
+
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(signature.trim())
+                .map_err(|e| VerifyError::InvalidSignature(format!("Invalid base64: {}", e)))?;
+            if bytes.len() != 65 || !(27..=42).contains(&bytes[0]) {
+                return Err(VerifyError::InvalidSignature(
+                    "Expected 65 bytes with a BIP137 header (27-42)".to_string(),
+                ));
+            }
+            let header = bytes[0] - 27;
+            let prefix = if chain == Chain::Bitcoin {
+                "Bitcoin Signed Message:\n"
+            } else {
+                "Litecoin Signed Message:\n"
+            };
+            let prehash = signed_message_hash(prefix, message);
+            let public_key = recover_public_key(&prehash, &bytes[1..], header % 4)?;
+            let pubkey_format = if header < 4 {
+                PubkeyFormat::Uncompressed
+            } else {
+                PubkeyFormat::Compressed
+            };
+            let key_hash = hash160(&sec1_bytes(&public_key, pubkey_format));
+
+            if chain == Chain::Litecoin {
+                return verify_litecoin_hash160(&key_hash, address);
+            }
+            // 35-38: P2WPKH nested in P2SH, the redeem script is `0 <hash160>`.
+            if (8..12).contains(&header) {
+                let mut script = vec![0x00, 0x14];
+                script.extend_from_slice(&key_hash);
+                return verify_p2sh(&hash160(&script), address);
+            }
+            verify_bitcoin_key(&public_key, pubkey_format, address)
+        }
+        Chain::Ethereum => {
+            use tiny_keccak::{Hasher, Keccak};
+
+            let hex_sig = signature.trim();
+            let bytes = hex::decode(hex_sig.strip_prefix("0x").unwrap_or(hex_sig))
+                .map_err(|e| VerifyError::InvalidSignature(format!("Invalid hex: {}", e)))?;
+            if bytes.len() != 65 {
+                return Err(VerifyError::InvalidSignature(format!(
+                    "Expected 65 bytes, got {}",
+                    bytes.len()
+                )));
+            }
+            let v = bytes[64];
+            let recovery = match v {
+                0 | 1 => v,
+                27 | 28 => v - 27,
+                _ => {
+                    return Err(VerifyError::InvalidSignature(format!(
+                        "Invalid recovery byte: {}",
+                        v
+                    )))
+                }
+            };
+
+            let mut keccak = Keccak::v256();
+            let mut prehash = [0u8; 32];
+            keccak.update(format!("\x19Ethereum Signed Message:\n{}", message.len()).as_bytes());
+            keccak.update(message.as_bytes());
+            keccak.finalize(&mut prehash);
+
+            let public_key = recover_public_key(&prehash, &bytes[..64], recovery)?;
+            verify_ethereum_pubkey(&public_key, address)
+        }
+        _ => Err(VerifyError::UnsupportedChain(format!(
+            "Message signatures are not supported on {}",
+            chain.name()
+        ))),
     }
+}
 
-    let checksum_start = decoded.len() - 4;
-    let payload = &decoded[..checksum_start];
-    let checksum = &decoded[checksum_start..];
+/// Double SHA-256 of the length-prefixed `prefix` and `message`.
+fn signed_message_hash(prefix: &str, message: &str) -> [u8; 32] {
+    let mut data = Vec::with_capacity(prefix.len() + message.len() + 10);
+    push_compact_size(&mut data, prefix.len());
+    data.extend_from_slice(prefix.as_bytes());
+    push_compact_size(&mut data, message.len());
+    data.extend_from_slice(message.as_bytes());
+    sha256(&sha256(&data))
+}
 
-    let hash = sha256(&sha256(payload));
-    if &hash[..4] != checksum {
-        return Err(VerifyError::InvalidKey(
-            "WIF checksum verification failed".to_string(),
+fn push_compact_size(data: &mut Vec<u8>, len: usize) {
+    match len {
+        0..=0xfc => data.push(len as u8),
+        0xfd..=0xffff => {
+            data.push(0xfd);
+            data.extend_from_slice(&(len as u16).to_le_bytes());
+        }
+        _ => {
+            data.push(0xfe);
+            data.extend_from_slice(&(len as u32).to_le_bytes());
+        }
+    }
+}
+
+/// Public key recovered from a 64-byte `r || s` signature over `prehash`.
+/// High-S signatures are accepted by flipping the recovery id.
+fn recover_public_key(
+    prehash: &[u8; 32],
+    rs: &[u8],
+    recovery: u8,
+) -> Result<PublicKey, VerifyError> {
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+    let mut signature = Signature::from_slice(rs)
+        .map_err(|e| VerifyError::InvalidSignature(format!("Invalid r/s: {}", e)))?;
+    let mut recovery = recovery;
+    if let Some(normalized) = signature.normalize_s() {
+        signature = normalized;
+        recovery ^= 1;
+    }
+    let recovery_id = RecoveryId::from_byte(recovery)
+        .ok_or_else(|| VerifyError::InvalidSignature("Invalid recovery id".to_string()))?;
+    let key = VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id)
+        .map_err(|e| VerifyError::InvalidSignature(format!("Key recovery failed: {}", e)))?;
+    Ok(PublicKey::from(&key))
+}
+
+/// Largest stack element consensus allows a script to push.
+const MAX_SCRIPT_ELEMENT_SIZE: usize = 520;
+
+/// Check a claim on a hash-collision bounty.
+///
+/// The preimages must differ and make the puzzle's redeem script succeed
+/// when pushed by a spending `scriptSig` (`preimage_a` first); for the
+/// Peter Todd bounties that means hashing to the same digest under the
+/// script's hash opcode. Like on-chain, pushes over 520 bytes are refused.
+/// Returns the hex digest (for `OP_ABS`, the value) the preimages share.
+pub fn verify_collision(
+    puzzle: &Puzzle,
+    preimage_a: &[u8],
+    preimage_b: &[u8],
+) -> Result<String, VerifyError> {
+    let redeem_script = puzzle.address.redeem_script.ok_or_else(|| {
+        VerifyError::UnverifiableKey(format!("{} has no redeem script", puzzle.id))
+    })?;
+    let script = hex::decode(redeem_script.script)
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid redeem script hex: {}", e)))?;
+    let instructions =
+        crate::script::parse(&script).map_err(|e| VerifyError::InvalidKey(e.to_string()))?;
+
+    if preimage_a == preimage_b {
+        return Err(VerifyError::InvalidCollision(
+            "the preimages are identical".to_string(),
         ));
     }
+    if let Some(long) = [preimage_a, preimage_b]
+        .iter()
+        .find(|p| p.len() > MAX_SCRIPT_ELEMENT_SIZE)
+    {
+        return Err(VerifyError::InvalidCollision(format!(
+            "a {}-byte preimage exceeds the {}-byte push limit",
+            long.len(),
+            MAX_SCRIPT_ELEMENT_SIZE
+        )));
+    }
+
+    let mut stack = vec![preimage_a.to_vec(), preimage_b.to_vec()];
+    let mut digests = Vec::new();
+    for instruction in &instructions {
+        execute(instruction, &mut stack, &mut digests)?;
+    }
+
+    if stack.last().is_some_and(|top| script_bool(top)) {
+        return Ok(digests.last().map(hex::encode).unwrap_or_default());
+    }
+    match digests.as_slice() {
+        [.., a, b] if a != b => Err(VerifyError::Mismatch {
+            expected: hex::encode(a),
+            derived: hex::encode(b),
+        }),
+        _ => Err(VerifyError::InvalidCollision(
+            "the redeem script evaluates to false".to_string(),
+        )),
+    }
+}
+
+/// Run one instruction of a collision script. Only the stack, comparison,
+/// numeric and hash opcodes those scripts use are supported. Every hash or
+/// `OP_ABS` result is also appended to `digests`.
+fn execute(
+    instruction: &crate::script::Instruction<'_>,
+    stack: &mut Vec<Vec<u8>>,
+    digests: &mut Vec<Vec<u8>>,
+) -> Result<(), VerifyError> {
+    use ripemd::{Digest, Ripemd160};
+
+    let underflow = || VerifyError::InvalidCollision("stack underflow".to_string());
+    let verify_failed = || VerifyError::InvalidCollision(format!("{} failed", instruction));
+
+    if let Some(data) = instruction.data {
+        stack.push(data.to_vec());
+        return Ok(());
+    }
+    match instruction.opcode {
+        0x00 => stack.push(Vec::new()),
+        0x4f => stack.push(script_num(-1)),
+        op @ 0x51..=0x60 => stack.push(script_num(i64::from(op - 0x50))),
+        // OP_VERIFY
+        0x69 => {
+            if !script_bool(&stack.pop().ok_or_else(underflow)?) {
+                return Err(verify_failed());
+            }
+        }
+        // OP_2DUP
+        0x6e => {
+            let top = stack.len().checked_sub(2).ok_or_else(underflow)?;
+            stack.extend_from_within(top..);
+        }
+        // OP_DROP
+        0x75 => {
+            stack.pop().ok_or_else(underflow)?;
+        }
+        // OP_DUP
+        0x76 => stack.push(stack.last().ok_or_else(underflow)?.clone()),
+        // OP_SWAP
+        0x7c => {
+            let top = stack.len().checked_sub(2).ok_or_else(underflow)?;
+            stack.swap(top, top + 1);
+        }
+        // OP_EQUAL, OP_EQUALVERIFY
+        0x87 | 0x88 => {
+            let b = stack.pop().ok_or_else(underflow)?;
+            let a = stack.pop().ok_or_else(underflow)?;
+            if instruction.opcode == 0x88 {
+                if a != b {
+                    return Err(verify_failed());
+                }
+            } else {
+                stack.push(script_num(i64::from(a == b)));
+            }
+        }
+        // OP_ABS
+        0x90 => {
+            let n = parse_script_num(&stack.pop().ok_or_else(underflow)?)?;
+            let abs = script_num(n.abs());
+            digests.push(abs.clone());
+            stack.push(abs);
+        }
+        // OP_NOT
+        0x91 => {
+            let n = parse_script_num(&stack.pop().ok_or_else(underflow)?)?;
+            stack.push(script_num(i64::from(n == 0)));
+        }
+        op @ 0xa6..=0xaa => {
+            let data = stack.pop().ok_or_else(underflow)?;
+            let digest = match op {
+                0xa6 => Ripemd160::digest(&data).to_vec(),
+                0xa7 => sha1::Sha1::digest(&data).to_vec(),
+                0xa8 => sha256(&data).to_vec(),
+                0xa9 => hash160(&data).to_vec(),
+                _ => sha256(&sha256(&data)).to_vec(),
+            };
+            digests.push(digest.clone());
+            stack.push(digest);
+        }
+        _ => {
+            return Err(VerifyError::UnverifiableKey(format!(
+                "{} is not supported by the collision checker",
+                instruction
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Script truthiness: any non-zero byte, except a lone sign bit
+/// (negative zero).
+fn script_bool(value: &[u8]) -> bool {
+    match value.split_last() {
+        Some((&last, rest)) => rest.iter().any(|&b| b != 0) || (last & 0x7f) != 0,
+        None => false,
+    }
+}
+
+/// Decode a script number: little-endian sign-magnitude, at most 4 bytes.
+fn parse_script_num(value: &[u8]) -> Result<i64, VerifyError> {
+    if value.len() > 4 {
+        return Err(VerifyError::InvalidCollision(format!(
+            "{}-byte numeric operand, at most 4 allowed",
+            value.len()
+        )));
+    }
+    let Some((&last, rest)) = value.split_last() else {
+        return Ok(0);
+    };
+    let magnitude = rest
+        .iter()
+        .rev()
+        .fold(i64::from(last & 0x7f), |acc, &b| acc << 8 | i64::from(b));
+    Ok(if last & 0x80 == 0 {
+        magnitude
+    } else {
+        -magnitude
+    })
+}
+
+/// Minimal script number encoding.
+fn script_num(n: i64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut abs = n.unsigned_abs();
+    while abs > 0 {
+        bytes.push((abs & 0xff) as u8);
+        abs >>= 8;
+    }
+    match bytes.last_mut() {
+        Some(last) if *last & 0x80 != 0 => bytes.push(if n < 0 { 0x80 } else { 0x00 }),
+        Some(last) if n < 0 => *last |= 0x80,
+        _ => {}
+    }
+    bytes
+}
 
-    let compressed = decoded.len() == 38;
-    if compressed && decoded[33] != 0x01 {
+/// Verify a BIP38-encrypted key decrypts to a key deriving the address.
+///
+/// Runs the BIP38 scrypt/AES decryption with `passphrase`, then derives a
+/// P2PKH address using the compression flag stored in the encrypted key.
+/// Returns the derived address and the decrypted private key hex.
+pub fn verify_bip38(
+    encrypted: &str,
+    passphrase: &str,
+    expected_address: &str,
+) -> Result<(String, String), VerifyError> {
+    use bip38::Decrypt;
+
+    if !encrypted.starts_with("6P") {
         return Err(VerifyError::InvalidKey(format!(
-            "Invalid compression flag: 0x{:02x} (expected 0x01)",
-            decoded[33]
+            "BIP38 key must start with 6P: {}",
+            encrypted
         )));
     }
 
-    let key_bytes = &decoded[1..33];
+    let (key_bytes, compressed) = encrypted
+        .decrypt(passphrase)
+        .map_err(|e| VerifyError::InvalidKey(format!("BIP38 decryption failed: {:?}", e)))?;
+
     let hex_key = hex::encode(key_bytes);
 
     let pubkey_format = if compressed {
@@ -499,6 +1130,118 @@ pub fn verify_wif(wif: &str, expected_address: &str) -> Result<(String, String),
     Ok((derived, hex_key))
 }
 
+/// Verify a classic brainwallet: `SHA256(passphrase)` as the private key.
+///
+/// Early brainwallet tools used uncompressed keys, later ones compressed, so
+/// both are tried. Returns the derived address and the private key hex.
+pub fn verify_brainwallet(
+    passphrase: &str,
+    expected_address: &str,
+) -> Result<(String, String), VerifyError> {
+    let hex_key = hex::encode(sha256(passphrase.as_bytes()));
+
+    match verify_bitcoin_address(&hex_key, expected_address, PubkeyFormat::Uncompressed) {
+        Err(VerifyError::Mismatch { .. }) => {
+            verify_bitcoin_address(&hex_key, expected_address, PubkeyFormat::Compressed)
+        }
+        result => result,
+    }
+    .map(|derived| (derived, hex_key))
+}
+
+/// Reconstruct a BIP39 mnemonic from Shamir shares.
+///
+/// Each share is itself a BIP39 mnemonic whose entropy is one point of a
+/// GF(256) polynomial per byte, with the share index as x (the bitaps/pybtc
+/// scheme). Interpolating at x = 0 with `threshold` shares yields the secret
+/// entropy, returned re-encoded as a mnemonic.
+pub fn combine_shares(shares: &[Share], threshold: u8) -> Result<String, VerifyError> {
+    use bip39::Mnemonic;
+
+    if threshold == 0 || shares.len() < usize::from(threshold) {
+        return Err(VerifyError::UnverifiableKey(format!(
+            "Only {} of {} required shares known",
+            shares.len(),
+            threshold
+        )));
+    }
+
+    let points = shares[..usize::from(threshold)]
+        .iter()
+        .map(|share| {
+            if share.index == 0 {
+                return Err(VerifyError::InvalidKey(
+                    "Share index must be non-zero".to_string(),
+                ));
+            }
+            let entropy = Mnemonic::parse_normalized(share.data)
+                .map_err(|e| {
+                    VerifyError::InvalidKey(format!("Invalid share {}: {}", share.index, e))
+                })?
+                .to_entropy();
+            Ok((share.index, entropy))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let len = points[0].1.len();
+    if points.iter().any(|(_, e)| e.len() != len) {
+        return Err(VerifyError::InvalidKey(
+            "Shares have different lengths".to_string(),
+        ));
+    }
+
+    let mut secret = vec![0u8; len];
+    for (j, (xj, yj)) in points.iter().enumerate() {
+        // Lagrange basis polynomial for point j, evaluated at x = 0.
+        let mut basis = 1u8;
+        for (m, (xm, _)) in points.iter().enumerate() {
+            if m == j {
+                continue;
+            }
+            if xm == xj {
+                return Err(VerifyError::InvalidKey(format!(
+                    "Duplicate share index {}",
+                    xj
+                )));
+            }
+            basis = gf256_mul(basis, gf256_div(*xm, xj ^ xm));
+        }
+        for (out, y) in secret.iter_mut().zip(yj) {
+            *out ^= gf256_mul(*y, basis);
+        }
+    }
+
+    Mnemonic::from_entropy(&secret)
+        .map(|m| m.to_string())
+        .map_err(|e| VerifyError::InvalidKey(format!("Invalid reconstructed entropy: {}", e)))
+}
+
+/// Multiply in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1 (the AES field).
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Divide in GF(2^8); `b` must be non-zero. Inverse via b^254.
+fn gf256_div(a: u8, b: u8) -> u8 {
+    let mut inverse = 1u8;
+    for _ in 0..254 {
+        inverse = gf256_mul(inverse, b);
+    }
+    gf256_mul(a, inverse)
+}
+
 /// Verify seed phrase derivation and address.
 ///
 /// BIP39 + BIP32 workflow:
@@ -513,24 +1256,204 @@ pub fn verify_seed(
     pubkey_format: PubkeyFormat,
     passphrase: &str,
 ) -> Result<(String, String), VerifyError> {
-    use bip32::{DerivationPath, XPrv};
-    use bip39::Mnemonic;
-    use std::str::FromStr;
+    let key = keytools::key_from_mnemonic(phrase, path, passphrase)?;
+    let hex_key = hex::encode(key.to_bytes());
 
-    let mnemonic = Mnemonic::parse_normalized(phrase)
-        .map_err(|e| VerifyError::InvalidKey(format!("Invalid mnemonic: {}", e)))?;
+    let derived = verify_bitcoin_address(&hex_key, expected_address, pubkey_format)?;
+    Ok((derived, hex_key))
+}
 
-    let seed = mnemonic.to_seed(passphrase);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use k256::ecdsa::SigningKey;
+
+    /// BIP137 signature by private key 1 (b1000/1), header for compressed P2PKH.
+    fn sign(message: &str, header: u8) -> String {
+        let key = SigningKey::from_slice(&[[0u8; 31].as_slice(), &[1]].concat()).unwrap();
+        let prehash = signed_message_hash("Bitcoin Signed Message:\n", message);
+        let (signature, recovery) = key.sign_prehash_recoverable(&prehash).unwrap();
+        let mut bytes = vec![header + recovery.to_byte()];
+        bytes.extend_from_slice(&signature.to_bytes());
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
 
-    let derivation_path = DerivationPath::from_str(path)
-        .map_err(|e| VerifyError::InvalidKey(format!("Invalid derivation path: {}", e)))?;
+    #[test]
+    fn testnet_wif_names_its_version_byte() {
+        let key = SigningKey::from_slice(&[[0u8; 31].as_slice(), &[1]].concat()).unwrap();
+        let wif = keytools::to_wif(&key, PubkeyFormat::Compressed, Network::Testnet);
+        let err = verify_wif(&wif, "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid private key format: Invalid network byte: 0xef (expected 0x80 for Bitcoin)"
+        );
+    }
 
-    let xprv = XPrv::derive_from_path(seed, &derivation_path)
-        .map_err(|e| VerifyError::DerivationFailed(format!("Key derivation failed: {}", e)))?;
+    #[test]
+    fn fork_chains_verify_key_one() {
+        const KEY_ONE: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        for (address, chain) in [
+            ("DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE", Chain::Dogecoin),
+            ("XmN7PQYWKn5MJFna5fRYgP6mxT2F7xpekE", Chain::Dash),
+            ("t1UYsZVJkLPeMjxEtACvSxfWuNmddpWfxzs", Chain::Zcash),
+            (
+                "bitcoincash:qp63uahgrxged4z5jswyt5dn5v3lzsem6cy4spdc2h",
+                Chain::BitcoinCash,
+            ),
+            (
+                "qp63uahgrxged4z5jswyt5dn5v3lzsem6cy4spdc2h",
+                Chain::BitcoinCash,
+            ),
+            ("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH", Chain::BitcoinCash),
+        ] {
+            assert_eq!(
+                verify_pubkey(KEY_ONE, PubkeyFormat::Compressed, address, chain).unwrap(),
+                address
+            );
+            assert_eq!(
+                verify_hex_by_chain(
+                    &format!("{:064x}", 1),
+                    address,
+                    chain,
+                    PubkeyFormat::Compressed
+                )
+                .unwrap(),
+                address
+            );
+        }
+        assert!(matches!(
+            verify_hex_by_chain(
+                &format!("{:064x}", 2),
+                "DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE",
+                Chain::Dogecoin,
+                PubkeyFormat::Compressed
+            ),
+            Err(VerifyError::Mismatch { .. })
+        ));
+    }
 
-    let private_key_bytes = xprv.private_key().to_bytes();
-    let hex_key = hex::encode(private_key_bytes);
+    #[test]
+    fn bip137_signature_recovers_the_address() {
+        let signature = sign("boha", 31);
+        assert_eq!(
+            verify_message(
+                Chain::Bitcoin,
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+                "boha",
+                &signature
+            ),
+            Ok("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH".to_string())
+        );
+        assert!(matches!(
+            verify_message(
+                Chain::Bitcoin,
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+                "other",
+                &signature
+            ),
+            Err(VerifyError::Mismatch { .. })
+        ));
+        // Same key, uncompressed header: a different address.
+        assert!(verify_message(
+            Chain::Bitcoin,
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            "boha",
+            &sign("boha", 27)
+        )
+        .is_err());
+    }
 
-    let derived = verify_bitcoin_address(&hex_key, expected_address, pubkey_format)?;
-    Ok((derived, hex_key))
+    #[test]
+    fn nested_segwit_header_checks_p2sh() {
+        assert_eq!(
+            verify_message(
+                Chain::Bitcoin,
+                "3JvL6Ymt8MVWiCNHC7oWU6nLeHNJKLZGLN",
+                "boha",
+                &sign("boha", 35),
+            ),
+            Ok("3JvL6Ymt8MVWiCNHC7oWU6nLeHNJKLZGLN".to_string())
+        );
+    }
+
+    #[test]
+    fn personal_sign_recovers_the_address() {
+        use tiny_keccak::{Hasher, Keccak};
+
+        let key = SigningKey::from_slice(&[[0u8; 31].as_slice(), &[1]].concat()).unwrap();
+        let mut keccak = Keccak::v256();
+        let mut prehash = [0u8; 32];
+        keccak.update(b"\x19Ethereum Signed Message:\n4boha");
+        keccak.finalize(&mut prehash);
+        let (signature, recovery) = key.sign_prehash_recoverable(&prehash).unwrap();
+        let signature = format!(
+            "0x{}{:02x}",
+            hex::encode(signature.to_bytes()),
+            27 + recovery.to_byte()
+        );
+
+        assert_eq!(
+            verify_message(
+                Chain::Ethereum,
+                "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+                "boha",
+                &signature
+            ),
+            Ok("0x7e5f4552091a69125d5dfcb7b8c2659029395bdf".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed_signatures_are_rejected() {
+        assert!(matches!(
+            verify_message(
+                Chain::Bitcoin,
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+                "x",
+                "!!"
+            ),
+            Err(VerifyError::InvalidSignature(_))
+        ));
+        assert!(matches!(
+            verify_message(Chain::Monero, "4", "x", "00"),
+            Err(VerifyError::UnsupportedChain(_))
+        ));
+    }
+
+    #[test]
+    fn collision_checks_the_redeem_script() {
+        let op_abs = crate::get("hash_collision/op_abs").unwrap();
+        assert_eq!(verify_collision(op_abs, &[0x01], &[0x81]).unwrap(), "01");
+
+        let sha256 = crate::get("hash_collision/sha256").unwrap();
+        assert!(matches!(
+            verify_collision(sha256, b"a", b"b"),
+            Err(VerifyError::Mismatch { .. })
+        ));
+        assert!(matches!(
+            verify_collision(sha256, b"a", b"a"),
+            Err(VerifyError::InvalidCollision(_))
+        ));
+        assert!(matches!(
+            verify_collision(sha256, &[0; 521], b"a"),
+            Err(VerifyError::InvalidCollision(_))
+        ));
+
+        let b1000 = crate::get("b1000/1").unwrap();
+        assert!(matches!(
+            verify_collision(b1000, b"a", b"b"),
+            Err(VerifyError::UnverifiableKey(_))
+        ));
+    }
+
+    #[test]
+    fn script_numbers_are_sign_magnitude() {
+        assert_eq!(parse_script_num(&[0x81]).unwrap(), -1);
+        assert_eq!(parse_script_num(&[0xff, 0x80]).unwrap(), -255);
+        assert_eq!(script_num(-255), vec![0xff, 0x80]);
+        assert_eq!(script_num(128), vec![0x80, 0x00]);
+        assert!(!script_bool(&[0x00, 0x80]));
+        assert!(script_bool(&[0x00, 0x01]));
+    }
 }
@@ -0,0 +1,145 @@
+//! Registration happens before the first lookup, so everything runs in one
+//! test: the indexes are per process.
+
+use std::sync::OnceLock;
+
+use boha::plugin::{self, DynCollection};
+use boha::{Author, Collection, Error, Puzzle};
+
+struct Community;
+
+static AUTHOR: Author = Author {
+    name: Some("community"),
+    aliases: &[],
+    addresses: &[],
+    profiles: &[],
+};
+
+impl DynCollection for Community {
+    fn name(&self) -> &'static str {
+        "community"
+    }
+
+    fn author(&self) -> &'static Author {
+        &AUTHOR
+    }
+
+    fn puzzles(&self) -> &'static [Puzzle] {
+        static PUZZLES: OnceLock<Vec<Puzzle>> = OnceLock::new();
+        PUZZLES.get_or_init(|| {
+            let template = boha::b1000::get(1).unwrap();
+            vec![Puzzle {
+                id: "community/first",
+                uuid: "00000000-0000-5000-8000-000000000001",
+                ..template.clone()
+            }]
+        })
+    }
+}
+
+struct Misnamed;
+
+impl DynCollection for Misnamed {
+    fn name(&self) -> &'static str {
+        "misnamed"
+    }
+
+    fn author(&self) -> &'static Author {
+        &AUTHOR
+    }
+
+    fn puzzles(&self) -> &'static [Puzzle] {
+        Community.puzzles()
+    }
+}
+
+struct Shadow;
+
+impl DynCollection for Shadow {
+    fn name(&self) -> &'static str {
+        "b1000"
+    }
+
+    fn author(&self) -> &'static Author {
+        &AUTHOR
+    }
+
+    fn puzzles(&self) -> &'static [Puzzle] {
+        &[]
+    }
+}
+
+struct Alias;
+
+impl DynCollection for Alias {
+    fn name(&self) -> &'static str {
+        "peter_todd"
+    }
+
+    fn author(&self) -> &'static Author {
+        &AUTHOR
+    }
+
+    fn puzzles(&self) -> &'static [Puzzle] {
+        &[]
+    }
+}
+
+struct Late;
+
+impl DynCollection for Late {
+    fn name(&self) -> &'static str {
+        "late"
+    }
+
+    fn author(&self) -> &'static Author {
+        &AUTHOR
+    }
+
+    fn puzzles(&self) -> &'static [Puzzle] {
+        &[]
+    }
+}
+
+#[test]
+fn registered_collection_joins_every_lookup() {
+    let builtin = boha::all().count();
+
+    assert!(matches!(
+        plugin::register(&Misnamed),
+        Err(Error::Registration(_))
+    ));
+    assert!(matches!(
+        plugin::register(&Shadow),
+        Err(Error::Registration(_))
+    ));
+    assert!(matches!(
+        plugin::register(&Alias),
+        Err(Error::Registration(_))
+    ));
+    plugin::register(&Community).unwrap();
+    assert!(plugin::register(&Community).is_err(), "names are unique");
+
+    assert_eq!(boha::all().count(), builtin + 1);
+    assert_eq!(boha::stats().total, builtin + 1);
+    assert_eq!(boha::collections().len(), Collection::ALL.len() + 1);
+
+    let collection = Collection::parse("community").unwrap();
+    assert_eq!(collection.name(), "community");
+    assert_eq!(collection.get("first").unwrap().id, "community/first");
+
+    let puzzle = boha::get("community/first").unwrap();
+    assert_eq!(puzzle.author().unwrap().name, Some("community"));
+    assert_eq!(
+        boha::get_by_uuid("00000000-0000-5000-8000-000000000001")
+            .unwrap()
+            .id,
+        "community/first"
+    );
+    assert!(boha::author_stats()
+        .iter()
+        .any(|stats| stats.collections == ["community"]));
+
+    // The lookups above built the indexes; later collections would miss them.
+    assert!(plugin::register(&Late).is_err());
+}
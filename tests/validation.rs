@@ -1,9 +1,19 @@
+use boha::validate::{self, Rule};
 use boha::{
     b1000, bitaps, bitimage, gsmg, hash_collision, zden, Chain, Passphrase, PubkeyFormat, Status,
     TransactionType,
 };
 use num_bigint::BigUint;
 
+/// Every puzzle `rule` applies to passes it.
+fn assert_rule(rule: Rule) {
+    for puzzle in boha::all() {
+        if let Some(Err(e)) = rule.check(puzzle) {
+            panic!("Puzzle {} fails {}: {}", puzzle.id, rule.name(), e);
+        }
+    }
+}
+
 #[test]
 fn b1000_has_256_puzzles() {
     assert_eq!(b1000::all().count(), 256);
@@ -78,19 +88,49 @@ fn puzzle_key_range_valid() {
 #[test]
 fn puzzle_key_range_big_valid() {
     let p1 = b1000::get(1).unwrap();
-    let (start, end) = p1.key_range_big().unwrap();
-    assert_eq!(start, BigUint::from(1u32));
-    assert_eq!(end, BigUint::from(1u32));
+    let range = p1.key_range_big().unwrap();
+    assert_eq!(range.start(), &BigUint::from(1u32));
+    assert_eq!(range.end(), &BigUint::from(1u32));
 
     let p66 = b1000::get(66).unwrap();
-    let (start, end) = p66.key_range_big().unwrap();
-    assert_eq!(start, BigUint::from(1u128) << 65);
-    assert_eq!(end, (BigUint::from(1u128) << 66) - 1u32);
+    let range = p66.key_range_big().unwrap();
+    assert_eq!(range.start(), &(BigUint::from(1u128) << 65));
+    assert_eq!(range.end(), &((BigUint::from(1u128) << 66) - 1u32));
 
     let p256 = b1000::get(256).unwrap();
-    let (start, end) = p256.key_range_big().unwrap();
-    assert!(start > BigUint::ZERO);
-    assert!(end > start);
+    let range = p256.key_range_big().unwrap();
+    assert!(range.start() > &BigUint::ZERO);
+    assert!(range.end() > range.start());
+}
+
+#[test]
+fn b1000_split_range_covers_range_exactly() {
+    for (bits, parts) in [(1, 1), (8, 3), (66, 7), (71, 16), (256, 1000)] {
+        let ranges = b1000::split_range(bits, parts);
+        assert_eq!(ranges.len() as u64, parts);
+        let range = b1000::get(bits).unwrap().key_range_big().unwrap();
+        assert_eq!(ranges[0].start(), range.start());
+        assert_eq!(ranges.last().unwrap().end(), range.end());
+        for pair in ranges.windows(2) {
+            assert_eq!(
+                &(pair[0].end() + 1u32),
+                pair[1].start(),
+                "gap or overlap at {}",
+                bits
+            );
+        }
+        let sizes: Vec<BigUint> = ranges.iter().map(boha::KeyRange::size).collect();
+        let (min, max) = (sizes.iter().min().unwrap(), sizes.iter().max().unwrap());
+        assert!(max - min <= BigUint::from(1u32));
+    }
+}
+
+#[test]
+fn b1000_split_range_edge_cases() {
+    assert_eq!(b1000::split_range(3, 10).len(), 4);
+    assert!(b1000::split_range(0, 4).is_empty());
+    assert!(b1000::split_range(257, 4).is_empty());
+    assert!(b1000::split_range(71, 0).is_empty());
 }
 
 #[test]
@@ -112,14 +152,13 @@ fn solved_puzzles_private_key_in_range() {
         let pk_bytes = hex::decode(pk_hex).unwrap();
         let key = BigUint::from_bytes_be(&pk_bytes);
 
-        let (start, end) = puzzle.key_range_big().unwrap();
+        let range = puzzle.key_range_big().unwrap();
         assert!(
-            key >= start && key <= end,
-            "Puzzle {} private_key not in range: key={}, range=[{}, {}]",
+            range.contains_key(&key) && range.contains(pk_hex),
+            "Puzzle {} private_key not in range: key={}, range={}",
             puzzle.id,
             key,
-            start,
-            end
+            range
         );
     }
 }
@@ -140,6 +179,24 @@ fn b1000_all_addresses_start_with_1() {
     }
 }
 
+#[test]
+fn addresses_decode_on_their_chain() {
+    assert_rule(Rule::Address);
+}
+
+#[test]
+fn dataset_passes_every_rule() {
+    for report in validate::validate(boha::all()) {
+        assert!(report.checked > 0, "{} checked nothing", report.rule.name());
+        assert!(
+            report.passed(),
+            "{} failed: {:?}",
+            report.rule.name(),
+            report.failures
+        );
+    }
+}
+
 #[test]
 fn b1000_solved_have_private_keys() {
     for puzzle in b1000::solved() {
@@ -224,43 +281,31 @@ fn all_puzzles_have_start_date() {
 
 #[test]
 fn all_dates_have_time() {
-    let datetime_regex = regex::Regex::new(r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}$").unwrap();
     for puzzle in boha::all() {
-        if let Some(date) = puzzle.start_date {
-            assert!(
-                datetime_regex.is_match(date),
-                "Puzzle {} start_date must include time: {}",
-                puzzle.id,
-                date
-            );
-        }
-        if let Some(date) = puzzle.solve_date {
-            assert!(
-                datetime_regex.is_match(date),
-                "Puzzle {} solve_date must include time: {}",
-                puzzle.id,
-                date
-            );
+        for (field, date) in [
+            ("start_date", puzzle.start_date),
+            ("solve_date", puzzle.solve_date),
+        ] {
+            if let Some(date) = date {
+                assert_eq!(
+                    date.to_string().parse::<boha::DateTime>(),
+                    Ok(date),
+                    "Puzzle {} {} does not round-trip",
+                    puzzle.id,
+                    field
+                );
+            }
         }
     }
 }
 
 #[test]
 fn start_date_before_solve_date() {
-    for puzzle in boha::all() {
-        if let (Some(start), Some(solve)) = (puzzle.start_date, puzzle.solve_date) {
-            assert!(
-                start <= solve,
-                "Puzzle {} has start_date {} after solve_date {}",
-                puzzle.id,
-                start,
-                solve
-            );
-        }
-    }
+    assert_rule(Rule::Dates);
 }
 
 #[test]
+#[allow(clippy::items_after_statements)]
 fn solve_time_matches_dates() {
     fn parse_datetime(s: &str) -> Option<i64> {
         let parts: Vec<&str> = s.split(&['-', ' ', ':'][..]).collect();
@@ -309,8 +354,10 @@ fn solve_time_matches_dates() {
         if let (Some(start), Some(solve), Some(solve_time)) =
             (puzzle.start_date, puzzle.solve_date, puzzle.solve_time)
         {
-            if let (Some(start_ts), Some(solve_ts)) = (parse_datetime(start), parse_datetime(solve))
-            {
+            if let (Some(start_ts), Some(solve_ts)) = (
+                parse_datetime(&start.to_string()),
+                parse_datetime(&solve.to_string()),
+            ) {
                 let calculated = (solve_ts - start_ts) as u64;
                 let diff = calculated.abs_diff(solve_time);
                 assert!(
@@ -397,6 +444,43 @@ fn chain_name_correct() {
     }
 }
 
+#[test]
+fn puzzle_uuids_are_unique_v5() {
+    use std::collections::HashSet;
+
+    let mut seen = HashSet::new();
+    for puzzle in boha::all() {
+        let uuid = puzzle.uuid;
+        let groups: Vec<usize> = uuid.split('-').map(str::len).collect();
+        assert_eq!(groups, [8, 4, 4, 4, 12], "Malformed uuid for {}", puzzle.id);
+        assert!(
+            uuid.chars()
+                .all(|c| c == '-' || matches!(c, '0'..='9' | 'a'..='f')),
+            "uuid should be lowercase hex for {}: {}",
+            puzzle.id,
+            uuid
+        );
+        assert_eq!(&uuid[14..15], "5", "Not a v5 uuid for {}", puzzle.id);
+        assert!(seen.insert(uuid), "Duplicate uuid: {}", uuid);
+    }
+}
+
+#[test]
+fn superseded_by_points_at_live_entry() {
+    for puzzle in boha::all() {
+        if let Some(successor) = puzzle.superseded_by {
+            let next = boha::get(successor)
+                .unwrap_or_else(|_| panic!("{} superseded by unknown id {}", puzzle.id, successor));
+            assert_ne!(next.id, puzzle.id);
+            assert!(
+                !puzzle.withdrawn,
+                "{} is both superseded and withdrawn",
+                puzzle.id
+            );
+        }
+    }
+}
+
 #[test]
 fn gsmg_has_uncompressed_pubkey() {
     let puzzle = gsmg::get();
@@ -424,28 +508,7 @@ fn b1000_pubkeys_are_compressed() {
 
 #[test]
 fn pubkey_format_matches_key_length() {
-    for puzzle in boha::all() {
-        if let Some(pubkey) = &puzzle.pubkey {
-            match pubkey.format {
-                PubkeyFormat::Compressed => {
-                    assert_eq!(
-                        pubkey.value.len(),
-                        66,
-                        "Compressed pubkey should be 66 hex chars: {}",
-                        puzzle.id
-                    );
-                }
-                PubkeyFormat::Uncompressed => {
-                    assert_eq!(
-                        pubkey.value.len(),
-                        130,
-                        "Uncompressed pubkey should be 130 hex chars: {}",
-                        puzzle.id
-                    );
-                }
-            }
-        }
-    }
+    assert_rule(Rule::Pubkey);
 }
 
 #[test]
@@ -507,31 +570,10 @@ fn hash160_format_valid() {
     }
 }
 
-fn address_to_hash160(address: &str) -> Option<String> {
-    let decoded = bs58::decode(address).into_vec().ok()?;
-    if decoded.len() != 25 {
-        return None;
-    }
-    let hash160 = &decoded[1..21];
-    Some(hex::encode(hash160))
-}
-
+#[cfg(feature = "verify")]
 #[test]
 fn hash160_matches_address() {
-    for puzzle in boha::all() {
-        if matches!(puzzle.address.kind, "p2wpkh" | "p2wsh") {
-            continue;
-        }
-        if let Some(hash160) = puzzle.address.hash160 {
-            let computed = address_to_hash160(puzzle.address.value)
-                .unwrap_or_else(|| panic!("Failed to compute hash160 for {}", puzzle.id));
-            assert_eq!(
-                hash160, computed,
-                "hash160 mismatch for {}: stored {} != computed {}",
-                puzzle.id, hash160, computed
-            );
-        }
-    }
+    assert_rule(Rule::Hash160);
 }
 
 #[test]
@@ -603,6 +645,50 @@ fn redeem_script_hash_matches_script() {
     }
 }
 
+#[test]
+#[cfg(feature = "verify")]
+fn redeem_script_derives_address() {
+    for puzzle in boha::all() {
+        if let Some(rs) = &puzzle.address.redeem_script {
+            let result = boha::verify::verify_redeem_script(rs.script, puzzle.address.value);
+            assert!(
+                result.is_ok(),
+                "redeem_script doesn't derive address for {}: {:?}",
+                puzzle.id,
+                result
+            );
+        }
+    }
+}
+
+#[test]
+fn redeem_scripts_disassemble() {
+    for puzzle in boha::all() {
+        if let Some(rs) = &puzzle.address.redeem_script {
+            let asm =
+                boha::script::to_asm(rs.script).unwrap_or_else(|e| panic!("{}: {}", puzzle.id, e));
+            assert!(!asm.is_empty(), "{}", puzzle.id);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn address_kind_matches_detected() {
+    use boha::address::AddressKind;
+
+    for puzzle in boha::all().filter(|p| p.chain != boha::Chain::Monero) {
+        for address in puzzle.addresses() {
+            let kind = AddressKind::detect(address.value, puzzle.chain)
+                .unwrap_or_else(|e| panic!("{}: {}", puzzle.id, e));
+            assert_eq!(kind.name(), address.kind, "{}", puzzle.id);
+            if let (Some(hash), Some(expected)) = (kind.key_hash(), address.hash160) {
+                assert_eq!(hex::encode(hash), expected, "{}", puzzle.id);
+            }
+        }
+    }
+}
+
 #[test]
 fn pubkey_matches_hash160() {
     for puzzle in boha::all() {
@@ -712,8 +798,8 @@ fn solve_time_is_reasonable() {
 #[test]
 fn b1000_66_solve_time_correct() {
     let p66 = b1000::get(66).unwrap();
-    assert_eq!(p66.start_date, Some("2015-01-15 18:07:14"));
-    assert_eq!(p66.solve_date, Some("2024-09-12 22:59:39"));
+    assert_eq!(p66.start_date, "2015-01-15 18:07:14".parse().ok());
+    assert_eq!(p66.solve_date, "2024-09-12 22:59:39".parse().ok());
     assert_eq!(p66.solve_time, Some(304836745));
     let formatted = p66.solve_time_formatted().unwrap();
     assert!(
@@ -768,6 +854,7 @@ fn hash_collision_has_author() {
 }
 
 #[test]
+#[allow(clippy::manual_strip)]
 fn author_addresses_valid_format() {
     fn is_valid_address(addr: &str) -> bool {
         // Base58 (P2PKH: 1..., P2SH: 3...)
@@ -808,6 +895,20 @@ fn author_addresses_valid_format() {
     }
 }
 
+#[test]
+fn transaction_txids_are_normalized() {
+    for puzzle in boha::all() {
+        for txid in puzzle.transactions.iter().filter_map(|tx| tx.txid) {
+            assert!(
+                boha::txid::is_normalized(puzzle.chain, txid),
+                "Txid in {} is not normalized: {}",
+                puzzle.id,
+                txid
+            );
+        }
+    }
+}
+
 #[test]
 fn transaction_txid_format_valid() {
     for puzzle in boha::all() {
@@ -992,9 +1093,12 @@ fn start_date_matches_first_funding() {
         let funding_date = puzzle.funding_tx().and_then(|t| t.date);
         if let (Some(start_date), Some(funding_date)) = (puzzle.start_date, funding_date) {
             assert_eq!(
-                start_date, funding_date,
+                start_date.to_string(),
+                funding_date,
                 "Puzzle {} start_date ({}) does not match first funding transaction date ({})",
-                puzzle.id, start_date, funding_date
+                puzzle.id,
+                start_date,
+                funding_date
             );
         }
     }
@@ -1019,9 +1123,12 @@ fn solve_date_matches_claim_transaction() {
         if let (Some(solve_date), Some(tx)) = (puzzle.solve_date, terminal_tx) {
             if let Some(tx_date) = tx.date {
                 assert_eq!(
-                    solve_date, tx_date,
+                    solve_date.to_string(),
+                    tx_date,
                     "Puzzle {} solve_date ({}) does not match claim/sweep transaction date ({})",
-                    puzzle.id, solve_date, tx_date
+                    puzzle.id,
+                    solve_date,
+                    tx_date
                 );
             }
         }
@@ -1060,6 +1167,7 @@ fn solver_name_non_empty() {
 }
 
 #[test]
+#[allow(clippy::manual_strip)]
 fn solver_addresses_format_valid() {
     fn is_valid_btc_address(addr: &str) -> bool {
         if addr.starts_with('1') || addr.starts_with('3') {